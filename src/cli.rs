@@ -0,0 +1,184 @@
+// 命令行模式 - 无需启动 GUI 即可执行查询（用于脚本）
+use crate::controller::BalanceController;
+use crate::model::{Balance, Network};
+use serde::Serialize;
+use std::str::FromStr;
+use sui_sdk::types::base_types::SuiAddress;
+use tokio::runtime::Runtime;
+
+/// 命令行用法说明
+const USAGE: &str = "Usage: sui_rust_wallet --address <ADDRESS> [--network <devnet|testnet|mainnet>] --balance [--json]";
+
+/// 命令行命令
+#[derive(Debug, Clone, PartialEq)]
+pub enum CliCommand {
+    /// 查询地址的 SUI 余额
+    Balance { address: SuiAddress, network: Network, json: bool },
+    /// 显示用法说明
+    Help,
+}
+
+/// 解析命令行参数（不含程序名）
+/// 没有参数时返回 `Ok(None)`，表示以 GUI 模式启动
+pub fn parse_args<I>(args: I) -> Result<Option<CliCommand>, String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut args = args.into_iter().peekable();
+    if args.peek().is_none() {
+        return Ok(None);
+    }
+
+    let mut address = None;
+    let mut network = Network::Testnet;
+    let mut balance = false;
+    let mut json = false;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--address" => {
+                let value = args.next().ok_or("Missing value for --address")?;
+                let parsed = SuiAddress::from_str(value.trim())
+                    .map_err(|e| format!("Invalid address '{}': {}", value, e))?;
+                address = Some(parsed);
+            }
+            "--network" => {
+                let value = args.next().ok_or("Missing value for --network")?;
+                network = Network::from_str(&value)
+                    .ok_or_else(|| format!("Unknown network '{}'", value))?;
+            }
+            "--balance" => balance = true,
+            "--json" => json = true,
+            "--help" | "-h" => return Ok(Some(CliCommand::Help)),
+            other => return Err(format!("Unknown argument '{}'", other)),
+        }
+    }
+
+    if !balance {
+        return Err("No command given (expected --balance)".to_string());
+    }
+
+    let address = address.ok_or("--balance requires --address <ADDRESS>")?;
+    Ok(Some(CliCommand::Balance { address, network, json }))
+}
+
+/// 执行命令行命令，返回进程退出码
+pub fn run(command: CliCommand) -> i32 {
+    match command {
+        CliCommand::Help => {
+            println!("{}", USAGE);
+            0
+        }
+        CliCommand::Balance { address, network, json } => {
+            let result = Runtime::new()
+                .map_err(|e| format!("Failed to create Tokio runtime: {}", e))
+                .and_then(|rt| rt.block_on(BalanceController::fetch_sui_balance_mist(address, network.url())));
+
+            match result {
+                Ok(mist) => {
+                    let report = BalanceReport::new(address, network, mist);
+                    if json {
+                        println!("{}", report.to_json());
+                    } else {
+                        println!("{} SUI", report.sui);
+                    }
+                    0
+                }
+                Err(e) => {
+                    if json {
+                        println!("{}", serde_json::json!({ "error": e }));
+                    } else {
+                        eprintln!("Error: {}", e);
+                    }
+                    1
+                }
+            }
+        }
+    }
+}
+
+/// 余额查询结果（用于 JSON 输出）
+#[derive(Debug, Clone, Serialize)]
+pub struct BalanceReport {
+    pub address: String,
+    pub network: String,
+    /// 精确的 SUI 十进制字符串，避免浮点舍入
+    pub sui: String,
+    pub mist: u128,
+}
+
+impl BalanceReport {
+    pub fn new(address: SuiAddress, network: Network, mist: u128) -> Self {
+        Self {
+            address: address.to_string(),
+            network: network.name().to_lowercase(),
+            sui: Balance::from_total_balance(mist).to_sui_string(),
+            mist,
+        }
+    }
+
+    /// 序列化为单行 JSON
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_else(|e| format!("{{\"error\":\"{}\"}}", e))
+    }
+}
+
+/// 打印参数错误和用法说明
+pub fn print_usage_error(error: &str) {
+    eprintln!("Error: {}", error);
+    eprintln!("{}", USAGE);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADDRESS: &str = "0x0000000000000000000000000000000000000000000000000000000000000a11";
+
+    fn parse(args: &[&str]) -> Result<Option<CliCommand>, String> {
+        parse_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn parses_balance_query_arguments() {
+        let address = SuiAddress::from_str(ADDRESS).unwrap();
+
+        // 没有参数时启动 GUI
+        assert_eq!(parse(&[]), Ok(None));
+        assert_eq!(parse(&["--help"]), Ok(Some(CliCommand::Help)));
+
+        // 默认使用测试网，参数顺序不影响结果
+        assert_eq!(
+            parse(&["--address", ADDRESS, "--balance"]),
+            Ok(Some(CliCommand::Balance { address, network: Network::Testnet, json: false }))
+        );
+        assert_eq!(
+            parse(&["--balance", "--json", "--network", "mainnet", "--address", ADDRESS]),
+            Ok(Some(CliCommand::Balance { address, network: Network::Mainnet, json: true }))
+        );
+
+        assert!(parse(&["--address", ADDRESS]).is_err());
+        assert!(parse(&["--balance"]).is_err());
+        assert!(parse(&["--address"]).is_err());
+        assert!(parse(&["--address", "0x1234", "--balance"]).is_err());
+        assert!(parse(&["--address", ADDRESS, "--network", "moonnet", "--balance"]).is_err());
+        assert!(parse(&["--address", ADDRESS, "--balance", "--verbose"]).is_err());
+    }
+
+    #[test]
+    fn balance_report_serializes_to_the_documented_json_shape() {
+        let address = SuiAddress::from_str(ADDRESS).unwrap();
+        let report = BalanceReport::new(address, Network::Testnet, 1_500_000_000);
+
+        let value: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "address": ADDRESS,
+                "network": "testnet",
+                "sui": "1.5",
+                "mist": 1_500_000_000u64,
+            })
+        );
+    }
+}
@@ -1,15 +1,25 @@
 // 主控制器 - 协调各个子控制器
-use crate::model::Model;
+use crate::model::{AsyncOperation, Model, Network, PasswordProtectedAction, StatusMessage};
+use std::path::PathBuf;
+use std::time::Instant;
 
 // 导入子控制器
 pub mod auth_controller;
 pub mod wallet_controller;
 pub mod balance_controller;
+pub mod network_controller;
+pub mod history_controller;
+pub mod transfer_controller;
+pub mod price_controller;
 
 // 重新导出控制器以便外部使用
 pub use auth_controller::AuthController;
 pub use wallet_controller::WalletController;
 pub use balance_controller::BalanceController;
+pub use network_controller::NetworkController;
+pub use history_controller::HistoryController;
+pub use transfer_controller::TransferController;
+pub use price_controller::PriceController;
 
 /// 主控制器 - 提供统一的入口点来协调各个子控制器
 pub struct MainController;
@@ -22,6 +32,23 @@ impl MainController {
         AuthController::handle_logout(model);
     }
 
+    /// 不登录进入临时会话
+    pub fn handle_start_temporary_session(model: &mut Model) {
+        AuthController::handle_start_temporary_session(model);
+    }
+
+    /// 处理会话活动（过期登出、交互时延长）
+    pub fn handle_session_activity(model: &mut Model, interacted: bool) {
+        AuthController::handle_session_activity(model, interacted);
+    }
+
+    /// 处理窗口焦点变化（失去焦点时锁定、重新获得焦点时刷新余额）
+    pub fn handle_window_focus(model: &mut Model, focused: Option<bool>, minimized: Option<bool>) {
+        let was_focused = std::mem::replace(&mut model.app_state.ui_state.window_focused, focused);
+        AuthController::handle_window_focus(model, was_focused, focused, minimized);
+        BalanceController::handle_focus_refresh(model, was_focused, focused, Instant::now());
+    }
+
     /// 处理设置密码请求
     pub fn handle_set_password(model: &mut Model) -> Result<(), String> {
         AuthController::handle_set_password(model)
@@ -32,16 +59,187 @@ impl MainController {
         AuthController::handle_verify_password(model)
     }
 
+    /// 处理重置请求（清除密码和已保存的私钥）
+    pub fn handle_reset_wallet(model: &mut Model) -> Result<(), String> {
+        AuthController::handle_reset_wallet(model)
+    }
+
+    /// 确认后删除已保存的加密私钥
+    pub fn handle_confirm_delete_key(model: &mut Model) -> Result<(), String> {
+        AuthController::handle_confirm_delete_key(model)
+    }
+
+    /// 锁定倒计时中选择保持解锁
+    pub fn handle_stay_unlocked(model: &mut Model) {
+        AuthController::handle_stay_unlocked(model);
+    }
+
+    /// 复制配置目录路径
+    pub fn handle_copy_config_path(model: &mut Model) {
+        AuthController::handle_copy_config_path(model);
+    }
+
+    /// 在文件管理器中打开配置目录
+    pub fn handle_open_config_folder(model: &mut Model) {
+        AuthController::handle_open_config_folder(model);
+    }
+
+    /// 启用或关闭本地崩溃报告
+    pub fn handle_set_write_crash_reports(model: &mut Model, enabled: bool) {
+        AuthController::handle_set_write_crash_reports(model, enabled);
+    }
+
     // --- 钱包相关功能代理 ---
     
     /// 处理私钥导入逻辑（不保存）
     pub fn handle_import_key(model: &mut Model) {
         WalletController::handle_import_key(model);
     }
+
+    /// 临时导入私钥，只保存在内存中
+    pub fn handle_import_key_temporarily(model: &mut Model) {
+        WalletController::handle_import_key_temporarily(model);
+    }
     
-    /// 处理私钥导入并加密保存
-    pub fn handle_import_and_save_key(model: &mut Model, password: &str) {
-        WalletController::handle_import_and_save_key(model, password);
+    /// 确认替换当前已加载的钱包
+    pub fn handle_confirm_replace_wallet(model: &mut Model) {
+        WalletController::handle_confirm_replace_wallet(model);
+    }
+    
+    /// 处理账户重命名
+    pub fn handle_rename_account(model: &mut Model, label: String) {
+        WalletController::handle_rename_account(model, label);
+    }
+
+    /// 处理账户切换
+    pub fn handle_select_account(model: &mut Model, index: usize) {
+        WalletController::handle_select_account(model, index);
+    }
+
+    /// 切换"按网络区分账户"设置
+    pub fn handle_set_separate_accounts(model: &mut Model, enabled: bool) {
+        WalletController::handle_set_separate_accounts(model, enabled);
+    }
+
+    /// 处理复制私钥请求：先重新输入密码确认
+    pub fn handle_copy_private_key(model: &mut Model) {
+        if WalletController::is_wallet_loaded(model) {
+            AuthController::request_password_confirmation(model, PasswordProtectedAction::CopyPrivateKey);
+        }
+    }
+
+    /// 验证确认对话框中的密码，正确时执行待确认的敏感操作
+    pub fn handle_confirm_with_password(model: &mut Model) {
+        let Some(action) = AuthController::confirm_with_password(model) else {
+            return;
+        };
+        match action {
+            PasswordProtectedAction::CopyPrivateKey => WalletController::handle_copy_private_key(model),
+            PasswordProtectedAction::SendTransfer => TransferController::handle_password_confirmed_send(model),
+            PasswordProtectedAction::SendBatchTransfer => TransferController::handle_password_confirmed_batch_send(model),
+            PasswordProtectedAction::ResetWallet => AuthController::show_reset_confirmation(model),
+            PasswordProtectedAction::DeleteSavedKey => AuthController::show_delete_key_confirmation(model),
+            PasswordProtectedAction::ChangePassword => AuthController::handle_change_password(model),
+        }
+    }
+
+    /// 提交修改密码对话框中的新密码（先确认当前密码）
+    pub fn handle_request_change_password(model: &mut Model) {
+        AuthController::request_change_password(model);
+    }
+
+    /// 取消修改密码
+    pub fn handle_cancel_change_password(model: &mut Model) {
+        AuthController::cancel_change_password(model);
+    }
+
+    /// 请求重置钱包
+    pub fn handle_request_reset(model: &mut Model) {
+        AuthController::request_reset_confirmation(model);
+    }
+
+    /// 请求删除已保存的加密私钥
+    pub fn handle_request_delete_key(model: &mut Model) {
+        AuthController::request_delete_key_confirmation(model);
+    }
+
+    /// 取消密码确认
+    pub fn handle_cancel_password_confirmation(model: &mut Model) {
+        AuthController::cancel_password_confirmation(model);
+    }
+
+    /// 确认剪贴板警告后复制私钥
+    pub fn handle_confirm_copy_private_key(model: &mut Model, dont_warn_again: bool) {
+        WalletController::handle_confirm_copy_private_key(model, dont_warn_again);
+    }
+
+    /// 处理拖放到窗口上的密钥文件
+    pub fn handle_file_dropped(model: &mut Model, name: String, path: Option<PathBuf>, bytes: Option<Vec<u8>>) {
+        WalletController::handle_file_dropped(model, name, path, bytes);
+    }
+
+    /// 确认导入拖放文件中的私钥
+    pub fn handle_confirm_key_file_import(model: &mut Model) {
+        WalletController::handle_confirm_key_file_import(model);
+    }
+
+    /// 取消导入拖放的文件
+    pub fn handle_cancel_key_file_import(model: &mut Model) {
+        WalletController::handle_cancel_key_file_import(model);
+    }
+
+    /// 导出账户地址列表
+    pub fn handle_export_addresses(model: &mut Model) {
+        WalletController::handle_export_addresses(model);
+    }
+
+    /// 预览助记词派生的账户
+    pub fn handle_preview_mnemonic(model: &mut Model) {
+        WalletController::handle_preview_mnemonic(model);
+    }
+
+    /// 验证已加载地址的所有权
+    pub fn handle_verify_ownership(model: &mut Model) {
+        WalletController::handle_verify_ownership(model);
+    }
+
+    /// 导入助记词派生的指定账户
+    pub fn handle_import_mnemonic_account(model: &mut Model, account: u32) {
+        WalletController::handle_import_mnemonic_account(model, account);
+    }
+
+    // --- 转账历史相关功能代理 ---
+
+    /// 修改已记录转账的本地备注
+    pub fn handle_save_transfer_note(model: &mut Model, digest: &str, note: &str) {
+        HistoryController::handle_save_transfer_note(model, digest, note);
+    }
+
+    // --- 转账相关功能代理 ---
+
+    /// 处理发送转账的请求
+    pub fn handle_send_transfer(model: &mut Model) {
+        TransferController::handle_send_transfer(model);
+    }
+
+    /// 处理收款地址栏内容改变（拆分粘贴的支付 URI）
+    pub fn handle_recipient_changed(model: &mut Model) {
+        TransferController::handle_recipient_changed(model);
+    }
+
+    /// 处理发送批量转账的请求
+    pub fn handle_send_batch_transfer(model: &mut Model) {
+        TransferController::handle_send_batch_transfer(model);
+    }
+
+    /// 确认向自己的地址转账后继续发送
+    pub fn handle_confirm_self_send(model: &mut Model) {
+        TransferController::handle_confirm_self_send(model);
+    }
+
+    /// 确认可疑的大额转账后继续发送
+    pub fn handle_confirm_large_amount(model: &mut Model) {
+        TransferController::handle_confirm_large_amount(model);
     }
 
     // --- 余额相关功能代理 ---
@@ -51,35 +249,104 @@ impl MainController {
         BalanceController::handle_refresh_balance(model);
     }
 
+    /// 查询当前地址在所有网络上的余额
+    pub fn handle_check_other_networks(model: &mut Model) {
+        BalanceController::handle_check_other_networks(model);
+    }
+
+    /// 取消进行中的操作，并提示已取消
+    pub fn handle_cancel_operation(model: &mut Model, operation: AsyncOperation) {
+        if model.cancel_operation(operation) {
+            let message = model
+                .i18n
+                .tr("operation_cancelled")
+                .replace("{}", &model.i18n.tr(operation.i18n_key()));
+            model.set_status(StatusMessage::info(message));
+        }
+    }
+
     /// 处理从后台线程接收到的异步结果
     pub fn handle_async_results(model: &mut Model) {
         BalanceController::handle_async_results(model);
+        TransferController::handle_async_results(model);
+        NetworkController::handle_health_results(model);
+        PriceController::handle_price_results(model);
+        PriceController::handle_price_tick(model, Instant::now());
+        NetworkController::handle_endpoint_test_results(model);
+        NetworkController::handle_endpoint_probe_results(model);
+        NetworkController::handle_endpoint_probe_tick(model, Instant::now());
     }
 
-    // --- 应用程序级别的协调功能 ---
+    // --- 网络相关功能代理 ---
 
-    /// 处理应用程序初始化
-    pub fn initialize_app(model: &mut Model) {
-        // 如果是首次运行，可以进行一些初始化操作
-        if model.auth_state.is_first_run {
-            model.result_text = model.i18n.tr("welcome_first_run");
-        } else {
-            model.result_text = model.i18n.tr("import_private_key_message");
-        }
+    /// 处理手动刷新网络健康状态的请求
+    pub fn handle_refresh_network_health(model: &mut Model) {
+        NetworkController::handle_refresh_network_health(model);
     }
 
-    /// 处理语言切换
-    pub fn handle_language_change(model: &mut Model) {
-        // 更新结果文本以反映新语言
-        if WalletController::is_wallet_loaded(model) {
-            if let Some(address) = WalletController::get_wallet_address(model) {
-                model.result_text = format!("{}: {}", model.i18n.tr("wallet_imported_success"), address);
-            }
-        } else {
-            model.result_text = model.i18n.tr("import_private_key_message");
-        }
+    /// 启动时检查 RPC 端点
+    pub fn handle_startup_health_check(model: &mut Model) {
+        NetworkController::handle_startup_health_check(model);
+    }
+
+    /// 打开代币显示过滤设置
+    pub fn handle_open_coin_filter(model: &mut Model) {
+        BalanceController::handle_open_coin_filter(model);
+    }
+
+    /// 保存代币显示过滤设置
+    pub fn handle_save_coin_filter(model: &mut Model) {
+        BalanceController::handle_save_coin_filter(model);
+    }
+
+    /// 打开备用 RPC 端点设置
+    pub fn handle_open_rpc_fallbacks(model: &mut Model) {
+        NetworkController::handle_open_rpc_fallbacks(model);
+    }
+
+    /// 保存备用 RPC 端点设置
+    pub fn handle_save_rpc_fallbacks(model: &mut Model) {
+        NetworkController::handle_save_rpc_fallbacks(model);
+    }
+
+    /// 打开自定义网络编辑对话框
+    pub fn handle_open_custom_network(model: &mut Model) {
+        NetworkController::handle_open_custom_network(model);
+    }
+
+    /// 测试自定义端点连接
+    pub fn handle_test_custom_network(model: &mut Model, url: String) {
+        NetworkController::handle_test_custom_network(model, url);
+    }
+
+    /// 保存自定义网络并切换
+    pub fn handle_save_custom_network(model: &mut Model, name: String, url: String) {
+        NetworkController::handle_save_custom_network(model, name, url);
+    }
+
+    /// 切换网络
+    pub fn handle_switch_network(model: &mut Model, network: Network) {
+        NetworkController::handle_switch_network(model, network);
+        Self::handle_network_change(model);
+    }
+
+    /// 切换自动选择端点
+    pub fn handle_set_auto_select_endpoint(model: &mut Model, enabled: bool) {
+        NetworkController::handle_set_auto_select_endpoint(model, enabled);
+    }
+
+    /// 切换当前网络不可达时自动切换到首选网络
+    pub fn handle_set_auto_switch_network(model: &mut Model, enabled: bool) {
+        NetworkController::handle_set_auto_switch_network(model, enabled);
+    }
+
+    /// 设置自动切换时使用的首选网络
+    pub fn handle_set_preferred_network(model: &mut Model, network: Network) {
+        NetworkController::handle_set_preferred_network(model, network);
     }
 
+    // --- 应用程序级别的协调功能 ---
+
     /// 处理网络切换
     pub fn handle_network_change(model: &mut Model) {
         // 如果钱包已加载，切换网络后需要刷新余额
@@ -87,25 +354,6 @@ impl MainController {
             Self::handle_refresh_balance(model);
         }
     }
-
-    /// 获取应用程序状态摘要
-    pub fn get_app_status(model: &Model) -> AppStatus {
-        AppStatus {
-            is_authenticated: AuthController::is_authenticated(model),
-            wallet_loaded: WalletController::is_wallet_loaded(model),
-            is_loading: BalanceController::is_loading(model),
-            wallet_address: WalletController::get_wallet_address(model),
-        }
-    }
-}
-
-/// 应用程序状态摘要
-#[derive(Debug, Clone)]
-pub struct AppStatus {
-    pub is_authenticated: bool,
-    pub wallet_loaded: bool,
-    pub is_loading: bool,
-    pub wallet_address: Option<sui_sdk::types::base_types::SuiAddress>,
 }
 
 // --- 向后兼容性函数 ---
@@ -116,9 +364,104 @@ pub fn handle_import_key(model: &mut Model) {
     MainController::handle_import_key(model);
 }
 
-/// 处理私钥导入并保存（向后兼容）
-pub fn handle_import_and_save_key(model: &mut Model, password: &str) {
-    MainController::handle_import_and_save_key(model, password);
+/// 确认替换当前已加载的钱包（向后兼容）
+pub fn handle_confirm_replace_wallet(model: &mut Model) {
+    MainController::handle_confirm_replace_wallet(model);
+}
+
+/// 处理账户重命名（向后兼容）
+pub fn handle_rename_account(model: &mut Model, label: String) {
+    MainController::handle_rename_account(model, label);
+}
+
+/// 处理账户切换（向后兼容）
+pub fn handle_select_account(model: &mut Model, index: usize) {
+    MainController::handle_select_account(model, index);
+}
+
+/// 切换"按网络区分账户"设置（向后兼容）
+pub fn handle_set_separate_accounts(model: &mut Model, enabled: bool) {
+    MainController::handle_set_separate_accounts(model, enabled);
+}
+
+/// 处理复制私钥请求（向后兼容）
+pub fn handle_copy_private_key(model: &mut Model) {
+    MainController::handle_copy_private_key(model);
+}
+
+/// 确认剪贴板警告后复制私钥（向后兼容）
+pub fn handle_confirm_copy_private_key(model: &mut Model, dont_warn_again: bool) {
+    MainController::handle_confirm_copy_private_key(model, dont_warn_again);
+}
+
+/// 处理拖放到窗口上的密钥文件（向后兼容）
+pub fn handle_file_dropped(model: &mut Model, name: String, path: Option<PathBuf>, bytes: Option<Vec<u8>>) {
+    MainController::handle_file_dropped(model, name, path, bytes);
+}
+
+/// 确认导入拖放文件中的私钥（向后兼容）
+pub fn handle_confirm_key_file_import(model: &mut Model) {
+    MainController::handle_confirm_key_file_import(model);
+}
+
+/// 取消导入拖放的文件（向后兼容）
+pub fn handle_cancel_key_file_import(model: &mut Model) {
+    MainController::handle_cancel_key_file_import(model);
+}
+
+/// 导出账户地址列表（向后兼容）
+pub fn handle_export_addresses(model: &mut Model) {
+    MainController::handle_export_addresses(model);
+}
+
+/// 预览助记词派生的账户（向后兼容）
+pub fn handle_preview_mnemonic(model: &mut Model) {
+    MainController::handle_preview_mnemonic(model);
+}
+
+/// 导入助记词派生的指定账户（向后兼容）
+pub fn handle_import_mnemonic_account(model: &mut Model, account: u32) {
+    MainController::handle_import_mnemonic_account(model, account);
+}
+
+/// 修改已记录转账的本地备注（向后兼容）
+pub fn handle_save_transfer_note(model: &mut Model, digest: &str, note: &str) {
+    MainController::handle_save_transfer_note(model, digest, note);
+}
+
+/// 处理发送转账的请求（向后兼容）
+pub fn handle_send_transfer(model: &mut Model) {
+    MainController::handle_send_transfer(model);
+}
+
+/// 处理收款地址栏内容改变（向后兼容）
+pub fn handle_recipient_changed(model: &mut Model) {
+    MainController::handle_recipient_changed(model);
+}
+
+/// 处理发送批量转账的请求（向后兼容）
+pub fn handle_send_batch_transfer(model: &mut Model) {
+    MainController::handle_send_batch_transfer(model);
+}
+
+/// 确认向自己的地址转账后继续发送（向后兼容）
+pub fn handle_confirm_self_send(model: &mut Model) {
+    MainController::handle_confirm_self_send(model);
+}
+
+/// 确认可疑的大额转账后继续发送（向后兼容）
+pub fn handle_confirm_large_amount(model: &mut Model) {
+    MainController::handle_confirm_large_amount(model);
+}
+
+/// 处理会话活动（向后兼容）
+pub fn handle_session_activity(model: &mut Model, interacted: bool) {
+    MainController::handle_session_activity(model, interacted);
+}
+
+/// 处理窗口焦点变化（向后兼容）
+pub fn handle_window_focus(model: &mut Model, focused: Option<bool>, minimized: Option<bool>) {
+    MainController::handle_window_focus(model, focused, minimized);
 }
 
 /// 处理登出逻辑（向后兼容）
@@ -131,11 +474,76 @@ pub fn handle_refresh_balance(model: &mut Model) {
     MainController::handle_refresh_balance(model);
 }
 
+/// 查询当前地址在所有网络上的余额（向后兼容）
+pub fn handle_check_other_networks(model: &mut Model) {
+    MainController::handle_check_other_networks(model);
+}
+
 /// 处理从后台线程接收到的异步结果（向后兼容）
 pub fn handle_async_results(model: &mut Model) {
     MainController::handle_async_results(model);
 }
 
+/// 处理刷新网络健康状态的请求（向后兼容）
+pub fn handle_refresh_network_health(model: &mut Model) {
+    MainController::handle_refresh_network_health(model);
+}
+
+/// 打开代币显示过滤设置（向后兼容）
+pub fn handle_open_coin_filter(model: &mut Model) {
+    MainController::handle_open_coin_filter(model);
+}
+
+/// 保存代币显示过滤设置（向后兼容）
+pub fn handle_save_coin_filter(model: &mut Model) {
+    MainController::handle_save_coin_filter(model);
+}
+
+/// 打开备用 RPC 端点设置（向后兼容）
+pub fn handle_open_rpc_fallbacks(model: &mut Model) {
+    MainController::handle_open_rpc_fallbacks(model);
+}
+
+/// 保存备用 RPC 端点设置（向后兼容）
+pub fn handle_save_rpc_fallbacks(model: &mut Model) {
+    MainController::handle_save_rpc_fallbacks(model);
+}
+
+/// 打开自定义网络编辑对话框（向后兼容）
+pub fn handle_open_custom_network(model: &mut Model) {
+    MainController::handle_open_custom_network(model);
+}
+
+/// 测试自定义端点连接（向后兼容）
+pub fn handle_test_custom_network(model: &mut Model, url: String) {
+    MainController::handle_test_custom_network(model, url);
+}
+
+/// 保存自定义网络并切换（向后兼容）
+pub fn handle_save_custom_network(model: &mut Model, name: String, url: String) {
+    MainController::handle_save_custom_network(model, name, url);
+}
+
+/// 切换网络（向后兼容）
+pub fn handle_switch_network(model: &mut Model, network: Network) {
+    MainController::handle_switch_network(model, network);
+}
+
+/// 切换自动选择端点（向后兼容）
+pub fn handle_set_auto_select_endpoint(model: &mut Model, enabled: bool) {
+    MainController::handle_set_auto_select_endpoint(model, enabled);
+}
+
+/// 切换当前网络不可达时自动切换到首选网络（向后兼容）
+pub fn handle_set_auto_switch_network(model: &mut Model, enabled: bool) {
+    MainController::handle_set_auto_switch_network(model, enabled);
+}
+
+/// 设置自动切换时使用的首选网络（向后兼容）
+pub fn handle_set_preferred_network(model: &mut Model, network: Network) {
+    MainController::handle_set_preferred_network(model, network);
+}
+
 /// 处理设置密码请求（向后兼容）
 pub fn handle_set_password(model: &mut Model) -> Result<(), String> {
     MainController::handle_set_password(model)
@@ -144,4 +552,84 @@ pub fn handle_set_password(model: &mut Model) -> Result<(), String> {
 /// 处理验证密码请求（向后兼容）
 pub fn handle_verify_password(model: &mut Model) -> Result<(), String> {
     MainController::handle_verify_password(model)
+}
+
+/// 处理重置请求（向后兼容）
+pub fn handle_reset_wallet(model: &mut Model) -> Result<(), String> {
+    MainController::handle_reset_wallet(model)
+}
+
+/// 确认后删除已保存的加密私钥（向后兼容）
+pub fn handle_confirm_delete_key(model: &mut Model) -> Result<(), String> {
+    MainController::handle_confirm_delete_key(model)
+}
+
+/// 锁定倒计时中选择保持解锁（向后兼容）
+pub fn handle_stay_unlocked(model: &mut Model) {
+    MainController::handle_stay_unlocked(model);
+}
+
+/// 验证已加载地址的所有权（向后兼容）
+pub fn handle_verify_ownership(model: &mut Model) {
+    MainController::handle_verify_ownership(model);
+}
+
+/// 复制配置目录路径（向后兼容）
+pub fn handle_copy_config_path(model: &mut Model) {
+    MainController::handle_copy_config_path(model);
+}
+
+/// 在文件管理器中打开配置目录（向后兼容）
+pub fn handle_open_config_folder(model: &mut Model) {
+    MainController::handle_open_config_folder(model);
+}
+
+/// 启用或关闭本地崩溃报告（向后兼容）
+pub fn handle_set_write_crash_reports(model: &mut Model, enabled: bool) {
+    MainController::handle_set_write_crash_reports(model, enabled);
+}
+
+/// 取消进行中的操作（向后兼容）
+pub fn handle_cancel_operation(model: &mut Model, operation: AsyncOperation) {
+    MainController::handle_cancel_operation(model, operation);
+}
+
+/// 验证确认对话框中的密码并执行待确认的操作（向后兼容）
+pub fn handle_confirm_with_password(model: &mut Model) {
+    MainController::handle_confirm_with_password(model);
+}
+
+/// 取消密码确认（向后兼容）
+pub fn handle_cancel_password_confirmation(model: &mut Model) {
+    MainController::handle_cancel_password_confirmation(model);
+}
+
+/// 提交修改密码对话框中的新密码（向后兼容）
+pub fn handle_request_change_password(model: &mut Model) {
+    MainController::handle_request_change_password(model);
+}
+
+/// 取消修改密码（向后兼容）
+pub fn handle_cancel_change_password(model: &mut Model) {
+    MainController::handle_cancel_change_password(model);
+}
+
+/// 请求重置钱包（向后兼容）
+pub fn handle_request_reset(model: &mut Model) {
+    MainController::handle_request_reset(model);
+}
+
+/// 请求删除已保存的加密私钥（向后兼容）
+pub fn handle_request_delete_key(model: &mut Model) {
+    MainController::handle_request_delete_key(model);
+}
+
+/// 临时导入私钥，只保存在内存中（向后兼容）
+pub fn handle_import_key_temporarily(model: &mut Model) {
+    MainController::handle_import_key_temporarily(model);
+}
+
+/// 不登录进入临时会话（向后兼容）
+pub fn handle_start_temporary_session(model: &mut Model) {
+    MainController::handle_start_temporary_session(model);
 }
\ No newline at end of file
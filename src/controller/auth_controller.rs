@@ -1,20 +1,112 @@
-use crate::model::{Model, WalletState};
+use crate::model::{log_error, log_warn, open_in_file_manager, set_crash_reports_enabled, wipe_secret, AuthManager, DialogState, Model, PasswordProtectedAction, StatusMessage, WalletState};
 use crate::controller::WalletController;
+use eframe::egui;
+use zeroize::Zeroizing;
 
 /// 认证控制器 - 处理登录、登出和密码验证相关功能
 pub struct AuthController;
 
 impl AuthController {
+    /// 处理每帧的会话活动：会话过期时登出，有真实用户交互时延长会话
+    /// 临时会话同样受空闲超时限制
+    pub fn handle_session_activity(model: &mut Model, interacted: bool) {
+        if !model.auth_state.has_wallet_access() {
+            return;
+        }
+
+        if model.auth_state.is_session_expired() {
+            Self::handle_logout(model);
+            model.set_status(StatusMessage::warning(model.i18n.tr("session_expired_message")));
+            return;
+        }
+
+        model.auth_state.record_activity(interacted);
+    }
+
+    /// 用户在锁定倒计时中选择"保持解锁"：重新开始空闲计时
+    pub fn handle_stay_unlocked(model: &mut Model) {
+        if model.auth_state.has_wallet_access() && !model.auth_state.is_session_expired() {
+            model.auth_state.start_session();
+        }
+    }
+
+    /// 处理窗口焦点变化：启用"失去焦点时锁定"后，窗口失去焦点或最小化时锁定钱包
+    /// 临时会话没有密码可以解锁，直接登出
+    pub fn handle_window_focus(
+        model: &mut Model,
+        was_focused: Option<bool>,
+        focused: Option<bool>,
+        minimized: Option<bool>,
+    ) {
+        if !model.auth_state.has_wallet_access()
+            || !model
+                .app_state
+                .settings
+                .security_settings
+                .should_lock_on_blur(was_focused, focused, minimized)
+        {
+            return;
+        }
+
+        if model.auth_state.temporary_session {
+            Self::handle_logout(model);
+            model.set_status(StatusMessage::info(model.i18n.tr("temporary_session_ended")));
+        } else {
+            model.auth_state.lock();
+            model.app_state.ui_state.request_password_focus();
+            model.set_status(StatusMessage::info(model.i18n.tr("wallet_locked_message")));
+        }
+    }
+
+    /// 检查输入事件中是否包含真实的用户交互（指针、键盘、滚动、触摸）
+    /// 单纯的重绘、窗口焦点变化和截图事件不算
+    pub fn is_user_interaction(events: &[egui::Event]) -> bool {
+        events.iter().any(|event| {
+            matches!(
+                event,
+                egui::Event::Key { .. }
+                    | egui::Event::Text(_)
+                    | egui::Event::Copy
+                    | egui::Event::Cut
+                    | egui::Event::Paste(_)
+                    | egui::Event::PointerMoved(_)
+                    | egui::Event::PointerButton { .. }
+                    | egui::Event::MouseWheel { .. }
+                    | egui::Event::Zoom(_)
+                    | egui::Event::Touch { .. }
+                    | egui::Event::Ime(_)
+            )
+        })
+    }
+
+    /// 不登录直接进入临时会话：只能临时导入钱包，私钥不会写入磁盘
+    /// 锁定时必须输入密码解锁，不能借临时会话绕过锁定屏幕
+    pub fn handle_start_temporary_session(model: &mut Model) {
+        if !model.can_start_temporary_session() {
+            return;
+        }
+        model.auth_state.clear_password_inputs();
+        model.auth_state.temporary_session = true;
+        model.auth_state.start_session();
+        model.set_status(StatusMessage::info(model.i18n.tr("temporary_session_started")));
+    }
+
     /// 处理登出逻辑
     pub fn handle_logout(model: &mut Model) {
         // 清理认证状态和会话密码
         model.auth_state.logout();
+
+        // 主动登出时结束"保持登录"会话
+        if let Err(e) = model.auth_state.session_store.clear() {
+            log_error(&format!("Failed to clear session token: {}", e));
+        }
         
-        // 清理钱包状态
-        model.wallet = WalletState::NoWallet {
-            private_key_input: "".to_string(),
-        };
-        model.result_text = model.i18n.tr("wallet_logged_out_message");
+        // 清理钱包状态；未启用自动保存或临时导入时私钥不在磁盘上，登出后即被遗忘
+        model.wallet.wipe_private_key_input(model.auth_state.memory_protection);
+        model.wallet = WalletState::new_no_wallet();
+        model.reset_balance();
+        model.app_state.ui_state.request_password_focus();
+        model.set_status(StatusMessage::info(model.i18n.tr("wallet_logged_out_message")));
     }
 
     /// 处理设置密码请求（由 UI 触发）
@@ -22,27 +114,83 @@ impl AuthController {
         model.set_password()
     }
 
-    /// 处理登录
+    /// 处理登录：取出输入框中的密码进行验证，用完后清除，不在内存中留下副本
     pub fn handle_login(model: &mut crate::model::Model) -> Result<(), String> {
-        let attempt = model.auth_state.password_input.clone();
+        let mut attempt = std::mem::take(&mut model.auth_state.password_input);
         
-        match model.verify_password(&attempt) {
+        let result = match model.verify_password(&attempt) {
             Ok(true) => {
-                model.auth_state.is_authenticated = true;
-                
-                // 设置会话密码，用于后续的加密操作
-                model.auth_state.set_session_password(attempt.clone());
-                
-                // 认证成功后，尝试加载保存的私钥
-                if let Err(e) = WalletController::try_load_saved_key(model, &attempt) {
-                    eprintln!("Failed to load saved private key: {}", e);
-                    // 不影响登录流程，用户可以手动导入
-                }
-                
+                Self::update_session_token(model, &attempt);
+                Self::complete_login(model, &attempt);
                 Ok(())
             }
             Ok(false) => Err(model.i18n.tr("password_incorrect_error")),
             Err(e) => Err(e),
+        };
+        wipe_secret(&mut attempt, model.auth_state.memory_protection);
+        result
+    }
+
+    /// 登录成功后的共同处理
+    fn complete_login(model: &mut Model, password: &str) {
+        model.auth_state.is_authenticated = true;
+        model.auth_state.is_locked = false;
+        
+        // 设置会话密码，用于后续的加密操作
+        model.auth_state.set_session_password(password.to_string());
+        
+        // 认证成功后，尝试加载保存的私钥
+        if let Err(e) = WalletController::try_load_saved_key(model, password) {
+            log_error(&format!("Failed to load saved private key: {}", e));
+            // 不影响登录流程，用户可以手动导入
+        }
+    }
+
+    /// 根据"保持登录"选项创建或清除会话令牌
+    fn update_session_token(model: &mut Model, password: &str) {
+        let store = &model.auth_state.session_store;
+        let result = if model.auth_state.stay_signed_in {
+            store.create(password, model.app_state.settings.security_settings.stay_signed_in_duration())
+        } else {
+            store.clear()
+        };
+
+        if let Err(e) = result {
+            log_error(&format!("Failed to update session token: {}", e));
+            // 令牌无法安全保存时不保持登录
+            if model.auth_state.stay_signed_in {
+                model.auth_state.stay_signed_in = false;
+                model.set_status(StatusMessage::warning(model.i18n.tr("stay_signed_in_unavailable")).with_detail(e));
+            }
+        }
+    }
+
+    /// 尝试使用"保持登录"令牌自动登录
+    /// 令牌缺失、过期、被篡改、有效期超过当前设置或密码已更改时一律拒绝
+    pub fn try_resume_session(model: &mut Model) -> bool {
+        let max_valid_for = model.app_state.settings.security_settings.stay_signed_in_duration();
+        let password = match model.auth_state.session_store.load(max_valid_for) {
+            Ok(Some(password)) => password,
+            Ok(None) => return false,
+            Err(e) => {
+                log_warn(&format!("Rejected session token: {}", e));
+                return false;
+            }
+        };
+
+        match model.verify_password(&password) {
+            Ok(true) => {
+                model.auth_state.stay_signed_in = true;
+                Self::complete_login(model, &password);
+                true
+            }
+            _ => {
+                model.auth_state.logout();
+                if let Err(e) = model.auth_state.session_store.clear() {
+                    log_error(&format!("Failed to clear session token: {}", e));
+                }
+                false
+            }
         }
     }
 
@@ -53,21 +201,489 @@ impl AuthController {
         result
     }
 
-    /// 检查是否已认证
-    pub fn is_authenticated(model: &crate::model::Model) -> bool {
-        model.auth_state.is_authenticated && !model.auth_state.is_session_expired()
+    /// 敏感操作前要求重新输入密码：显示确认对话框，验证通过后由调用方执行 `action`
+    pub fn request_password_confirmation(model: &mut Model, action: PasswordProtectedAction) {
+        Self::clear_confirm_password_input(model);
+        model.app_state.ui_state.show_dialog(DialogState::ConfirmPassword(action));
+    }
+
+    /// 验证确认对话框中输入的密码（经过与登录相同的锁定检查）
+    /// 密码正确时关闭对话框并返回待执行的操作，否则在对话框中显示原因并返回 None
+    pub fn confirm_with_password(model: &mut Model) -> Option<PasswordProtectedAction> {
+        let DialogState::ConfirmPassword(action) = model.app_state.ui_state.dialog_state else {
+            return None;
+        };
+
+        let mut attempt = std::mem::take(&mut model.app_state.ui_state.confirm_password_input);
+        let result = model.verify_password(&attempt);
+        wipe_secret(&mut attempt, model.auth_state.memory_protection);
+
+        let ui_state = &mut model.app_state.ui_state;
+        match result {
+            Ok(true) => {
+                ui_state.confirm_password_error = None;
+                ui_state.close_dialog();
+                Some(action)
+            }
+            Ok(false) => {
+                ui_state.confirm_password_error = Some(model.i18n.tr("password_incorrect_error"));
+                None
+            }
+            Err(e) => {
+                ui_state.confirm_password_error = Some(e);
+                None
+            }
+        }
+    }
+
+    /// 取消密码确认，清除已输入的密码（包括修改密码时输入的新密码）
+    pub fn cancel_password_confirmation(model: &mut Model) {
+        Self::clear_confirm_password_input(model);
+        Self::clear_new_password_inputs(model);
+        model.app_state.ui_state.close_dialog();
+    }
+
+    /// 修改密码对话框中提交新密码：先重新输入当前密码确认
+    pub fn request_change_password(model: &mut Model) {
+        if model.app_state.ui_state.dialog_state != DialogState::ChangePassword {
+            return;
+        }
+        Self::request_password_confirmation(model, PasswordProtectedAction::ChangePassword);
+    }
+
+    /// 取消修改密码，清除已输入的新密码
+    pub fn cancel_change_password(model: &mut Model) {
+        Self::clear_new_password_inputs(model);
+        model.app_state.ui_state.close_dialog();
+    }
+
+    /// 密码确认通过后修改登录密码，并用新密码重新加密已保存的私钥
+    pub fn handle_change_password(model: &mut Model) {
+        let ui_state = &mut model.app_state.ui_state;
+        let mut new_password = std::mem::take(&mut ui_state.new_password_input);
+        let mut confirm = std::mem::take(&mut ui_state.new_password_confirm);
+        let result = Self::change_password(model, &new_password, &confirm);
+        wipe_secret(&mut new_password, model.auth_state.memory_protection);
+        wipe_secret(&mut confirm, model.auth_state.memory_protection);
+
+        match result {
+            Ok(()) => model.set_status(StatusMessage::success(model.i18n.tr("password_changed"))),
+            Err(e) => {
+                log_error(&format!("Failed to change password: {}", e));
+                let message = format!("{}: {}", model.i18n.tr("password_change_failed"), e);
+                model.set_status(StatusMessage::error(message).with_detail(e));
+            }
+        }
+    }
+
+    /// 写入新的密码哈希，所有账户范围中已保存的私钥先用当前密码解密，再用新密码重新加密
+    /// 重新加密失败时恢复原来的密码哈希，保证私钥始终能用有效的密码解密
+    fn change_password(model: &mut Model, new_password: &str, confirm: &str) -> Result<(), String> {
+        let current = model
+            .auth_state
+            .get_session_password()
+            .ok_or_else(|| model.i18n.tr("session_password_unavailable"))?;
+
+        let previous_hash = model.auth_state.password_hash.clone();
+        model.auth_state.password_input = new_password.to_string();
+        model.auth_state.password_confirm = confirm.to_string();
+        let result = model
+            .auth_state
+            .set_password(&model.i18n)
+            .and_then(|()| model.auth_state.reencrypt_private_keys(&current, new_password.trim()));
+        model.auth_state.clear_password_inputs();
+
+        if let Err(e) = result {
+            if model.auth_state.password_hash != previous_hash
+                && let Some(hash) = previous_hash
+            {
+                model.auth_state.restore_password_hash(hash)?;
+            }
+            return Err(e);
+        }
+        model.auth_state.set_session_password(new_password.trim().to_string());
+        Ok(())
+    }
+
+    fn clear_new_password_inputs(model: &mut Model) {
+        let ui_state = &mut model.app_state.ui_state;
+        wipe_secret(&mut ui_state.new_password_input, model.auth_state.memory_protection);
+        wipe_secret(&mut ui_state.new_password_confirm, model.auth_state.memory_protection);
     }
 
-    /// 登出
-    pub fn logout(model: &mut crate::model::Model) {
-        model.auth_state.is_authenticated = false;
-        model.auth_state.password_input.clear();
-        model.auth_state.password_confirm.clear();
+    fn clear_confirm_password_input(model: &mut Model) {
+        let ui_state = &mut model.app_state.ui_state;
+        wipe_secret(&mut ui_state.confirm_password_input, model.auth_state.memory_protection);
+        ui_state.confirm_password_error = None;
     }
 
-    /// 清除密码输入字段
-    pub fn clear_password_inputs(model: &mut crate::model::Model) {
-        model.auth_state.password_input.clear();
-        model.auth_state.password_confirm.clear();
+    /// 复制配置目录路径（密码文件和加密私钥所在位置），便于排查问题
+    pub fn handle_copy_config_path(model: &mut Model) {
+        let path = model.auth_state.config_dir().display().to_string();
+        model.app_state.ui_state.pending_copy = Some(Zeroizing::new(path.clone()));
+        model.set_status(StatusMessage::success(model.i18n.tr("config_path_copied").replace("{}", &path)));
+    }
+
+    /// 启用或关闭本地崩溃报告（panic hook 只能读取全局开关，需同步更新）
+    pub fn handle_set_write_crash_reports(model: &mut Model, enabled: bool) {
+        model.app_state.settings.write_crash_reports = enabled;
+        set_crash_reports_enabled(enabled);
+    }
+
+    /// 在系统文件管理器中打开配置目录
+    pub fn handle_open_config_folder(model: &mut Model) {
+        let dir = model.auth_state.config_dir().to_path_buf();
+        if let Err(e) = open_in_file_manager(&dir) {
+            log_error(&format!("Failed to open config folder: {}", e));
+            let message = model.i18n.tr("open_config_folder_failed").replace("{}", &e);
+            model.set_status(StatusMessage::error(message).with_detail(e));
+        }
+    }
+
+    /// 处理重置（清除密码和已保存的私钥），必须先经过确认
+    pub fn handle_reset_wallet(model: &mut Model) -> Result<(), String> {
+        if model.app_state.ui_state.dialog_state != DialogState::ConfirmReset {
+            return Err("Reset requires confirmation".to_string());
+        }
+        model.auth_state.delete_all_private_keys()?;
+        AuthManager::reset_password(&mut model.auth_state)?;
+        model.auth_state.clear_session_password();
+        // 重置后旧密码作废，同时结束"保持登录"会话
+        model.auth_state.stay_signed_in = false;
+        if let Err(e) = model.auth_state.session_store.clear() {
+            log_error(&format!("Failed to clear session token: {}", e));
+        }
+        model.app_state.ui_state.close_dialog();
+
+        WalletController::clear_wallet(model);
+        model.set_status(StatusMessage::info(model.i18n.tr("welcome_first_run")));
+        Ok(())
+    }
+
+    /// 请求确认删除已保存的加密私钥（孤立私钥或"忘记私钥"）
+    /// 已登录时先重新输入密码；孤立私钥没有可验证的密码，直接显示确认对话框
+    pub fn request_delete_key_confirmation(model: &mut Model) {
+        if model.auth_state.is_authenticated {
+            Self::request_password_confirmation(model, PasswordProtectedAction::DeleteSavedKey);
+        } else {
+            Self::show_delete_key_confirmation(model);
+        }
+    }
+
+    /// 显示删除已保存私钥的确认对话框（密码确认通过后调用）
+    pub fn show_delete_key_confirmation(model: &mut Model) {
+        model.app_state.ui_state.show_dialog(DialogState::ConfirmDeleteKey);
+    }
+
+    /// 确认后删除已保存的加密私钥，之后需要重新导入私钥
+    /// 未经确认对话框调用时不删除任何文件
+    pub fn handle_confirm_delete_key(model: &mut Model) -> Result<(), String> {
+        if model.app_state.ui_state.dialog_state != DialogState::ConfirmDeleteKey {
+            return Err("Deleting the saved key requires confirmation".to_string());
+        }
+        model.auth_state.delete_encrypted_private_key()?;
+        model.app_state.ui_state.close_dialog();
+
+        if model.wallet.is_loaded() {
+            WalletController::clear_wallet(model);
+        }
+        model.set_status(StatusMessage::info(model.i18n.tr("saved_key_deleted")));
+        Ok(())
+    }
+
+    /// 请求重置确认
+    /// 已登录时先重新输入密码；密码文件损坏时无法验证密码，直接显示确认
+    pub fn request_reset_confirmation(model: &mut Model) {
+        if model.auth_state.is_authenticated {
+            Self::request_password_confirmation(model, PasswordProtectedAction::ResetWallet);
+        } else {
+            Self::show_reset_confirmation(model);
+        }
+    }
+
+    /// 显示重置确认（密码确认通过后调用）
+    pub fn show_reset_confirmation(model: &mut Model) {
+        model.app_state.ui_state.show_dialog(DialogState::ConfirmReset);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{lock_crash_report_flag, use_test_keyring, AccountScope, Network, StatusKind, TestDir};
+    use std::time::Duration;
+
+    const TEST_KEY: &str = "suiprivkey1qqqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0jqa4ffsr";
+
+    #[test]
+    fn only_genuine_input_counts_as_interaction() {
+        assert!(!AuthController::is_user_interaction(&[]));
+        assert!(!AuthController::is_user_interaction(&[egui::Event::WindowFocused(true)]));
+        assert!(AuthController::is_user_interaction(&[egui::Event::PointerMoved(egui::pos2(1.0, 2.0))]));
+        assert!(AuthController::is_user_interaction(&[egui::Event::Text("a".to_string())]));
+    }
+
+    #[test]
+    fn losing_focus_locks_with_info_status() {
+        let dir = TestDir::new("losing_focus_locks_with_info_status");
+        let mut model = Model::with_config_dir(&dir);
+        model.auth_state.is_authenticated = true;
+        model.app_state.settings.security_settings.lock_on_blur = true;
+
+        AuthController::handle_window_focus(&mut model, None, Some(true), Some(false));
+        assert!(model.auth_state.is_authenticated);
+
+        AuthController::handle_window_focus(&mut model, Some(true), Some(false), Some(false));
+        assert!(model.auth_state.is_locked);
+        assert_eq!(model.status.map(|s| s.kind), Some(StatusKind::Info));
+    }
+
+    #[test]
+    fn temporary_session_is_refused_while_locked_or_with_a_wallet() {
+        let dir = TestDir::new("temporary_session_refused");
+        let mut model = Model::with_config_dir(&dir);
+        model.auth_state.is_authenticated = true;
+        model.auth_state.lock();
+        AuthController::handle_start_temporary_session(&mut model);
+        assert!(!model.auth_state.has_wallet_access());
+
+        let mut model = Model::with_config_dir(&dir);
+        let (address, keypair) = crate::model::WalletUtils::import_private_key(TEST_KEY).unwrap();
+        model.wallet = WalletState::new_loaded(address, keypair);
+        assert!(!model.can_start_temporary_session());
+        AuthController::handle_start_temporary_session(&mut model);
+        assert!(!model.auth_state.has_wallet_access());
+    }
+
+    #[test]
+    fn temporary_session_ends_on_idle_timeout_and_blur() {
+        let dir = TestDir::new("temporary_session_timeout");
+        let mut model = Model::with_config_dir(&dir);
+        AuthController::handle_start_temporary_session(&mut model);
+        assert!(model.auth_state.temporary_session);
+        assert!(model.auth_state.session_remaining(std::time::Instant::now()).is_some());
+
+        // 空闲超时后登出并清除临时导入的钱包
+        let (address, keypair) = crate::model::WalletUtils::import_private_key(TEST_KEY).unwrap();
+        model.wallet = WalletState::new_loaded(address, keypair);
+        model.auth_state.set_session_timeout(0);
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        AuthController::handle_session_activity(&mut model, true);
+        assert!(!model.auth_state.has_wallet_access());
+        assert!(!model.wallet.is_loaded());
+
+        // 临时会话没有密码可以解锁，失去焦点时直接登出
+        AuthController::handle_start_temporary_session(&mut model);
+        model.app_state.settings.security_settings.lock_on_blur = true;
+        AuthController::handle_window_focus(&mut model, Some(true), Some(false), Some(false));
+        assert!(!model.auth_state.has_wallet_access());
+        assert!(!model.auth_state.is_locked);
+    }
+
+    #[test]
+    fn saved_key_is_deleted_only_after_confirmation() {
+        let dir = TestDir::new("delete_key");
+        let mut model = Model::with_config_dir(&dir);
+        std::fs::write(&model.auth_state.encrypted_private_key_file, b"encrypted").unwrap();
+
+        assert!(AuthController::handle_confirm_delete_key(&mut model).is_err());
+        assert!(model.auth_state.has_encrypted_private_key());
+
+        AuthController::request_delete_key_confirmation(&mut model);
+        AuthController::handle_confirm_delete_key(&mut model).unwrap();
+        assert!(!model.auth_state.has_encrypted_private_key());
+        assert_eq!(model.app_state.ui_state.dialog_state, DialogState::None);
+    }
+
+    #[test]
+    fn password_confirmation_runs_action_only_with_correct_password() {
+        let dir = TestDir::new("confirm_password");
+        let mut model = Model::with_config_dir(&dir);
+        model.auth_state.password_input = "Correct-horse-1".to_string();
+        model.auth_state.password_confirm = "Correct-horse-1".to_string();
+        model.auth_state.set_password(&model.i18n).unwrap();
+        let (address, keypair) = crate::model::WalletUtils::import_private_key(TEST_KEY).unwrap();
+        model.wallet = WalletState::new_loaded(address, keypair);
+        model.app_state.settings.dismiss_copy_warning();
+
+        crate::controller::MainController::handle_copy_private_key(&mut model);
+        let dialog = DialogState::ConfirmPassword(PasswordProtectedAction::CopyPrivateKey);
+        assert_eq!(model.app_state.ui_state.dialog_state, dialog);
+
+        model.app_state.ui_state.confirm_password_input = "wrong".to_string();
+        crate::controller::MainController::handle_confirm_with_password(&mut model);
+        assert_eq!(model.app_state.ui_state.pending_copy, None);
+        assert_eq!(model.app_state.ui_state.dialog_state, dialog);
+        assert!(model.app_state.ui_state.confirm_password_error.is_some());
+        assert!(model.app_state.ui_state.confirm_password_input.is_empty());
+
+        model.app_state.ui_state.confirm_password_input = "Correct-horse-1".to_string();
+        crate::controller::MainController::handle_confirm_with_password(&mut model);
+        assert_eq!(model.app_state.ui_state.pending_copy.as_deref().map(String::as_str), Some(TEST_KEY));
+        assert_eq!(model.app_state.ui_state.dialog_state, DialogState::None);
+
+        // 没有待确认的操作时不执行任何操作
+        model.app_state.ui_state.pending_copy = None;
+        crate::controller::MainController::handle_confirm_with_password(&mut model);
+        assert_eq!(model.app_state.ui_state.pending_copy, None);
+    }
+
+    /// 已设置密码并登录的模型
+    fn logged_in_model(dir: &std::path::Path, password: &str) -> Model {
+        let mut model = Model::with_config_dir(dir);
+        model.auth_state.password_input = password.to_string();
+        model.auth_state.password_confirm = password.to_string();
+        model.auth_state.set_password(&model.i18n).unwrap();
+        model.auth_state.set_session_password(password.to_string());
+        model
+    }
+
+    #[test]
+    fn login_clears_the_password_input_whether_or_not_it_succeeds() {
+        let dir = TestDir::new("login_clears_the_password_input_whether_or_not_it_succeeds");
+        let mut model = logged_in_model(&dir, "Correct-horse-1");
+        model.auth_state.password_input = "wrong".to_string();
+        assert!(AuthController::handle_login(&mut model).is_err());
+        assert!(model.auth_state.password_input.is_empty());
+
+        model.auth_state.password_input = "Correct-horse-1".to_string();
+        assert!(AuthController::handle_login(&mut model).is_ok());
+        assert!(model.auth_state.password_input.is_empty());
+        assert!(model.auth_state.is_authenticated);
+    }
+
+    #[test]
+    fn destructive_actions_ask_for_the_password_when_logged_in() {
+        let dir = TestDir::new("destructive_actions_ask_for_the_password_when_logged_in");
+        let mut model = logged_in_model(&dir, "Correct-horse-1");
+
+        let cases = [
+            (PasswordProtectedAction::DeleteSavedKey, DialogState::ConfirmDeleteKey),
+            (PasswordProtectedAction::ResetWallet, DialogState::ConfirmReset),
+        ];
+        for (protected, confirmation) in cases {
+            match protected {
+                PasswordProtectedAction::DeleteSavedKey => AuthController::request_delete_key_confirmation(&mut model),
+                _ => AuthController::request_reset_confirmation(&mut model),
+            }
+            assert_eq!(model.app_state.ui_state.dialog_state, DialogState::ConfirmPassword(protected));
+            model.app_state.ui_state.confirm_password_input = "Correct-horse-1".to_string();
+            crate::controller::MainController::handle_confirm_with_password(&mut model);
+            assert_eq!(model.app_state.ui_state.dialog_state, confirmation);
+            model.app_state.ui_state.close_dialog();
+        }
+    }
+
+    #[test]
+    fn changing_the_password_reencrypts_the_saved_key() {
+        let dir = TestDir::new("changing_the_password_reencrypts_the_saved_key");
+        let mut model = logged_in_model(&dir, "Correct-horse-1");
+        model.auth_state.save_encrypted_private_key(TEST_KEY, "Correct-horse-1").unwrap();
+
+        model.app_state.ui_state.show_dialog(DialogState::ChangePassword);
+        model.app_state.ui_state.new_password_input = "Battery-staple-2".to_string();
+        model.app_state.ui_state.new_password_confirm = "Battery-staple-2".to_string();
+        AuthController::request_change_password(&mut model);
+        let dialog = DialogState::ConfirmPassword(PasswordProtectedAction::ChangePassword);
+        assert_eq!(model.app_state.ui_state.dialog_state, dialog);
+
+        model.app_state.ui_state.confirm_password_input = "Correct-horse-1".to_string();
+        crate::controller::MainController::handle_confirm_with_password(&mut model);
+        assert_eq!(model.status.as_ref().map(|s| s.kind), Some(StatusKind::Success));
+        assert!(model.app_state.ui_state.new_password_input.is_empty());
+        assert!(model.app_state.ui_state.new_password_confirm.is_empty());
+
+        assert_eq!(model.verify_password("Correct-horse-1"), Ok(false));
+        assert_eq!(model.verify_password("Battery-staple-2"), Ok(true));
+        let saved = model.auth_state.load_encrypted_private_key("Battery-staple-2").unwrap();
+        assert_eq!(saved.as_deref().map(String::as_str), Some(TEST_KEY));
+    }
+
+    /// 启用按网络区分账户，并在两个网络下各保存一个私钥，最后停留在 `first`
+    fn save_keys_for_two_networks(model: &mut Model, first: &Network, second: &Network, password: &str) {
+        model.app_state.settings.separate_accounts_per_network = true;
+        for network in [second, first] {
+            model.set_network(network.clone());
+            let scope = model.account_scope();
+            model.auth_state.set_account_scope(&scope);
+            model.auth_state.save_encrypted_private_key(TEST_KEY, password).unwrap();
+        }
+    }
+
+    #[test]
+    fn changing_the_password_reencrypts_every_network_account() {
+        let dir = TestDir::new("changing_the_password_reencrypts_every_network_account");
+        let mut model = logged_in_model(&dir, "Correct-horse-1");
+        // 指向本机的自定义网络，加载账户后的余额查询不会访问外部节点
+        let first = Network::custom("Node A", "http://127.0.0.1:9");
+        let second = Network::custom("Node B", "http://127.0.0.1:9");
+        save_keys_for_two_networks(&mut model, &first, &second, "Correct-horse-1");
+
+        AuthController::change_password(&mut model, "Battery-staple-2", "Battery-staple-2").unwrap();
+
+        // 切换到另一个网络时用新密码加载该网络的私钥
+        crate::controller::NetworkController::handle_switch_network(&mut model, second);
+        assert!(model.wallet.is_loaded());
+        assert_ne!(model.status.as_ref().map(|s| s.kind), Some(StatusKind::Error));
+        let saved = model.auth_state.load_encrypted_private_key("Battery-staple-2").unwrap();
+        assert_eq!(saved.as_deref().map(String::as_str), Some(TEST_KEY));
+    }
+
+    #[test]
+    fn reset_deletes_the_keys_of_every_account_scope() {
+        let dir = TestDir::new("reset_deletes_the_keys_of_every_account_scope");
+        let mut model = logged_in_model(&dir, "Correct-horse-1");
+        let first = Network::custom("Node A", "http://127.0.0.1:9");
+        let second = Network::custom("Node B", "http://127.0.0.1:9");
+        save_keys_for_two_networks(&mut model, &first, &second, "Correct-horse-1");
+        model.auth_state.save_account_label("Main").unwrap();
+        let saved: Vec<_> = AccountScope::all(&dir).iter().map(|scope| scope.private_key_file(&dir)).collect();
+        assert_eq!(saved.iter().filter(|path| path.exists()).count(), 2);
+        use_test_keyring();
+        let hour = Duration::from_secs(3600);
+        model.auth_state.stay_signed_in = true;
+        model.auth_state.session_store.create("Correct-horse-1", hour).unwrap();
+
+        model.app_state.ui_state.show_dialog(DialogState::ConfirmReset);
+        AuthController::handle_reset_wallet(&mut model).unwrap();
+        assert!(saved.iter().all(|path| !path.exists()));
+        assert_eq!(model.auth_state.load_account_label(), None);
+        assert!(model.auth_state.is_first_run);
+        assert!(!model.auth_state.stay_signed_in);
+        assert_eq!(model.auth_state.session_store.load(hour), Ok(None));
+    }
+
+    #[test]
+    fn reset_requires_confirmation() {
+        let dir = TestDir::new("reset_requires_confirmation");
+        let mut model = Model::with_config_dir(&dir);
+        assert!(AuthController::handle_reset_wallet(&mut model).is_err());
+    }
+
+    #[test]
+    fn copied_config_path_is_the_password_file_directory() {
+        let dir = TestDir::new("copied_config_path_is_the_password_file_directory");
+        let mut model = Model::with_config_dir(&dir);
+        AuthController::handle_copy_config_path(&mut model);
+
+        let expected = model.auth_state.config_dir().display().to_string();
+        assert_eq!(expected, dir.display().to_string());
+        assert_eq!(model.app_state.ui_state.pending_copy.as_deref(), Some(&expected));
+        assert_eq!(model.status.map(|s| s.kind), Some(StatusKind::Success));
+    }
+
+    #[test]
+    fn crash_report_toggle_updates_the_setting_and_the_panic_hook_switch() {
+        let _crash_report_flag = lock_crash_report_flag();
+        let dir = TestDir::new("crash_report_toggle");
+        let mut model = Model::with_config_dir(&dir);
+
+        AuthController::handle_set_write_crash_reports(&mut model, false);
+        assert!(!model.app_state.settings.write_crash_reports);
+        assert!(!crate::model::crash_reports_enabled());
+
+        AuthController::handle_set_write_crash_reports(&mut model, true);
+        assert!(model.app_state.settings.write_crash_reports);
+        assert!(crate::model::crash_reports_enabled());
     }
-}
\ No newline at end of file
+}
@@ -1,4 +1,6 @@
-use crate::model::{Model, WalletState};
+use crate::model::{log_error, AsyncOperation, BalanceChange, BalanceRequest, BalanceSnapshot, BalanceUpdate, CoinFilterSettings, DialogState, Model, Network, NetworkBalances, NetworkUtils, StatusMessage, WalletState, SUI_COIN_TYPE};
+use std::sync::mpsc::Sender;
+use std::time::Instant;
 use sui_sdk::{
     types::base_types::SuiAddress,
     SuiClientBuilder,
@@ -11,34 +13,228 @@ impl BalanceController {
     /// 处理刷新余额的请求
     pub fn handle_refresh_balance(model: &mut Model) {
         if let WalletState::Loaded { address, .. } = &model.wallet {
-            model.is_loading = true;
-            model.result_text = model.i18n.tr("refreshing_balance");
-            let sender = model.sender.clone();
             let address = *address;
-            let network_url = model.network.url();
+            let network_url = model.network.url().to_string();
 
-            model.rt.spawn(async move {
-                let result = Self::fetch_balance(address, network_url).await;
-                sender.send(result).expect("Failed to send message");
+            // 同一地址和网络的查询仍在进行时不重复发起
+            if model
+                .balance_request
+                .as_ref()
+                .is_some_and(|request| request.is_same_query(address, &network_url))
+            {
+                return;
+            }
+
+            // 同一地址和网络的上一次余额保留到新结果到达（查询被取消时继续显示）
+            // 地址或网络不同时旧余额不再适用
+            if !model
+                .balance_source
+                .as_ref()
+                .is_some_and(|source| source.is_same_query(address, &network_url))
+            {
+                model.clear_displayed_balance();
+            }
+
+            model.balance_generation += 1;
+            let generation = model.balance_generation;
+            model.balance_request = Some(BalanceRequest {
+                generation,
+                address,
+                network_url: network_url.clone(),
             });
+            let sender = model.balance_sender.clone();
+            let endpoints = model.rpc_endpoints(&model.network);
+            let coin_filter = model.app_state.settings.coin_filter.clone();
+
+            let task = model.rt.spawn(async move {
+                let result = Self::fetch_balance(address, &endpoints, &coin_filter).await;
+                Self::send_balance_result(&sender, generation, result);
+            });
+            // 被新查询取代的旧查询不再需要，中止后只跟踪新查询
+            model.operations.cancel(AsyncOperation::RefreshBalance);
+            model.operations.track(AsyncOperation::RefreshBalance, task.abort_handle());
         } else {
-            model.result_text = model.i18n.tr("no_wallet_loaded");
+            model.set_status(StatusMessage::warning(model.i18n.tr("no_wallet_loaded")));
+        }
+    }
+
+    /// 打开代币显示过滤设置对话框
+    pub fn handle_open_coin_filter(model: &mut Model) {
+        let filter = &model.app_state.settings.coin_filter;
+        let ui_state = &mut model.app_state.ui_state;
+        ui_state.coin_filter_mode_input = filter.mode;
+        ui_state.coin_allowlist_input = CoinFilterSettings::list_text(&filter.allowlist);
+        ui_state.coin_denylist_input = CoinFilterSettings::list_text(&filter.denylist);
+        ui_state.show_dialog(DialogState::CoinFilter);
+    }
+
+    /// 保存代币显示过滤设置；过滤在查询余额时应用，已加载钱包时重新查询
+    pub fn handle_save_coin_filter(model: &mut Model) {
+        let ui_state = &model.app_state.ui_state;
+        model.app_state.settings.coin_filter = CoinFilterSettings {
+            mode: ui_state.coin_filter_mode_input,
+            allowlist: CoinFilterSettings::parse_list(&ui_state.coin_allowlist_input),
+            denylist: CoinFilterSettings::parse_list(&ui_state.coin_denylist_input),
+        };
+        model.app_state.ui_state.close_dialog();
+        model.set_status(StatusMessage::success(model.i18n.tr("coin_filter_saved")));
+
+        if model.wallet.is_loaded() {
+            Self::handle_refresh_balance(model);
+        }
+    }
+
+    /// 将余额查询结果发回 UI 线程
+    /// 接收端已释放（例如程序正在退出）时只记录日志，不让后台任务 panic
+    fn send_balance_result(
+        sender: &Sender<BalanceUpdate>,
+        generation: u64,
+        result: Result<(BalanceSnapshot, String), String>,
+    ) {
+        if let Err(e) = sender.send((generation, result)) {
+            log_error(&format!("Failed to send balance result: {}", e));
+        }
+    }
+
+    /// 查询当前地址在所有内置网络上的余额
+    /// 同一地址在每个网络上都存在，方便用户确认资金实际位于哪个网络
+    pub fn handle_check_other_networks(model: &mut Model) {
+        let Some(address) = model.wallet.address().copied() else {
+            return;
+        };
+
+        let networks = Network::all();
+        model.network_balances = NetworkBalances::start(address, networks.clone());
+        model.operations.cancel(AsyncOperation::CheckOtherNetworks);
+        for network in networks {
+            let sender = model.network_balance_sender.clone();
+            let endpoints = model.rpc_endpoints(&network);
+            let coin_filter = model.app_state.settings.coin_filter.clone();
+            let task = model.rt.spawn(async move {
+                let result = Self::fetch_balance(address, &endpoints, &coin_filter)
+                    .await
+                    .map(|(snapshot, _)| snapshot.sui);
+                if let Err(e) = sender.send((address, network, result)) {
+                    log_error(&format!("Failed to send network balance result: {}", e));
+                }
+            });
+            model.operations.track(AsyncOperation::CheckOtherNetworks, task.abort_handle());
+        }
+    }
+
+    /// 并发查询助记词预览中各账户在当前网络上的余额
+    pub fn handle_fetch_preview_balances(model: &mut Model) {
+        let Some(preview) = &model.mnemonic_preview else {
+            return;
+        };
+
+        model.operations.cancel(AsyncOperation::MnemonicPreview);
+        let endpoints = model.rpc_endpoints(&preview.network);
+        for (address, _) in &preview.accounts {
+            let address = *address;
+            let sender = model.preview_balance_sender.clone();
+            let endpoints = endpoints.clone();
+            let coin_filter = model.app_state.settings.coin_filter.clone();
+            let task = model.rt.spawn(async move {
+                let result = Self::fetch_balance(address, &endpoints, &coin_filter)
+                    .await
+                    .map(|(snapshot, _)| snapshot.sui);
+                if let Err(e) = sender.send((address, result)) {
+                    log_error(&format!("Failed to send preview balance result: {}", e));
+                }
+            });
+            model.operations.track(AsyncOperation::MnemonicPreview, task.abort_handle());
+        }
+    }
+
+    /// 窗口重新获得焦点时刷新余额（需在设置中启用，带防抖）
+    pub fn handle_focus_refresh(model: &mut Model, was_focused: Option<bool>, focused: Option<bool>, now: Instant) {
+        let should_refresh = model.auth_state.is_authenticated
+            && !model.operations.is_running(AsyncOperation::RefreshBalance)
+            && model.app_state.settings.should_refresh_on_focus(
+                was_focused,
+                focused,
+                model.wallet.is_loaded(),
+                model.app_state.ui_state.last_focus_refresh,
+                now,
+            );
+        if should_refresh {
+            model.app_state.ui_state.last_focus_refresh = Some(now);
+            Self::handle_refresh_balance(model);
         }
     }
 
     /// 处理从后台线程接收到的异步结果
     pub fn handle_async_results(model: &mut Model) {
-        if let Ok(result) = model.receiver.try_recv() {
-            model.is_loading = false;
+        while let Ok((address, network, result)) = model.network_balance_receiver.try_recv() {
+            if model.network_balances.record(address, &network, result) && !model.network_balances.is_checking() {
+                model.operations.finish(AsyncOperation::CheckOtherNetworks);
+            }
+        }
+
+        while let Ok((address, result)) = model.preview_balance_receiver.try_recv() {
+            if let Some(preview) = &mut model.mnemonic_preview
+                && preview.record(address, result) && !preview.is_fetching()
+            {
+                model.operations.finish(AsyncOperation::MnemonicPreview);
+            }
+        }
+
+        if let Ok((generation, result)) = model.balance_receiver.try_recv() {
+            // 忽略已被新查询取代或已取消的查询结果，避免旧结果覆盖新结果
+            if model.balance_request.as_ref().is_none_or(|request| request.generation != generation) {
+                return;
+            }
+            let request = model.balance_request.take();
+            model.operations.finish(AsyncOperation::RefreshBalance);
             match result {
-                Ok(message) => model.result_text = message,
-                Err(e) => model.result_text = format!("{}: {}", model.i18n.tr("async_error"), e),
+                // 余额单独保存，不覆盖导入成功等状态消息
+                Ok((snapshot, endpoint)) => {
+                    let now = Instant::now();
+                    // 同一地址和网络上余额有变化时高亮显示变化量（切换钱包或网络会先清除余额）
+                    if let Some(change) = model.balance.and_then(|previous| BalanceChange::between(previous, snapshot.sui, now)) {
+                        model.balance_change = Some(change);
+                    }
+                    model.balance = Some(snapshot.sui);
+                    model.balance_updated_at = Some(now);
+                    // "最大"金额按新余额重新计算
+                    if model.app_state.transfer_amount_is_max {
+                        model.fill_max_transfer_amount();
+                    }
+                    model.holdings = Some(snapshot.holdings);
+                    model.balance_endpoint = Some(endpoint);
+                    model.balance_source = request;
+                }
+                Err(e) => {
+                    let message = format!("{}: {}", model.i18n.tr("async_error"), e);
+                    model.set_status(StatusMessage::error(message).with_detail(e));
+                }
             }
         }
     }
 
-    /// 异步获取SUI代币余额
-    async fn fetch_balance(address: SuiAddress, network_url: &str) -> Result<String, String> {
+    /// 异步获取SUI代币余额及持有代币概况，按顺序尝试主端点和备用端点
+    /// 被代币过滤设置隐藏的代币不计入；返回结果及实际提供结果的端点
+    pub async fn fetch_balance(
+        address: SuiAddress,
+        endpoints: &[String],
+        coin_filter: &CoinFilterSettings,
+    ) -> Result<(BalanceSnapshot, String), String> {
+        let (endpoint, balances) = NetworkUtils::try_endpoints_in_order(endpoints, |url| async move {
+            Self::fetch_coin_balances(address, &url).await
+        })
+        .await?;
+        let snapshot = BalanceSnapshot::from_coin_balances(
+            balances
+                .iter()
+                .filter(|(coin_type, _)| coin_filter.is_visible(coin_type))
+                .map(|(coin_type, total_balance)| (coin_type.as_str(), *total_balance)),
+        );
+        Ok((snapshot, endpoint))
+    }
+
+    /// 异步获取地址持有的所有代币余额：`(代币类型, 总余额)`
+    pub async fn fetch_coin_balances(address: SuiAddress, network_url: &str) -> Result<Vec<(String, u128)>, String> {
         let sui_client = SuiClientBuilder::default()
             .build(network_url)
             .await
@@ -50,36 +246,192 @@ impl BalanceController {
             .await
             .map_err(|e| e.to_string())?;
 
-        // 只查找并显示 SUI 代币的余额
-        let sui_balance = balances.iter().find(|b| b.coin_type == "0x2::sui::SUI");
-        if let Some(balance) = sui_balance {
-            let amount = balance.total_balance as f64 / 1_000_000_000.0;
-            Ok(format!("{:.4} SUI", amount))
-        } else {
-            Ok("0.0000 SUI".to_string())
-        }
+        Ok(balances
+            .into_iter()
+            .map(|balance| (balance.coin_type, balance.total_balance))
+            .collect())
+    }
+
+    /// 异步获取SUI代币余额（单位：MIST）
+    pub async fn fetch_sui_balance_mist(address: SuiAddress, network_url: &str) -> Result<u128, String> {
+        let balances = Self::fetch_coin_balances(address, network_url).await?;
+
+        // 只查找 SUI 代币的余额
+        let sui_balance = balances.iter().find(|(coin_type, _)| coin_type == SUI_COIN_TYPE);
+        Ok(sui_balance.map(|(_, total_balance)| *total_balance).unwrap_or(0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{Balance, CoinFilterMode, StatusKind, TestDir, WalletUtils, MIST_PER_SUI};
+    use std::path::Path;
+
+    #[test]
+    fn refresh_without_wallet_sets_warning() {
+        let dir = TestDir::new("refresh_without_wallet_sets_warning");
+        let mut model = Model::with_config_dir(&dir);
+        BalanceController::handle_refresh_balance(&mut model);
+        assert_eq!(model.status.as_ref().map(|s| s.kind), Some(StatusKind::Warning));
+        assert!(!model.operations.is_running(AsyncOperation::RefreshBalance));
     }
 
-    /// 检查是否正在加载余额
-    pub fn is_loading(model: &Model) -> bool {
-        model.is_loading
+    const TEST_KEY: &str = "suiprivkey1qqqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0jqa4ffsr";
+
+    fn model_with_wallet(dir: &Path) -> Model {
+        let mut model = Model::with_config_dir(dir);
+        let (address, keypair) = WalletUtils::import_private_key(TEST_KEY).unwrap();
+        model.wallet = WalletState::new_loaded(address, keypair);
+        model
     }
 
-    /// 设置加载状态
-    pub fn set_loading(model: &mut Model, loading: bool) {
-        model.is_loading = loading;
+    fn snapshot(mist: u64) -> BalanceSnapshot {
+        BalanceSnapshot::from_coin_balances([(SUI_COIN_TYPE, mist as u128)])
     }
 
-    /// 获取余额显示格式化
-    pub fn format_balance(balance: f64) -> String {
-        format!("{:.4} SUI", balance)
+    /// 模拟一次进行中的查询
+    fn pending_request(model: &mut Model, generation: u64) {
+        let address = *model.wallet.address().unwrap();
+        model.balance_request = Some(BalanceRequest {
+            generation,
+            address,
+            network_url: model.network.url().to_string(),
+        });
+        let task = model.rt.spawn(std::future::pending::<()>());
+        model.operations.track(AsyncOperation::RefreshBalance, task.abort_handle());
+    }
+
+    #[test]
+    fn balance_results_update_balance_or_set_error() {
+        let dir = TestDir::new("balance_results_update_balance_or_set_error");
+        let mut model = model_with_wallet(&dir);
+        pending_request(&mut model, 1);
+        model.balance_sender.send((1, Ok((snapshot(42), "https://rpc.example.org".to_string())))).unwrap();
+        BalanceController::handle_async_results(&mut model);
+        assert_eq!(model.balance, Some(Balance::from_mist(42)));
+        assert_eq!(model.balance_endpoint.as_deref(), Some("https://rpc.example.org"));
+        assert!(!model.operations.is_running(AsyncOperation::RefreshBalance));
+
+        pending_request(&mut model, 2);
+        model.balance_sender.send((2, Err("connection refused".to_string()))).unwrap();
+        BalanceController::handle_async_results(&mut model);
+        let status = model.status.unwrap();
+        assert_eq!(status.kind, StatusKind::Error);
+        assert_eq!(status.copy_text(), "connection refused");
     }
 
-    /// 解析余额字符串
-    pub fn parse_balance(balance_str: &str) -> Option<f64> {
-        balance_str
-            .trim_end_matches(" SUI")
-            .parse::<f64>()
-            .ok()
+    #[test]
+    fn max_amount_is_recomputed_when_balance_refreshes() {
+        let dir = TestDir::new("max_amount_is_recomputed_when_balance_refreshes");
+        let mut model = model_with_wallet(&dir);
+        model.balance = Some(Balance::from_mist(MIST_PER_SUI));
+        assert!(model.fill_max_transfer_amount());
+
+        pending_request(&mut model, 1);
+        model.balance_sender.send((1, Ok((snapshot(3 * MIST_PER_SUI), String::new())))).unwrap();
+        BalanceController::handle_async_results(&mut model);
+        let fee_mist = model.network.estimated_tx_fee_mist();
+        assert_eq!(model.app_state.transfer_amount_mist(), Ok(3 * MIST_PER_SUI - fee_mist));
+    }
+
+    #[test]
+    fn second_refresh_while_loading_does_not_spawn_another_fetch() {
+        let dir = TestDir::new("second_refresh_while_loading_does_not_spawn_another_fetch");
+        let mut model = model_with_wallet(&dir);
+        BalanceController::handle_refresh_balance(&mut model);
+        BalanceController::handle_refresh_balance(&mut model);
+        assert_eq!(model.balance_generation, 1);
+        assert!(model.operations.is_running(AsyncOperation::RefreshBalance));
+
+        // 切换网络后的查询会取代旧查询
+        model.set_network(Network::Mainnet);
+        BalanceController::handle_refresh_balance(&mut model);
+        assert_eq!(model.balance_generation, 2);
+    }
+
+    #[test]
+    fn cancelled_refresh_clears_loading_and_ignores_late_result() {
+        let dir = TestDir::new("cancelled_refresh_clears_loading_and_ignores_late_result");
+        let mut model = model_with_wallet(&dir);
+        BalanceController::handle_refresh_balance(&mut model);
+        assert!(model.operations.is_running(AsyncOperation::RefreshBalance));
+
+        crate::controller::MainController::handle_cancel_operation(&mut model, AsyncOperation::RefreshBalance);
+        assert!(!model.operations.is_running(AsyncOperation::RefreshBalance));
+        assert!(model.operations.is_idle());
+        assert_eq!(model.status.as_ref().map(|s| s.kind), Some(StatusKind::Info));
+
+        let generation = model.balance_generation;
+        model.balance_sender.send((generation, Ok((snapshot(5), String::new())))).unwrap();
+        BalanceController::handle_async_results(&mut model);
+        assert_eq!(model.balance, None);
+    }
+
+    #[test]
+    fn cancelled_refresh_keeps_the_previous_balance() {
+        let dir = TestDir::new("cancelled_refresh_keeps_the_previous_balance");
+        let mut model = model_with_wallet(&dir);
+        pending_request(&mut model, 0);
+        model.balance_sender.send((0, Ok((snapshot(5), String::new())))).unwrap();
+        BalanceController::handle_async_results(&mut model);
+
+        // 同一地址和网络的刷新被取消后仍显示上一次的余额
+        BalanceController::handle_refresh_balance(&mut model);
+        assert_eq!(model.balance, Some(Balance::from_mist(5)));
+        crate::controller::MainController::handle_cancel_operation(&mut model, AsyncOperation::RefreshBalance);
+        assert_eq!(model.balance, Some(Balance::from_mist(5)));
+        assert!(model.balance_state(Instant::now()).known_mist().is_some());
+
+        // 切换网络后旧余额不再适用
+        model.set_network(Network::Mainnet);
+        BalanceController::handle_refresh_balance(&mut model);
+        assert_eq!(model.balance, None);
+    }
+
+    #[test]
+    fn stale_balance_results_are_ignored() {
+        let dir = TestDir::new("stale_balance_results_are_ignored");
+        let mut model = model_with_wallet(&dir);
+        pending_request(&mut model, 2);
+        model.balance_sender.send((1, Ok((snapshot(7), Network::Testnet.url().to_string())))).unwrap();
+        BalanceController::handle_async_results(&mut model);
+        assert_eq!(model.balance, None);
+        assert!(model.operations.is_running(AsyncOperation::RefreshBalance));
+    }
+
+    #[test]
+    fn sending_to_dropped_receiver_does_not_panic_task() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let (sender, receiver) = std::sync::mpsc::channel();
+        drop(receiver);
+
+        let task = rt.spawn(async move {
+            BalanceController::send_balance_result(&sender, 1, Ok((snapshot(1), String::new())));
+        });
+        assert!(rt.block_on(task).is_ok());
+    }
+
+    #[test]
+    fn coin_filter_edits_are_saved_to_settings() {
+        let dir = TestDir::new("coin_filter_edits_are_saved_to_settings");
+        let mut model = Model::with_config_dir(&dir);
+        BalanceController::handle_open_coin_filter(&mut model);
+        assert_eq!(model.app_state.ui_state.dialog_state, DialogState::CoinFilter);
+        assert!(model.app_state.ui_state.coin_denylist_input.contains("airdrop"));
+
+        let ui_state = &mut model.app_state.ui_state;
+        ui_state.coin_filter_mode_input = CoinFilterMode::Allowlist;
+        ui_state.coin_allowlist_input = "0xdba3::usdc::USDC\n\n".to_string();
+        BalanceController::handle_save_coin_filter(&mut model);
+
+        let filter = &model.app_state.settings.coin_filter;
+        assert_eq!(filter.mode, CoinFilterMode::Allowlist);
+        assert_eq!(filter.allowlist, vec!["0xdba3::usdc::USDC".to_string()]);
+        // 未编辑的黑名单保持不变
+        assert_eq!(filter.denylist, CoinFilterSettings::default().denylist);
+        assert!(!filter.is_visible("0xc0ff::cetus::CETUS"));
+        assert_eq!(model.app_state.ui_state.dialog_state, DialogState::None);
+        assert_eq!(model.status.as_ref().map(|s| s.kind), Some(StatusKind::Success));
     }
-}
\ No newline at end of file
+}
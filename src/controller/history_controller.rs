@@ -0,0 +1,71 @@
+use crate::model::{log_error, log_warn, Model, StatusMessage, TransferRecord, TransferStatus};
+
+/// 历史控制器 - 处理本地转账历史记录
+pub struct HistoryController;
+
+impl HistoryController {
+    /// 记录用户发起的转账（附带发送时填写的本地备注）
+    /// 钱包未加载时不记录；保存失败只记录错误，不影响转账流程
+    pub fn record_transfer(
+        model: &mut Model,
+        recipient: String,
+        amount_mist: u64,
+        digest: Option<String>,
+        status: TransferStatus,
+        note: &str,
+    ) {
+        let Some(sender) = model.wallet.address().map(|a| a.to_string()) else {
+            return;
+        };
+
+        let mut record = TransferRecord::new(sender, recipient, amount_mist, model.network.name().to_string())
+            .with_note(note);
+        record.digest = digest;
+        record.status = status;
+
+        if let Err(e) = model.transfer_history.append(record) {
+            log_error(&format!("Failed to record transfer: {}", e));
+        }
+    }
+
+    /// 更新已记录转账的状态（例如交易确认后）
+    pub fn update_transfer_status(model: &mut Model, digest: &str, status: TransferStatus) {
+        match model.transfer_history.update_status(digest, status) {
+            Ok(true) => {}
+            Ok(false) => log_warn(&format!("No recorded transfer with digest {}", digest)),
+            Err(e) => log_error(&format!("Failed to update transfer status: {}", e)),
+        }
+    }
+
+    /// 修改已记录转账的本地备注（空备注表示删除）
+    pub fn handle_save_transfer_note(model: &mut Model, digest: &str, note: &str) {
+        match model.transfer_history.set_note(digest, note) {
+            Ok(true) => model.set_status(StatusMessage::success(model.i18n.tr("transfer_note_saved"))),
+            Ok(false) => log_warn(&format!("No recorded transfer with digest {}", digest)),
+            Err(e) => {
+                log_error(&format!("Failed to save transfer note: {}", e));
+                let message = format!("{}: {}", model.i18n.tr("transfer_note_save_failed"), e);
+                model.set_status(StatusMessage::error(message).with_detail(e));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{StatusKind, TestDir};
+
+    #[test]
+    fn edited_note_is_saved_with_its_transfer() {
+        let dir = TestDir::new("edited_note_is_saved_with_its_transfer");
+        let mut model = Model::with_config_dir(&dir);
+        let mut record = TransferRecord::new("0xa".to_string(), "0xb".to_string(), 1, "Testnet".to_string());
+        record.digest = Some("digest".to_string());
+        model.transfer_history.append(record).unwrap();
+
+        HistoryController::handle_save_transfer_note(&mut model, "digest", " Rent ");
+        assert_eq!(model.transfer_history.note_for("digest"), Some("Rent"));
+        assert_eq!(model.status.map(|s| s.kind), Some(StatusKind::Success));
+    }
+}
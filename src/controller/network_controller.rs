@@ -0,0 +1,403 @@
+use crate::controller::WalletController;
+use crate::model::{log_error, AsyncOperation, CustomNetworkSettings, DialogState, EndpointTestStatus, Model, Network, NetworkHealthStatus, NetworkUtils, RpcFallbackSettings, StatusMessage};
+use std::time::Instant;
+
+/// 网络控制器 - 处理网络连接健康检查相关功能
+pub struct NetworkController;
+
+impl NetworkController {
+    /// 处理手动刷新网络健康状态的请求（带防抖）
+    pub fn handle_refresh_network_health(model: &mut Model) {
+        if !model.network_health.can_refresh() {
+            return;
+        }
+
+        model.network_health.begin_check();
+        Self::spawn_health_check(model);
+    }
+
+    /// 启动时检查当前 RPC 端点是否可达（非阻塞）
+    pub fn handle_startup_health_check(model: &mut Model) {
+        if !model.app_state.settings.check_endpoint_on_startup {
+            return;
+        }
+
+        model.network_health.begin_startup_check();
+        Self::spawn_health_check(model);
+    }
+
+    /// 在后台执行健康检查，结果通过通道返回
+    fn spawn_health_check(model: &mut Model) {
+        let sender = model.health_sender.clone();
+        let network = model.network.clone();
+
+        let task = model.rt.spawn(async move {
+            let status = NetworkUtils::check_network_health(network.clone()).await;
+            if let Err(e) = sender.send((network, status)) {
+                log_error(&format!("Failed to send network health result: {}", e));
+            }
+        });
+        model.operations.cancel(AsyncOperation::HealthCheck);
+        model.operations.track(AsyncOperation::HealthCheck, task.abort_handle());
+    }
+
+    /// 处理后台返回的网络健康检查结果
+    pub fn handle_health_results(model: &mut Model) {
+        while let Ok((network, status)) = model.health_receiver.try_recv() {
+            // 忽略切换网络前发起的或已取消的检查结果
+            if network != model.network || !model.network_health.checking {
+                continue;
+            }
+            model.operations.finish(AsyncOperation::HealthCheck);
+            let warn = model.network_health.apply_result(status);
+            // 当前网络不可用时自动切换到首选网络，切换后不再提醒旧网络不可达
+            if status == NetworkHealthStatus::Unhealthy && Self::handle_auto_switch(model) {
+                continue;
+            }
+            if warn {
+                let message = model
+                    .i18n
+                    .tr("rpc_unreachable_warning")
+                    .replace("{}", &format!("{} ({})", network.name(), network.url()));
+                model.app_state.ui_state.show_dialog(DialogState::Warning(message));
+            }
+        }
+    }
+
+    /// 开启自动切换且当前网络不是首选网络时，切换到首选网络并在状态栏记录，返回是否已切换
+    pub fn handle_auto_switch(model: &mut Model) -> bool {
+        if !model.network_config.should_auto_switch() {
+            return false;
+        }
+
+        let from = model.network.clone();
+        let to = model.network_config.preferred_network.clone();
+        Self::handle_switch_network(model, to.clone());
+        let message = model
+            .i18n
+            .tr("auto_switched_network")
+            .replacen("{}", from.name(), 1)
+            .replacen("{}", to.name(), 1);
+        model.set_status(StatusMessage::warning(message));
+
+        // 检查新网络是否可用
+        model.network_health.begin_check();
+        Self::spawn_health_check(model);
+        true
+    }
+
+    /// 开启自动选择端点时，定期在后台探测当前网络的所有端点
+    pub fn handle_endpoint_probe_tick(model: &mut Model, now: Instant) {
+        if !model.network_config.should_probe(now) {
+            return;
+        }
+
+        model.network_config.probing = true;
+        model.network_config.last_probe = Some(now);
+        let sender = model.endpoint_probe_sender.clone();
+        let network = model.network.clone();
+        let endpoints = model.app_state.settings.rpc_fallbacks.endpoints(&network);
+
+        model.rt.spawn(async move {
+            let mut probes = Vec::with_capacity(endpoints.len());
+            for url in endpoints {
+                probes.push(NetworkUtils::probe_endpoint(url).await);
+            }
+            if let Err(e) = sender.send((network, probes)) {
+                log_error(&format!("Failed to send endpoint probe results: {}", e));
+            }
+        });
+    }
+
+    /// 处理端点探测结果，选出延迟最低的健康端点
+    pub fn handle_endpoint_probe_results(model: &mut Model) {
+        while let Ok((network, probes)) = model.endpoint_probe_receiver.try_recv() {
+            model.network_config.apply_probes(&network, &probes);
+        }
+    }
+
+    /// 切换自动选择端点
+    pub fn handle_set_auto_select_endpoint(model: &mut Model, enabled: bool) {
+        model.app_state.settings.auto_select_endpoint = enabled;
+        model.network_config.set_auto_select_endpoint(enabled);
+    }
+
+    /// 切换当前网络不可达时自动切换到首选网络
+    pub fn handle_set_auto_switch_network(model: &mut Model, enabled: bool) {
+        model.app_state.settings.auto_switch_network = enabled;
+        if enabled {
+            model.network_config.enable_auto_switch();
+        } else {
+            model.network_config.disable_auto_switch();
+        }
+    }
+
+    /// 设置自动切换时使用的首选网络
+    pub fn handle_set_preferred_network(model: &mut Model, network: Network) {
+        model.app_state.settings.preferred_network = network.clone();
+        model.network_config.set_preferred(network);
+    }
+
+    /// 打开自定义网络编辑对话框，预填已保存的配置
+    pub fn handle_open_custom_network(model: &mut Model) {
+        let (name, url) = match &model.app_state.settings.custom_network {
+            Some(custom) => (custom.name.clone(), custom.url.clone()),
+            None => ("Localnet".to_string(), "http://127.0.0.1:9000".to_string()),
+        };
+        model.app_state.ui_state.custom_network_name_input = name;
+        model.app_state.ui_state.custom_network_url_input = url;
+        model.app_state.ui_state.custom_network_test = EndpointTestStatus::Idle;
+        model.operations.cancel(AsyncOperation::EndpointTest);
+        model.app_state.ui_state.show_dialog(DialogState::CustomNetwork);
+    }
+
+    /// 打开备用 RPC 端点设置对话框
+    pub fn handle_open_rpc_fallbacks(model: &mut Model) {
+        let fallbacks = &model.app_state.settings.rpc_fallbacks;
+        model.app_state.ui_state.rpc_fallback_inputs = Network::all()
+            .into_iter()
+            .map(|network| {
+                let text = fallbacks.for_network(&network).join("\n");
+                (network, text)
+            })
+            .collect();
+        model.app_state.ui_state.show_dialog(DialogState::RpcFallbacks);
+    }
+
+    /// 保存备用 RPC 端点，有无效 URL 时不保存并保持对话框打开
+    pub fn handle_save_rpc_fallbacks(model: &mut Model) {
+        let mut fallbacks = model.app_state.settings.rpc_fallbacks.clone();
+        for (network, input) in &model.app_state.ui_state.rpc_fallback_inputs {
+            match RpcFallbackSettings::parse_urls(input) {
+                Ok(urls) => fallbacks.set_for_network(network, urls),
+                Err(invalid) => {
+                    let message = model.i18n.tr("invalid_fallback_urls").replace("{}", &invalid.join(", "));
+                    model.set_status(StatusMessage::error(message));
+                    return;
+                }
+            }
+        }
+
+        model.app_state.settings.rpc_fallbacks = fallbacks;
+        // 自动选择端点时按新的端点列表重新探测
+        model.network_config.request_probe();
+        model.app_state.ui_state.close_dialog();
+        model.set_status(StatusMessage::success(model.i18n.tr("rpc_fallbacks_saved")));
+    }
+
+    /// 测试自定义端点连接（不切换当前网络）
+    pub fn handle_test_custom_network(model: &mut Model, url: String) {
+        let url = url.trim().to_string();
+        let ui_state = &mut model.app_state.ui_state;
+        if !Network::is_valid_rpc_url(&url) {
+            ui_state.custom_network_test = EndpointTestStatus::Failed(model.i18n.tr("invalid_rpc_url"));
+            ui_state.custom_network_tested_url = url;
+            return;
+        }
+
+        ui_state.custom_network_test = EndpointTestStatus::Testing;
+        ui_state.custom_network_tested_url = url.clone();
+
+        let sender = model.endpoint_test_sender.clone();
+        let task = model.rt.spawn(async move {
+            let result = NetworkUtils::test_endpoint(url.clone()).await;
+            if let Err(e) = sender.send((url, result)) {
+                log_error(&format!("Failed to send endpoint test result: {}", e));
+            }
+        });
+        model.operations.cancel(AsyncOperation::EndpointTest);
+        model.operations.track(AsyncOperation::EndpointTest, task.abort_handle());
+    }
+
+    /// 处理后台返回的端点测试结果
+    pub fn handle_endpoint_test_results(model: &mut Model) {
+        while let Ok((url, result)) = model.endpoint_test_receiver.try_recv() {
+            let ui_state = &mut model.app_state.ui_state;
+            // 忽略已被新测试取代的结果
+            if url == ui_state.custom_network_tested_url {
+                ui_state.custom_network_test = EndpointTestStatus::from_result(result);
+                model.operations.finish(AsyncOperation::EndpointTest);
+            }
+        }
+    }
+
+    /// 保存自定义网络并切换到该网络
+    pub fn handle_save_custom_network(model: &mut Model, name: String, url: String) {
+        let name = name.trim().to_string();
+        let url = url.trim().to_string();
+
+        if !Network::is_valid_rpc_url(&url) {
+            model.set_status(StatusMessage::error(model.i18n.tr("invalid_rpc_url")));
+            return;
+        }
+
+        let name = if name.is_empty() { "Custom".to_string() } else { name };
+        model.app_state.settings.custom_network = Some(CustomNetworkSettings {
+            name: name.clone(),
+            url: url.clone(),
+        });
+        model.app_state.ui_state.close_dialog();
+        Self::handle_switch_network(model, Network::custom(name, url));
+    }
+
+    /// 切换网络，启用按网络区分账户时同时切换到该网络的账户
+    pub fn handle_switch_network(model: &mut Model, network: Network) {
+        // 记录切换前后的网络，状态栏可在两者之间快速切换
+        let last_used = &mut model.app_state.settings.last_used;
+        if last_used.recent_networks.is_empty() {
+            last_used.record_network(model.network.clone());
+        }
+        last_used.record_network(network.clone());
+        model.set_network(network);
+        WalletController::handle_account_scope_change(model);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{lock_crash_report_flag, NetworkHealthState, StatusKind, TestDir};
+
+    #[test]
+    fn invalid_custom_network_url_sets_error() {
+        let dir = TestDir::new("invalid_custom_network_url_sets_error");
+        let mut model = Model::with_config_dir(&dir);
+        NetworkController::handle_save_custom_network(&mut model, "Local".to_string(), "not a url".to_string());
+        assert_eq!(model.status.map(|s| s.kind), Some(StatusKind::Error));
+        assert!(model.app_state.settings.custom_network.is_none());
+    }
+
+    /// 模拟当前网络的健康检查返回不可达
+    fn report_unhealthy(model: &mut Model) {
+        model.network_health.begin_check();
+        model.health_sender.send((model.network.clone(), NetworkHealthStatus::Unhealthy)).unwrap();
+        NetworkController::handle_health_results(model);
+    }
+
+    #[test]
+    fn unhealthy_network_switches_to_preferred_when_enabled() {
+        let dir = TestDir::new("unhealthy_network_switches_to_preferred_when_enabled");
+        let mut model = Model::with_config_dir(&dir);
+        model.network_config.set_preferred(Network::Mainnet);
+        model.network_config.enable_auto_switch();
+        assert!(model.network_config.should_auto_switch());
+
+        report_unhealthy(&mut model);
+
+        assert_eq!(model.network, Network::Mainnet);
+        assert_eq!(model.network_config.current_network, Network::Mainnet);
+        assert!(!model.network_config.should_auto_switch());
+        assert_eq!(model.status.map(|s| s.kind), Some(StatusKind::Warning));
+    }
+
+    #[test]
+    fn network_and_endpoint_switching_are_saved_separately() {
+        let _crash_report_flag = lock_crash_report_flag();
+        let dir = TestDir::new("network_and_endpoint_switching_are_saved_separately");
+        let mut model = Model::with_config_dir(&dir);
+        NetworkController::handle_set_auto_switch_network(&mut model, true);
+        NetworkController::handle_set_preferred_network(&mut model, Network::Mainnet);
+        assert!(model.network_config.auto_switch);
+        assert!(!model.network_config.auto_select_endpoint);
+
+        // 重启后从保存的设置恢复
+        let settings = model.app_state.settings.clone();
+        let mut restarted = Model::with_config_dir(&dir);
+        restarted.apply_settings(settings);
+        assert!(restarted.network_config.should_auto_switch());
+        assert_eq!(restarted.network_config.preferred_network, Network::Mainnet);
+        assert!(!restarted.network_config.auto_select_endpoint);
+
+        NetworkController::handle_set_auto_select_endpoint(&mut restarted, true);
+        NetworkController::handle_set_auto_switch_network(&mut restarted, false);
+        let settings = restarted.app_state.settings.clone();
+        let mut restarted = Model::with_config_dir(&dir);
+        restarted.apply_settings(settings);
+        assert!(!restarted.network_config.auto_switch);
+        assert!(restarted.network_config.auto_select_endpoint);
+    }
+
+    #[test]
+    fn unhealthy_network_stays_when_auto_switch_disabled() {
+        let dir = TestDir::new("unhealthy_network_stays_when_auto_switch_disabled");
+        let mut model = Model::with_config_dir(&dir);
+        model.network_config.set_preferred(Network::Mainnet);
+        assert!(!model.network_config.should_auto_switch());
+
+        report_unhealthy(&mut model);
+
+        assert_eq!(model.network, Network::Testnet);
+        assert_eq!(model.network_health.status, NetworkHealthStatus::Unhealthy);
+    }
+
+    #[test]
+    fn health_result_updates_the_stored_status() {
+        let dir = TestDir::new("health_result_updates_the_stored_status");
+        let mut model = Model::with_config_dir(&dir);
+        assert_eq!(model.network_health.status, NetworkHealthStatus::Unknown);
+        assert!(model.network_health.can_refresh());
+
+        model.network_health.begin_check();
+        // 检查进行中（以及防抖时间内）不会重复发起
+        assert!(!model.network_health.can_refresh());
+        model.health_sender.send((Network::Mainnet, NetworkHealthStatus::Unhealthy)).unwrap();
+        model.health_sender.send((model.network.clone(), NetworkHealthStatus::Healthy)).unwrap();
+        NetworkController::handle_health_results(&mut model);
+
+        // 其他网络的结果被忽略
+        assert_eq!(model.network_health.status, NetworkHealthStatus::Healthy);
+        assert!(!model.network_health.checking);
+        assert!(!model.network_health.can_refresh());
+    }
+
+    #[test]
+    fn unreachable_endpoint_on_startup_shows_a_warning() {
+        assert!(NetworkHealthState::should_warn_unreachable(true, NetworkHealthStatus::Unhealthy));
+        assert!(!NetworkHealthState::should_warn_unreachable(true, NetworkHealthStatus::Healthy));
+        // 手动检查的结果只由指示器显示
+        assert!(!NetworkHealthState::should_warn_unreachable(false, NetworkHealthStatus::Unhealthy));
+
+        let dir = TestDir::new("unreachable_endpoint_on_startup_shows_a_warning");
+        let mut model = Model::with_config_dir(&dir);
+        model.network_health.begin_startup_check();
+        model.health_sender.send((model.network.clone(), NetworkHealthStatus::Unhealthy)).unwrap();
+        NetworkController::handle_health_results(&mut model);
+        assert!(matches!(model.app_state.ui_state.dialog_state, DialogState::Warning(_)));
+        assert!(!model.network_health.startup_check);
+
+        // 启动检查成功时不提醒
+        let mut model = Model::with_config_dir(&dir);
+        model.network_health.begin_startup_check();
+        model.health_sender.send((model.network.clone(), NetworkHealthStatus::Healthy)).unwrap();
+        NetworkController::handle_health_results(&mut model);
+        assert_eq!(model.app_state.ui_state.dialog_state, DialogState::None);
+    }
+
+    #[test]
+    fn fallback_endpoints_are_saved_only_when_every_url_is_valid() {
+        let dir = TestDir::new("fallback_endpoints_are_saved_only_when_every_url_is_valid");
+        let mut model = Model::with_config_dir(&dir);
+        NetworkController::handle_open_rpc_fallbacks(&mut model);
+        assert_eq!(model.app_state.ui_state.dialog_state, DialogState::RpcFallbacks);
+        assert_eq!(model.app_state.ui_state.rpc_fallback_inputs.len(), Network::all().len());
+
+        let set_input = |model: &mut Model, network: Network, text: &str| {
+            let inputs = &mut model.app_state.ui_state.rpc_fallback_inputs;
+            inputs.iter_mut().find(|(n, _)| *n == network).unwrap().1 = text.to_string();
+        };
+        set_input(&mut model, Network::Testnet, "https://rpc-a.example.com\nnot a url");
+        NetworkController::handle_save_rpc_fallbacks(&mut model);
+        assert_eq!(model.status.as_ref().map(|s| s.kind), Some(StatusKind::Error));
+        assert_eq!(model.app_state.ui_state.dialog_state, DialogState::RpcFallbacks);
+        assert!(model.app_state.settings.rpc_fallbacks.testnet.is_empty());
+
+        set_input(&mut model, Network::Testnet, "https://rpc-a.example.com\n");
+        NetworkController::handle_save_rpc_fallbacks(&mut model);
+        assert_eq!(model.status.as_ref().map(|s| s.kind), Some(StatusKind::Success));
+        assert_eq!(model.app_state.ui_state.dialog_state, DialogState::None);
+        assert_eq!(
+            model.rpc_endpoints(&Network::Testnet),
+            vec![Network::Testnet.url().to_string(), "https://rpc-a.example.com".to_string()]
+        );
+    }
+}
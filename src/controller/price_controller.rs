@@ -0,0 +1,106 @@
+use crate::model::{log_error, log_warn, AmountUnit, Model, PriceFeed, StatusMessage, PRICE_REQUEST_TIMEOUT, SUI_PRICE_URL};
+use std::time::Instant;
+
+/// 价格控制器 - 查询 SUI 的美元价格，仅用于以美元输入转账金额
+pub struct PriceController;
+
+impl PriceController {
+    /// 用户选择以美元输入金额时按间隔在后台查询价格，其余时间不发出任何请求
+    pub fn handle_price_tick(model: &mut Model, now: Instant) {
+        if !Self::price_needed(model) || !model.price_feed.should_fetch(now) {
+            return;
+        }
+
+        model.price_feed.fetching = true;
+        model.price_feed.last_fetch = Some(now);
+        let sender = model.price_sender.clone();
+        model.rt.spawn(async move {
+            let result = Self::fetch_sui_price().await;
+            if let Err(e) = sender.send(result) {
+                log_error(&format!("Failed to send SUI price: {}", e));
+            }
+        });
+    }
+
+    /// 是否需要价格：已加载钱包且转账金额以美元输入
+    fn price_needed(model: &Model) -> bool {
+        model.wallet.is_loaded() && model.app_state.transfer_amount_unit == AmountUnit::Usd
+    }
+
+    /// 处理价格查询结果；查询失败时价格不可用，美元输入随之切回 SUI
+    pub fn handle_price_results(model: &mut Model) {
+        while let Ok(result) = model.price_receiver.try_recv() {
+            model.price_feed.fetching = false;
+            match result {
+                Ok(price) => model.app_state.set_sui_price(Some(price)),
+                Err(e) => {
+                    log_warn(&format!("Failed to fetch SUI price: {}", e));
+                    model.app_state.set_sui_price(None);
+                    model.set_status(StatusMessage::warning(model.i18n.tr("usd_price_unavailable")));
+                }
+            }
+        }
+    }
+
+    /// 查询 SUI 的美元价格
+    pub async fn fetch_sui_price() -> Result<f64, String> {
+        let client = reqwest::Client::builder()
+            .timeout(PRICE_REQUEST_TIMEOUT)
+            .build()
+            .map_err(|e| e.to_string())?;
+        let body: serde_json::Value = client
+            .get(SUI_PRICE_URL)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(|e| e.to_string())?
+            .json()
+            .await
+            .map_err(|e| e.to_string())?;
+        PriceFeed::parse_response(&body).ok_or_else(|| "Unexpected price response".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{TestDir, WalletState, WalletUtils};
+
+    #[test]
+    fn failed_price_fetch_switches_usd_input_back_to_sui() {
+        let dir = TestDir::new("failed_price_fetch_switches_usd_input_back_to_sui");
+        let mut model = Model::with_config_dir(&dir);
+        model.price_feed.fetching = true;
+        model.price_sender.send(Ok(2.0)).unwrap();
+        PriceController::handle_price_results(&mut model);
+        assert!(!model.price_feed.fetching);
+        model.app_state.set_transfer_amount_unit(AmountUnit::Usd);
+
+        model.price_sender.send(Err("timeout".to_string())).unwrap();
+        PriceController::handle_price_results(&mut model);
+        assert_eq!(model.app_state.sui_price_usd, None);
+        assert_eq!(model.app_state.transfer_amount_unit, AmountUnit::Sui);
+    }
+
+    #[test]
+    fn price_is_fetched_only_while_usd_input_is_selected() {
+        let dir = TestDir::new("price_is_fetched_only_while_usd_input_is_selected");
+        let mut model = Model::with_config_dir(&dir);
+        model.app_state.set_transfer_amount_unit(AmountUnit::Usd);
+        assert!(!PriceController::price_needed(&model));
+
+        // 已加载钱包但以 SUI 输入时不查询价格
+        let (address, keypair) = WalletUtils::import_private_key(
+            "suiprivkey1qqqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0jqa4ffsr",
+        )
+        .unwrap();
+        model.wallet = WalletState::new_loaded(address, keypair);
+        model.app_state.set_transfer_amount_unit(AmountUnit::Sui);
+        PriceController::handle_price_tick(&mut model, Instant::now());
+        assert!(!model.price_feed.fetching);
+        assert_eq!(model.price_feed.last_fetch, None);
+
+        model.app_state.set_transfer_amount_unit(AmountUnit::Usd);
+        assert!(PriceController::price_needed(&model));
+    }
+}
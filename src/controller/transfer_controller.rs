@@ -0,0 +1,595 @@
+use crate::model::{log_error, AsyncOperation, DialogState, Model, NetworkUtils, PanicWipeKeyPair, PasswordProtectedAction, StatusMessage, TransferEvent, TransferKind, TransferPlan, TransferStatus};
+use crate::controller::{AuthController, BalanceController, HistoryController};
+use shared_crypto::intent::{Intent, IntentMessage};
+use std::sync::mpsc::Sender;
+use std::time::Instant;
+use tokio::task::AbortHandle;
+use sui_sdk::{
+    rpc_types::{SuiExecutionStatus, SuiTransactionBlockEffectsAPI, SuiTransactionBlockResponseOptions},
+    types::{
+        base_types::SuiAddress,
+        crypto::Signature,
+        quorum_driver_types::ExecuteTransactionRequestType,
+        transaction::Transaction,
+    },
+    SuiClientBuilder,
+};
+
+/// 大额转账检查时比较的近期转账笔数
+const RECENT_TRANSFER_COUNT: usize = 10;
+
+/// 转账控制器 - 处理转账前的检查以及签名和提交
+pub struct TransferController;
+
+impl TransferController {
+    /// 处理发送按钮：冻结换算后的金额，检查发送条件后签名并提交转账
+    pub fn handle_send_transfer(model: &mut Model) {
+        model.app_state.freeze_transfer_amount();
+        Self::send_transfer(model, false);
+    }
+
+    /// 主网转账的密码确认通过后继续发送
+    pub fn handle_password_confirmed_send(model: &mut Model) {
+        Self::send_transfer(model, true);
+    }
+
+    fn send_transfer(model: &mut Model, password_confirmed: bool) {
+        if let Some(plan) = Self::checked_transfer_plan(model, password_confirmed) {
+            Self::submit_transfer(model, plan);
+        }
+    }
+
+    /// 依次检查发送条件、自转和大额确认；主网转账在提交前还需要重新输入密码
+    /// 全部通过时返回要提交的转账，否则显示对应的提示或确认对话框并返回 None
+    fn checked_transfer_plan(model: &mut Model, password_confirmed: bool) -> Option<TransferPlan> {
+        let now = Instant::now();
+        if let Some(blocker) = model.send_blocker(now) {
+            model.set_status(StatusMessage::warning(model.i18n.tr(blocker.i18n_key())));
+            return None;
+        }
+        // 签名前再检查一次输入和已知余额
+        if let Err(e) = model.precheck_transfer(now) {
+            model.set_status(StatusMessage::error(e));
+            return None;
+        }
+        // 向自己的地址转账需要用户确认
+        if model.app_state.check_self_send(model.wallet.address()).is_err() {
+            model.app_state.ui_state.show_dialog(DialogState::ConfirmSelfSend);
+            return None;
+        }
+        // 主网上占余额比例过高或远超近期转账的金额需要用户确认（防止小数点输错）
+        let recent_amounts = model.transfer_history.recent_amounts(model.network.name(), RECENT_TRANSFER_COUNT);
+        let balance_mist = model.balance_state(now).known_mist();
+        if let Err(warning) = model.app_state.check_amount_guard(&model.network, balance_mist, &recent_amounts) {
+            model.app_state.ui_state.show_dialog(DialogState::ConfirmLargeAmount(warning));
+            return None;
+        }
+
+        match model.transfer_plan() {
+            Ok(_) if model.network.is_mainnet() && !password_confirmed => {
+                AuthController::request_password_confirmation(model, PasswordProtectedAction::SendTransfer);
+                None
+            }
+            Ok(plan) => Some(plan),
+            Err(e) => {
+                model.set_status(StatusMessage::error(e));
+                None
+            }
+        }
+    }
+
+    /// 处理批量转账确认对话框中的发送按钮：所有收款人在同一笔交易中支付
+    pub fn handle_send_batch_transfer(model: &mut Model) {
+        Self::send_batch_transfer(model, false);
+    }
+
+    /// 主网批量转账的密码确认通过后继续发送
+    pub fn handle_password_confirmed_batch_send(model: &mut Model) {
+        Self::send_batch_transfer(model, true);
+    }
+
+    fn send_batch_transfer(model: &mut Model, password_confirmed: bool) {
+        if let Some(plan) = Self::checked_batch_transfer_plan(model, password_confirmed) {
+            Self::submit_transfer(model, plan);
+        }
+    }
+
+    /// 检查批量转账的发送条件，主网需要先确认密码；通过时关闭确认对话框并返回要提交的转账
+    fn checked_batch_transfer_plan(model: &mut Model, password_confirmed: bool) -> Option<TransferPlan> {
+        if let Some(blocker) = model.batch_send_blocker(Instant::now()) {
+            model.set_status(StatusMessage::warning(model.i18n.tr(blocker.i18n_key())));
+            return None;
+        }
+
+        match model.batch_transfer_plan() {
+            Ok(_) if model.network.is_mainnet() && !password_confirmed => {
+                AuthController::request_password_confirmation(model, PasswordProtectedAction::SendBatchTransfer);
+                None
+            }
+            Ok(plan) => {
+                model.app_state.ui_state.close_dialog();
+                Some(plan)
+            }
+            Err(e) => {
+                model.set_status(StatusMessage::error(e));
+                None
+            }
+        }
+    }
+
+    /// 收款地址栏内容改变：粘贴的是支付 URI 时拆出地址并预填金额，URI 无效时提示原因
+    pub fn handle_recipient_changed(model: &mut Model) {
+        if let Err(e) = model.app_state.apply_payment_uri() {
+            model.set_status(StatusMessage::error(model.i18n.tr(e.i18n_key())));
+        }
+    }
+
+    /// 用户确认向自己的地址转账后继续发送
+    pub fn handle_confirm_self_send(model: &mut Model) {
+        if Self::confirm_self_send(model) {
+            Self::send_transfer(model, false);
+        }
+    }
+
+    /// 记录用户已确认向自己的地址转账，对话框未打开时返回 false
+    fn confirm_self_send(model: &mut Model) -> bool {
+        if model.app_state.ui_state.dialog_state != DialogState::ConfirmSelfSend {
+            return false;
+        }
+        model.app_state.ui_state.close_dialog();
+        model.app_state.self_send_confirmed = true;
+        true
+    }
+
+    /// 用户核对可疑的大额转账后继续发送
+    pub fn handle_confirm_large_amount(model: &mut Model) {
+        if Self::confirm_large_amount(model) {
+            Self::send_transfer(model, false);
+        }
+    }
+
+    /// 记录用户已核对大额转账，对话框未打开时返回 false
+    fn confirm_large_amount(model: &mut Model) -> bool {
+        if !matches!(model.app_state.ui_state.dialog_state, DialogState::ConfirmLargeAmount(_)) {
+            return false;
+        }
+        model.app_state.ui_state.close_dialog();
+        model.app_state.large_amount_confirmed = true;
+        true
+    }
+
+    /// 在后台构建、签名并提交转账，进度通过 `transfer_sender` 发回 UI 线程
+    fn submit_transfer(model: &mut Model, plan: TransferPlan) {
+        let Some(sender) = model.wallet.address().copied() else {
+            return;
+        };
+        // 后台任务使用私钥的副本，副本同样登记到 panic 清除表，任务结束时释放
+        let Some(keypair) = model.wallet.with_keypair(|keypair| PanicWipeKeyPair::new(keypair.copy())) else {
+            return;
+        };
+
+        let endpoints = model.rpc_endpoints(&model.network);
+        let events = model.transfer_sender.clone();
+        let submitted = plan.clone();
+        let task = model.rt.spawn(async move {
+            let event = Self::execute_transfer(sender, &keypair, &submitted, &endpoints, &events).await;
+            Self::send_event(&events, event);
+        });
+        Self::track_submission(model, plan, task.abort_handle());
+    }
+
+    /// 记录正在提交的转账及其后台任务
+    fn track_submission(model: &mut Model, plan: TransferPlan, task: AbortHandle) {
+        model.pending_transfer = Some(plan);
+        model.operations.track(AsyncOperation::SendTransfer, task);
+        model.set_status(StatusMessage::info(model.i18n.tr("transfer_submitting")));
+    }
+
+    /// 选取代币、构建并签名交易后提交，返回最终结果
+    /// 构建交易时可以按顺序尝试备用端点；提交只使用构建交易的端点，避免同一笔交易被重复提交
+    async fn execute_transfer(
+        sender: SuiAddress,
+        keypair: &PanicWipeKeyPair,
+        plan: &TransferPlan,
+        endpoints: &[String],
+        events: &Sender<TransferEvent>,
+    ) -> TransferEvent {
+        let Some(required_mist) = plan.required_mist() else {
+            return TransferEvent::Failed { digest: None, error: "Transfer total overflows".to_string() };
+        };
+
+        let built = NetworkUtils::try_endpoints_in_order(endpoints, |url| async move {
+            let client = SuiClientBuilder::default().build(&url).await.map_err(|e| e.to_string())?;
+            let coins = client
+                .coin_read_api()
+                .select_coins(sender, None, required_mist as u128, vec![])
+                .await
+                .map_err(|e| e.to_string())?;
+            let (recipients, amounts) = plan.recipients.iter().copied().unzip();
+            let tx_data = client
+                .transaction_builder()
+                .pay_sui(
+                    sender,
+                    coins.iter().map(|coin| coin.coin_object_id).collect(),
+                    recipients,
+                    amounts,
+                    plan.gas_budget_mist,
+                )
+                .await
+                .map_err(|e| e.to_string())?;
+            Ok((client, tx_data))
+        })
+        .await;
+        let (client, tx_data) = match built {
+            Ok((_, built)) => built,
+            Err(error) => return TransferEvent::Failed { digest: None, error },
+        };
+
+        let intent_message = IntentMessage::new(Intent::sui_transaction(), tx_data.clone());
+        let Some(signature) = keypair.with(|keypair| Signature::new_secure(&intent_message, keypair)) else {
+            return TransferEvent::Failed { digest: None, error: "Private key is no longer available".to_string() };
+        };
+        let digest = tx_data.digest().to_string();
+        Self::send_event(events, TransferEvent::Submitted { digest: digest.clone() });
+
+        let response = client
+            .quorum_driver_api()
+            .execute_transaction_block(
+                Transaction::from_data(tx_data, vec![signature]),
+                SuiTransactionBlockResponseOptions::new().with_effects(),
+                Some(ExecuteTransactionRequestType::WaitForLocalExecution),
+            )
+            .await;
+        match response {
+            Ok(response) => match response.effects.map(|effects| effects.status().clone()) {
+                Some(SuiExecutionStatus::Success) => {
+                    TransferEvent::Executed { digest, status: TransferStatus::Success, error: None }
+                }
+                Some(SuiExecutionStatus::Failure { error }) => {
+                    TransferEvent::Executed { digest, status: TransferStatus::Failed, error: Some(error) }
+                }
+                None => TransferEvent::Executed { digest, status: TransferStatus::Pending, error: None },
+            },
+            Err(e) => TransferEvent::Failed { digest: Some(digest), error: e.to_string() },
+        }
+    }
+
+    /// 将转账进度发回 UI 线程，接收端已释放时只记录日志
+    fn send_event(events: &Sender<TransferEvent>, event: TransferEvent) {
+        if let Err(e) = events.send(event) {
+            log_error(&format!("Failed to send transfer result: {}", e));
+        }
+    }
+
+    /// 处理后台转账任务发回的进度
+    pub fn handle_async_results(model: &mut Model) {
+        while let Ok(event) = model.transfer_receiver.try_recv() {
+            match event {
+                TransferEvent::Submitted { digest } => {
+                    Self::record_pending_transfer(model, Some(digest.clone()), TransferStatus::Pending);
+                    let message = model.i18n.tr("transfer_submitted").replace("{}", &digest);
+                    model.set_status(StatusMessage::info(message));
+                }
+                TransferEvent::Executed { digest, status, error } => {
+                    let kind = Self::finish_transfer(model);
+                    if status != TransferStatus::Pending {
+                        HistoryController::update_transfer_status(model, &digest, status);
+                    }
+                    match (status, error) {
+                        (TransferStatus::Success, _) => {
+                            let message = model.i18n.tr("transfer_succeeded").replace("{}", &digest);
+                            model.set_status(StatusMessage::success(message));
+                            Self::clear_submitted_form(model, kind);
+                        }
+                        (TransferStatus::Pending, _) => {
+                            let message = model.i18n.tr("transfer_pending").replace("{}", &digest);
+                            model.set_status(StatusMessage::warning(message));
+                            Self::clear_submitted_form(model, kind);
+                        }
+                        (TransferStatus::Failed, error) => {
+                            let error = error.unwrap_or_default();
+                            let message = format!("{}: {}", model.i18n.tr("transfer_failed"), error);
+                            model.set_status(StatusMessage::error(message).with_detail(error));
+                        }
+                    }
+                    // 无论成功与否都已消耗 Gas，刷新余额
+                    BalanceController::handle_refresh_balance(model);
+                }
+                TransferEvent::Failed { digest, error } => {
+                    // 提交前失败的转账没有摘要，在历史中记录为失败；已提交的转账更新其状态
+                    match digest {
+                        Some(digest) => HistoryController::update_transfer_status(model, &digest, TransferStatus::Failed),
+                        None => Self::record_pending_transfer(model, None, TransferStatus::Failed),
+                    }
+                    Self::finish_transfer(model);
+                    log_error(&format!("Transfer failed: {}", error));
+                    let message = format!("{}: {}", model.i18n.tr("transfer_failed"), error);
+                    model.set_status(StatusMessage::error(message).with_detail(error));
+                }
+            }
+        }
+    }
+
+    /// 为正在提交的转账的每个收款人记录一条历史
+    fn record_pending_transfer(model: &mut Model, digest: Option<String>, status: TransferStatus) {
+        let Some(plan) = model.pending_transfer.clone() else {
+            return;
+        };
+        let note = plan.note.unwrap_or_default();
+        for (recipient, amount_mist) in plan.recipients {
+            HistoryController::record_transfer(model, recipient.to_string(), amount_mist, digest.clone(), status, &note);
+        }
+    }
+
+    /// 结束转账操作，返回转账的来源
+    fn finish_transfer(model: &mut Model) -> Option<TransferKind> {
+        model.operations.finish(AsyncOperation::SendTransfer);
+        model.pending_transfer.take().map(|plan| plan.kind)
+    }
+
+    /// 转账已提交后清空对应的输入，避免重复发送
+    fn clear_submitted_form(model: &mut Model, kind: Option<TransferKind>) {
+        match kind {
+            Some(TransferKind::Single) => model.app_state.reset_transfer_info(),
+            Some(TransferKind::Batch) => {
+                model.app_state.ui_state.batch_input.clear();
+                model.app_state.gas_budget_input.clear();
+            }
+            None => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{AmountUnit, Balance, BatchTransfer, Network, SendBlocker, StatusKind, TestDir, WalletState, WalletUtils, MIST_PER_SUI};
+    use crate::view::ViewAction;
+    use std::path::Path;
+
+    const TEST_KEY: &str = "suiprivkey1qqqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0jqa4ffsr";
+    const RECIPIENT: &str = "0x0000000000000000000000000000000000000000000000000000000000000a11";
+
+    /// 已加载钱包、余额 2 SUI、向有效地址转账 1 SUI 的模型
+    fn ready_model(dir: &Path) -> Model {
+        let mut model = Model::with_config_dir(dir);
+        let (address, keypair) = WalletUtils::import_private_key(TEST_KEY).unwrap();
+        model.wallet = WalletState::new_loaded(address, keypair);
+        model.balance = Some(Balance::from_mist(2 * MIST_PER_SUI));
+        model.balance_updated_at = Some(Instant::now());
+        model.app_state.recipient_address = RECIPIENT.to_string();
+        model.app_state.transfer_amount = "1".to_string();
+        model
+    }
+
+    #[test]
+    fn blocked_send_does_not_submit() {
+        let dir = TestDir::new("blocked_send_does_not_submit");
+        let mut model = ready_model(&dir);
+        model.app_state.transfer_amount = "5".to_string();
+        TransferController::handle_send_transfer(&mut model);
+        assert!(!model.operations.is_running(AsyncOperation::SendTransfer));
+        assert!(model.pending_transfer.is_none());
+        assert_eq!(model.status.map(|s| s.kind), Some(StatusKind::Warning));
+    }
+
+    /// 像 `submit_transfer` 一样记录提交，但后台任务不连接网络也不签名
+    fn track_without_sending(model: &mut Model, plan: TransferPlan) {
+        let task = model.rt.spawn(std::future::pending::<()>());
+        TransferController::track_submission(model, plan, task.abort_handle());
+    }
+
+    #[test]
+    fn send_starts_the_transfer_operation() {
+        let dir = TestDir::new("send_starts_the_transfer_operation");
+        let mut model = ready_model(&dir);
+        let plan = TransferController::checked_transfer_plan(&mut model, false).unwrap();
+        track_without_sending(&mut model, plan);
+        assert!(model.operations.is_running(AsyncOperation::SendTransfer));
+        assert!(model.operations.blocks_wallet_actions());
+        let plan = model.pending_transfer.clone().unwrap();
+        assert_eq!(plan.total_amount_mist(), Some(MIST_PER_SUI));
+        assert_eq!(model.status.as_ref().map(|s| s.kind), Some(StatusKind::Info));
+
+        // 已提交的转账不能取消
+        assert!(!model.cancel_operation(AsyncOperation::SendTransfer));
+        assert!(model.operations.is_running(AsyncOperation::SendTransfer));
+    }
+
+    /// 模拟正在提交表单中的转账
+    fn pending_transfer(model: &mut Model) {
+        model.pending_transfer = Some(model.transfer_plan().unwrap());
+        let task = model.rt.spawn(std::future::pending::<()>());
+        model.operations.track(AsyncOperation::SendTransfer, task.abort_handle());
+    }
+
+    #[test]
+    fn logout_still_works_while_a_send_is_in_flight() {
+        let dir = TestDir::new("logout_still_works_while_a_send_is_in_flight");
+        let mut model = ready_model(&dir);
+        model.auth_state.is_authenticated = true;
+        pending_transfer(&mut model);
+        assert!(model.operations.blocks_wallet_actions());
+
+        // 登出不会与转账冲突，不会被进行中的转账拦下
+        assert!(!ViewAction::Logout.is_wallet_mutation());
+        AuthController::handle_logout(&mut model);
+        assert!(!model.auth_state.is_authenticated);
+        assert!(!model.wallet.is_loaded());
+    }
+
+    #[test]
+    fn usd_amount_is_converted_once_when_send_is_pressed() {
+        let dir = TestDir::new("usd_amount_is_converted_once_when_send_is_pressed");
+        let mut model = ready_model(&dir);
+        model.app_state.recipient_address = model.wallet.address().unwrap().to_string();
+        model.app_state.set_sui_price(Some(2.0));
+        model.app_state.set_transfer_amount_unit(AmountUnit::Usd);
+        model.app_state.transfer_amount = "2".to_string();
+
+        // 向自己转账需要先确认，确认期间价格更新不影响提交的金额
+        TransferController::handle_send_transfer(&mut model);
+        assert_eq!(model.app_state.ui_state.dialog_state, DialogState::ConfirmSelfSend);
+        model.app_state.set_sui_price(Some(4.0));
+        assert!(TransferController::confirm_self_send(&mut model));
+        let plan = TransferController::checked_transfer_plan(&mut model, false).unwrap();
+        assert_eq!(plan.total_amount_mist(), Some(MIST_PER_SUI));
+
+        // 修改金额后按最新价格重新换算
+        model.app_state.transfer_amount = "2.0".to_string();
+        assert_eq!(model.app_state.transfer_amount_mist(), Ok(MIST_PER_SUI / 2));
+    }
+
+    #[test]
+    fn pasted_payment_uri_fills_the_form() {
+        let dir = TestDir::new("pasted_payment_uri_fills_the_form");
+        let mut model = ready_model(&dir);
+        model.app_state.recipient_address = format!("sui:{}?amount=1500000000", RECIPIENT);
+        TransferController::handle_recipient_changed(&mut model);
+        assert_eq!(model.app_state.normalized_recipient(), RECIPIENT);
+        assert_eq!(model.app_state.transfer_amount_mist(), Ok(MIST_PER_SUI * 3 / 2));
+        assert!(model.status.as_ref().is_none_or(|s| s.kind != StatusKind::Error));
+
+        model.app_state.recipient_address = "sui:0x123?amount=1".to_string();
+        TransferController::handle_recipient_changed(&mut model);
+        assert_eq!(model.app_state.recipient_address, "sui:0x123?amount=1");
+        let status = model.status.unwrap();
+        assert_eq!(status.kind, StatusKind::Error);
+        assert_eq!(status.text, model.i18n.tr("payment_uri_invalid_address"));
+    }
+
+    #[test]
+    fn mainnet_send_requires_the_password() {
+        let dir = TestDir::new("mainnet_send_requires_the_password");
+        let mut model = ready_model(&dir);
+        model.network = Network::Mainnet;
+        model.app_state.large_amount_confirmed = true;
+
+        TransferController::handle_send_transfer(&mut model);
+        let dialog = DialogState::ConfirmPassword(PasswordProtectedAction::SendTransfer);
+        assert_eq!(model.app_state.ui_state.dialog_state, dialog);
+        assert!(!model.operations.is_running(AsyncOperation::SendTransfer));
+
+        // 确认密码后的检查与 handle_password_confirmed_send 相同
+        assert!(TransferController::checked_transfer_plan(&mut model, true).is_some());
+    }
+
+    #[test]
+    fn sending_to_yourself_requires_confirmation() {
+        let dir = TestDir::new("sending_to_yourself_requires_confirmation");
+        let mut model = ready_model(&dir);
+        model.app_state.recipient_address = model.wallet.address().unwrap().to_string();
+
+        TransferController::handle_send_transfer(&mut model);
+        assert_eq!(model.app_state.ui_state.dialog_state, DialogState::ConfirmSelfSend);
+        assert!(!model.operations.is_running(AsyncOperation::SendTransfer));
+
+        assert!(TransferController::confirm_self_send(&mut model));
+        assert_eq!(model.app_state.ui_state.dialog_state, DialogState::None);
+        assert!(TransferController::checked_transfer_plan(&mut model, false).is_some());
+    }
+
+    #[test]
+    fn large_mainnet_amount_requires_confirmation() {
+        let dir = TestDir::new("large_mainnet_amount_requires_confirmation");
+        let mut model = ready_model(&dir);
+        model.network = Network::Mainnet;
+        model.app_state.transfer_amount = "1.9".to_string();
+
+        TransferController::handle_send_transfer(&mut model);
+        assert!(matches!(model.app_state.ui_state.dialog_state, DialogState::ConfirmLargeAmount(_)));
+        assert!(!model.operations.is_running(AsyncOperation::SendTransfer));
+
+        // 核对金额后主网转账还需要重新输入密码
+        assert!(TransferController::confirm_large_amount(&mut model));
+        assert!(TransferController::checked_transfer_plan(&mut model, false).is_none());
+        let dialog = DialogState::ConfirmPassword(PasswordProtectedAction::SendTransfer);
+        assert_eq!(model.app_state.ui_state.dialog_state, dialog);
+        assert!(TransferController::checked_transfer_plan(&mut model, true).is_some());
+    }
+
+    #[test]
+    fn batch_send_pays_every_row_in_one_transaction() {
+        let dir = TestDir::new("batch_send_pays_every_row_in_one_transaction");
+        let mut model = ready_model(&dir);
+        model.app_state.ui_state.batch_input = format!("{},0.5\n{},0.25", RECIPIENT, RECIPIENT);
+        model.app_state.ui_state.show_dialog(DialogState::ConfirmBatchTransfer);
+
+        let plan = TransferController::checked_batch_transfer_plan(&mut model, false).unwrap();
+        assert_eq!(model.app_state.ui_state.dialog_state, DialogState::None);
+        assert_eq!(plan.kind, TransferKind::Batch);
+        assert_eq!(plan.recipients.len(), 2);
+        assert_eq!(plan.total_amount_mist(), Some(MIST_PER_SUI * 3 / 4));
+
+        // 提交的 Gas 预算与摘要和确认对话框显示的数值一致（包括手动预算）
+        let batch = BatchTransfer::parse_csv(&model.app_state.ui_state.batch_input);
+        assert_eq!(Ok(plan.gas_budget_mist), model.batch_gas_budget(&batch));
+        model.app_state.ui_state.show_advanced_options = true;
+        model.app_state.gas_budget_input = "5000000".to_string();
+        let plan = TransferController::checked_batch_transfer_plan(&mut model, false).unwrap();
+        assert_eq!(plan.gas_budget_mist, 5_000_000);
+        assert_eq!(model.batch_gas_budget(&batch), Ok(5_000_000));
+    }
+
+    #[test]
+    fn batch_send_is_blocked_when_the_total_exceeds_the_balance() {
+        let dir = TestDir::new("batch_send_is_blocked_when_the_total_exceeds_the_balance");
+        let mut model = ready_model(&dir);
+        model.app_state.ui_state.batch_input = format!("{},1.5\n{},0.5", RECIPIENT, RECIPIENT);
+        assert_eq!(model.batch_send_blocker(Instant::now()), Some(SendBlocker::ExceedsSpendable));
+
+        model.app_state.ui_state.batch_input = "not an address,1".to_string();
+        assert_eq!(model.batch_send_blocker(Instant::now()), Some(SendBlocker::InvalidBatch));
+        TransferController::handle_send_batch_transfer(&mut model);
+        assert!(!model.operations.is_running(AsyncOperation::SendTransfer));
+    }
+
+    #[test]
+    fn successful_send_clears_the_form() {
+        let dir = TestDir::new("successful_send_clears_the_form");
+        let mut model = ready_model(&dir);
+        model.app_state.transfer_note = "Rent for March".to_string();
+        pending_transfer(&mut model);
+        model.transfer_sender.send(TransferEvent::Submitted { digest: "digest".to_string() }).unwrap();
+        model.transfer_sender.send(TransferEvent::Executed {
+            digest: "digest".to_string(),
+            status: TransferStatus::Success,
+            error: None,
+        }).unwrap();
+        TransferController::handle_async_results(&mut model);
+        assert!(!model.operations.is_running(AsyncOperation::SendTransfer));
+        assert!(model.pending_transfer.is_none());
+        assert_eq!(model.status.as_ref().map(|s| s.kind), Some(StatusKind::Success));
+        assert!(model.app_state.recipient_address.is_empty());
+        assert!(model.app_state.transfer_note.is_empty());
+
+        // 提交时记录到本地历史（附带备注），执行结果更新其状态
+        let records = model.transfer_history.records();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].recipient, RECIPIENT);
+        assert_eq!(records[0].amount_mist, MIST_PER_SUI);
+        assert_eq!(records[0].digest.as_deref(), Some("digest"));
+        assert_eq!(records[0].status, TransferStatus::Success);
+        assert_eq!(model.transfer_history.note_for("digest"), Some("Rent for March"));
+    }
+
+    #[test]
+    fn failed_send_keeps_the_form() {
+        let dir = TestDir::new("failed_send_keeps_the_form");
+        let mut model = ready_model(&dir);
+        pending_transfer(&mut model);
+        model.transfer_sender.send(TransferEvent::Failed { digest: None, error: "Insufficient gas".to_string() }).unwrap();
+        TransferController::handle_async_results(&mut model);
+        assert!(!model.operations.is_running(AsyncOperation::SendTransfer));
+        let status = model.status.unwrap();
+        assert_eq!(status.kind, StatusKind::Error);
+        assert_eq!(status.copy_text(), "Insufficient gas");
+        assert_eq!(model.app_state.recipient_address, RECIPIENT);
+
+        // 提交前失败的转账没有摘要，记录为失败
+        let records = model.transfer_history.records();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].digest, None);
+        assert_eq!(records[0].status, TransferStatus::Failed);
+    }
+}
@@ -1,5 +1,11 @@
-use crate::model::{Model, WalletState};
+use crate::model::{
+    log_error, log_info, wipe_secret, AddressExport, CopyKind, DialogState, KeyFileContents, MnemonicPreview, MnemonicUtils, Model, OwnershipCheck, OwnershipProof, PendingKeyFile,
+    StatusMessage, WalletState, WalletUtils, MAX_KEY_FILE_BYTES, MNEMONIC_PREVIEW_ACCOUNTS,
+};
 use crate::controller::BalanceController;
+use std::fs;
+use std::path::{Path, PathBuf};
+use zeroize::Zeroizing;
 use sui_sdk::{
     types::{base_types::SuiAddress, crypto::SuiKeyPair},
 };
@@ -10,74 +16,276 @@ pub struct WalletController;
 impl WalletController {
     /// 处理私钥导入逻辑
     pub fn handle_import_key(model: &mut Model) {
-        if let WalletState::NoWallet { private_key_input } = &model.wallet {
-            let trimmed_input = private_key_input.trim().to_string();
-
-            // 1. 尝试使用 `decode` 解析 Bech32 格式 (suiprivkey1...)
-            // 2. 如果失败，则回退尝试使用 `decode_base64` 解析 Base64 格式
-            let keypair_result = SuiKeyPair::decode(&trimmed_input);
-
-            match keypair_result {
-                Ok(keypair) => {
-                    let address: SuiAddress = (&keypair.public()).into();
-                    model.wallet = WalletState::Loaded { address, keypair };
-                    model.result_text = format!("{}: {}", model.i18n.tr("wallet_imported_success"), address);
+        // 临时会话中的导入一律不保存
+        let persist = !model.auth_state.temporary_session;
+        Self::import_key(model, persist);
+    }
+
+    /// 临时导入私钥：只加载到内存，无论是否登录、是否启用自动保存都不写入磁盘，登出时清除
+    pub fn handle_import_key_temporarily(model: &mut Model) {
+        Self::import_key(model, false);
+    }
+
+    /// 导入输入框中的私钥，`persist` 为 false 时不保存加密私钥
+    fn import_key(model: &mut Model, persist: bool) {
+        // 已加载钱包时不直接替换，先请求用户确认，避免意外丢失当前私钥
+        if model.wallet.is_loaded() {
+            model.app_state.ui_state.show_dialog(DialogState::ConfirmReplaceWallet);
+            return;
+        }
+
+        if let Some(private_key_input) = model.wallet.private_key_input() {
+            // 去除空白、零宽和控制字符，防止粘贴的内容干扰解析
+            let trimmed_input = Zeroizing::new(WalletUtils::normalize_input(&private_key_input));
+            // 先检查结构给出具体错误，再解码（支持 Ed25519、Secp256k1、Secp256r1）
+            match WalletUtils::import_private_key(&trimmed_input) {
+                Ok((address, keypair)) => {
+                    model.wallet.wipe_private_key_input(model.auth_state.memory_protection);
+                    model.wallet = WalletState::new_loaded(address, keypair);
+                    let success_key = if persist { "wallet_imported_success" } else { "wallet_imported_temporarily" };
+                    model.set_status(StatusMessage::success(format!("{}: {}", model.i18n.tr(success_key), address)));
                     
-                    // 如果用户已认证，自动保存加密的私钥
-                    if model.auth_state.is_authenticated {
-                        if let Some(password) = model.auth_state.get_session_password() {
-                            if let Err(e) = model.auth_state.save_encrypted_private_key(&trimmed_input, password) {
-                                eprintln!("Failed to save encrypted private key: {}", e);
-                                // 不影响导入流程，只记录错误
-                            } else {
-                                println!("Private key saved successfully");
-                            }
+                    // 如果用户已认证且启用了自动保存，保存加密的私钥
+                    if persist && model.auth_state.is_authenticated && model.app_state.settings.security_settings.auto_save_keys
+                        && let Some(password) = model.auth_state.get_session_password()
+                    {
+                        if let Err(e) = model.auth_state.save_encrypted_private_key(&trimmed_input, &password) {
+                            log_error(&format!("Failed to save encrypted private key: {}", e));
+                            // 钱包已加载，但需提醒用户私钥没有保存，下次需要重新导入
+                            let message = model.i18n.tr("wallet_save_failed_warning").replace("{}", &e);
+                            model.set_status(StatusMessage::warning(message).with_detail(e));
+                        } else {
+                            log_info("Private key saved successfully");
                         }
                     }
                     
                     // 导入成功后自动刷新余额
                     BalanceController::handle_refresh_balance(model);
                 }
-                Err(_) => {
-                    model.result_text = model.i18n.tr("import_private_key_failed");
+                Err(e) => {
+                    model.set_status(StatusMessage::error(e.message(&model.i18n)));
                 }
             }
         }
     }
 
-    /// 处理私钥导入并保存
-    pub fn handle_import_and_save_key(model: &mut Model, password: &str) {
-        if let WalletState::NoWallet { private_key_input } = &model.wallet {
-            let trimmed_input = private_key_input.trim().to_string();
+    /// 预览输入的助记词对应的前几个账户，并查询它们在当前网络上的余额
+    pub fn handle_preview_mnemonic(model: &mut Model) {
+        let Some(phrase) = model.wallet.private_key_input() else {
+            return;
+        };
 
-            // 1. 尝试使用 `decode` 解析 Bech32 格式 (suiprivkey1...)
-            // 2. 如果失败，则回退尝试使用 `decode_base64` 解析 Base64 格式
-            let keypair_result = SuiKeyPair::decode(&trimmed_input);
+        match MnemonicUtils::derive_addresses(&phrase, MNEMONIC_PREVIEW_ACCOUNTS) {
+            Ok(addresses) => {
+                model.mnemonic_preview = Some(MnemonicPreview::new(model.network.clone(), addresses));
+                BalanceController::handle_fetch_preview_balances(model);
+            }
+            Err(e) => {
+                model.clear_mnemonic_preview();
+                model.set_status(StatusMessage::error(format!("{}: {}", model.i18n.tr("mnemonic_invalid"), e)));
+            }
+        }
+    }
 
-            match keypair_result {
-                Ok(keypair) => {
-                    let address: SuiAddress = (&keypair.public()).into();
-                    model.wallet = WalletState::Loaded { address, keypair };
-                    model.result_text = format!("{}: {}", model.i18n.tr("wallet_imported_success"), address);
-                    
-                    // 自动保存加密的私钥（如果用户已认证）
-                    if model.auth_state.is_authenticated {
-                        if let Err(e) = model.auth_state.save_encrypted_private_key(&trimmed_input, password) {
-                            eprintln!("Failed to save encrypted private key: {}", e);
-                            // 不影响导入流程，只记录错误
-                        } else {
-                            println!("Private key saved successfully");
-                        }
-                    }
-                    
-                    // 导入成功后自动刷新余额
-                    BalanceController::handle_refresh_balance(model);
+    /// 导入助记词预览中选中的账户：派生私钥后走与手动输入相同的导入流程
+    /// 派生出的地址必须与用户在预览中看到的地址一致，否则拒绝导入
+    pub fn handle_import_mnemonic_account(model: &mut Model, account: u32) {
+        let Some(phrase) = model.wallet.private_key_input() else {
+            return;
+        };
+        let Some(previewed) = model.mnemonic_preview.as_ref().and_then(|preview| preview.address(account)) else {
+            model.set_status(StatusMessage::error(model.i18n.tr("mnemonic_preview_stale")));
+            return;
+        };
+
+        let derived = MnemonicUtils::derive_account(&phrase, account);
+        if matches!(&derived, Ok((address, _)) if *address != previewed) {
+            model.clear_mnemonic_preview();
+            model.set_status(StatusMessage::error(model.i18n.tr("mnemonic_preview_stale")));
+            return;
+        }
+
+        let encoded = derived.and_then(|(_, keypair)| keypair.encode().map(Zeroizing::new).map_err(|e| e.to_string()));
+        match encoded {
+            Ok(mut key) => {
+                model.clear_mnemonic_preview();
+                model.wallet.wipe_private_key_input(model.auth_state.memory_protection);
+                // 直接移交编码后的私钥，不另外复制
+                Self::set_private_key_input(model, std::mem::take(&mut *key));
+                Self::handle_import_key(model);
+            }
+            Err(e) => {
+                log_error(&format!("Failed to derive account from recovery phrase: {}", e));
+                model.set_status(StatusMessage::error(format!("{}: {}", model.i18n.tr("mnemonic_invalid"), e)));
+            }
+        }
+    }
+
+    /// 处理拖放到窗口上的文件：识别为 keystore 或单个私钥时请求用户确认，不会直接导入
+    pub fn handle_file_dropped(model: &mut Model, name: String, path: Option<PathBuf>, bytes: Option<Vec<u8>>) {
+        let contents = match (path, bytes) {
+            (Some(path), _) => Self::read_key_file(&path),
+            (None, Some(bytes)) if bytes.len() as u64 <= MAX_KEY_FILE_BYTES => {
+                String::from_utf8(bytes).map_err(|e| e.to_string())
+            }
+            (None, Some(_)) => Err(model.i18n.tr("key_file_too_large")),
+            (None, None) => Err("Dropped file has no path or contents".to_string()),
+        };
+
+        let mut contents = match contents {
+            Ok(contents) => contents,
+            Err(e) => {
+                log_error(&format!("Failed to read dropped file: {}", e));
+                model.set_status(StatusMessage::error(format!("{}: {}", model.i18n.tr("key_file_read_error"), e)));
+                return;
+            }
+        };
+        let classified = WalletUtils::classify_key_file(&contents);
+        wipe_secret(&mut contents, model.auth_state.memory_protection);
+
+        if classified == KeyFileContents::Unknown {
+            model.set_status(StatusMessage::error(model.i18n.tr("key_file_unrecognized")));
+            return;
+        }
+        model.app_state.ui_state.pending_key_file = Some(PendingKeyFile {
+            file_name: name,
+            contents: classified,
+            selected: 0,
+        });
+        model.app_state.ui_state.show_dialog(DialogState::ConfirmKeyFileImport);
+    }
+
+    /// 读取拖放的文件，拒绝过大的文件
+    fn read_key_file(path: &Path) -> Result<String, String> {
+        let size = fs::metadata(path).map_err(|e| e.to_string())?.len();
+        if size > MAX_KEY_FILE_BYTES {
+            return Err(format!("File is larger than {} bytes", MAX_KEY_FILE_BYTES));
+        }
+        fs::read_to_string(path).map_err(|e| e.to_string())
+    }
+
+    /// 用户确认导入拖放文件中选中的私钥，走与手动输入相同的校验和导入流程
+    pub fn handle_confirm_key_file_import(model: &mut Model) {
+        model.app_state.ui_state.close_dialog();
+        let Some(mut pending) = model.app_state.ui_state.pending_key_file.take() else {
+            return;
+        };
+
+        if let Some(mut key) = pending.take_selected_key() {
+            // 先解码，私钥无效时保留当前钱包，不替换
+            match WalletUtils::import_private_key(&key) {
+                Ok(_) => {
+                    // 对话框中已提示会替换当前钱包，确认即视为同意替换
+                    Self::clear_wallet(model);
+                    Self::set_private_key_input(model, key);
+                    Self::handle_import_key(model);
                 }
-                Err(_) => {
-                    model.result_text = model.i18n.tr("import_private_key_failed");
+                Err(e) => {
+                    wipe_secret(&mut key, model.auth_state.memory_protection);
+                    model.set_status(StatusMessage::error(e.message(&model.i18n)));
                 }
             }
         }
+        pending.wipe(model.auth_state.memory_protection);
+    }
+
+    /// 取消导入拖放的文件，清除其中的私钥
+    pub fn handle_cancel_key_file_import(model: &mut Model) {
+        model.app_state.ui_state.close_dialog();
+        if let Some(mut pending) = model.app_state.ui_state.pending_key_file.take() {
+            pending.wipe(model.auth_state.memory_protection);
+        }
+    }
+
+    /// 地址所有权自检：用已加载的私钥签名随机挑战，验证签名对应当前地址
+    pub fn handle_verify_ownership(model: &mut Model) {
+        let Some(&address) = model.wallet.address() else {
+            return;
+        };
+        let Some(verified) = model.wallet.with_keypair(|keypair| OwnershipProof::check(keypair, address)) else {
+            return;
+        };
+        model.ownership_check = Some(OwnershipCheck { address, verified });
+        if !verified {
+            model.set_status(StatusMessage::error(model.i18n.tr("ownership_mismatch")));
+        }
+    }
+
+    /// 导出账户地址列表（标签 + 地址）到用户指定的文件，不包含任何私钥数据
+    pub fn handle_export_addresses(model: &mut Model) {
+        let ui_state = &model.app_state.ui_state;
+        let path = match AddressExport::validate_path(&ui_state.export_path_input) {
+            Ok(path) => path,
+            Err(e) => {
+                model.set_status(StatusMessage::error(model.i18n.tr(e.i18n_key())));
+                return;
+            }
+        };
+
+        let entries = model.wallet.address_entries();
+        let result = AddressExport::render(&entries, ui_state.export_format)
+            .and_then(|content| fs::write(&path, content).map_err(|e| e.to_string()));
+        match result {
+            Ok(()) => {
+                let last_used = &mut model.app_state.settings.last_used;
+                last_used.export_format = Some(model.app_state.ui_state.export_format);
+                last_used.export_path = Some(path.to_string_lossy().into_owned());
+                model.app_state.ui_state.close_dialog();
+                model.set_status(StatusMessage::success(format!(
+                    "{}: {}",
+                    model.i18n.tr("addresses_exported"),
+                    path.display()
+                )));
+            }
+            Err(e) => {
+                log_error(&format!("Failed to export addresses: {}", e));
+                model.set_status(StatusMessage::error(format!("{}: {}", model.i18n.tr("export_addresses_failed"), e)));
+            }
+        }
+    }
+
+    /// 处理复制私钥请求：需要时先提醒剪贴板历史风险
+    pub fn handle_copy_private_key(model: &mut Model) {
+        if !model.wallet.is_loaded() {
+            return;
+        }
+
+        if model.app_state.settings.should_warn_before_copy(CopyKind::PrivateKey) {
+            model.app_state.ui_state.dont_warn_copy_again = false;
+            model.app_state.ui_state.show_dialog(DialogState::ClipboardWarning);
+        } else {
+            Self::copy_private_key(model);
+        }
+    }
+
+    /// 用户确认剪贴板警告后复制私钥
+    pub fn handle_confirm_copy_private_key(model: &mut Model, dont_warn_again: bool) {
+        if dont_warn_again {
+            model.app_state.settings.dismiss_copy_warning();
+        }
+        model.app_state.ui_state.close_dialog();
+        Self::copy_private_key(model);
+    }
+
+    /// 将私钥（Bech32 格式）交给视图写入剪贴板
+    fn copy_private_key(model: &mut Model) {
+        let Some(encoded) = model.wallet.with_keypair(|keypair| keypair.encode().map(Zeroizing::new)) else {
+            return;
+        };
+        match encoded {
+            Ok(encoded) => {
+                model.app_state.ui_state.pending_copy = Some(encoded);
+                model.set_status(StatusMessage::success(model.i18n.tr("private_key_copied")));
+            }
+            Err(e) => log_error(&format!("Failed to encode private key: {}", e)),
+        }
+    }
+
+    /// 确认替换当前钱包：卸载当前钱包并回到导入表单
+    pub fn handle_confirm_replace_wallet(model: &mut Model) {
+        model.app_state.ui_state.close_dialog();
+        Self::clear_wallet(model);
+        model.set_status(StatusMessage::info(model.i18n.tr("import_private_key_message")));
     }
 
     /// 尝试从加密存储加载私钥
@@ -89,8 +297,9 @@ impl WalletController {
                 match keypair_result {
                     Ok(keypair) => {
                         let address: SuiAddress = (&keypair.public()).into();
-                        model.wallet = WalletState::Loaded { address, keypair };
-                        model.result_text = format!("{}: {}", model.i18n.tr("wallet_loaded_from_storage"), address);
+                        model.wallet = WalletState::new_loaded(address, keypair);
+                        model.wallet.set_label(model.auth_state.load_account_label());
+                        model.set_status(StatusMessage::success(format!("{}: {}", model.i18n.tr("wallet_loaded_from_storage"), address)));
                         
                         // 自动刷新余额
                         BalanceController::handle_refresh_balance(model);
@@ -105,50 +314,274 @@ impl WalletController {
         }
     }
 
-    /// 获取当前钱包地址
-    pub fn get_wallet_address(model: &Model) -> Option<SuiAddress> {
-        if let WalletState::Loaded { address, .. } = &model.wallet {
-            Some(*address)
-        } else {
-            None
+    /// 切换到第 `index` 个账户并刷新余额，超出账户数量时忽略
+    /// 目前钱包一次只加载一个账户，因此只有当前账户（索引 0）有效
+    pub fn handle_select_account(model: &mut Model, index: usize) {
+        if index >= model.wallet.account_count() {
+            return;
         }
+        BalanceController::handle_refresh_balance(model);
     }
 
-    /// 检查钱包是否已加载
-    pub fn is_wallet_loaded(model: &Model) -> bool {
-        matches!(model.wallet, WalletState::Loaded { .. })
+    /// 切换"按网络区分账户"设置
+    pub fn handle_set_separate_accounts(model: &mut Model, enabled: bool) {
+        model.app_state.settings.separate_accounts_per_network = enabled;
+        Self::handle_account_scope_change(model);
     }
 
-    /// 获取私钥输入
-    pub fn get_private_key_input(model: &Model) -> String {
-        if let WalletState::NoWallet { private_key_input } = &model.wallet {
-            private_key_input.clone()
-        } else {
-            String::new()
+    /// 网络或账户设置变化后切换到对应的账户
+    /// 范围变化时卸载当前钱包，已登录则加载新范围下保存的私钥，没有保存的私钥时提示为该网络导入
+    pub fn handle_account_scope_change(model: &mut Model) {
+        let scope = model.account_scope();
+        if !model.auth_state.set_account_scope(&scope) {
+            return;
+        }
+
+        Self::clear_wallet(model);
+        let Some(password) = model.auth_state.get_session_password() else {
+            return;
+        };
+        match Self::try_load_saved_key(model, &password) {
+            Ok(true) => {}
+            Ok(false) => {
+                let message = model.i18n.tr("import_for_network").replace("{}", model.network.name());
+                model.set_status(StatusMessage::info(message));
+            }
+            Err(e) => {
+                log_error(&format!("Failed to load account for network: {}", e));
+                model.set_status(StatusMessage::error(e));
+            }
         }
     }
 
+    /// 处理账户重命名，并将标签与加密私钥一同保存
+    pub fn handle_rename_account(model: &mut Model, label: String) {
+        if !model.wallet.is_loaded() {
+            return;
+        }
+
+        model.wallet.set_label(Some(label));
+        let result = match model.wallet.label() {
+            Some(label) => model.auth_state.save_account_label(label),
+            None => model.auth_state.delete_account_label(),
+        };
+        if let Err(e) = result {
+            log_error(&format!("Failed to save account label: {}", e));
+        }
+    }
+
+    /// 检查钱包是否已加载
+    pub fn is_wallet_loaded(model: &Model) -> bool {
+        matches!(model.wallet, WalletState::Loaded { .. })
+    }
+
     /// 设置私钥输入
     pub fn set_private_key_input(model: &mut Model, input: String) {
-        if let WalletState::NoWallet { private_key_input } = &mut model.wallet {
-            *private_key_input = input;
-        }
+        model.wallet.set_private_key_input(input);
     }
 
     /// 清除钱包数据
     pub fn clear_wallet(model: &mut Model) {
-        model.wallet = WalletState::NoWallet {
-            private_key_input: String::new(),
-        };
+        model.wallet = WalletState::new_no_wallet();
+        model.clear_mnemonic_preview();
+        model.reset_balance();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{StatusKind, TestDir};
+    use std::path::Path;
+
+    /// 测试用 Ed25519 私钥（字节 0x01..=0x20）
+    const TEST_KEY: &str = "suiprivkey1qqqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0jqa4ffsr";
+
+    fn model_with_input(dir: &Path, input: &str) -> Model {
+        let mut model = Model::with_config_dir(dir);
+        model.wallet.set_private_key_input(input.to_string());
+        model
     }
 
-    /// 验证私钥格式
-    pub fn validate_private_key(private_key: &str) -> bool {
-        let trimmed = private_key.trim();
-        !trimmed.is_empty() && (
-            trimmed.starts_with("suiprivkey1") || // Bech32 format
-            trimmed.len() == 44 || // Base64 format (typical length)
-            trimmed.len() == 64    // Hex format
-        )
+    #[test]
+    fn invalid_key_import_sets_error() {
+        let dir = TestDir::new("invalid_key_import_sets_error");
+        let mut model = model_with_input(&dir, "not-a-key");
+        WalletController::handle_import_key(&mut model);
+        assert_eq!(model.status.map(|s| s.kind), Some(StatusKind::Error));
+        assert!(!model.wallet.is_loaded());
+    }
+
+    #[test]
+    fn valid_key_import_sets_success() {
+        let dir = TestDir::new("valid_key_import_sets_success");
+        let mut model = model_with_input(&dir, TEST_KEY);
+        WalletController::handle_import_key(&mut model);
+        assert_eq!(model.status.map(|s| s.kind), Some(StatusKind::Success));
+        assert!(model.wallet.is_loaded());
+    }
+
+    #[test]
+    fn temporary_import_loads_wallet_without_saving() {
+        let dir = TestDir::new("temporary_import");
+        let mut model = model_with_input(&dir, TEST_KEY);
+        model.auth_state.is_authenticated = true;
+        model.auth_state.set_session_password("correct horse".to_string());
+        model.app_state.settings.security_settings.auto_save_keys = true;
+
+        WalletController::handle_import_key_temporarily(&mut model);
+        assert_eq!(model.status.as_ref().map(|s| s.kind), Some(StatusKind::Success));
+        assert!(model.wallet.is_loaded());
+        assert!(!model.auth_state.encrypted_private_key_file.exists());
+
+        // 登出后临时钱包被清除
+        crate::controller::AuthController::handle_logout(&mut model);
+        assert!(!model.wallet.is_loaded());
+    }
+
+    #[test]
+    fn confirming_replace_wallet_sets_info() {
+        let dir = TestDir::new("confirming_replace_wallet_sets_info");
+        let mut model = model_with_input(&dir, TEST_KEY);
+        WalletController::handle_import_key(&mut model);
+        WalletController::handle_confirm_replace_wallet(&mut model);
+        assert_eq!(model.status.map(|s| s.kind), Some(StatusKind::Info));
+        assert!(!model.wallet.is_loaded());
+    }
+
+    #[test]
+    fn import_does_not_save_key_when_auto_save_is_disabled() {
+        let dir = TestDir::new("auto_save");
+        let mut model = model_with_input(&dir, TEST_KEY);
+        model.auth_state.is_authenticated = true;
+        model.auth_state.set_session_password("hunter2".to_string());
+        model.app_state.settings.security_settings.auto_save_keys = false;
+
+        WalletController::handle_import_key(&mut model);
+
+        assert!(model.wallet.is_loaded());
+        assert!(!model.auth_state.encrypted_private_key_file.exists());
+    }
+
+    #[test]
+    fn undecodable_dropped_key_keeps_the_current_wallet() {
+        let dir = TestDir::new("undecodable_dropped_key");
+        let mut model = model_with_input(&dir, TEST_KEY);
+        WalletController::handle_import_key(&mut model);
+        let address = *model.wallet.address().unwrap();
+
+        // 结构检查能通过但校验和错误的私钥
+        let corrupted = format!("{}q", &TEST_KEY[..TEST_KEY.len() - 1]);
+        assert!(WalletUtils::diagnose_private_key(&corrupted).is_ok());
+        model.app_state.ui_state.pending_key_file = Some(PendingKeyFile {
+            file_name: "key.txt".to_string(),
+            contents: KeyFileContents::SingleKey(corrupted),
+            selected: 0,
+        });
+        WalletController::handle_confirm_key_file_import(&mut model);
+
+        assert_eq!(model.status.map(|s| s.kind), Some(StatusKind::Error));
+        assert_eq!(model.wallet.address(), Some(&address));
+        assert!(model.wallet.has_keypair());
+        assert!(model.app_state.ui_state.pending_key_file.is_none());
+    }
+
+    #[test]
+    fn account_label_round_trips_with_the_saved_key() {
+        let dir = TestDir::new("account_label_round_trips_with_the_saved_key");
+        let mut model = model_with_input(&dir, TEST_KEY);
+        model.auth_state.is_authenticated = true;
+        model.auth_state.set_session_password("hunter2".to_string());
+        model.app_state.settings.security_settings.auto_save_keys = true;
+        WalletController::handle_import_key(&mut model);
+        let address = *model.wallet.address().unwrap();
+        let truncated = WalletUtils::truncate_address(&address, 6, 4);
+
+        // 未设置标签时显示截断的地址
+        assert_eq!(model.wallet.account_labels(), vec![truncated.clone()]);
+
+        WalletController::handle_rename_account(&mut model, " Trading ".to_string());
+        assert_eq!(model.wallet.label(), Some("Trading"));
+        assert_eq!(model.wallet.account_labels(), vec!["Trading".to_string()]);
+
+        // 重新登录加载保存的私钥时恢复标签
+        let mut restarted = Model::with_config_dir(&dir);
+        assert_eq!(WalletController::try_load_saved_key(&mut restarted, "hunter2"), Ok(true));
+        assert_eq!(restarted.wallet.display_label(), Some("Trading".to_string()));
+
+        // 清空标签后删除保存的标签
+        WalletController::handle_rename_account(&mut restarted, "  ".to_string());
+        assert!(!restarted.auth_state.account_label_file.exists());
+        let mut restarted = Model::with_config_dir(&dir);
+        WalletController::try_load_saved_key(&mut restarted, "hunter2").unwrap();
+        assert_eq!(restarted.wallet.display_label(), Some(truncated));
+    }
+
+    #[test]
+    fn import_while_loaded_asks_before_replacing_the_wallet() {
+        let dir = TestDir::new("import_while_loaded");
+        let mut model = model_with_input(&dir, TEST_KEY);
+        WalletController::handle_import_key(&mut model);
+        let address = *model.wallet.address().unwrap();
+        model.app_state.ui_state.close_dialog();
+
+        let imports: [fn(&mut Model); 2] = [
+            WalletController::handle_import_key,
+            WalletController::handle_import_key_temporarily,
+        ];
+        for import in imports {
+            import(&mut model);
+            assert_eq!(model.app_state.ui_state.dialog_state, DialogState::ConfirmReplaceWallet);
+            assert_eq!(model.wallet.address(), Some(&address));
+            assert!(model.wallet.has_keypair());
+            model.app_state.ui_state.close_dialog();
+        }
+        assert!(!model.auth_state.encrypted_private_key_file.exists());
+    }
+
+    #[test]
+    fn mnemonic_import_requires_the_previewed_address() {
+        const PHRASE: &str = "film crazy soon outside stand loop subway crumble thrive popular green nuclear struggle pistol arm wife phrase warfare march wheat nephew ask sunny firm";
+        let dir = TestDir::new("mnemonic_import_requires_preview");
+        let addresses = MnemonicUtils::derive_addresses(PHRASE, 2).unwrap();
+
+        // 没有预览时拒绝导入
+        let mut model = model_with_input(&dir, PHRASE);
+        WalletController::handle_import_mnemonic_account(&mut model, 0);
+        assert!(!model.wallet.is_loaded());
+        assert_eq!(model.status.map(|s| s.kind), Some(StatusKind::Error));
+
+        // 预览之后输入被替换为其他助记词：派生地址与预览不一致，拒绝导入
+        let other = format!("{}about", "abandon ".repeat(11));
+        let mut model = model_with_input(&dir, &other);
+        model.mnemonic_preview = Some(MnemonicPreview::new(model.network.clone(), addresses.clone()));
+        WalletController::handle_import_mnemonic_account(&mut model, 1);
+        assert!(!model.wallet.is_loaded());
+        assert!(model.mnemonic_preview.is_none());
+
+        // 地址一致时导入预览中选中的账户
+        let mut model = model_with_input(&dir, PHRASE);
+        model.mnemonic_preview = Some(MnemonicPreview::new(model.network.clone(), addresses.clone()));
+        WalletController::handle_import_mnemonic_account(&mut model, 1);
+        assert_eq!(model.wallet.address(), Some(&addresses[1]));
+    }
+
+    #[test]
+    fn save_failure_warns_but_still_loads_wallet() {
+        let dir = TestDir::new("save_failure");
+        // 父路径是普通文件，保存必然失败
+        let blocker = dir.join("not_a_dir");
+        std::fs::write(&blocker, b"").unwrap();
+        let mut model = model_with_input(&dir, TEST_KEY);
+        model.auth_state.encrypted_private_key_file = blocker.join("private_key.enc");
+        model.auth_state.is_authenticated = true;
+        model.auth_state.set_session_password("hunter2".to_string());
+
+        WalletController::handle_import_key(&mut model);
+
+        assert!(model.wallet.is_loaded());
+        let status = model.status.unwrap();
+        assert_eq!(status.kind, StatusKind::Warning);
+        assert!(status.detail.is_some());
     }
-}
\ No newline at end of file
+}
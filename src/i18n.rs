@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use std::sync::OnceLock;
+use std::time::Duration;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Language {
@@ -26,6 +27,29 @@ impl Language {
     pub fn all() -> Vec<Language> {
         vec![Language::English, Language::Chinese]
     }
+
+    /// 数字分组分隔符
+    pub fn group_separator(&self) -> char {
+        match self {
+            Language::English | Language::Chinese => ',',
+        }
+    }
+
+    /// 数字分组的位数：英文按千分组，中文按万分组（与"万""亿"的读法一致）
+    pub fn group_size(&self) -> usize {
+        match self {
+            Language::English => 3,
+            Language::Chinese => 4,
+        }
+    }
+
+    /// 小数点
+    pub fn decimal_separator(&self) -> char {
+        match self {
+            Language::English => '.',
+            Language::Chinese => '.',
+        }
+    }
 }
 
 impl Default for Language {
@@ -55,12 +79,28 @@ fn init_translations() -> &'static HashMap<&'static str, HashMap<&'static str, &
         en.insert("create_password_button", "Create Password and Enter");
         en.insert("login_message", "Please enter your password to log in");
         en.insert("login_button", "Login");
+        en.insert("use_os_keyring", "Store session password in OS keyring");
+        en.insert("os_keyring_unavailable", "OS keyring unavailable, using memory");
+        en.insert("stay_signed_in", "Keep me signed in for {} hours");
+        en.insert("stay_signed_in_hint", "Only use this on a personal computer. Logging out ends the remembered session.");
+        en.insert("stay_signed_in_unavailable", "Could not keep you signed in: the OS keyring is unavailable");
         en.insert("exit_button", "Exit");
         en.insert("password_info", "The password will be encrypted with the Argon2 algorithm and saved in the local configuration directory.");
         en.insert("network_label", "Network");
         en.insert("devnet", "Devnet");
         en.insert("testnet", "Testnet");
         en.insert("mainnet", "Mainnet");
+        en.insert("custom_network", "Custom Network");
+        en.insert("custom_network_menu", "Custom Network...");
+        en.insert("rpc_fallbacks_menu", "Fallback RPC Endpoints...");
+        en.insert("rpc_fallbacks_title", "Fallback RPC Endpoints");
+        en.insert("rpc_fallbacks_hint", "One URL per line. When the default endpoint fails, these are tried in order.");
+        en.insert("invalid_fallback_urls", "Invalid RPC URL (must start with http:// or https://): {}");
+        en.insert("rpc_fallbacks_saved", "Fallback RPC endpoints saved");
+        en.insert("custom_network_title", "Custom Network");
+        en.insert("custom_network_name", "Name");
+        en.insert("custom_network_url", "RPC URL");
+        en.insert("invalid_rpc_url", "RPC URL must start with http:// or https://");
         en.insert("import_wallet_message", "Import your wallet using a Base64 private key:");
         en.insert("import_wallet_button", "Import Wallet");
         en.insert("wallet_loaded", "Wallet Loaded");
@@ -72,16 +112,35 @@ fn init_translations() -> &'static HashMap<&'static str, HashMap<&'static str, &
         // Error messages
         en.insert("password_empty_error", "Password cannot be empty");
         en.insert("password_mismatch_error", "The two passwords entered do not match");
+        en.insert("password_incorrect_error", "Incorrect password");
         en.insert("hash_error", "Hash error: {}");
         en.insert("create_dir_error", "Failed to create directory: {}");
         en.insert("write_error", "Write failed: {}");
         en.insert("password_not_found_error", "No saved password found");
         en.insert("parse_hash_error", "Failed to parse hash: {}");
         en.insert("password_error", "Password error");
+        en.insert("password_file_corrupt", "The saved password file is corrupted and cannot be read.");
+        en.insert("password_file_corrupt_hint", "To continue, reset the wallet. This wipes the saved password and the encrypted private key; you will need to import your key again.");
+        en.insert("reset_wipe_data_button", "Reset (wipe data)");
+        en.insert("reset_confirm_title", "Reset Wallet");
+        en.insert("reset_confirm_message", "This permanently deletes the saved password and encrypted private key. Continue?");
+        en.insert("reset_confirm_button", "Yes, wipe data");
+        en.insert("cancel_button", "Cancel");
+        en.insert("orphaned_key_title", "Saved Key Without Password");
+        en.insert("orphaned_key_message", "An encrypted private key was found, but the password file is missing. A new password cannot decrypt it. Delete the old key file, or keep it and re-import your key after setting a new password (the import will replace it).");
+        en.insert("delete_orphaned_key_button", "Delete Old Key");
+        en.insert("reimport_key_button", "Re-import Later");
         // Default messages
         en.insert("import_private_key_message", "Please import a private key to begin.");
         // Wallet messages
         en.insert("wallet_imported_success", "Wallet imported successfully for address");
+        en.insert("unrecognized_key_format", "Unrecognized key format. Please paste a Bech32, Base64 or Hex private key.");
+        en.insert("key_error_empty", "Please paste a private key");
+        en.insert("key_error_too_short", "Private key is too short for the {} format");
+        en.insert("key_error_too_long", "Private key is too long for the {} format");
+        en.insert("key_error_invalid_hrp", "Bech32 key must start with suiprivkey1");
+        en.insert("key_error_invalid_base64", "Private key is not valid Base64");
+        en.insert("key_error_unknown_scheme", "Unknown signature scheme flag. Supported: Ed25519, Secp256k1, Secp256r1");
         en.insert("import_private_key_failed", "Failed to import private key. Please check the format (Bech32 or Base64).");
         en.insert("wallet_loaded_from_storage", "Wallet loaded from secure storage");
         en.insert("private_key_save_failed", "Failed to save private key securely");
@@ -91,6 +150,10 @@ fn init_translations() -> &'static HashMap<&'static str, HashMap<&'static str, &
         en.insert("refreshing_balance", "Refreshing balance...");
         en.insert("no_wallet_loaded", "No wallet loaded. Please import a key first.");
         en.insert("async_error", "Error");
+        en.insert("recipient_required", "Recipient address is required");
+        en.insert("invalid_recipient", "Invalid recipient address");
+        en.insert("amount_required", "Amount is required");
+        en.insert("invalid_amount", "Invalid amount");
         // App messages
         en.insert("welcome_first_run", "Welcome! Please set up your password to get started.");
         // New view module translations
@@ -107,15 +170,37 @@ fn init_translations() -> &'static HashMap<&'static str, HashMap<&'static str, &
         en.insert("security_warning_title", "Security Warning");
         en.insert("security_warning_message", "Never share your private key with anyone!");
         en.insert("copy_address_button", "Copy Address");
+        en.insert("import_another_key_button", "Import Another Key");
+        en.insert("replace_wallet_title", "Replace Current Wallet?");
+        en.insert("replace_wallet_message", "A wallet is already loaded. Importing a new key will replace it in this session and overwrite the saved key. Make sure you have a backup of the current private key.");
+        en.insert("replace_wallet_confirm_button", "Replace Wallet");
+        en.insert("copy_address_prefixed", "Copy 0x…");
+        en.insert("copy_address_bare", "Copy without prefix");
+        en.insert("copy_address_qr", "Copy as QR image");
+        en.insert("account_label_hint", "Account label (e.g. Trading)");
+        en.insert("rename_account", "Rename account");
+        en.insert("switch_account", "Switch account");
+        en.insert("save_button", "Save");
         en.insert("balance_unknown", "Unknown");
         en.insert("loading", "Loading...");
+        en.insert("time_just_now", "just now");
+        en.insert("time_unknown", "unknown");
         en.insert("tools_label", "Tools");
+        en.insert("explorer_provider_label", "Block Explorer");
+        en.insert("open_explorer_home", "Open Explorer");
         en.insert("clear_cache", "Clear Cache");
         en.insert("export_logs", "Export Logs");
         en.insert("about", "About");
         en.insert("status_loading", "Loading...");
         en.insert("status_connected", "Connected");
         en.insert("status_no_wallet", "No Wallet");
+        en.insert("check_connection", "Check connection");
+        en.insert("rpc_unreachable_warning", "The RPC endpoint {} is not responding. Switch to another network or set a custom endpoint from the Network menu.");
+        en.insert("error_title", "Error");
+        en.insert("warning_title", "Warning");
+        en.insert("info_title", "Information");
+        en.insert("status_unreachable", "Network unreachable");
+        en.insert("status_degraded", "Network slow");
         en.insert("current_language", "Current Language");
         en.insert("quick_actions", "Quick Actions");
         en.insert("settings", "Settings");
@@ -129,13 +214,242 @@ fn init_translations() -> &'static HashMap<&'static str, HashMap<&'static str, &
         en.insert("balance_error", "Balance Error");
         en.insert("retry_button", "Retry");
         en.insert("current_balance", "Current Balance");
-        en.insert("estimated_value", "Estimated Value");
         en.insert("network_fee", "Network Fee");
         en.insert("send_button", "Send");
         en.insert("receive_button", "Receive");
         en.insert("transaction_history", "Transaction History");
         en.insert("no_transactions", "No transactions yet");
+        en.insert("self_send_warning", "The recipient is your own address. Confirm again to send to yourself.");
+        en.insert("amount_large_share_warning", "This amount is most of your balance. Check the decimal point and confirm again to send.");
+        en.insert("amount_unusually_large_warning", "This amount is much larger than your recent transfers. Check the decimal point and confirm again to send.");
+        en.insert("batch_transfer_menu", "Batch Transfer...");
+        en.insert("batch_transfer_title", "Batch Transfer");
+        en.insert("batch_transfer_hint", "One transfer per line: address,amount (SUI)");
+        en.insert("batch_total_overflow", "Total amount is too large");
+        en.insert("batch_missing_amount", "Missing amount");
+        en.insert("batch_recipients", "Recipients");
+        en.insert("batch_total_amount", "Total Amount");
+        en.insert("batch_estimated_total", "Estimated Total");
+        en.insert("coin_filter_menu", "Coin Filter...");
+        en.insert("coin_filter_title", "Coin Filter");
+        en.insert("coin_filter_hint", "Choose which coins appear in the balance view. SUI is always shown.");
+        en.insert("coin_filter_denylist", "Hide matching coins");
+        en.insert("coin_filter_allowlist", "Show only listed coins");
+        en.insert("coin_denylist_hint", "One pattern per line; coin types containing any pattern are hidden (case-insensitive).");
+        en.insert("coin_allowlist_hint", "One full coin type per line, e.g. 0x...::usdc::USDC.");
+        en.insert("coin_filter_saved", "Coin filter saved");
+        en.insert("restore_defaults_button", "Restore Defaults");
+        en.insert("show_advanced_options", "Show Advanced Options");
+        en.insert("gas_budget_label", "Gas Budget");
+        en.insert("gas_budget_invalid", "Gas budget must be a whole number of MIST");
+        en.insert("gas_budget_out_of_bounds", "Gas budget must be between 1,000,000 and 50,000,000,000 MIST");
+        en.insert("gas_budget_below_estimate", "Gas budget is below the estimate; the transaction may fail");
+        en.insert("test_connection_button", "Test");
+        en.insert("testing_connection", "Testing connection...");
+        en.insert("connection_ok", "Connected. Chain ID:");
+        en.insert("connection_failed", "Connection failed:");
+        en.insert("screenshot_protection", "Screenshot Protection");
+        en.insert("screenshot_protection_unavailable", "Screenshot protection is not available on this platform");
+        en.insert("copy_private_key_button", "Copy Private Key");
+        en.insert("private_key_copied", "Private key copied to clipboard");
+        en.insert("clipboard_warning_title", "Copy Private Key?");
+        en.insert("clipboard_warning_message", "Clipboard managers and clipboard history (e.g. Windows Win+V, macOS/Linux clipboard tools) may keep a copy of your private key. Anyone with access to it can take your funds.");
+        en.insert("dont_warn_again", "Don't warn me again");
+        en.insert("copy_anyway_button", "Copy Anyway");
+        en.insert("self_send_title", "Send to Yourself?");
+        en.insert("send_anyway_button", "Send Anyway");
+        en.insert("large_amount_title", "Check the Amount");
+        en.insert("session_expired_message", "Session expired due to inactivity. Please log in again.");
+        en.insert("session_locks_in", "Locks in {}");
+        en.insert("lock_on_blur", "Lock when window loses focus");
+        en.insert("lock_on_blur_hint", "Require the password again after switching away from or minimizing the window");
+        en.insert("wallet_locked_message", "Wallet locked. Enter your password to unlock");
+        en.insert("copy_public_key_base64", "Copy public key (Base64)");
+        en.insert("copy_public_key_hex", "Copy public key (hex)");
+        en.insert("public_key_unavailable", "No key pair loaded for this account");
+        en.insert("low_balance_threshold_hint", "Balance is below {} SUI, the recommended minimum on {}");
+        en.insert("switch_account_shortcut", "Switch account");
+        en.insert("refresh_on_focus", "Refresh balance when window regains focus");
+        en.insert("separate_accounts_per_network", "Separate accounts per network");
+        en.insert("separate_accounts_per_network_hint", "Keep a different saved account for each network instead of sharing one");
+        en.insert("import_for_network", "No account saved for {} yet, import a private key for this network");
+        en.insert("format_help_what_is_key", "Your private key is the secret that controls your wallet. Wallet apps and the Sui CLI can export it in one of the formats below.");
+        en.insert("format_help_bech32_detail", "The standard Sui export format, starting with \"suiprivkey1\". It also records the key scheme.");
+        en.insert("format_help_base64_detail", "A Base64 string, usually from older Sui keystore files. It includes a one-byte scheme flag.");
+        en.insert("format_help_hex_detail", "The raw 32-byte key as 64 hexadecimal characters, optionally prefixed with 0x.");
+        en.insert("format_help_safety_warning", "Never share your private key or paste it into websites or chats. Anyone who has it can take all of your funds.");
+        en.insert("format_help_docs_link", "Learn more about Sui keys and addresses");
+        en.insert("same_address_all_networks", "The same address exists on every network, but funds on testnet are not on mainnet.");
+        en.insert("check_other_networks", "Check other networks");
+        en.insert("network_summary_title", "Balance on each network");
+        en.insert("network_unavailable", "Unavailable");
+        en.insert("network_summary_partial", "{} of {} networks could not be reached, other balances are still up to date");
+        en.insert("copy_full_message", "Copy full message");
+        en.insert("served_by_fallback_endpoint", "Primary RPC unavailable, loaded from fallback {}");
+        en.insert("auto_select_endpoint", "Automatically select the RPC endpoint");
+        en.insert("auto_select_endpoint_hint", "Periodically probes all endpoints of the current network and prefers the fastest healthy one");
+        en.insert("auto_switch_network", "Automatically switch networks");
+        en.insert("auto_switch_network_hint", "Switches to the preferred network if the current one is unreachable");
+        en.insert("active_endpoint", "Active endpoint");
+        en.insert("preferred_network_label", "Preferred network");
+        en.insert("auto_switched_network", "{} is unreachable, switched to {}");
+        en.insert("address_empty", "This address holds no coins yet");
+        en.insert("no_sui_other_coins", "No SUI (holds {} other coins)");
+        en.insert("key_file_import_title", "Import Key File");
+        en.insert("key_file_name", "File");
+        en.insert("key_file_keystore", "Keystore with {} keys. Choose the key to import:");
+        en.insert("key_file_entry", "Key #{}");
+        en.insert("key_file_single_key", "The file contains a private key.");
+        en.insert("key_file_mnemonic_unsupported", "The file looks like a {}-word recovery phrase. Paste it into the import field to preview and import its accounts.");
+        en.insert("key_file_import_button", "Import");
+        en.insert("key_file_unrecognized", "The dropped file does not contain a private key or keystore");
+        en.insert("key_file_read_error", "Failed to read the dropped file");
+        en.insert("key_file_too_large", "The dropped file is too large to be a key file");
+        en.insert("export_addresses_menu", "Export Addresses...");
+        en.insert("export_addresses_title", "Export Addresses");
+        en.insert("export_addresses_hint", "Exports account labels and addresses for sharing. Private keys are never included.");
+        en.insert("export_format", "Format");
+        en.insert("export_path", "File");
+        en.insert("export_button", "Export");
+        en.insert("export_path_empty", "Enter a file path");
+        en.insert("export_path_is_directory", "The path is a folder, enter a file name");
+        en.insert("export_path_missing_parent", "The folder does not exist");
+        en.insert("addresses_exported", "Addresses exported to");
+        en.insert("export_addresses_failed", "Failed to export addresses");
+        en.insert("forget_saved_key_menu", "Forget Saved Key...");
+        en.insert("delete_key_confirm_title", "Delete Saved Key");
+        en.insert("delete_key_confirm_message", "This permanently deletes the encrypted private key saved on this device. You will have to import the private key again to use this wallet. Make sure you have a backup before continuing.");
+        en.insert("delete_key_confirm_button", "Delete Key");
+        en.insert("saved_key_deleted", "Saved private key deleted. Import the key again to use this wallet.");
+        en.insert("mnemonic_detected", "This looks like a recovery phrase.");
+        en.insert("mnemonic_preview_button", "Preview Accounts");
+        en.insert("mnemonic_preview_hint", "First accounts derived from this phrase, with balances on {}. Pick the account to import:");
+        en.insert("mnemonic_import_selected", "Import Selected Account");
+        en.insert("mnemonic_invalid", "Invalid recovery phrase");
+        en.insert("mnemonic_preview_stale", "The recovery phrase changed since the preview. Preview the accounts again before importing.");
+        en.insert("copy_error", "Copy Error");
+        en.insert("amount_too_many_decimals", "Amounts can have at most {} decimal places");
+        en.insert("amount_exceeds_balance", "Amount exceeds your balance");
+        en.insert("idle_lock_title", "Locking Soon");
+        en.insert("idle_lock_countdown", "Locking in {}…");
+        en.insert("stay_unlocked_button", "Stay Unlocked");
+        en.insert("session_timeout_label", "Session timeout (minutes)");
+        en.insert("session_never_times_out", "Never");
+        en.insert("idle_lock_grace_label", "Lock countdown (seconds)");
+        en.insert("batch_review_button", "Review...");
+        en.insert("batch_confirm_title", "Confirm Batch Transfer");
+        en.insert("batch_confirm_hint", "Check every recipient and amount before signing");
+        en.insert("back_button", "Back");
+        en.insert("auto_save_keys", "Save imported keys (encrypted)");
+        en.insert("auto_save_keys_hint", "When off, an imported key is kept only until you log out");
+        en.insert("send_blocked_loading", "Please wait for the current operation to finish");
+        en.insert("send_blocked_no_wallet", "Import a wallet to send");
+        en.insert("send_blocked_zero_amount", "Amount must be greater than 0");
+        en.insert("send_blocked_invalid_gas_budget", "Enter a valid gas budget or clear the field to use the estimate");
+        en.insert("max_button", "Max");
+        en.insert("max_button_tooltip", "Send the whole balance minus the gas budget");
+        en.insert("send_blocked_invalid_batch", "Fix the invalid rows before sending");
+        en.insert("send_blocked_balance_unknown", "Refresh your balance before sending");
+        en.insert("send_blocked_balance_stale", "Your balance is out of date. Refresh it before sending");
+        en.insert("send_blocked_exceeds_spendable", "Amount exceeds your spendable balance (balance minus network fee)");
+        en.insert("verify_ownership_button", "Verify Ownership");
+        en.insert("verify_ownership_hint", "Sign a random challenge with the loaded key and check it matches this address");
+        en.insert("ownership_verified", "Key controls this address");
+        en.insert("ownership_mismatch", "The loaded key does not match this address");
+        en.insert("default_network_label", "Default network:");
+        en.insert("ui_scale_label", "Text size:");
+        en.insert("ui_scale_reset", "Reset");
+        en.insert("ui_scale_preview", "Preview: Balance 1,234.5 SUI");
+        en.insert("theme_label", "Theme");
+        en.insert("theme_light", "Light");
+        en.insert("theme_dark", "Dark");
+        en.insert("theme_auto", "Follow system");
+        en.insert("theme_high_contrast", "High contrast");
+        en.insert("wallet_save_failed_warning", "Wallet loaded but could not be saved: {}. You will need to import the key again next time.");
+        en.insert("login_locked_out", "Too many failed login attempts. Try again in {} minute(s).");
+        en.insert("max_failed_login_attempts_label", "Lock login after failed attempts (0 = never):");
+        en.insert("login_lockout_minutes_label", "for minutes:");
+        en.insert("copy_config_path_menu", "Copy Config Folder Path");
+        en.insert("open_config_folder_menu", "Open Config Folder");
+        en.insert("config_path_copied", "Config folder path copied: {}");
+        en.insert("open_config_folder_failed", "Could not open config folder: {}");
+        en.insert("passwords_match", "✔ Passwords match");
+        en.insert("passwords_do_not_match", "✖ Passwords do not match");
+        en.insert("payment_uri_invalid_address", "The payment link contains an invalid address");
+        en.insert("payment_uri_invalid_amount", "The payment link contains an invalid amount");
+        en.insert("toggle_recent_network_hint", "Switch to {}");
+        en.insert("write_crash_reports", "Write local crash reports");
+        en.insert("write_crash_reports_hint", "On a crash, save a sanitized report (version, OS, recent log) to the config folder. Nothing is sent anywhere.");
+        en.insert("crash_report_written", "The wallet crashed last time. A crash report without secrets was saved to:\n{}\nYou can attach it to an issue.");
+        en.insert("operation_refresh_balance", "Refreshing balance");
+        en.insert("operation_check_other_networks", "Checking other networks");
+        en.insert("operation_mnemonic_preview", "Loading account previews");
+        en.insert("operation_health_check", "Checking RPC endpoint");
+        en.insert("operation_endpoint_test", "Testing endpoint");
+        en.insert("operation_send_transfer", "Sending transfer");
+        en.insert("operation_cancelled", "Cancelled: {}");
+        en.insert("confirm_password_title", "Confirm with Password");
+        en.insert("confirm_password_copy_private_key", "Enter your password to copy the private key.");
+        en.insert("confirm_password_send_transfer", "Enter your password to send this transfer on Mainnet.");
+        en.insert("confirm_password_send_batch_transfer", "Enter your password to send this batch transfer on Mainnet.");
+        en.insert("confirm_password_reset_wallet", "Enter your password to reset the wallet.");
+        en.insert("confirm_password_delete_saved_key", "Enter your password to delete the saved private key.");
+        en.insert("confirm_password_change_password", "Enter your current password to change it.");
+        en.insert("change_password_menu", "Change Password...");
+        en.insert("change_password_title", "Change Password");
+        en.insert("new_password_hint", "New password");
+        en.insert("confirm_new_password_hint", "Confirm new password");
+        en.insert("change_password_button", "Change Password");
+        en.insert("password_changed", "Password changed");
+        en.insert("password_change_failed", "Failed to change password");
+        en.insert("session_password_unavailable", "The current password is no longer available; log in again and retry");
+        en.insert("reset_wallet_menu", "Reset Wallet...");
+        en.insert("confirm_button", "Confirm");
+        en.insert("security_warning_dismiss", "Don't show again");
+        en.insert("show_security_warning", "Show security warning");
+        en.insert("import_temporarily_button", "Import Temporarily");
+        en.insert("import_temporarily_hint", "Load the key in memory only. It is never saved and is cleared on logout.");
+        en.insert("wallet_imported_temporarily", "Wallet imported temporarily (not saved)");
+        en.insert("temporary_session_button", "Use a key temporarily without signing in");
+        en.insert("temporary_session_started", "Temporary session: imported keys are not saved and are cleared on logout");
+        en.insert("temporary_session_ended", "Temporary session ended. The imported key was cleared from memory");
+        en.insert("temporary_session_label", "Temporary session: keys are not saved");
+        en.insert("end_temporary_session_button", "End session");
+        en.insert("password_too_short", "Password must be at least {} characters long");
+        en.insert("password_missing_lowercase", "Password must contain at least one lowercase letter");
+        en.insert("password_missing_uppercase", "Password must contain at least one uppercase letter");
+        en.insert("password_missing_digit", "Password must contain at least one digit");
+        en.insert("password_missing_special", "Password must contain at least one special character");
+        en.insert("password_too_common", "This password is too common and easy to guess, please choose another one");
+        en.insert("reject_common_passwords", "Reject common passwords");
+        en.insert("min_password_length_label", "Minimum password length:");
+        en.insert("password_require_lowercase", "Lowercase");
+        en.insert("password_require_uppercase", "Uppercase");
+        en.insert("password_require_digit", "Digit");
+        en.insert("password_require_special", "Special character");
+        en.insert("reject_common_passwords_hint", "When setting a password, reject passwords from the bundled list of common passwords and their simple variants such as added digits or p@ssw0rd-style substitutions (checked offline)");
+        en.insert("usd_converted_amount", "≈ {} SUI at ${} per SUI");
+        en.insert("usd_price_unavailable", "SUI price unavailable, enter the amount in SUI");
+        en.insert("usd_price_fetching", "Fetching the SUI price...");
+        en.insert("usd_price_source", "Entering the amount in USD looks up the SUI price on CoinGecko");
+        en.insert("transfer_status_pending", "Pending");
+        en.insert("transfer_status_success", "Success");
+        en.insert("transfer_status_failed", "Failed");
+        en.insert("transfer_title", "Send SUI");
+        en.insert("recipient_label", "Recipient:");
+        en.insert("amount_label", "Amount:");
+        en.insert("transfer_submitting", "Signing and submitting the transfer...");
+        en.insert("transfer_submitted", "Transfer submitted: {}");
+        en.insert("transfer_succeeded", "Transfer succeeded: {}");
+        en.insert("transfer_pending", "Transfer submitted but its result is not confirmed yet: {}");
+        en.insert("transfer_failed", "Transfer failed");
+        en.insert("transfer_note_label", "Note (local only):");
+        en.insert("transfer_note_hint", "Only stored on this device");
+        en.insert("edit_transfer_note", "Edit note");
+        en.insert("transfer_note_saved", "Note saved");
+        en.insert("transfer_note_save_failed", "Failed to save the note");
         en.insert("view_explorer", "View in Explorer");
+        en.insert("view_transaction_explorer", "View transaction in explorer");
         en.insert("balance_chart", "Balance Chart");
         en.insert("chart_placeholder", "Chart coming soon...");
         en.insert("low_balance_warning", "Low Balance Warning");
@@ -152,12 +466,28 @@ fn init_translations() -> &'static HashMap<&'static str, HashMap<&'static str, &
         zh.insert("create_password_button", "创建密码并进入");
         zh.insert("login_message", "请输入您的密码以登录");
         zh.insert("login_button", "登录");
+        zh.insert("use_os_keyring", "将会话密码保存在系统钥匙串中");
+        zh.insert("os_keyring_unavailable", "系统钥匙串不可用，已使用内存");
+        zh.insert("stay_signed_in", "保持登录 {} 小时");
+        zh.insert("stay_signed_in_hint", "仅在个人电脑上使用。退出登录会结束保持的会话。");
+        zh.insert("stay_signed_in_unavailable", "无法保持登录：操作系统钥匙串不可用");
         zh.insert("exit_button", "退出");
         zh.insert("password_info", "密码将使用Argon2算法加密并保存在本地配置目录中。");
         zh.insert("network_label", "网络");
         zh.insert("devnet", "开发网");
         zh.insert("testnet", "测试网");
         zh.insert("mainnet", "主网");
+        zh.insert("custom_network", "自定义网络");
+        zh.insert("custom_network_menu", "自定义网络...");
+        zh.insert("rpc_fallbacks_menu", "备用 RPC 端点...");
+        zh.insert("rpc_fallbacks_title", "备用 RPC 端点");
+        zh.insert("rpc_fallbacks_hint", "每行一个 URL，默认端点不可用时按顺序尝试。");
+        zh.insert("invalid_fallback_urls", "无效的 RPC 地址（必须以 http:// 或 https:// 开头）：{}");
+        zh.insert("rpc_fallbacks_saved", "备用 RPC 端点已保存");
+        zh.insert("custom_network_title", "自定义网络");
+        zh.insert("custom_network_name", "名称");
+        zh.insert("custom_network_url", "RPC 地址");
+        zh.insert("invalid_rpc_url", "RPC 地址必须以 http:// 或 https:// 开头");
         zh.insert("import_wallet_message", "使用Base64私钥导入您的钱包：");
         zh.insert("import_wallet_button", "导入钱包");
         zh.insert("wallet_loaded", "钱包已加载");
@@ -169,16 +499,35 @@ fn init_translations() -> &'static HashMap<&'static str, HashMap<&'static str, &
         // Error messages
         zh.insert("password_empty_error", "密码不能为空");
         zh.insert("password_mismatch_error", "两次输入的密码不一致");
+        zh.insert("password_incorrect_error", "密码不正确");
         zh.insert("hash_error", "哈希错误: {}");
         zh.insert("create_dir_error", "创建目录失败: {}");
         zh.insert("write_error", "写入失败: {}");
         zh.insert("password_not_found_error", "未找到已保存的密码");
         zh.insert("parse_hash_error", "解析哈希失败: {}");
         zh.insert("password_error", "密码错误");
+        zh.insert("password_file_corrupt", "已保存的密码文件已损坏，无法读取。");
+        zh.insert("password_file_corrupt_hint", "如需继续，请重置钱包。这将清除已保存的密码和加密的私钥，之后需要重新导入私钥。");
+        zh.insert("reset_wipe_data_button", "重置（清除数据）");
+        zh.insert("reset_confirm_title", "重置钱包");
+        zh.insert("reset_confirm_message", "这将永久删除已保存的密码和加密私钥。是否继续？");
+        zh.insert("reset_confirm_button", "确定清除数据");
+        zh.insert("cancel_button", "取消");
+        zh.insert("orphaned_key_title", "发现无密码的已保存私钥");
+        zh.insert("orphaned_key_message", "发现已加密的私钥，但密码文件缺失，新密码无法解密该私钥。您可以删除旧的私钥文件，或保留它并在设置新密码后重新导入私钥（导入会覆盖旧文件）。");
+        zh.insert("delete_orphaned_key_button", "删除旧私钥");
+        zh.insert("reimport_key_button", "稍后重新导入");
         // Default messages
         zh.insert("import_private_key_message", "请导入私钥以开始使用。");
         // Wallet messages
         zh.insert("wallet_imported_success", "钱包导入成功，地址为");
+        zh.insert("unrecognized_key_format", "无法识别的私钥格式。请粘贴 Bech32、Base64 或十六进制格式的私钥。");
+        zh.insert("key_error_empty", "请粘贴私钥");
+        zh.insert("key_error_too_short", "私钥对于 {} 格式来说太短");
+        zh.insert("key_error_too_long", "私钥对于 {} 格式来说太长");
+        zh.insert("key_error_invalid_hrp", "Bech32 私钥必须以 suiprivkey1 开头");
+        zh.insert("key_error_invalid_base64", "私钥不是有效的 Base64");
+        zh.insert("key_error_unknown_scheme", "未知的签名方案标志。支持：Ed25519、Secp256k1、Secp256r1");
         zh.insert("import_private_key_failed", "导入私钥失败。请检查格式（Bech32 或 Base64）。");
         zh.insert("wallet_loaded_from_storage", "从安全存储加载钱包");
         zh.insert("private_key_save_failed", "私钥安全保存失败");
@@ -188,6 +537,10 @@ fn init_translations() -> &'static HashMap<&'static str, HashMap<&'static str, &
         zh.insert("refreshing_balance", "正在刷新余额...");
         zh.insert("no_wallet_loaded", "未加载钱包。请先导入私钥。");
         zh.insert("async_error", "错误");
+        zh.insert("recipient_required", "请输入收款地址");
+        zh.insert("invalid_recipient", "收款地址无效");
+        zh.insert("amount_required", "请输入金额");
+        zh.insert("invalid_amount", "金额无效");
         // App messages
         zh.insert("welcome_first_run", "欢迎！请设置您的密码以开始使用。");
         // New view module translations
@@ -204,15 +557,37 @@ fn init_translations() -> &'static HashMap<&'static str, HashMap<&'static str, &
         zh.insert("security_warning_title", "安全警告");
         zh.insert("security_warning_message", "绝不要与任何人分享您的私钥！");
         zh.insert("copy_address_button", "复制地址");
+        zh.insert("import_another_key_button", "导入其他私钥");
+        zh.insert("replace_wallet_title", "替换当前钱包？");
+        zh.insert("replace_wallet_message", "当前已加载钱包。导入新私钥将替换本次会话中的钱包，并覆盖已保存的私钥。请确认已备份当前私钥。");
+        zh.insert("replace_wallet_confirm_button", "替换钱包");
+        zh.insert("copy_address_prefixed", "复制 0x…");
+        zh.insert("copy_address_bare", "复制（不带前缀）");
+        zh.insert("copy_address_qr", "复制为二维码图片");
+        zh.insert("account_label_hint", "账户标签（如：交易）");
+        zh.insert("rename_account", "重命名账户");
+        zh.insert("switch_account", "切换账户");
+        zh.insert("save_button", "保存");
         zh.insert("balance_unknown", "未知");
         zh.insert("loading", "加载中...");
+        zh.insert("time_just_now", "刚刚");
+        zh.insert("time_unknown", "未知");
         zh.insert("tools_label", "工具");
+        zh.insert("explorer_provider_label", "区块浏览器");
+        zh.insert("open_explorer_home", "打开区块浏览器");
         zh.insert("clear_cache", "清除缓存");
         zh.insert("export_logs", "导出日志");
         zh.insert("about", "关于");
         zh.insert("status_loading", "加载中...");
         zh.insert("status_connected", "已连接");
         zh.insert("status_no_wallet", "无钱包");
+        zh.insert("check_connection", "检查连接");
+        zh.insert("rpc_unreachable_warning", "RPC 端点 {} 没有响应。请从网络菜单切换到其他网络或设置自定义端点。");
+        zh.insert("error_title", "错误");
+        zh.insert("warning_title", "警告");
+        zh.insert("info_title", "信息");
+        zh.insert("status_unreachable", "网络不可达");
+        zh.insert("status_degraded", "网络较慢");
         zh.insert("current_language", "当前语言");
         zh.insert("quick_actions", "快速操作");
         zh.insert("settings", "设置");
@@ -226,13 +601,242 @@ fn init_translations() -> &'static HashMap<&'static str, HashMap<&'static str, &
         zh.insert("balance_error", "余额错误");
         zh.insert("retry_button", "重试");
         zh.insert("current_balance", "当前余额");
-        zh.insert("estimated_value", "估算价值");
         zh.insert("network_fee", "网络费用");
         zh.insert("send_button", "发送");
         zh.insert("receive_button", "接收");
         zh.insert("transaction_history", "交易历史");
         zh.insert("no_transactions", "暂无交易记录");
+        zh.insert("self_send_warning", "收款地址是您自己的地址。再次确认后将转账给自己。");
+        zh.insert("amount_large_share_warning", "该金额占您余额的大部分。请检查小数点，再次确认后发送。");
+        zh.insert("amount_unusually_large_warning", "该金额远大于您近期的转账。请检查小数点，再次确认后发送。");
+        zh.insert("batch_transfer_menu", "批量转账...");
+        zh.insert("batch_transfer_title", "批量转账");
+        zh.insert("batch_transfer_hint", "每行一笔转账：地址,金额（SUI）");
+        zh.insert("batch_total_overflow", "总金额过大");
+        zh.insert("batch_missing_amount", "缺少金额");
+        zh.insert("batch_recipients", "收款人数");
+        zh.insert("batch_total_amount", "总金额");
+        zh.insert("batch_estimated_total", "预估总花费");
+        zh.insert("coin_filter_menu", "代币过滤...");
+        zh.insert("coin_filter_title", "代币过滤");
+        zh.insert("coin_filter_hint", "选择余额中显示哪些代币，SUI 始终显示。");
+        zh.insert("coin_filter_denylist", "隐藏匹配的代币");
+        zh.insert("coin_filter_allowlist", "只显示列出的代币");
+        zh.insert("coin_denylist_hint", "每行一个片段，代币类型包含任一片段时隐藏（不区分大小写）。");
+        zh.insert("coin_allowlist_hint", "每行一个完整的代币类型，如 0x...::usdc::USDC。");
+        zh.insert("coin_filter_saved", "代币过滤设置已保存");
+        zh.insert("restore_defaults_button", "恢复默认");
+        zh.insert("show_advanced_options", "显示高级选项");
+        zh.insert("gas_budget_label", "Gas 预算");
+        zh.insert("gas_budget_invalid", "Gas 预算必须是整数（MIST）");
+        zh.insert("gas_budget_out_of_bounds", "Gas 预算必须在 1,000,000 到 50,000,000,000 MIST 之间");
+        zh.insert("gas_budget_below_estimate", "Gas 预算低于预估值，交易可能失败");
+        zh.insert("test_connection_button", "测试");
+        zh.insert("testing_connection", "正在测试连接...");
+        zh.insert("connection_ok", "连接成功。链 ID：");
+        zh.insert("connection_failed", "连接失败：");
+        zh.insert("screenshot_protection", "防截图保护");
+        zh.insert("screenshot_protection_unavailable", "当前平台不支持防截图保护");
+        zh.insert("copy_private_key_button", "复制私钥");
+        zh.insert("private_key_copied", "私钥已复制到剪贴板");
+        zh.insert("clipboard_warning_title", "复制私钥？");
+        zh.insert("clipboard_warning_message", "剪贴板管理器和剪贴板历史（如 Windows 的 Win+V、macOS/Linux 的剪贴板工具）可能会保存您私钥的副本。任何能访问它的人都可以转走您的资金。");
+        zh.insert("dont_warn_again", "不再提醒");
+        zh.insert("copy_anyway_button", "仍然复制");
+        zh.insert("self_send_title", "转账给自己？");
+        zh.insert("send_anyway_button", "仍然发送");
+        zh.insert("large_amount_title", "请核对金额");
+        zh.insert("session_expired_message", "会话因长时间未操作已过期，请重新登录。");
+        zh.insert("session_locks_in", "{} 后锁定");
+        zh.insert("lock_on_blur", "窗口失去焦点时锁定");
+        zh.insert("lock_on_blur_hint", "切换到其他窗口或最小化后需要重新输入密码");
+        zh.insert("wallet_locked_message", "钱包已锁定，请输入密码解锁");
+        zh.insert("copy_public_key_base64", "复制公钥（Base64）");
+        zh.insert("copy_public_key_hex", "复制公钥（十六进制）");
+        zh.insert("public_key_unavailable", "此账户未加载密钥对");
+        zh.insert("low_balance_threshold_hint", "余额低于 {} SUI（{} 上建议的最低余额）");
+        zh.insert("switch_account_shortcut", "切换账户");
+        zh.insert("refresh_on_focus", "窗口重新获得焦点时刷新余额");
+        zh.insert("separate_accounts_per_network", "每个网络使用独立账户");
+        zh.insert("separate_accounts_per_network_hint", "为每个网络分别保存账户，而不是所有网络共用一个");
+        zh.insert("import_for_network", "{} 尚未保存账户，请为该网络导入私钥");
+        zh.insert("format_help_what_is_key", "私钥是控制钱包的密钥。钱包应用和 Sui CLI 可以按以下任一格式导出私钥。");
+        zh.insert("format_help_bech32_detail", "Sui 标准导出格式，以 \"suiprivkey1\" 开头，同时记录密钥算法。");
+        zh.insert("format_help_base64_detail", "Base64 字符串，通常来自旧版 Sui keystore 文件，包含 1 字节的算法标识。");
+        zh.insert("format_help_hex_detail", "32 字节原始私钥，写成 64 个十六进制字符，可带 0x 前缀。");
+        zh.insert("format_help_safety_warning", "切勿分享私钥，也不要将其粘贴到网站或聊天中。任何拿到私钥的人都能转走你的全部资产。");
+        zh.insert("format_help_docs_link", "了解 Sui 密钥和地址");
+        zh.insert("same_address_all_networks", "同一地址在所有网络上都存在，但测试网上的资金不会出现在主网。");
+        zh.insert("check_other_networks", "查看其他网络");
+        zh.insert("network_summary_title", "各网络余额");
+        zh.insert("network_unavailable", "不可用");
+        zh.insert("network_summary_partial", "{} / {} 个网络无法连接，其他网络的余额仍为最新");
+        zh.insert("copy_full_message", "复制完整消息");
+        zh.insert("served_by_fallback_endpoint", "主 RPC 不可用，已从备用端点 {} 加载");
+        zh.insert("auto_select_endpoint", "自动选择 RPC 端点");
+        zh.insert("auto_select_endpoint_hint", "定期探测当前网络的所有端点，优先使用延迟最低的可用端点");
+        zh.insert("auto_switch_network", "自动切换网络");
+        zh.insert("auto_switch_network_hint", "当前网络不可达时切换到首选网络");
+        zh.insert("active_endpoint", "当前端点");
+        zh.insert("preferred_network_label", "首选网络");
+        zh.insert("auto_switched_network", "{} 无法连接，已切换到 {}");
+        zh.insert("address_empty", "该地址尚未持有任何代币");
+        zh.insert("no_sui_other_coins", "没有 SUI（持有 {} 种其他代币）");
+        zh.insert("key_file_import_title", "导入密钥文件");
+        zh.insert("key_file_name", "文件");
+        zh.insert("key_file_keystore", "包含 {} 个私钥的 keystore，请选择要导入的私钥：");
+        zh.insert("key_file_entry", "私钥 #{}");
+        zh.insert("key_file_single_key", "文件中包含一个私钥。");
+        zh.insert("key_file_mnemonic_unsupported", "文件内容像是 {} 个单词的助记词。请将其粘贴到导入输入框中预览并导入账户。");
+        zh.insert("key_file_import_button", "导入");
+        zh.insert("key_file_unrecognized", "拖放的文件中没有私钥或 keystore");
+        zh.insert("key_file_read_error", "读取拖放的文件失败");
+        zh.insert("key_file_too_large", "拖放的文件过大，不是密钥文件");
+        zh.insert("export_addresses_menu", "导出地址...");
+        zh.insert("export_addresses_title", "导出地址");
+        zh.insert("export_addresses_hint", "导出账户标签和地址以便分享，不会包含任何私钥。");
+        zh.insert("export_format", "格式");
+        zh.insert("export_path", "文件");
+        zh.insert("export_button", "导出");
+        zh.insert("export_path_empty", "请输入文件路径");
+        zh.insert("export_path_is_directory", "该路径是文件夹，请输入文件名");
+        zh.insert("export_path_missing_parent", "文件夹不存在");
+        zh.insert("addresses_exported", "地址已导出到");
+        zh.insert("export_addresses_failed", "导出地址失败");
+        zh.insert("forget_saved_key_menu", "删除已保存的私钥...");
+        zh.insert("delete_key_confirm_title", "删除已保存的私钥");
+        zh.insert("delete_key_confirm_message", "这将永久删除本设备上保存的加密私钥，之后需要重新导入私钥才能使用此钱包。继续前请确认已备份私钥。");
+        zh.insert("delete_key_confirm_button", "删除私钥");
+        zh.insert("saved_key_deleted", "已删除保存的私钥，需要重新导入私钥才能使用此钱包。");
+        zh.insert("mnemonic_detected", "输入的内容像是助记词。");
+        zh.insert("mnemonic_preview_button", "预览账户");
+        zh.insert("mnemonic_preview_hint", "由该助记词派生的前几个账户及其在 {} 上的余额，请选择要导入的账户：");
+        zh.insert("mnemonic_import_selected", "导入选中的账户");
+        zh.insert("mnemonic_invalid", "助记词无效");
+        zh.insert("mnemonic_preview_stale", "助记词在预览后已更改，请重新预览账户后再导入。");
+        zh.insert("copy_error", "复制错误信息");
+        zh.insert("amount_too_many_decimals", "金额最多只能有 {} 位小数");
+        zh.insert("amount_exceeds_balance", "金额超过余额");
+        zh.insert("idle_lock_title", "即将锁定");
+        zh.insert("idle_lock_countdown", "{} 秒后锁定…");
+        zh.insert("stay_unlocked_button", "保持解锁");
+        zh.insert("session_timeout_label", "会话超时（分钟）");
+        zh.insert("session_never_times_out", "永不超时");
+        zh.insert("idle_lock_grace_label", "锁定倒计时（秒）");
+        zh.insert("batch_review_button", "检查...");
+        zh.insert("batch_confirm_title", "确认批量转账");
+        zh.insert("batch_confirm_hint", "签名前请核对每个收款地址和金额");
+        zh.insert("back_button", "返回");
+        zh.insert("auto_save_keys", "保存导入的私钥（加密）");
+        zh.insert("auto_save_keys_hint", "关闭后，导入的私钥只保留到登出为止");
+        zh.insert("send_blocked_loading", "请等待当前操作完成");
+        zh.insert("send_blocked_no_wallet", "请先导入钱包");
+        zh.insert("send_blocked_zero_amount", "金额必须大于 0");
+        zh.insert("send_blocked_invalid_gas_budget", "请输入有效的 Gas 预算，或清空以使用预估值");
+        zh.insert("max_button", "最大");
+        zh.insert("max_button_tooltip", "转出全部余额（扣除 Gas 预算）");
+        zh.insert("send_blocked_invalid_batch", "请先修正无效的行");
+        zh.insert("send_blocked_balance_unknown", "请先刷新余额");
+        zh.insert("send_blocked_balance_stale", "余额已过期，请先刷新余额");
+        zh.insert("send_blocked_exceeds_spendable", "金额超过可用余额（余额减去网络费用）");
+        zh.insert("verify_ownership_button", "验证所有权");
+        zh.insert("verify_ownership_hint", "用已加载的私钥签名随机挑战，检查签名是否对应此地址");
+        zh.insert("ownership_verified", "私钥与此地址匹配");
+        zh.insert("ownership_mismatch", "已加载的私钥与此地址不匹配");
+        zh.insert("default_network_label", "默认网络：");
+        zh.insert("ui_scale_label", "文字大小：");
+        zh.insert("ui_scale_reset", "重置");
+        zh.insert("ui_scale_preview", "预览：余额 1,234.5 SUI");
+        zh.insert("theme_label", "主题");
+        zh.insert("theme_light", "浅色");
+        zh.insert("theme_dark", "深色");
+        zh.insert("theme_auto", "跟随系统");
+        zh.insert("theme_high_contrast", "高对比度");
+        zh.insert("wallet_save_failed_warning", "钱包已加载，但无法保存：{}。下次启动需要重新导入私钥。");
+        zh.insert("login_locked_out", "登录失败次数过多，请在 {} 分钟后重试。");
+        zh.insert("max_failed_login_attempts_label", "登录失败多少次后锁定（0 为不锁定）：");
+        zh.insert("login_lockout_minutes_label", "锁定分钟数：");
+        zh.insert("copy_config_path_menu", "复制配置目录路径");
+        zh.insert("open_config_folder_menu", "打开配置目录");
+        zh.insert("config_path_copied", "已复制配置目录路径：{}");
+        zh.insert("open_config_folder_failed", "无法打开配置目录：{}");
+        zh.insert("passwords_match", "✔ 两次输入的密码一致");
+        zh.insert("passwords_do_not_match", "✖ 两次输入的密码不一致");
+        zh.insert("payment_uri_invalid_address", "支付链接中的地址无效");
+        zh.insert("payment_uri_invalid_amount", "支付链接中的金额无效");
+        zh.insert("toggle_recent_network_hint", "切换到 {}");
+        zh.insert("write_crash_reports", "写入本地崩溃报告");
+        zh.insert("write_crash_reports_hint", "崩溃时将脱敏后的报告（版本、系统、最近日志）保存到配置目录，不会发送到任何地方");
+        zh.insert("crash_report_written", "钱包上次运行时崩溃，已将不含敏感信息的崩溃报告保存到：\n{}\n可将其附加到问题反馈中");
+        zh.insert("operation_refresh_balance", "正在刷新余额");
+        zh.insert("operation_check_other_networks", "正在查询其他网络");
+        zh.insert("operation_mnemonic_preview", "正在加载账户预览");
+        zh.insert("operation_health_check", "正在检查 RPC 端点");
+        zh.insert("operation_endpoint_test", "正在测试端点");
+        zh.insert("operation_send_transfer", "正在发送转账");
+        zh.insert("operation_cancelled", "已取消：{}");
+        zh.insert("confirm_password_title", "输入密码确认");
+        zh.insert("confirm_password_copy_private_key", "请输入密码以复制私钥");
+        zh.insert("confirm_password_send_transfer", "请输入密码以在主网上发送此笔转账");
+        zh.insert("confirm_password_send_batch_transfer", "请输入密码以在主网上发送此批量转账");
+        zh.insert("confirm_password_reset_wallet", "请输入密码以重置钱包");
+        zh.insert("confirm_password_delete_saved_key", "请输入密码以删除已保存的私钥");
+        zh.insert("confirm_password_change_password", "请输入当前密码以修改密码");
+        zh.insert("change_password_menu", "修改密码...");
+        zh.insert("change_password_title", "修改密码");
+        zh.insert("new_password_hint", "新密码");
+        zh.insert("confirm_new_password_hint", "再次输入新密码");
+        zh.insert("change_password_button", "修改密码");
+        zh.insert("password_changed", "密码已修改");
+        zh.insert("password_change_failed", "修改密码失败");
+        zh.insert("session_password_unavailable", "当前密码已不可用，请重新登录后再试");
+        zh.insert("reset_wallet_menu", "重置钱包...");
+        zh.insert("confirm_button", "确认");
+        zh.insert("security_warning_dismiss", "不再显示");
+        zh.insert("show_security_warning", "显示安全提醒");
+        zh.insert("import_temporarily_button", "临时导入");
+        zh.insert("import_temporarily_hint", "只在内存中加载私钥，不会保存，登出时清除。");
+        zh.insert("wallet_imported_temporarily", "已临时导入钱包（未保存）");
+        zh.insert("temporary_session_button", "不登录，临时使用私钥");
+        zh.insert("temporary_session_started", "临时会话：导入的私钥不会保存，登出时清除");
+        zh.insert("temporary_session_ended", "临时会话已结束，导入的私钥已从内存中清除");
+        zh.insert("temporary_session_label", "临时会话：私钥不会被保存");
+        zh.insert("end_temporary_session_button", "结束会话");
+        zh.insert("password_too_short", "密码至少需要 {} 个字符");
+        zh.insert("password_missing_lowercase", "密码至少需要包含一个小写字母");
+        zh.insert("password_missing_uppercase", "密码至少需要包含一个大写字母");
+        zh.insert("password_missing_digit", "密码至少需要包含一个数字");
+        zh.insert("password_missing_special", "密码至少需要包含一个特殊字符");
+        zh.insert("password_too_common", "该密码过于常见，容易被猜到，请换一个");
+        zh.insert("reject_common_passwords", "拒绝常见弱密码");
+        zh.insert("min_password_length_label", "密码最小长度：");
+        zh.insert("password_require_lowercase", "小写字母");
+        zh.insert("password_require_uppercase", "大写字母");
+        zh.insert("password_require_digit", "数字");
+        zh.insert("password_require_special", "特殊字符");
+        zh.insert("reject_common_passwords_hint", "设置密码时拒绝内置常见密码列表中的密码，以及加数字、用 p@ssw0rd 式字符替换的简单变体（离线检查）");
+        zh.insert("usd_converted_amount", "≈ {} SUI（按 ${} / SUI 计算）");
+        zh.insert("usd_price_unavailable", "SUI 价格不可用，请以 SUI 输入金额");
+        zh.insert("usd_price_fetching", "正在查询 SUI 价格...");
+        zh.insert("usd_price_source", "以美元输入金额时会向 CoinGecko 查询 SUI 价格");
+        zh.insert("transfer_status_pending", "处理中");
+        zh.insert("transfer_status_success", "成功");
+        zh.insert("transfer_status_failed", "失败");
+        zh.insert("transfer_title", "发送 SUI");
+        zh.insert("recipient_label", "收款地址：");
+        zh.insert("amount_label", "金额：");
+        zh.insert("transfer_submitting", "正在签名并提交转账...");
+        zh.insert("transfer_submitted", "转账已提交：{}");
+        zh.insert("transfer_succeeded", "转账成功：{}");
+        zh.insert("transfer_pending", "转账已提交，但尚未确认执行结果：{}");
+        zh.insert("transfer_failed", "转账失败");
+        zh.insert("transfer_note_label", "备注（仅本地）：");
+        zh.insert("transfer_note_hint", "只保存在本设备上");
+        zh.insert("edit_transfer_note", "编辑备注");
+        zh.insert("transfer_note_saved", "备注已保存");
+        zh.insert("transfer_note_save_failed", "保存备注失败");
         zh.insert("view_explorer", "在浏览器中查看");
+        zh.insert("view_transaction_explorer", "在浏览器中查看交易");
         zh.insert("balance_chart", "余额图表");
         zh.insert("chart_placeholder", "图表即将推出...");
         zh.insert("low_balance_warning", "余额不足警告");
@@ -270,26 +874,146 @@ impl I18nManager {
         let translations = init_translations();
         let lang_code = self.current_language.code();
         
-        if let Some(lang_map) = translations.get(lang_code) {
-            if let Some(text) = lang_map.get(key) {
-                return text.to_string();
-            }
+        if let Some(lang_map) = translations.get(lang_code)
+            && let Some(text) = lang_map.get(key)
+        {
+            return text.to_string();
         }
         
         // 回退到英文
-        if let Some(en_map) = translations.get("en") {
-            if let Some(text) = en_map.get(key) {
-                return text.to_string();
-            }
+        if let Some(en_map) = translations.get("en")
+            && let Some(text) = en_map.get(key)
+        {
+            return text.to_string();
         }
         
         // 如果都找不到，返回key本身
         key.to_string()
     }
+
+    /// 按当前语言格式化数字（带数字分组分隔符）
+    pub fn format_number(&self, value: f64, decimals: usize) -> String {
+        let formatted = format!("{:.*}", decimals, value.abs());
+        let grouped = self.format_decimal(&formatted);
+        if value.is_sign_negative() && value != 0.0 {
+            format!("-{}", grouped)
+        } else {
+            grouped
+        }
+    }
+
+    /// 按当前语言格式化非负十进制数字字符串（如 `1234.5678`），不经过浮点转换
+    pub fn format_decimal(&self, formatted: &str) -> String {
+        let (int_part, frac_part) = match formatted.split_once('.') {
+            Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+            None => (formatted, None),
+        };
+
+        let separator = self.current_language.group_separator();
+        let group_size = self.current_language.group_size();
+        let mut grouped = String::with_capacity(int_part.len() * 2);
+        for (i, digit) in int_part.chars().enumerate() {
+            if i > 0 && (int_part.len() - i) % group_size == 0 {
+                grouped.push(separator);
+            }
+            grouped.push(digit);
+        }
+
+        if let Some(frac_part) = frac_part {
+            grouped.push(self.current_language.decimal_separator());
+            grouped.push_str(frac_part);
+        }
+        grouped
+    }
+
+    /// 格式化可能未知的相对时间（系统时钟异常时为 None），未知时显示"未知"
+    pub fn format_relative_age(&self, elapsed: Option<Duration>) -> String {
+        match elapsed {
+            Some(elapsed) => self.format_relative_time(elapsed),
+            None => self.tr("time_unknown"),
+        }
+    }
+
+    /// 按当前语言格式化相对时间（如 "5 minutes ago" / "5 分钟前"）
+    pub fn format_relative_time(&self, elapsed: Duration) -> String {
+        let seconds = elapsed.as_secs();
+        let (count, unit) = match seconds {
+            0..=59 => return self.tr("time_just_now"),
+            60..=3_599 => (seconds / 60, "minute"),
+            3_600..=86_399 => (seconds / 3_600, "hour"),
+            _ => (seconds / 86_400, "day"),
+        };
+
+        match self.current_language {
+            Language::English => {
+                let plural = if count == 1 { "" } else { "s" };
+                format!("{} {}{} ago", count, unit, plural)
+            }
+            Language::Chinese => {
+                let unit = match unit {
+                    "minute" => "分钟",
+                    "hour" => "小时",
+                    _ => "天",
+                };
+                format!("{} {}前", count, unit)
+            }
+        }
+    }
 }
 
 impl Default for I18nManager {
     fn default() -> Self {
         Self::new()
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manager(language: Language) -> I18nManager {
+        let mut i18n = I18nManager::new();
+        i18n.set_language(language);
+        i18n
+    }
+
+    #[test]
+    fn login_errors_are_translated() {
+        let en = manager(Language::English);
+        let zh = manager(Language::Chinese);
+        assert_eq!(en.tr("password_incorrect_error"), "Incorrect password");
+        assert_eq!(zh.tr("password_incorrect_error"), "密码不正确");
+    }
+
+    #[test]
+    fn formats_numbers_per_language() {
+        let en = manager(Language::English);
+        assert_eq!(en.format_number(1_234_567.891, 2), "1,234,567.89");
+        assert_eq!(en.format_number(-9_876.5, 1), "-9,876.5");
+        assert_eq!(en.format_number(999.0, 0), "999");
+        assert_eq!(en.format_decimal("12345678.000000001"), "12,345,678.000000001");
+
+        // 中文按万分组
+        let zh = manager(Language::Chinese);
+        assert_eq!(zh.format_number(1_234_567.891, 2), "123,4567.89");
+        assert_eq!(zh.format_number(-9_876.5, 1), "-9876.5");
+        assert_eq!(zh.format_decimal("123456789"), "1,2345,6789");
+    }
+
+    #[test]
+    fn formats_relative_time_per_language() {
+        let en = manager(Language::English);
+        assert_eq!(en.format_relative_time(Duration::from_secs(30)), en.tr("time_just_now"));
+        assert_eq!(en.format_relative_time(Duration::from_secs(60)), "1 minute ago");
+        assert_eq!(en.format_relative_time(Duration::from_secs(5 * 60 + 30)), "5 minutes ago");
+        assert_eq!(en.format_relative_time(Duration::from_secs(2 * 3_600)), "2 hours ago");
+        assert_eq!(en.format_relative_time(Duration::from_secs(3 * 86_400)), "3 days ago");
+
+        let zh = manager(Language::Chinese);
+        assert_eq!(zh.format_relative_time(Duration::from_secs(30)), zh.tr("time_just_now"));
+        assert_eq!(zh.format_relative_time(Duration::from_secs(5 * 60 + 30)), "5 分钟前");
+        assert_eq!(zh.format_relative_time(Duration::from_secs(2 * 3_600)), "2 小时前");
+        assert_eq!(zh.format_relative_time(Duration::from_secs(3 * 86_400)), "3 天前");
+        assert_eq!(zh.format_relative_age(None), zh.tr("time_unknown"));
+    }
+}
@@ -1,16 +1,32 @@
+mod cli;
 mod controller;
 mod model;
 mod view;
 mod i18n;
 
 use eframe::{egui, App, Frame};
-use model::Model;
+use model::{AppSettings, CjkFont, Model, SETTINGS_STORAGE_KEY};
 use view::ViewAction; // 导入 ViewAction 枚举
 
 impl App for Model {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut Frame) {
+        // 登录界面同样需要防截图
+        view::MainView::sync_screenshot_protection(self, ctx);
+        view::MainView::sync_ui_scale(self, ctx);
+        view::MainView::sync_theme(self, ctx);
+
+        // 窗口失去焦点或最小化时锁定（需在设置中启用）
+        let (focused, minimized) = ctx.input(|i| (i.viewport().focused, i.viewport().minimized));
+        controller::handle_window_focus(self, focused, minimized);
+
+        // 有真实用户交互时延长会话，空闲超时则登出（临时会话同样适用）
+        if self.auth_state.has_wallet_access() {
+            let interacted = ctx.input(|i| controller::AuthController::is_user_interaction(&i.events));
+            controller::handle_session_activity(self, interacted);
+        }
+
         // 新增：未认证时显示密码面板（首次设置或登录）
-        if !self.auth_state.is_authenticated {
+        if !self.auth_state.has_wallet_access() {
             view::show_password_panel(self, ctx);
             ctx.request_repaint();
             return;
@@ -19,63 +35,188 @@ impl App for Model {
         // Controller: 处理后台消息
         controller::handle_async_results(self);
 
+        // 自动关闭过期的临时状态消息
+        self.dismiss_expired_status();
+
         // View: 绘制 UI 并获取用户动作
         let action = view::show(self, ctx);
 
         // Controller: 根据用户动作执行相应逻辑
-        if !self.is_loading {
-            match action {
-                ViewAction::ImportKey => {
-                    // 如果用户已认证，则导入并保存私钥
-                    // 注意：这里无法直接获取密码，因为认证后会清空密码字段
-                    // 在实际应用中，我们可以在导入时询问用户是否要保存，并要求再次输入密码
-                    // 或者在会话中保持一个临时的密码副本（需要谨慎处理安全性）
-                    // 目前我们使用简单的导入，用户可以在登录时自动加载之前保存的私钥
-                    controller::handle_import_key(self);
-                },
-                ViewAction::RefreshBalance => controller::handle_refresh_balance(self),
-                ViewAction::Logout => controller::handle_logout(self),
-                ViewAction::LanguageChanged(lang) => self.set_language(lang),
-                ViewAction::None => {}
+        // 取消操作不受进行中操作的限制，且保留"已取消"提示
+        let action = match action {
+            ViewAction::CancelOperation(operation) => {
+                controller::handle_cancel_operation(self, operation);
+                ViewAction::None
+            }
+            other => other,
+        };
+
+        // 临时会话只允许导入、查看和登出
+        let action = if self.auth_state.temporary_session && !action.allowed_in_temporary_session() {
+            ViewAction::None
+        } else {
+            action
+        };
+
+        // 转账、余额刷新等操作进行中时不处理会与之冲突的动作，其余动作（如登出）照常处理
+        let action = if self.operations.blocks_wallet_actions() && action.is_wallet_mutation() {
+            ViewAction::None
+        } else {
+            action
+        };
+
+        // 用户开始新的操作时清除上一条消息（包括一直显示的错误）
+        if action != ViewAction::None {
+            self.clear_status();
+        }
+        match action {
+            ViewAction::ImportKey => {
+                // 如果用户已认证，则导入并保存私钥
+                // 注意：这里无法直接获取密码，因为认证后会清空密码字段
+                // 在实际应用中，我们可以在导入时询问用户是否要保存，并要求再次输入密码
+                // 或者在会话中保持一个临时的密码副本（需要谨慎处理安全性）
+                // 目前我们使用简单的导入，用户可以在登录时自动加载之前保存的私钥
+                controller::handle_import_key(self);
+            },
+            ViewAction::ImportKeyTemporarily => controller::handle_import_key_temporarily(self),
+            ViewAction::ConfirmReplaceWallet => controller::handle_confirm_replace_wallet(self),
+            ViewAction::RefreshBalance => controller::handle_refresh_balance(self),
+            ViewAction::RecipientChanged => controller::handle_recipient_changed(self),
+            ViewAction::SendTransfer => controller::handle_send_transfer(self),
+            ViewAction::SendBatchTransfer => controller::handle_send_batch_transfer(self),
+            ViewAction::ConfirmSelfSend => controller::handle_confirm_self_send(self),
+            ViewAction::ConfirmLargeAmount => controller::handle_confirm_large_amount(self),
+            ViewAction::SaveTransferNote { digest, note } => controller::handle_save_transfer_note(self, &digest, &note),
+            ViewAction::CheckOtherNetworks => controller::handle_check_other_networks(self),
+            ViewAction::RefreshNetworkHealth => controller::handle_refresh_network_health(self),
+            ViewAction::OpenCustomNetwork => controller::handle_open_custom_network(self),
+            ViewAction::OpenRpcFallbacks => controller::handle_open_rpc_fallbacks(self),
+            ViewAction::SaveRpcFallbacks => controller::handle_save_rpc_fallbacks(self),
+            ViewAction::SaveCustomNetwork { name, url } => controller::handle_save_custom_network(self, name, url),
+            ViewAction::TestCustomNetwork(url) => controller::handle_test_custom_network(self, url),
+            ViewAction::CopyPrivateKey => controller::handle_copy_private_key(self),
+            ViewAction::ConfirmCopyPrivateKey { dont_warn_again } => {
+                controller::handle_confirm_copy_private_key(self, dont_warn_again)
+            }
+            ViewAction::KeyFileDropped { name, path, bytes } => {
+                controller::handle_file_dropped(self, name, path, bytes)
             }
+            ViewAction::ConfirmKeyFileImport => controller::handle_confirm_key_file_import(self),
+            ViewAction::CancelKeyFileImport => controller::handle_cancel_key_file_import(self),
+            ViewAction::ExportAddresses => controller::handle_export_addresses(self),
+            ViewAction::OpenCoinFilter => controller::handle_open_coin_filter(self),
+            ViewAction::SaveCoinFilter => controller::handle_save_coin_filter(self),
+            ViewAction::VerifyOwnership => controller::handle_verify_ownership(self),
+            ViewAction::CopyConfigPath => controller::handle_copy_config_path(self),
+            ViewAction::OpenConfigFolder => controller::handle_open_config_folder(self),
+            ViewAction::SetWriteCrashReports(enabled) => controller::handle_set_write_crash_reports(self, enabled),
+            ViewAction::StayUnlocked => controller::handle_stay_unlocked(self),
+            ViewAction::ConfirmWithPassword => controller::handle_confirm_with_password(self),
+            ViewAction::CancelPasswordConfirmation => controller::handle_cancel_password_confirmation(self),
+            // 已在上面处理
+            ViewAction::CancelOperation(_) => {}
+            ViewAction::PreviewMnemonicAccounts => controller::handle_preview_mnemonic(self),
+            ViewAction::ImportMnemonicAccount(account) => controller::handle_import_mnemonic_account(self, account),
+            ViewAction::RequestDeleteSavedKey => controller::handle_request_delete_key(self),
+            ViewAction::ConfirmDeleteSavedKey => {
+                if let Err(e) = controller::handle_confirm_delete_key(self) {
+                    model::log_error(&format!("Failed to delete saved private key: {}", e));
+                }
+            }
+            ViewAction::RequestResetWallet => controller::handle_request_reset(self),
+            ViewAction::ConfirmResetWallet => {
+                if let Err(e) = controller::handle_reset_wallet(self) {
+                    model::log_error(&format!("Failed to reset wallet data: {}", e));
+                }
+            }
+            ViewAction::SubmitChangePassword => controller::handle_request_change_password(self),
+            ViewAction::CancelChangePassword => controller::handle_cancel_change_password(self),
+            ViewAction::Logout => controller::handle_logout(self),
+            ViewAction::RenameAccount(label) => controller::handle_rename_account(self, label),
+            ViewAction::SelectAccount(index) => controller::handle_select_account(self, index),
+            ViewAction::SwitchNetwork(network) => controller::handle_switch_network(self, network),
+            ViewAction::SetSeparateAccountsPerNetwork(enabled) => controller::handle_set_separate_accounts(self, enabled),
+            ViewAction::SetAutoSelectEndpoint(enabled) => controller::handle_set_auto_select_endpoint(self, enabled),
+            ViewAction::SetAutoSwitchNetwork(enabled) => controller::handle_set_auto_switch_network(self, enabled),
+            ViewAction::SetPreferredNetwork(network) => controller::handle_set_preferred_network(self, network),
+            ViewAction::LanguageChanged(lang) => self.set_language(lang),
+            ViewAction::None => {}
         }
 
         // 持续请求重绘
         ctx.request_repaint();
     }
+
+    fn save(&mut self, storage: &mut dyn eframe::Storage) {
+        // 保存应用设置；egui 自身的界面状态由 eframe 自动持久化
+        eframe::set_value(storage, SETTINGS_STORAGE_KEY, &self.app_state.settings);
+    }
 }
 
 fn main() -> Result<(), eframe::Error> {
+    // 崩溃时清除内存中的会话密码和钥匙串条目，并只记录脱敏后的 panic 消息
+    model::install_panic_hook();
+
+    // 带参数运行时进入命令行模式，不启动 GUI
+    match cli::parse_args(std::env::args().skip(1)) {
+        Ok(Some(command)) => std::process::exit(cli::run(command)),
+        Ok(None) => {}
+        Err(e) => {
+            cli::print_usage_error(&e);
+            std::process::exit(2);
+        }
+    }
+
     let options = eframe::NativeOptions {
-        viewport: egui::ViewportBuilder::default().with_inner_size([550.0, 450.0]),
+        // 固定 app_id，保证持久化目录不随界面语言变化
+        viewport: egui::ViewportBuilder::default()
+            .with_inner_size([550.0, 450.0])
+            .with_app_id("sui_rust_wallet"),
+        persist_window: true,
         ..Default::default()
     };
 
-    let model = Model::default();
-    let window_title = model.i18n.tr("app_title");
+    // 升级旧版本的配置目录结构，失败时继续启动
+    if let Err(e) = model::ConfigMigrator::run(&model::wallet_config_dir()) {
+        model::log_error(&format!("Config directory migration failed: {}", e));
+    }
+
+    // 上次运行异常退出时钥匙串中可能残留会话密码条目，只清理所有者已退出的条目
+    model::SessionSecret::clear_stale_keyring_entries(&model::wallet_config_dir());
+
+    // 已保存的设置只能在 setup 中读取，先用默认语言的标题，应用设置后再更新
+    let window_title = i18n::I18nManager::new().tr("app_title");
 
     eframe::run_native(
         &window_title,
         options,
         Box::new(|cc| {
-            // 字体设置 - 使用 egui 内置字体支持中文
-            let ctx = &cc.egui_ctx;
-            let mut fonts = egui::FontDefinitions::default();
-            
-            // 尝试加载字体文件，如果失败则使用系统字体
-            if let Ok(font_data) = std::fs::read("assets/NotoSansSC-Regular.ttf") {
-                fonts.font_data.insert(
-                    "noto_sans_sc".to_owned(),
-                    egui::FontData::from_owned(font_data).into(),
-                );
-                // 将中文字体设为首选
-                fonts.families.entry(egui::FontFamily::Proportional).or_default().insert(0, "noto_sans_sc".to_owned());
-                fonts.families.entry(egui::FontFamily::Monospace).or_default().insert(0, "noto_sans_sc".to_owned());
+            // 字体设置：内置中文字体，可通过环境变量指定自定义字体
+            let font_source = CjkFont::resolve(CjkFont::override_path().as_deref());
+            cc.egui_ctx.set_fonts(view::font_definitions(font_source));
+
+            let mut model = Model::default();
+            if let Some(settings) = cc
+                .storage
+                .and_then(|storage| eframe::get_value::<AppSettings>(storage, SETTINGS_STORAGE_KEY))
+            {
+                model.apply_settings(settings);
+            }
+            cc.egui_ctx.send_viewport_cmd(egui::ViewportCommand::Title(model.i18n.tr("app_title")));
+
+            // 上次运行崩溃时提示崩溃报告的位置，方便用户附加到问题反馈
+            if let Some(path) = model::CrashReport::take_pending(&model::wallet_config_dir()) {
+                let message = model.i18n.tr("crash_report_written").replace("{}", &path.display().to_string());
+                model.app_state.ui_state.show_dialog(model::DialogState::Info(message));
             }
-            
-            ctx.set_fonts(fonts);
 
-            Ok(Box::new(Model::default()))
+            // "保持登录"令牌有效时自动登录
+            controller::AuthController::try_resume_session(&mut model);
+
+            // 非阻塞地检查 RPC 端点，结果在登录后提示
+            controller::MainController::handle_startup_health_check(&mut model);
+
+            Ok(Box::new(model))
         }),
     )
 }
@@ -5,14 +5,62 @@ mod wallet_model;
 mod network_model;
 mod auth_model;
 mod app_state;
+mod session_model;
+mod secret_model;
+mod migration_model;
+mod history_model;
+mod batch_model;
+mod balance_model;
+mod status_model;
+mod account_model;
+mod coin_model;
+mod export_model;
+mod mnemonic_model;
+mod log_model;
+mod ownership_model;
+mod font_model;
+mod lockout_model;
+mod payment_model;
+mod crash_model;
+mod operation_model;
+mod transfer_model;
+mod price_model;
+#[cfg(test)]
+mod test_support;
 
 // 重新导出子模块的公共类型
 pub use wallet_model::*;
 pub use network_model::*;
 pub use auth_model::*;
 pub use app_state::*;
+pub use session_model::*;
+pub use secret_model::*;
+pub use migration_model::*;
+pub use history_model::*;
+pub use batch_model::*;
+pub use balance_model::*;
+pub use status_model::*;
+pub use account_model::*;
+pub use coin_model::*;
+pub use export_model::*;
+pub use mnemonic_model::*;
+pub use log_model::*;
+pub use ownership_model::*;
+pub use font_model::*;
+pub use lockout_model::*;
+pub use payment_model::*;
+pub use crash_model::*;
+pub use operation_model::*;
+pub use transfer_model::*;
+pub use price_model::*;
+#[cfg(test)]
+pub use test_support::*;
 
+use std::path::Path;
+use std::str::FromStr;
 use std::sync::mpsc::{self, Receiver, Sender};
+use std::time::Instant;
+use sui_sdk::types::base_types::SuiAddress;
 use tokio::runtime::Runtime;
 use crate::i18n::{I18nManager, Language};
 
@@ -24,6 +72,8 @@ pub struct Model {
     
     // 网络配置
     pub network: Network,
+    pub network_health: NetworkHealthState,
+    pub network_config: NetworkConfig,
     
     // 认证相关状态
     pub auth_state: AuthState,
@@ -32,49 +82,340 @@ pub struct Model {
     pub app_state: AppState,
     
     // UI 状态
-    pub result_text: String,
-    pub is_loading: bool,
-    
-    // 转账信息
-    pub recipient_address: String,
-    pub transfer_amount: String,
+    /// 临时状态消息（None 表示没有需要显示的消息）
+    pub status: Option<StatusMessage>,
+    /// 进行中的异步操作
+    pub operations: InFlightOperations,
+
+    // 最近一次查询到的 SUI 余额
+    pub balance: Option<Balance>,
+    // 最近一次查询到的代币持有情况
+    pub holdings: Option<Holdings>,
+    // 最近一次查询到余额的时间
+    pub balance_updated_at: Option<Instant>,
+    // 最近一次刷新带来的余额变化（短暂高亮显示）
+    pub balance_change: Option<BalanceChange>,
+    // 提供最近一次余额的 RPC 端点（可能是备用端点）
+    pub balance_endpoint: Option<String>,
+    // 最近一次余额所属的查询（地址和网络）
+    pub balance_source: Option<BalanceRequest>,
+    // 进行中的余额查询及最近一次查询的编号
+    pub balance_request: Option<BalanceRequest>,
+    pub balance_generation: u64,
+    // 当前地址在各网络上的余额
+    pub network_balances: NetworkBalances,
+    // 助记词导入预览（前几个账户的地址和余额）
+    pub mnemonic_preview: Option<MnemonicPreview>,
+    // 最近一次地址所有权自检的结果
+    pub ownership_check: Option<OwnershipCheck>,
+
+    // 本地转账历史
+    pub transfer_history: TransferHistory,
+    // 正在提交的转账
+    pub pending_transfer: Option<TransferPlan>,
+    // SUI 美元价格的查询状态
+    pub price_feed: PriceFeed,
 
     // 国际化相关
     pub i18n: I18nManager,
 
     // 异步处理
     pub rt: Runtime,
-    pub sender: Sender<Result<String, String>>,
-    pub receiver: Receiver<Result<String, String>>,
+    pub balance_sender: Sender<BalanceUpdate>,
+    pub balance_receiver: Receiver<BalanceUpdate>,
+    pub network_balance_sender: Sender<(SuiAddress, Network, Result<Balance, String>)>,
+    pub network_balance_receiver: Receiver<(SuiAddress, Network, Result<Balance, String>)>,
+    pub preview_balance_sender: Sender<(SuiAddress, Result<Balance, String>)>,
+    pub preview_balance_receiver: Receiver<(SuiAddress, Result<Balance, String>)>,
+    pub health_sender: Sender<(Network, NetworkHealthStatus)>,
+    pub health_receiver: Receiver<(Network, NetworkHealthStatus)>,
+    pub price_sender: Sender<Result<f64, String>>,
+    pub price_receiver: Receiver<Result<f64, String>>,
+    pub endpoint_test_sender: Sender<(String, Result<String, String>)>,
+    pub endpoint_test_receiver: Receiver<(String, Result<String, String>)>,
+    pub endpoint_probe_sender: Sender<(Network, Vec<EndpointProbe>)>,
+    pub endpoint_probe_receiver: Receiver<(Network, Vec<EndpointProbe>)>,
+    pub transfer_sender: Sender<TransferEvent>,
+    pub transfer_receiver: Receiver<TransferEvent>,
 }
 
 impl Default for Model {
     fn default() -> Self {
-        let (sender, receiver) = mpsc::channel();
+        Self::with_config_dir(&wallet_config_dir())
+    }
+}
+
+impl Model {
+    /// 使用指定的配置目录创建模型：密码、加密私钥、会话令牌和转账历史都读写该目录
+    pub fn with_config_dir(config_dir: &Path) -> Self {
+        let (balance_sender, balance_receiver) = mpsc::channel();
+        let (network_balance_sender, network_balance_receiver) = mpsc::channel();
+        let (preview_balance_sender, preview_balance_receiver) = mpsc::channel();
+        let (health_sender, health_receiver) = mpsc::channel();
+        let (price_sender, price_receiver) = mpsc::channel();
+        let (endpoint_test_sender, endpoint_test_receiver) = mpsc::channel();
+        let (endpoint_probe_sender, endpoint_probe_receiver) = mpsc::channel();
+        let (transfer_sender, transfer_receiver) = mpsc::channel();
         let i18n_manager = I18nManager::new();
         let import_message = i18n_manager.tr("import_private_key_message");
+        let mut app_state = AppState::default();
+        app_state.settings.language = i18n_manager.current_language();
+        let network_config = NetworkConfig::new();
+        let mut auth_state = AuthState::with_config_dir(config_dir);
+        auth_state.set_session_timeout_minutes(app_state.settings.session_timeout_minutes);
+
+        // 私钥文件存在但密码文件缺失：提示用户处理孤立的私钥
+        if auth_state.has_orphaned_private_key() {
+            app_state.ui_state.show_dialog(DialogState::OrphanedKey);
+        }
 
         Self {
             wallet: WalletState::default(),
-            network: Network::Testnet,
-            auth_state: AuthState::default(),
-            app_state: AppState::default(),
-            result_text: import_message,
-            is_loading: false,
-            recipient_address: String::new(),
-            transfer_amount: String::new(),
+            network: Network::default(),
+            network_health: NetworkHealthState::default(),
+            network_config,
+            auth_state,
+            app_state,
+            status: Some(StatusMessage::info(import_message)),
+            operations: InFlightOperations::default(),
+            balance: None,
+            holdings: None,
+            balance_updated_at: None,
+            balance_change: None,
+            balance_endpoint: None,
+            balance_source: None,
+            balance_request: None,
+            balance_generation: 0,
+            network_balances: NetworkBalances::default(),
+            mnemonic_preview: None,
+            ownership_check: None,
+            transfer_history: TransferHistory::load_from_dir(config_dir),
+            pending_transfer: None,
+            price_feed: PriceFeed::default(),
             i18n: i18n_manager,
             rt: Runtime::new().expect("Failed to create Tokio runtime"),
-            sender,
-            receiver,
+            balance_sender,
+            balance_receiver,
+            network_balance_sender,
+            network_balance_receiver,
+            preview_balance_sender,
+            preview_balance_receiver,
+            health_sender,
+            health_receiver,
+            price_sender,
+            price_receiver,
+            endpoint_test_sender,
+            endpoint_test_receiver,
+            endpoint_probe_sender,
+            endpoint_probe_receiver,
+            transfer_sender,
+            transfer_receiver,
+        }
+    }
+
+    /// 设置状态消息
+    pub fn set_status(&mut self, status: StatusMessage) {
+        self.status = Some(status);
+    }
+
+    /// 清除状态消息
+    pub fn clear_status(&mut self) {
+        self.status = None;
+    }
+
+    /// 清除余额，进行中的查询结果到达后会被忽略
+    pub fn reset_balance(&mut self) {
+        self.clear_displayed_balance();
+        self.network_balances = NetworkBalances::default();
+        self.operations.cancel(AsyncOperation::CheckOtherNetworks);
+        self.balance_request = None;
+        self.operations.cancel(AsyncOperation::RefreshBalance);
+    }
+
+    /// 清除显示的余额（不影响进行中的查询）
+    pub fn clear_displayed_balance(&mut self) {
+        self.balance = None;
+        self.holdings = None;
+        self.balance_updated_at = None;
+        self.balance_change = None;
+        self.balance_endpoint = None;
+        self.balance_source = None;
+    }
+
+    /// 关闭助记词预览，进行中的预览余额查询结果到达后会被忽略
+    pub fn clear_mnemonic_preview(&mut self) {
+        self.mnemonic_preview = None;
+        self.operations.cancel(AsyncOperation::MnemonicPreview);
+    }
+
+    /// 取消进行中的操作：中止后台任务，并清除对应的进行中状态，之后到达的结果会被忽略
+    /// 返回该操作之前是否在进行
+    pub fn cancel_operation(&mut self, operation: AsyncOperation) -> bool {
+        if !self.operations.is_running(operation) {
+            return false;
+        }
+        match operation {
+            AsyncOperation::RefreshBalance => {
+                self.balance_request = None;
+                self.operations.cancel(operation);
+            }
+            AsyncOperation::CheckOtherNetworks => {
+                self.network_balances = NetworkBalances::default();
+                self.operations.cancel(operation);
+            }
+            AsyncOperation::MnemonicPreview => self.clear_mnemonic_preview(),
+            AsyncOperation::HealthCheck => {
+                self.network_health.cancel_check();
+                self.operations.cancel(operation);
+            }
+            AsyncOperation::EndpointTest => {
+                let ui_state = &mut self.app_state.ui_state;
+                ui_state.custom_network_test = EndpointTestStatus::Idle;
+                ui_state.custom_network_tested_url.clear();
+                self.operations.cancel(operation);
+            }
+            // 已提交的交易无法撤回，等待执行结果
+            AsyncOperation::SendTransfer => return false,
+        }
+        true
+    }
+
+    /// 当前余额的可信程度，用于转账前的快速检查
+    pub fn balance_state(&self, now: Instant) -> BalanceState {
+        BalanceState::from_last_fetch(self.balance, self.balance_updated_at, self.balance_request.is_some(), now)
+    }
+
+    /// 转账前检查：先验证输入，再用已知余额快速拒绝超额转账（在试运行之前调用）
+    pub fn precheck_transfer(&self, now: Instant) -> Result<(), String> {
+        self.app_state.validate_transfer_info()?;
+        self.app_state.check_amount_within_balance(self.balance_state(now))
+    }
+
+    /// 发送按钮不可用的原因，None 表示可以发送
+    /// 依次检查：没有进行中的操作、已加载钱包、收款地址有效、金额为正、Gas 预算有效且金额不超过可用余额
+    /// 余额使用结构化的余额状态：未查询或已过期时都不能发送
+    pub fn send_blocker(&self, now: Instant) -> Option<SendBlocker> {
+        if self.operations.blocks_wallet_actions() {
+            return Some(SendBlocker::Loading);
+        }
+        if !self.wallet.is_loaded() {
+            return Some(SendBlocker::NoWallet);
+        }
+
+        let recipient = self.app_state.normalized_recipient();
+        if recipient.is_empty() {
+            return Some(SendBlocker::MissingRecipient);
+        }
+        if !WalletUtils::is_valid_address_format(&recipient) || SuiAddress::from_str(&recipient).is_err() {
+            return Some(SendBlocker::InvalidRecipient);
+        }
+
+        if WalletUtils::normalize_input(&self.app_state.transfer_amount).is_empty() {
+            return Some(SendBlocker::MissingAmount);
+        }
+        let amount_mist = match self.app_state.transfer_amount_mist() {
+            Ok(0) => return Some(SendBlocker::ZeroAmount),
+            Ok(mist) => mist,
+            Err(_) => return Some(SendBlocker::InvalidAmount),
+        };
+        if self.app_state.transfer_gas_budget(self.network.estimated_tx_fee_mist()).is_err() {
+            return Some(SendBlocker::InvalidGasBudget);
+        }
+
+        let balance_mist = match self.balance_state(now) {
+            BalanceState::Unknown => return Some(SendBlocker::BalanceUnknown),
+            BalanceState::Stale(_) => return Some(SendBlocker::BalanceStale),
+            BalanceState::Known(balance) => balance.mist(),
+        };
+        let spendable_mist = Balance::from_mist(balance_mist).spendable_after_gas(self.gas_reserve_mist()).mist();
+        (amount_mist > spendable_mist).then_some(SendBlocker::ExceedsSpendable)
+    }
+
+    /// 根据转账表单生成要提交的转账（收款地址、金额、Gas 预算和备注）
+    pub fn transfer_plan(&self) -> Result<TransferPlan, String> {
+        let recipient = SuiAddress::from_str(&self.app_state.normalized_recipient())
+            .map_err(|_| self.i18n.tr("invalid_recipient"))?;
+        let amount_mist = self.app_state.transfer_amount_mist()?;
+        let estimate_mist = self.network.estimated_tx_fee_mist();
+        let gas_budget_mist = self.app_state.transfer_gas_budget(estimate_mist)?;
+        Ok(TransferPlan::single(recipient, amount_mist, gas_budget_mist).with_note(&self.app_state.transfer_note))
+    }
+
+    /// 根据批量转账输入生成在同一笔交易中执行的转账
+    pub fn batch_transfer_plan(&self) -> Result<TransferPlan, String> {
+        let batch = BatchTransfer::parse_csv(&self.app_state.ui_state.batch_input);
+        if !batch.is_valid() {
+            return Err(self.i18n.tr(SendBlocker::InvalidBatch.i18n_key()));
+        }
+        let gas_budget_mist = self.batch_gas_budget(&batch)?;
+        let recipients = batch.rows.iter().map(|row| (row.recipient, row.amount_mist)).collect();
+        Ok(TransferPlan::batch(recipients, gas_budget_mist))
+    }
+
+    /// 批量转账的 Gas 预算（手动预算或按收款人数的预估值），摘要、确认对话框和提交的交易都使用该数值
+    pub fn batch_gas_budget(&self, batch: &BatchTransfer) -> Result<u64, String> {
+        self.app_state.transfer_gas_budget(batch.estimated_gas_mist())
+    }
+
+    /// 批量转账不可发送的原因，None 表示可以发送
+    /// 依次检查：没有进行中的操作、已加载钱包、批量输入有效，且总金额加 Gas 预算不超过已知余额
+    pub fn batch_send_blocker(&self, now: Instant) -> Option<SendBlocker> {
+        if self.operations.blocks_wallet_actions() {
+            return Some(SendBlocker::Loading);
+        }
+        if !self.wallet.is_loaded() {
+            return Some(SendBlocker::NoWallet);
+        }
+        let Ok(plan) = self.batch_transfer_plan() else {
+            return Some(SendBlocker::InvalidBatch);
+        };
+
+        let balance_mist = match self.balance_state(now) {
+            BalanceState::Unknown => return Some(SendBlocker::BalanceUnknown),
+            BalanceState::Stale(_) => return Some(SendBlocker::BalanceStale),
+            BalanceState::Known(balance) => balance.mist(),
+        };
+        plan.required_mist()
+            .is_none_or(|required_mist| required_mist > balance_mist)
+            .then_some(SendBlocker::ExceedsSpendable)
+    }
+
+    /// 转账需要预留的 Gas（MIST）：使用交易的 Gas 预算（手动预算或当前网络的预估费用）
+    pub fn gas_reserve_mist(&self) -> u64 {
+        let estimate_mist = self.network.estimated_tx_fee_mist();
+        self.app_state.transfer_gas_budget(estimate_mist).unwrap_or(estimate_mist)
+    }
+
+    /// 可以转出的最大金额（MIST）：余额减去预留的 Gas，余额未知时为 None
+    pub fn max_transfer_mist(&self) -> Option<u64> {
+        self.balance.map(|balance| balance.spendable_after_gas(self.gas_reserve_mist()).mist())
+    }
+
+    /// 用最大可转金额填写转账金额（以 SUI 精确填写），余额刷新后会重新计算，返回是否已填写
+    pub fn fill_max_transfer_amount(&mut self) -> bool {
+        let Some(max_mist) = self.max_transfer_mist() else {
+            return false;
+        };
+        let app_state = &mut self.app_state;
+        app_state.transfer_amount_unit = AmountUnit::Sui;
+        app_state.transfer_amount = Balance::from_mist(max_mist).to_sui_string();
+        app_state.transfer_amount_is_max = true;
+        true
+    }
+
+    /// 关闭已超过显示时长的状态消息
+    pub fn dismiss_expired_status(&mut self) {
+        let ttl = &self.app_state.settings.status_ttl;
+        if self.status.as_ref().is_some_and(|status| status.is_expired(ttl)) {
+            self.status = None;
         }
     }
-}
 
-impl Model {
     // 国际化方法
     pub fn set_language(&mut self, language: Language) {
         self.i18n.set_language(language);
+        // 转账校验消息由 AppState 自己的 I18nManager 生成，需要同步切换
+        self.app_state.set_language(language);
     }
 
     pub fn current_language(&self) -> Language {
@@ -83,7 +424,13 @@ impl Model {
 
     // 密码相关方法 - 委托给 AuthState
     pub fn set_password(&mut self) -> Result<(), String> {
-        self.auth_state.set_password(&self.i18n)
+        let first_run = self.auth_state.is_first_run;
+        self.auth_state.set_password(&self.i18n)?;
+        // 新钱包（首次运行或重置后）重新显示安全提醒，不沿用之前的确认
+        if first_run {
+            self.app_state.settings.reset_security_warning();
+        }
+        Ok(())
     }
 
     pub fn verify_password(&mut self, attempt: &str) -> Result<bool, String> {
@@ -91,54 +438,276 @@ impl Model {
     }
 
     // 向后兼容的字段访问器
-    pub fn is_authenticated(&self) -> bool {
-        self.auth_state.is_authenticated
-    }
-
     pub fn is_first_run(&self) -> bool {
         self.auth_state.is_first_run
     }
 
-    pub fn password_input(&self) -> &str {
-        &self.auth_state.password_input
+    /// 是否可以不登录进入临时会话：已登录、已锁定（需输入密码解锁）或已加载钱包时不允许
+    pub fn can_start_temporary_session(&self) -> bool {
+        !self.auth_state.is_authenticated && !self.auth_state.is_locked && !self.wallet.is_loaded()
     }
 
-    pub fn password_confirm(&self) -> &str {
-        &self.auth_state.password_confirm
+    // 网络相关方法委托
+    pub fn set_network(&mut self, network: Network) {
+        if self.network != network {
+            self.network_health.reset();
+            self.operations.cancel(AsyncOperation::HealthCheck);
+        }
+        self.network_config.switch_to(network.clone());
+        self.network = network;
     }
 
-    pub fn password_input_mut(&mut self) -> &mut String {
-        &mut self.auth_state.password_input
+    /// 应用已持久化的设置
+    pub fn apply_settings(&mut self, mut settings: AppSettings) {
+        settings.security_settings.clamp_to_limits();
+        self.set_language(settings.language);
+        self.auth_state.set_use_os_keyring(settings.security_settings.use_os_keyring);
+        self.auth_state.set_memory_protection(settings.security_settings.memory_protection);
+        self.auth_state.apply_config(&settings.auth_config());
+        self.set_network(settings.startup_network());
+        self.network_config.auto_switch = settings.auto_switch_network;
+        self.network_config.set_preferred(settings.preferred_network.clone());
+        self.network_config.set_auto_select_endpoint(settings.auto_select_endpoint);
+        set_crash_reports_enabled(settings.write_crash_reports);
+        self.app_state.settings = settings;
+        let scope = self.account_scope();
+        self.auth_state.set_account_scope(&scope);
     }
 
-    pub fn password_confirm_mut(&mut self) -> &mut String {
-        &mut self.auth_state.password_confirm
+    /// 指定网络按尝试顺序排列的 RPC 端点（含备用端点和自动选择结果）
+    pub fn rpc_endpoints(&self, network: &Network) -> Vec<String> {
+        self.network_config.ordered_endpoints(network, &self.app_state.settings.rpc_fallbacks)
     }
 
-    // 钱包相关方法委托
-    pub fn get_wallet_state(&self) -> &WalletState {
-        &self.wallet
+    /// 当前网络优先使用的 RPC 端点
+    pub fn active_endpoint(&self) -> String {
+        self.network_config.active_endpoint_for(&self.network, &self.app_state.settings.rpc_fallbacks)
     }
 
-    pub fn get_wallet_state_mut(&mut self) -> &mut WalletState {
-        &mut self.wallet
+    /// 当前设置和网络对应的账户范围
+    pub fn account_scope(&self) -> AccountScope {
+        AccountScope::resolve(self.app_state.settings.separate_accounts_per_network, &self.network)
     }
 
-    // 网络相关方法委托
-    pub fn get_network(&self) -> Network {
-        self.network
+    /// 设置会话超时（None 表示永不超时），已登录时立即按新时长重新计时
+    pub fn set_session_timeout_minutes(&mut self, minutes: Option<u64>) {
+        self.app_state.settings.session_timeout_minutes = minutes;
+        self.auth_state.set_session_timeout_minutes(minutes);
+        if self.auth_state.is_authenticated() {
+            self.auth_state.start_session();
+        }
     }
+}
 
-    pub fn set_network(&mut self, network: Network) {
-        self.network = network;
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 测试用 Ed25519 私钥（字节 0x01..=0x20）
+    const TEST_KEY: &str = "suiprivkey1qqqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0jqa4ffsr";
+    const RECIPIENT: &str = "0x0000000000000000000000000000000000000000000000000000000000000a11";
+
+    /// 已加载钱包、余额 2 SUI、向有效地址转账 1 SUI 的模型
+    fn ready_model(dir: &Path) -> Model {
+        let mut model = Model::with_config_dir(dir);
+        let (address, keypair) = WalletUtils::import_private_key(TEST_KEY).unwrap();
+        model.wallet = WalletState::new_loaded(address, keypair);
+        model.balance = Some(Balance::from_mist(2 * MIST_PER_SUI));
+        model.balance_updated_at = Some(Instant::now());
+        model.app_state.recipient_address = RECIPIENT.to_string();
+        model.app_state.transfer_amount = "1".to_string();
+        model
+    }
+
+    #[test]
+    fn precheck_rejects_amounts_above_the_known_balance() {
+        let dir = TestDir::new("precheck_rejects_amounts_above_the_known_balance");
+        let mut model = ready_model(&dir);
+        assert_eq!(model.precheck_transfer(Instant::now()), Ok(()));
+
+        model.app_state.transfer_amount = "3".to_string();
+        assert_eq!(model.precheck_transfer(Instant::now()), Err(model.i18n.tr("amount_exceeds_balance")));
+
+        // 余额已过期时跳过余额检查，只验证输入
+        model.balance_updated_at = Instant::now().checked_sub(BALANCE_STALE_AFTER);
+        assert_eq!(model.precheck_transfer(Instant::now()), Ok(()));
+        model.app_state.recipient_address.clear();
+        assert_eq!(model.precheck_transfer(Instant::now()), Err(model.i18n.tr("recipient_required")));
+    }
+
+    #[test]
+    fn transfer_validation_messages_follow_the_selected_language() {
+        let dir = TestDir::new("transfer_validation_messages_follow_the_selected_language");
+        let mut model = ready_model(&dir);
+        model.set_language(Language::Chinese);
+        model.app_state.transfer_amount = "3".to_string();
+
+        let mut zh = crate::i18n::I18nManager::new();
+        zh.set_language(Language::Chinese);
+        assert_eq!(model.precheck_transfer(Instant::now()), Err(zh.tr("amount_exceeds_balance")));
+        assert_ne!(zh.tr("amount_exceeds_balance"), crate::i18n::I18nManager::new().tr("amount_exceeds_balance"));
+    }
+
+    #[test]
+    fn send_is_enabled_only_when_every_precondition_holds() {
+        let dir = TestDir::new("send_is_enabled_only_when_every_precondition_holds");
+        assert_eq!(ready_model(&dir).send_blocker(Instant::now()), None);
+
+        type Case = (fn(&mut Model), SendBlocker);
+        let cases: Vec<Case> = vec![
+            (|m| m.operations.track(AsyncOperation::RefreshBalance, m.rt.spawn(std::future::pending::<()>()).abort_handle()), SendBlocker::Loading),
+            (|m| m.wallet = WalletState::default(), SendBlocker::NoWallet),
+            (|m| m.app_state.recipient_address = " ".to_string(), SendBlocker::MissingRecipient),
+            (|m| m.app_state.recipient_address = "0x1234".to_string(), SendBlocker::InvalidRecipient),
+            (|m| m.app_state.transfer_amount = String::new(), SendBlocker::MissingAmount),
+            (|m| m.app_state.transfer_amount = "abc".to_string(), SendBlocker::InvalidAmount),
+            (|m| m.app_state.transfer_amount = "0.000".to_string(), SendBlocker::ZeroAmount),
+            (|m| {
+                m.app_state.ui_state.show_advanced_options = true;
+                m.app_state.gas_budget_input = "abc".to_string();
+            }, SendBlocker::InvalidGasBudget),
+            (|m| m.balance = None, SendBlocker::BalanceUnknown),
+            (|m| m.balance_updated_at = Instant::now().checked_sub(BALANCE_STALE_AFTER), SendBlocker::BalanceStale),
+            // 需要为 Gas 预留余额
+            (|m| m.app_state.transfer_amount = "2".to_string(), SendBlocker::ExceedsSpendable),
+        ];
+        for (break_precondition, expected) in cases {
+            let mut model = ready_model(&dir);
+            break_precondition(&mut model);
+            assert_eq!(model.send_blocker(Instant::now()), Some(expected));
+        }
+    }
+
+    #[test]
+    fn max_amount_reserves_gas_and_never_goes_negative() {
+        let dir = TestDir::new("max_amount_reserves_gas_and_never_goes_negative");
+        let mut model = ready_model(&dir);
+        let fee_mist = model.network.estimated_tx_fee_mist();
+        assert!(model.fill_max_transfer_amount());
+        assert_eq!(model.app_state.transfer_amount_mist(), Ok(2 * MIST_PER_SUI - fee_mist));
+        assert_eq!(model.send_blocker(Instant::now()), None);
+
+        // 余额不足以支付 Gas 时为零
+        model.balance = Some(Balance::from_mist(fee_mist - 1));
+        assert_eq!(model.max_transfer_mist(), Some(0));
+        assert!(model.fill_max_transfer_amount());
+        assert_eq!(model.app_state.transfer_amount, "0");
+
+        // 换成美元输入后不再按余额重新填写
+        model.balance = Some(Balance::from_mist(2 * MIST_PER_SUI));
+        assert!(model.fill_max_transfer_amount());
+        model.app_state.set_sui_price(Some(2.0));
+        model.app_state.set_transfer_amount_unit(AmountUnit::Usd);
+        assert!(!model.app_state.transfer_amount_is_max);
+
+        model.balance = None;
+        assert_eq!(model.max_transfer_mist(), None);
+        assert!(!model.fill_max_transfer_amount());
     }
 
-    // 应用状态相关方法委托
-    pub fn get_app_settings(&self) -> &AppSettings {
-        &self.app_state.settings
+    #[test]
+    fn model_starts_on_the_configured_default_network() {
+        let _crash_report_flag = lock_crash_report_flag();
+        let dir = TestDir::new("model_starts_on_the_configured_default_network");
+        let model = Model::with_config_dir(&dir);
+        assert_eq!(model.network, DEFAULT_NETWORK);
+        assert_eq!(model.network, Network::default());
+        assert_eq!(model.network_config.current_network, DEFAULT_NETWORK);
+        assert_eq!(model.app_state.settings.default_network, DEFAULT_NETWORK);
+
+        // 首次设置中选择的默认网络在启动时生效
+        let mut model = Model::with_config_dir(&dir);
+        model.apply_settings(AppSettings { default_network: Network::Mainnet, ..AppSettings::default() });
+        assert_eq!(model.network, Network::Mainnet);
     }
 
-    pub fn get_app_settings_mut(&mut self) -> &mut AppSettings {
-        &mut self.app_state.settings
+    #[test]
+    fn orphaned_private_key_is_detected_on_startup() {
+        let dir = TestDir::new("orphaned_private_key_is_detected_on_startup");
+        assert_eq!(Model::with_config_dir(&dir).app_state.ui_state.dialog_state, DialogState::None);
+
+        // 私钥文件存在但密码文件缺失
+        let key_file = AccountScope::Shared.private_key_file(&dir);
+        std::fs::write(&key_file, b"encrypted").unwrap();
+        let model = Model::with_config_dir(&dir);
+        assert!(model.auth_state.is_first_run);
+        assert!(model.auth_state.has_orphaned_private_key());
+        assert_eq!(model.app_state.ui_state.dialog_state, DialogState::OrphanedKey);
+
+        // 密码文件存在时不是孤立私钥
+        std::fs::write(dir.join("password.hash"), "not-a-hash").unwrap();
+        let model = Model::with_config_dir(&dir);
+        assert!(!model.auth_state.has_orphaned_private_key());
+        assert_eq!(model.app_state.ui_state.dialog_state, DialogState::None);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn saved_settings_are_restored_after_restart() {
+        let _crash_report_flag = lock_crash_report_flag();
+        let dir = TestDir::new("saved_settings_are_restored_after_restart");
+        let mut model = Model::with_config_dir(&dir);
+        model.set_language(Language::Chinese);
+        model.set_session_timeout_minutes(Some(15));
+        model.app_state.settings.theme = AppTheme::Dark;
+        model.app_state.settings.set_ui_scale(1.25);
+        model.app_state.settings.security_settings.max_failed_login_attempts = 3;
+
+        // 与 App::save / 启动时加载使用同一份可序列化设置
+        let saved = serde_json::to_string(&model.app_state.settings).unwrap();
+        let mut restarted = Model::with_config_dir(&dir);
+        restarted.apply_settings(serde_json::from_str(&saved).unwrap());
+
+        assert_eq!(restarted.i18n.current_language(), Language::Chinese);
+        assert_eq!(restarted.auth_state.session_timeout_minutes, Some(15));
+        assert_eq!(restarted.app_state.settings.theme, AppTheme::Dark);
+        assert_eq!(restarted.app_state.settings.ui_scale, 1.25);
+        assert_eq!(restarted.app_state.settings.security_settings.max_failed_login_attempts, 3);
+    }
+
+    #[test]
+    fn first_run_setup_shows_the_security_warning_again() {
+        let _crash_report_flag = lock_crash_report_flag();
+        let dir = TestDir::new("first_run_setup_shows_the_security_warning_again");
+        let mut model = Model::with_config_dir(&dir);
+        // 旧的设置中已确认过提醒（例如删除配置目录后重新安装）
+        model.app_state.settings.acknowledge_security_warning();
+        assert!(model.is_first_run());
+
+        model.auth_state.password_input = "Correct-horse-1".to_string();
+        model.auth_state.password_confirm = "Correct-horse-1".to_string();
+        model.set_password().unwrap();
+        assert!(model.app_state.settings.should_show_security_warning());
+
+        // 之后的确认在同一钱包中保持有效
+        model.app_state.settings.acknowledge_security_warning();
+        let mut restarted = Model::with_config_dir(&dir);
+        restarted.apply_settings(model.app_state.settings.clone());
+        assert!(!restarted.is_first_run());
+        assert!(!restarted.app_state.settings.should_show_security_warning());
+    }
+
+    #[test]
+    fn password_policy_settings_are_applied_after_restart() {
+        let _crash_report_flag = lock_crash_report_flag();
+        let dir = TestDir::new("password_policy_settings_are_applied_after_restart");
+        let mut model = Model::with_config_dir(&dir);
+        model.app_state.settings.security_settings.min_password_length = 16;
+        model.app_state.settings.security_settings.password_require_special = false;
+
+        let saved = serde_json::to_string(&model.app_state.settings).unwrap();
+        let mut restarted = Model::with_config_dir(&dir);
+        restarted.apply_settings(serde_json::from_str(&saved).unwrap());
+        assert_eq!(restarted.auth_state.password_policy.min_length, 16);
+        assert!(!restarted.auth_state.password_policy.require_special);
+
+        // 15 个字符的密码符合默认策略，但低于保存的最小长度
+        restarted.auth_state.password_input = "Correct-horse-1".to_string();
+        restarted.auth_state.password_confirm = "Correct-horse-1".to_string();
+        assert!(restarted.set_password().is_err());
+        assert!(restarted.is_first_run());
+
+        restarted.auth_state.password_input = "Correcthorsebattery1".to_string();
+        restarted.auth_state.password_confirm = "Correcthorsebattery1".to_string();
+        restarted.set_password().unwrap();
+    }
+}
@@ -0,0 +1,152 @@
+use std::path::{Path, PathBuf};
+use super::Network;
+
+/// 加密私钥文件名
+const PRIVATE_KEY_FILE: &str = "private_key.enc";
+/// 账户标签文件名
+const ACCOUNT_LABEL_FILE: &str = "account.label";
+/// 按网络区分的账户目录（由配置迁移版本 1 创建）
+const ACCOUNTS_DIR: &str = "accounts";
+
+/// 账户存储范围
+/// Sui 地址与网络无关，默认所有网络共用一个账户；
+/// 启用"按网络区分账户"后，每个网络在 `accounts/<网络>/` 下保存各自的私钥和标签
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccountScope {
+    /// 所有网络共用（保存在配置目录根下，与旧版本兼容）
+    Shared,
+    /// 指定网络独立的账户，值为目录名
+    Network(String),
+}
+
+impl AccountScope {
+    /// 根据设置和当前网络确定账户范围
+    pub fn resolve(separate_per_network: bool, network: &Network) -> Self {
+        if separate_per_network {
+            AccountScope::Network(Self::network_dir_name(network))
+        } else {
+            AccountScope::Shared
+        }
+    }
+
+    /// 网络对应的目录名；自定义网络加前缀并只保留字母、数字和连字符，避免与内置网络冲突
+    fn network_dir_name(network: &Network) -> String {
+        match network {
+            Network::Custom { name, .. } => {
+                let slug: String = name
+                    .trim()
+                    .to_lowercase()
+                    .chars()
+                    .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+                    .collect();
+                let slug = slug.trim_matches('-');
+                if slug.is_empty() {
+                    "custom".to_string()
+                } else {
+                    format!("custom-{}", slug)
+                }
+            }
+            other => other.name().to_lowercase(),
+        }
+    }
+
+    /// 配置目录中存在的所有账户范围：共用账户以及 `accounts/` 下的每个网络目录
+    pub fn all(config_dir: &Path) -> Vec<Self> {
+        let mut scopes = vec![AccountScope::Shared];
+        if let Ok(entries) = std::fs::read_dir(config_dir.join(ACCOUNTS_DIR)) {
+            let mut names: Vec<String> = entries
+                .filter_map(Result::ok)
+                .filter(|entry| entry.path().is_dir())
+                .filter_map(|entry| entry.file_name().into_string().ok())
+                .collect();
+            names.sort();
+            scopes.extend(names.into_iter().map(AccountScope::Network));
+        }
+        scopes
+    }
+
+    /// 账户文件所在目录
+    pub fn dir(&self, config_dir: &Path) -> PathBuf {
+        match self {
+            AccountScope::Shared => config_dir.to_path_buf(),
+            AccountScope::Network(name) => config_dir.join(ACCOUNTS_DIR).join(name),
+        }
+    }
+
+    /// 加密私钥文件路径
+    pub fn private_key_file(&self, config_dir: &Path) -> PathBuf {
+        self.dir(config_dir).join(PRIVATE_KEY_FILE)
+    }
+
+    /// 账户标签文件路径
+    pub fn label_file(&self, config_dir: &Path) -> PathBuf {
+        self.dir(config_dir).join(ACCOUNT_LABEL_FILE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::TestDir;
+
+    #[test]
+    fn shared_scope_uses_same_files_for_every_network() {
+        let config = Path::new("/cfg");
+        for network in [Network::Devnet, Network::Testnet, Network::Mainnet] {
+            let scope = AccountScope::resolve(false, &network);
+            assert_eq!(scope, AccountScope::Shared);
+            assert_eq!(scope.private_key_file(config), PathBuf::from("/cfg/private_key.enc"));
+            assert_eq!(scope.label_file(config), PathBuf::from("/cfg/account.label"));
+        }
+    }
+
+    #[test]
+    fn separate_scope_resolves_per_network_files() {
+        let config = Path::new("/cfg");
+        assert_eq!(
+            AccountScope::resolve(true, &Network::Mainnet).private_key_file(config),
+            PathBuf::from("/cfg/accounts/mainnet/private_key.enc")
+        );
+        assert_eq!(
+            AccountScope::resolve(true, &Network::Testnet).label_file(config),
+            PathBuf::from("/cfg/accounts/testnet/account.label")
+        );
+        assert_ne!(
+            AccountScope::resolve(true, &Network::Devnet),
+            AccountScope::resolve(true, &Network::Testnet)
+        );
+    }
+
+    #[test]
+    fn lists_the_shared_scope_and_every_network_directory() {
+        let dir = TestDir::new("lists_every_account_scope");
+        assert_eq!(AccountScope::all(&dir), vec![AccountScope::Shared]);
+
+        for name in ["testnet", "mainnet"] {
+            std::fs::create_dir_all(dir.join(ACCOUNTS_DIR).join(name)).unwrap();
+        }
+        std::fs::write(dir.join(ACCOUNTS_DIR).join("notes.txt"), "").unwrap();
+        assert_eq!(
+            AccountScope::all(&dir),
+            vec![
+                AccountScope::Shared,
+                AccountScope::Network("mainnet".to_string()),
+                AccountScope::Network("testnet".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn custom_network_names_are_sanitized() {
+        let local = Network::custom("My Local/Node", "http://127.0.0.1:9000");
+        assert_eq!(
+            AccountScope::resolve(true, &local),
+            AccountScope::Network("custom-my-local-node".to_string())
+        );
+        // 自定义网络不会与同名的内置网络共用账户
+        let fake_mainnet = Network::custom("mainnet", "http://127.0.0.1:9000");
+        assert_ne!(AccountScope::resolve(true, &fake_mainnet), AccountScope::resolve(true, &Network::Mainnet));
+        let unnamed = Network::custom("..", "http://127.0.0.1:9000");
+        assert_eq!(AccountScope::resolve(true, &unnamed), AccountScope::Network("custom".to_string()));
+    }
+}
@@ -1,24 +1,37 @@
-use std::sync::mpsc::{self, Receiver, Sender};
-use tokio::runtime::Runtime;
+use std::time::{Duration, Instant};
+use serde::{Deserialize, Serialize};
 use crate::i18n::{I18nManager, Language};
+use sui_sdk::types::base_types::SuiAddress;
+use zeroize::Zeroizing;
+use super::{AddressExportFormat, AmountUnit, Balance, BalanceState, PaymentUri, PaymentUriError, CoinFilterMode, CoinFilterSettings, EndpointTestStatus, ExplorerProvider, KeyFileContents, Network, RpcFallbackSettings, AuthConfig, PasswordPolicy, StatusTtlSettings, WalletUtils, wipe_secret, SUI_DECIMALS};
+
+/// eframe 持久化存储中应用设置的键
+pub const SETTINGS_STORAGE_KEY: &str = "app_settings";
 
 /// 应用程序状态
 #[derive(Debug)]
 pub struct AppState {
-    /// 结果文本显示
-    pub result_text: String,
-    /// 是否正在加载
-    pub is_loading: bool,
-    /// 转账相关信息（未来功能）
+    /// 转账相关信息
     pub recipient_address: String,
     pub transfer_amount: String,
+    /// 转账金额由"最大"按钮填写，余额刷新后按新余额重新计算
+    pub transfer_amount_is_max: bool,
+    /// 发送时附加的本地备注（保存在本地转账历史中）
+    pub transfer_note: String,
+    /// 转账金额的输入单位
+    pub transfer_amount_unit: AmountUnit,
+    /// 当前 SUI 美元价格（只在以美元输入时查询），None 表示价格不可用或尚未查询
+    pub sui_price_usd: Option<f64>,
+    /// 按下发送时换算出的金额，确认和提交都使用该金额，不随之后的价格更新变化
+    pub frozen_transfer_amount: Option<FrozenTransferAmount>,
+    /// 用户已确认向自己的地址转账
+    pub self_send_confirmed: bool,
+    /// 用户已确认可疑的大额转账
+    pub large_amount_confirmed: bool,
+    /// 手动 Gas 预算（MIST，高级选项），为空时使用预估值
+    pub gas_budget_input: String,
     /// 国际化管理器
     pub i18n: I18nManager,
-    /// 异步运行时
-    pub rt: Runtime,
-    /// 异步消息通道
-    pub sender: Sender<Result<String, String>>,
-    pub receiver: Receiver<Result<String, String>>,
     /// 应用程序设置
     pub settings: AppSettings,
     /// 用户界面状态
@@ -28,19 +41,18 @@ pub struct AppState {
 impl AppState {
     /// 创建新的应用程序状态
     pub fn new() -> Self {
-        let (sender, receiver) = mpsc::channel();
-        let i18n_manager = I18nManager::new();
-        let import_message = i18n_manager.tr("import_private_key_message");
-
         Self {
-            result_text: import_message,
-            is_loading: false,
             recipient_address: String::new(),
             transfer_amount: String::new(),
-            i18n: i18n_manager,
-            rt: Runtime::new().expect("Failed to create Tokio runtime"),
-            sender,
-            receiver,
+            transfer_amount_is_max: false,
+            transfer_note: String::new(),
+            transfer_amount_unit: AmountUnit::Sui,
+            sui_price_usd: None,
+            frozen_transfer_amount: None,
+            self_send_confirmed: false,
+            large_amount_confirmed: false,
+            gas_budget_input: String::new(),
+            i18n: I18nManager::new(),
             settings: AppSettings::default(),
             ui_state: UiState::default(),
         }
@@ -52,81 +64,177 @@ impl AppState {
         self.settings.language = language;
     }
 
-    /// 获取当前语言
-    pub fn current_language(&self) -> Language {
-        self.i18n.current_language()
+    /// 重置转账信息
+    pub fn reset_transfer_info(&mut self) {
+        self.recipient_address.clear();
+        self.transfer_amount.clear();
+        self.transfer_amount_is_max = false;
+        self.transfer_note.clear();
+        self.frozen_transfer_amount = None;
+        self.self_send_confirmed = false;
+        self.large_amount_confirmed = false;
+        self.gas_budget_input.clear();
     }
 
-    /// 设置结果文本
-    pub fn set_result_text(&mut self, text: String) {
-        self.result_text = text;
+    /// 交易使用的 Gas 预算（MIST）：未开启高级选项或未填写时使用预估值
+    pub fn transfer_gas_budget(&self, estimate_mist: u64) -> Result<u64, String> {
+        if !self.ui_state.show_advanced_options {
+            return Ok(estimate_mist);
+        }
+        WalletUtils::resolve_gas_budget(&self.gas_budget_input, estimate_mist)
+            .map_err(|e| self.i18n.tr(e.i18n_key()))
     }
 
-    /// 设置加载状态
-    pub fn set_loading(&mut self, loading: bool) {
-        self.is_loading = loading;
-        if loading {
-            self.result_text = self.i18n.tr("loading");
+    /// 手动 Gas 预算低于预估值时返回警告（交易可能因 Gas 不足而失败）
+    pub fn gas_budget_warning(&self, estimate_mist: u64) -> Option<String> {
+        match self.transfer_gas_budget(estimate_mist) {
+            Ok(budget) if budget < estimate_mist => Some(self.i18n.tr("gas_budget_below_estimate")),
+            _ => None,
         }
     }
 
-    /// 清除结果文本
-    pub fn clear_result_text(&mut self) {
-        self.result_text.clear();
+    /// 检查转账金额是否可疑（占余额比例过高或远超近期转账）
+    pub fn suspicious_amount(&self, balance_mist: Option<u64>, recent_amounts: &[u64]) -> Option<SuspiciousAmount> {
+        let amount_mist = self.transfer_amount_mist().ok()?;
+        self.settings
+            .security_settings
+            .amount_guard
+            .check(amount_mist, balance_mist, recent_amounts)
     }
 
-    /// 显示错误消息
-    pub fn show_error(&mut self, error: &str) {
-        self.result_text = format!("{}: {}", self.i18n.tr("error"), error);
-        self.is_loading = false;
+    /// 大额转账防误操作检查：主网上的可疑金额需要用户确认（设置 `large_amount_confirmed`）
+    pub fn check_amount_guard(
+        &self,
+        network: &Network,
+        balance_mist: Option<u64>,
+        recent_amounts: &[u64],
+    ) -> Result<(), String> {
+        if *network != Network::Mainnet || self.large_amount_confirmed {
+            return Ok(());
+        }
+        match self.suspicious_amount(balance_mist, recent_amounts) {
+            Some(reason) => Err(self.i18n.tr(reason.i18n_key())),
+            None => Ok(()),
+        }
     }
 
-    /// 显示成功消息
-    pub fn show_success(&mut self, message: &str) {
-        self.result_text = message.to_string();
-        self.is_loading = false;
+    /// 转账前的快速余额检查：金额超过已知余额时立即拒绝，无需等待试运行
+    /// 余额未知或已过期时跳过，由试运行给出准确结果
+    pub fn check_amount_within_balance(&self, balance: BalanceState) -> Result<(), String> {
+        let (Ok(amount_mist), Some(available_mist)) = (self.transfer_amount_mist(), balance.known_mist()) else {
+            return Ok(());
+        };
+        if amount_mist > available_mist {
+            return Err(self.i18n.tr("amount_exceeds_balance"));
+        }
+        Ok(())
     }
 
-    /// 处理异步结果
-    pub fn handle_async_results(&mut self) {
-        if let Ok(result) = self.receiver.try_recv() {
-            self.is_loading = false;
-            match result {
-                Ok(message) => self.result_text = message,
-                Err(error) => self.show_error(&error),
+    /// 检查是否向自己的地址转账
+    /// 只是警告：用户确认（设置 `self_send_confirmed`）后允许继续
+    pub fn check_self_send(&self, sender: Option<&SuiAddress>) -> Result<(), String> {
+        match sender {
+            Some(sender)
+                if !self.self_send_confirmed
+                    && WalletUtils::is_self_send(&self.recipient_address, sender) =>
+            {
+                Err(self.i18n.tr("self_send_warning"))
             }
+            _ => Ok(()),
         }
     }
 
-    /// 发送异步任务结果
-    pub fn send_result(&self, result: Result<String, String>) {
-        if let Err(e) = self.sender.send(result) {
-            eprintln!("Failed to send async result: {}", e);
+    /// 更新 SUI 美元价格，价格不可用时切回 SUI 输入
+    pub fn set_sui_price(&mut self, price: Option<f64>) {
+        self.sui_price_usd = price.filter(|p| p.is_finite() && *p > 0.0);
+        if self.sui_price_usd.is_none() {
+            self.transfer_amount_unit = AmountUnit::Sui;
         }
     }
 
-    /// 重置转账信息
-    pub fn reset_transfer_info(&mut self) {
-        self.recipient_address.clear();
-        self.transfer_amount.clear();
+    /// 切换转账金额单位；切换到美元后才开始查询价格，价格到达前无法换算金额
+    pub fn set_transfer_amount_unit(&mut self, unit: AmountUnit) {
+        // 最大金额按 SUI 填写，换单位后不再代表最大金额
+        if unit != self.transfer_amount_unit {
+            self.transfer_amount_is_max = false;
+        }
+        self.transfer_amount_unit = unit;
     }
 
-    /// 验证转账信息
-    pub fn validate_transfer_info(&self) -> Result<(), String> {
-        if self.recipient_address.trim().is_empty() {
-            return Err(self.i18n.tr("recipient_required"));
+    /// 按下发送时冻结当前换算出的金额，之后的确认步骤和提交都不再按最新价格重新换算
+    pub fn freeze_transfer_amount(&mut self) {
+        self.frozen_transfer_amount = None;
+        self.frozen_transfer_amount = self.transfer_amount_mist().ok().map(|mist| FrozenTransferAmount {
+            input: self.transfer_amount.clone(),
+            unit: self.transfer_amount_unit,
+            mist,
+        });
+    }
+
+    /// 将输入的转账金额换算为 MIST（用于实际交易）
+    /// 输入和单位与按下发送时相同时返回当时冻结的金额
+    pub fn transfer_amount_mist(&self) -> Result<u64, String> {
+        if let Some(frozen) = &self.frozen_transfer_amount
+            && frozen.input == self.transfer_amount
+            && frozen.unit == self.transfer_amount_unit
+        {
+            return Ok(frozen.mist);
         }
-        
-        if self.transfer_amount.trim().is_empty() {
+
+        let amount = WalletUtils::normalize_input(&self.transfer_amount);
+        if amount.is_empty() {
             return Err(self.i18n.tr("amount_required"));
         }
 
-        // 验证金额格式
-        if self.transfer_amount.parse::<f64>().is_err() {
-            return Err(self.i18n.tr("invalid_amount"));
+        match self.transfer_amount_unit {
+            // SUI 金额按十进制字符串精确换算，不经过 f64
+            AmountUnit::Sui => WalletUtils::parse_sui_amount(&amount).map_err(|e| {
+                self.i18n.tr(e.i18n_key()).replace("{}", &SUI_DECIMALS.to_string())
+            }),
+            AmountUnit::Usd => {
+                let value = WalletUtils::parse_usd_amount(&amount).map_err(|_| self.i18n.tr("invalid_amount"))?;
+                let price = self.sui_price_usd.ok_or_else(|| self.i18n.tr("usd_price_unavailable"))?;
+                WalletUtils::usd_to_mist(value, price).ok_or_else(|| self.i18n.tr("invalid_amount"))
+            }
         }
+    }
 
-        Ok(())
+    /// 收款地址栏粘贴的是支付 URI 时，拆出地址并预填金额（以 SUI 输入），返回是否已应用
+    /// URI 无效时保持输入不变并返回错误
+    pub fn apply_payment_uri(&mut self) -> Result<bool, PaymentUriError> {
+        if !PaymentUri::is_payment_uri(&self.recipient_address) {
+            return Ok(false);
+        }
+        let uri = PaymentUri::parse(&self.recipient_address)?;
+        self.recipient_address = uri.address.to_string();
+        if let Some(amount_mist) = uri.amount_mist {
+            self.transfer_amount_unit = AmountUnit::Sui;
+            self.transfer_amount = Balance::from_mist(amount_mist).to_sui_string();
+            self.transfer_amount_is_max = false;
+        }
+        self.self_send_confirmed = false;
+        self.large_amount_confirmed = false;
+        Ok(true)
+    }
+
+    /// 获取规范化后的收款地址
+    pub fn normalized_recipient(&self) -> String {
+        WalletUtils::normalize_input(&self.recipient_address)
+    }
+
+    /// 验证转账信息
+    pub fn validate_transfer_info(&self) -> Result<(), String> {
+        let recipient = self.normalized_recipient();
+        if recipient.is_empty() {
+            return Err(self.i18n.tr("recipient_required"));
+        }
+
+        if !WalletUtils::is_valid_address_format(&recipient) {
+            return Err(self.i18n.tr("invalid_recipient"));
+        }
+        
+        // 验证金额格式（美元输入按当前价格换算）
+        self.transfer_amount_mist().map(|_| ())
     }
 }
 
@@ -136,17 +244,94 @@ impl Default for AppState {
     }
 }
 
+/// 按下发送时冻结的转账金额，记录换算时的输入，输入或单位改变后失效
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrozenTransferAmount {
+    input: String,
+    unit: AmountUnit,
+    mist: u64,
+}
+
+/// 复制到剪贴板的数据类型
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CopyKind {
+    /// 私钥
+    PrivateKey,
+}
+
+impl CopyKind {
+    /// 是否为敏感数据（剪贴板管理器可能会持久保存剪贴板历史）
+    pub fn is_sensitive(&self) -> bool {
+        matches!(self, CopyKind::PrivateKey)
+    }
+}
+
+/// 发送按钮不可用的原因（按检查顺序排列）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SendBlocker {
+    /// 正在执行其他操作
+    Loading,
+    /// 未加载带私钥的钱包
+    NoWallet,
+    /// 未填写收款地址
+    MissingRecipient,
+    /// 收款地址无效
+    InvalidRecipient,
+    /// 未填写金额
+    MissingAmount,
+    /// 金额无法解析
+    InvalidAmount,
+    /// 金额为 0
+    ZeroAmount,
+    /// 手动 Gas 预算无效（高级选项）
+    InvalidGasBudget,
+    /// 批量转账输入有无效行或为空
+    InvalidBatch,
+    /// 尚未查询到余额
+    BalanceUnknown,
+    /// 余额已过期，需要刷新
+    BalanceStale,
+    /// 金额超过可用余额（余额减去预估 Gas）
+    ExceedsSpendable,
+}
+
+impl SendBlocker {
+    /// 获取提示的翻译键
+    pub fn i18n_key(&self) -> &'static str {
+        match self {
+            SendBlocker::Loading => "send_blocked_loading",
+            SendBlocker::NoWallet => "send_blocked_no_wallet",
+            SendBlocker::MissingRecipient => "recipient_required",
+            SendBlocker::InvalidRecipient => "invalid_recipient",
+            SendBlocker::MissingAmount => "amount_required",
+            SendBlocker::InvalidAmount => "invalid_amount",
+            SendBlocker::ZeroAmount => "send_blocked_zero_amount",
+            SendBlocker::InvalidGasBudget => "send_blocked_invalid_gas_budget",
+            SendBlocker::InvalidBatch => "send_blocked_invalid_batch",
+            SendBlocker::BalanceUnknown => "send_blocked_balance_unknown",
+            SendBlocker::BalanceStale => "send_blocked_balance_stale",
+            SendBlocker::ExceedsSpendable => "send_blocked_exceeds_spendable",
+        }
+    }
+}
+
+/// 重新获得焦点时自动刷新余额的最短间隔
+pub const FOCUS_REFRESH_DEBOUNCE: Duration = Duration::from_secs(30);
+/// 界面缩放的允许范围和默认值
+pub const UI_SCALE_MIN: f32 = 0.75;
+pub const UI_SCALE_MAX: f32 = 2.0;
+pub const UI_SCALE_DEFAULT: f32 = 1.0;
+
 /// 应用程序设置
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct AppSettings {
     /// 当前语言
     pub language: Language,
     /// 主题设置
     pub theme: AppTheme,
-    /// 自动保存设置
-    pub auto_save: bool,
-    /// 会话超时时间（分钟）
-    pub session_timeout_minutes: u64,
+    /// 会话超时时间（分钟），None 表示永不超时
+    pub session_timeout_minutes: Option<u64>,
     /// 启用通知
     pub enable_notifications: bool,
     /// 启用音效
@@ -155,6 +340,42 @@ pub struct AppSettings {
     pub window_settings: WindowSettings,
     /// 安全设置
     pub security_settings: SecuritySettings,
+    /// 自定义网络端点（如本地节点）
+    pub custom_network: Option<CustomNetworkSettings>,
+    /// 区块链浏览器提供方
+    pub explorer_provider: ExplorerProvider,
+    /// 启动时检查 RPC 端点是否可达
+    pub check_endpoint_on_startup: bool,
+    /// 复制私钥等敏感数据前提醒剪贴板历史风险
+    pub warn_on_sensitive_copy: bool,
+    /// 状态消息的自动关闭时间
+    pub status_ttl: StatusTtlSettings,
+    /// 窗口重新获得焦点时自动刷新余额
+    pub refresh_on_focus: bool,
+    /// 每个网络使用独立的账户（默认所有网络共用同一个账户）
+    pub separate_accounts_per_network: bool,
+    /// 私钥格式帮助是否展开
+    pub format_help_expanded: bool,
+    /// 多代币余额的显示过滤（白名单/黑名单）
+    pub coin_filter: CoinFilterSettings,
+    /// 各网络的备用 RPC 端点
+    pub rpc_fallbacks: RpcFallbackSettings,
+    /// 当前网络不可达时自动切换到首选网络
+    pub auto_switch_network: bool,
+    /// 自动切换时使用的首选网络
+    pub preferred_network: Network,
+    /// 定期探测端点，自动使用延迟最低的健康端点
+    pub auto_select_endpoint: bool,
+    /// 各操作最近一次使用的选项
+    pub last_used: LastUsedSettings,
+    /// 没有最近使用的网络时启动的默认网络（首次设置时选择）
+    pub default_network: Network,
+    /// 界面缩放比例（放大文字和控件，便于阅读）
+    pub ui_scale: f32,
+    /// 崩溃时在配置目录写入本地崩溃报告（已脱敏，不会上传）
+    pub write_crash_reports: bool,
+    /// 用户已确认导入表单中的安全提醒，不再显示（可在设置中重新开启）
+    pub security_warning_acknowledged: bool,
 }
 
 impl AppSettings {
@@ -162,30 +383,119 @@ impl AppSettings {
         Self {
             language: Language::English,
             theme: AppTheme::Light,
-            auto_save: true,
-            session_timeout_minutes: 30,
+            session_timeout_minutes: Some(30),
             enable_notifications: true,
             enable_sounds: false,
             window_settings: WindowSettings::default(),
             security_settings: SecuritySettings::default(),
+            custom_network: None,
+            explorer_provider: ExplorerProvider::default(),
+            check_endpoint_on_startup: true,
+            warn_on_sensitive_copy: true,
+            status_ttl: StatusTtlSettings::default(),
+            refresh_on_focus: true,
+            separate_accounts_per_network: false,
+            format_help_expanded: false,
+            coin_filter: CoinFilterSettings::default(),
+            rpc_fallbacks: RpcFallbackSettings::default(),
+            auto_switch_network: false,
+            preferred_network: Network::default(),
+            auto_select_endpoint: false,
+            last_used: LastUsedSettings::default(),
+            default_network: Network::default(),
+            ui_scale: UI_SCALE_DEFAULT,
+            write_crash_reports: true,
+            security_warning_acknowledged: false,
         }
     }
 
-    /// 加载设置（从文件或注册表）
-    pub fn load() -> Self {
-        // TODO: 实现从配置文件加载设置
-        Self::new()
+    /// 将界面缩放限制在允许范围内，非法值（NaN、无穷大）恢复默认
+    pub fn clamp_ui_scale(scale: f32) -> f32 {
+        if scale.is_finite() {
+            scale.clamp(UI_SCALE_MIN, UI_SCALE_MAX)
+        } else {
+            UI_SCALE_DEFAULT
+        }
     }
 
-    /// 保存设置
-    pub fn save(&self) -> Result<(), String> {
-        // TODO: 实现保存设置到配置文件
-        Ok(())
+    /// 设置界面缩放（自动限制范围）
+    pub fn set_ui_scale(&mut self, scale: f32) {
+        self.ui_scale = Self::clamp_ui_scale(scale);
+    }
+
+    /// 实际使用的界面缩放，保存的设置被手动修改过也不会超出范围
+    pub fn effective_ui_scale(&self) -> f32 {
+        Self::clamp_ui_scale(self.ui_scale)
+    }
+
+    /// 启动时使用的网络：优先恢复最近一次使用的网络，否则使用默认网络
+    /// 自定义网络已从设置中删除时回退到内置默认网络
+    pub fn startup_network(&self) -> Network {
+        let custom_network = self.custom_network();
+        if let Some(network) = self.last_used.network_to_restore(custom_network.as_ref()) {
+            return network;
+        }
+        match &self.default_network {
+            Network::Custom { .. } if custom_network.as_ref() != Some(&self.default_network) => Network::default(),
+            network => network.clone(),
+        }
     }
 
-    /// 重置为默认设置
-    pub fn reset_to_defaults(&mut self) {
-        *self = Self::new();
+    /// 窗口焦点变化时是否应自动刷新余额
+    /// 只在从失去焦点变为获得焦点、已加载钱包且距离上次自动刷新超过防抖间隔时刷新
+    pub fn should_refresh_on_focus(
+        &self,
+        was_focused: Option<bool>,
+        focused: Option<bool>,
+        wallet_loaded: bool,
+        last_refresh: Option<Instant>,
+        now: Instant,
+    ) -> bool {
+        let gained_focus = was_focused == Some(false) && focused == Some(true);
+        let debounced = last_refresh.is_none_or(|last| now.saturating_duration_since(last) >= FOCUS_REFRESH_DEBOUNCE);
+        self.refresh_on_focus && wallet_loaded && gained_focus && debounced
+    }
+
+    /// 复制前是否需要提醒剪贴板历史风险（只针对敏感数据）
+    pub fn should_warn_before_copy(&self, kind: CopyKind) -> bool {
+        kind.is_sensitive() && self.warn_on_sensitive_copy
+    }
+
+    /// 用户选择"不再提醒"
+    pub fn dismiss_copy_warning(&mut self) {
+        self.warn_on_sensitive_copy = false;
+    }
+
+    /// 是否显示导入表单中的安全提醒（首次设置密码时会清除之前的确认）
+    pub fn should_show_security_warning(&self) -> bool {
+        !self.security_warning_acknowledged
+    }
+
+    /// 用户确认安全提醒后不再显示
+    pub fn acknowledge_security_warning(&mut self) {
+        self.security_warning_acknowledged = true;
+    }
+
+    /// 重新显示安全提醒
+    pub fn reset_security_warning(&mut self) {
+        self.security_warning_acknowledged = false;
+    }
+
+    /// 由设置生成认证配置：会话超时、登录失败锁定和密码策略
+    pub fn auth_config(&self) -> AuthConfig {
+        AuthConfig {
+            session_timeout_minutes: self.session_timeout_minutes,
+            max_failed_attempts: self.security_settings.max_failed_login_attempts,
+            lockout_duration_minutes: self.security_settings.login_lockout_minutes,
+            password_policy: self.security_settings.password_policy(),
+        }
+    }
+
+    /// 获取自定义网络（如果已配置）
+    pub fn custom_network(&self) -> Option<Network> {
+        self.custom_network
+            .as_ref()
+            .map(|custom| Network::custom(custom.name.clone(), custom.url.clone()))
     }
 }
 
@@ -195,41 +505,90 @@ impl Default for AppSettings {
     }
 }
 
+/// 各操作最近一次使用的选项，下次执行同一操作时作为默认值
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LastUsedSettings {
+    /// 最近一次手动切换到的网络，启动时恢复
+    pub network: Option<Network>,
+    /// 地址列表最近一次的导出格式
+    pub export_format: Option<AddressExportFormat>,
+    /// 地址列表最近一次的导出路径
+    pub export_path: Option<String>,
+    /// 最近使用的网络，最近的在前（用于状态栏的快速切换）
+    pub recent_networks: Vec<Network>,
+}
+
+/// 保留的最近使用网络数量
+pub const RECENT_NETWORKS_MAX: usize = 5;
+
+impl LastUsedSettings {
+    /// 记录切换到的网络：移到最近列表的最前面，并作为下次启动时恢复的网络
+    pub fn record_network(&mut self, network: Network) {
+        self.recent_networks.retain(|recent| *recent != network);
+        self.recent_networks.insert(0, network.clone());
+        self.recent_networks.truncate(RECENT_NETWORKS_MAX);
+        self.network = Some(network);
+    }
+
+    /// 快速切换的目标：最近使用的网络中第一个不是当前网络的（即在最近两个网络之间切换）
+    /// 自定义网络只有仍在设置中时才可切换
+    pub fn toggle_network_target(&self, current: &Network, custom_network: Option<&Network>) -> Option<Network> {
+        self.recent_networks
+            .iter()
+            .filter(|network| !network.is_custom() || custom_network == Some(*network))
+            .find(|network| *network != current)
+            .cloned()
+    }
+
+    /// 启动时要恢复的网络；自定义网络只有仍在设置中时才恢复
+    pub fn network_to_restore(&self, custom_network: Option<&Network>) -> Option<Network> {
+        match &self.network {
+            Some(network @ Network::Custom { .. }) => (custom_network == Some(network)).then(|| network.clone()),
+            other => other.clone(),
+        }
+    }
+}
+
+/// 自定义网络端点设置
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CustomNetworkSettings {
+    /// 显示名称
+    pub name: String,
+    /// RPC URL
+    pub url: String,
+}
+
 /// 应用程序主题
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum AppTheme {
     Light,
     Dark,
     Auto, // 跟随系统
+    /// 高对比度（黑底白字、加粗的焦点边框），便于视力不佳的用户阅读
+    HighContrast,
 }
 
 impl AppTheme {
     /// 获取所有可用主题
     pub fn all() -> Vec<AppTheme> {
-        vec![AppTheme::Light, AppTheme::Dark, AppTheme::Auto]
+        vec![AppTheme::Light, AppTheme::Dark, AppTheme::Auto, AppTheme::HighContrast]
     }
 
-    /// 获取主题名称
-    pub fn name(&self) -> &'static str {
+    /// 获取主题名称的翻译键
+    pub fn i18n_key(&self) -> &'static str {
         match self {
-            AppTheme::Light => "Light",
-            AppTheme::Dark => "Dark",
-            AppTheme::Auto => "Auto",
-        }
-    }
-
-    /// 获取主题描述
-    pub fn description(&self) -> &'static str {
-        match self {
-            AppTheme::Light => "Light theme",
-            AppTheme::Dark => "Dark theme",
-            AppTheme::Auto => "Follow system theme",
+            AppTheme::Light => "theme_light",
+            AppTheme::Dark => "theme_dark",
+            AppTheme::Auto => "theme_auto",
+            AppTheme::HighContrast => "theme_high_contrast",
         }
     }
 }
 
 /// 窗口设置
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct WindowSettings {
     /// 窗口大小
     pub size: (f32, f32),
@@ -261,8 +620,20 @@ impl Default for WindowSettings {
     }
 }
 
+/// "保持登录"有效时长的上限（小时）
+pub const STAY_SIGNED_IN_HOURS_MAX: u64 = 30 * 24;
+/// 锁定登录前允许的连续失败次数上限
+pub const MAX_FAILED_LOGIN_ATTEMPTS_MAX: u32 = 20;
+/// 登录锁定时长的上限（分钟）
+pub const LOGIN_LOCKOUT_MINUTES_MAX: u64 = 24 * 60;
+/// 密码最小长度设置的下限
+pub const MIN_PASSWORD_LENGTH_FLOOR: usize = 8;
+/// 密码最小长度设置的上限
+pub const MIN_PASSWORD_LENGTH_MAX: usize = 64;
+
 /// 安全设置
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct SecuritySettings {
     /// 启用自动锁定
     pub auto_lock: bool,
@@ -272,13 +643,95 @@ pub struct SecuritySettings {
     pub clear_clipboard: bool,
     /// 剪贴板清理时间（秒）
     pub clipboard_clear_seconds: u64,
-    /// 启用屏幕截图保护
+    /// 启用屏幕截图保护：在支持的平台上设置窗口内容保护标志
+    /// （Windows 的 SetWindowDisplayAffinity、macOS 的 NSWindow sharingType），其他平台无效
     pub screenshot_protection: bool,
-    /// 启用内存保护
+    /// 启用内存保护：清除密码输入、私钥输入和内存中的会话密码时，
+    /// 先将其缓冲区覆盖为 0（登出、登录、导入私钥后）。
+    /// 不包括界面库或操作系统自行复制的数据，也不会锁定内存页防止换出
     pub memory_protection: bool,
+    /// "保持登录"的有效时长（小时）
+    pub stay_signed_in_hours: u64,
+    /// 将会话密码保存在操作系统钥匙串中，而不是进程内存
+    pub use_os_keyring: bool,
+    /// 大额转账防误操作阈值
+    pub amount_guard: AmountGuardSettings,
+    /// 窗口失去焦点或最小化时锁定钱包
+    pub lock_on_blur: bool,
+    /// 空闲锁定前显示倒计时的时长（秒），0 表示不显示倒计时直接锁定
+    pub idle_lock_grace_seconds: u64,
+    /// 导入私钥时自动加密保存到磁盘；关闭后私钥只保留到登出为止
+    pub auto_save_keys: bool,
+    /// 锁定登录前允许的连续失败次数，0 表示不限制
+    pub max_failed_login_attempts: u32,
+    /// 登录失败过多后的锁定时长（分钟），重启应用不会解除
+    pub login_lockout_minutes: u64,
+    /// 设置密码时拒绝内置列表中的常见弱密码（离线检查）
+    pub reject_common_passwords: bool,
+    /// 设置或修改密码时要求的最小长度（字符数）
+    pub min_password_length: usize,
+    /// 密码必须包含小写字母
+    pub password_require_lowercase: bool,
+    /// 密码必须包含大写字母
+    pub password_require_uppercase: bool,
+    /// 密码必须包含数字
+    pub password_require_digit: bool,
+    /// 密码必须包含特殊字符
+    pub password_require_special: bool,
 }
 
 impl SecuritySettings {
+    /// 当前平台是否支持屏幕截图保护
+    pub fn screenshot_protection_supported() -> bool {
+        Self::screenshot_protection_supported_on(std::env::consts::OS)
+    }
+
+    /// 指定平台是否支持屏幕截图保护（窗口内容保护只在 Windows 和 macOS 上可用）
+    pub fn screenshot_protection_supported_on(target_os: &str) -> bool {
+        matches!(target_os, "windows" | "macos")
+    }
+
+    /// 空闲锁定前的倒计时时长
+    pub fn idle_lock_grace(&self) -> Duration {
+        Duration::from_secs(self.idle_lock_grace_seconds)
+    }
+
+    /// "保持登录"令牌的有效期
+    pub fn stay_signed_in_duration(&self) -> Duration {
+        Duration::from_secs(self.stay_signed_in_hours.saturating_mul(3600))
+    }
+
+    /// 将从磁盘读取的设置限制在允许的范围内，防止手动修改的设置文件导致溢出或异常行为
+    pub fn clamp_to_limits(&mut self) {
+        self.stay_signed_in_hours = self.stay_signed_in_hours.clamp(1, STAY_SIGNED_IN_HOURS_MAX);
+        self.max_failed_login_attempts = self.max_failed_login_attempts.min(MAX_FAILED_LOGIN_ATTEMPTS_MAX);
+        self.login_lockout_minutes = self.login_lockout_minutes.clamp(1, LOGIN_LOCKOUT_MINUTES_MAX);
+        self.min_password_length = self.min_password_length.clamp(MIN_PASSWORD_LENGTH_FLOOR, MIN_PASSWORD_LENGTH_MAX);
+    }
+
+    /// 根据窗口状态判断是否应锁定钱包
+    /// 只在窗口从有焦点变为失去焦点时锁定，避免启动时窗口尚未获得焦点就立即锁定；
+    /// 最小化时直接锁定。平台未报告的状态（None）不触发锁定
+    pub fn should_lock_on_blur(&self, was_focused: Option<bool>, focused: Option<bool>, minimized: Option<bool>) -> bool {
+        if !self.lock_on_blur {
+            return false;
+        }
+        let lost_focus = was_focused == Some(true) && focused == Some(false);
+        lost_focus || minimized == Some(true)
+    }
+
+    /// 根据设置生成设置密码时使用的密码策略
+    pub fn password_policy(&self) -> PasswordPolicy {
+        PasswordPolicy {
+            min_length: self.min_password_length,
+            require_lowercase: self.password_require_lowercase,
+            require_uppercase: self.password_require_uppercase,
+            require_digit: self.password_require_digit,
+            require_special: self.password_require_special,
+            reject_common: self.reject_common_passwords,
+        }
+    }
+
     pub fn new() -> Self {
         Self {
             auto_lock: true,
@@ -287,74 +740,236 @@ impl SecuritySettings {
             clipboard_clear_seconds: 60,
             screenshot_protection: false,
             memory_protection: true,
+            stay_signed_in_hours: 24,
+            use_os_keyring: false,
+            amount_guard: AmountGuardSettings::default(),
+            lock_on_blur: false,
+            idle_lock_grace_seconds: 10,
+            auto_save_keys: true,
+            max_failed_login_attempts: 5,
+            login_lockout_minutes: 15,
+            reject_common_passwords: true,
+            min_password_length: 8,
+            password_require_lowercase: true,
+            password_require_uppercase: true,
+            password_require_digit: true,
+            password_require_special: true,
         }
     }
+}
 
-    /// 获取高安全性设置
-    pub fn high_security() -> Self {
-        Self {
-            auto_lock: true,
-            auto_lock_minutes: 15,
-            clear_clipboard: true,
-            clipboard_clear_seconds: 30,
-            screenshot_protection: true,
-            memory_protection: true,
+impl Default for SecuritySettings {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 可疑金额的原因
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SuspiciousAmount {
+    /// 占余额比例过高
+    LargeShareOfBalance,
+    /// 远超近期转账金额
+    MuchLargerThanUsual,
+}
+
+impl SuspiciousAmount {
+    /// 获取警告的翻译键
+    pub fn i18n_key(&self) -> &'static str {
+        match self {
+            SuspiciousAmount::LargeShareOfBalance => "amount_large_share_warning",
+            SuspiciousAmount::MuchLargerThanUsual => "amount_unusually_large_warning",
         }
     }
+}
 
-    /// 获取低安全性设置
-    pub fn low_security() -> Self {
-        Self {
-            auto_lock: false,
-            auto_lock_minutes: 60,
-            clear_clipboard: false,
-            clipboard_clear_seconds: 120,
-            screenshot_protection: false,
-            memory_protection: false,
+/// 大额转账防误操作阈值（防止小数点输错等失误）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AmountGuardSettings {
+    /// 金额超过余额的该比例时警告
+    pub max_balance_ratio: f64,
+    /// 金额超过近期最大转账的该倍数时警告
+    pub max_history_multiple: f64,
+    /// 比较近期转账所需的最少记录数
+    pub min_history_len: usize,
+}
+
+impl AmountGuardSettings {
+    /// 判断金额是否可疑，余额未知或历史记录不足时跳过对应检查
+    pub fn check(&self, amount_mist: u64, balance_mist: Option<u64>, recent_amounts: &[u64]) -> Option<SuspiciousAmount> {
+        if let Some(balance) = balance_mist.filter(|b| *b > 0)
+            && amount_mist as f64 > balance as f64 * self.max_balance_ratio
+        {
+            return Some(SuspiciousAmount::LargeShareOfBalance);
         }
+
+        if recent_amounts.len() >= self.min_history_len {
+            let largest = recent_amounts.iter().copied().max().unwrap_or(0);
+            if largest > 0 && amount_mist as f64 > largest as f64 * self.max_history_multiple {
+                return Some(SuspiciousAmount::MuchLargerThanUsual);
+            }
+        }
+
+        None
     }
 }
 
-impl Default for SecuritySettings {
+impl Default for AmountGuardSettings {
     fn default() -> Self {
-        Self::new()
+        Self {
+            max_balance_ratio: 0.9,
+            max_history_multiple: 10.0,
+            min_history_len: 3,
+        }
+    }
+}
+
+/// 拖放的密钥文件，等待用户确认导入
+#[derive(Debug, Clone, PartialEq)]
+pub struct PendingKeyFile {
+    /// 文件名（仅用于显示）
+    pub file_name: String,
+    pub contents: KeyFileContents,
+    /// keystore 中选中的私钥索引
+    pub selected: usize,
+}
+
+impl PendingKeyFile {
+    /// 用户选中要导入的私钥，助记词和无法识别的内容没有可导入的私钥
+    pub fn selected_key(&self) -> Option<&str> {
+        match &self.contents {
+            KeyFileContents::Keystore(keys) => keys.get(self.selected).map(String::as_str),
+            KeyFileContents::SingleKey(key) => Some(key),
+            KeyFileContents::Mnemonic(_) | KeyFileContents::Unknown => None,
+        }
+    }
+
+    /// 取出选中的私钥（移交而不复制），文件中对应的位置留空
+    pub fn take_selected_key(&mut self) -> Option<String> {
+        match &mut self.contents {
+            KeyFileContents::Keystore(keys) => keys.get_mut(self.selected).map(std::mem::take),
+            KeyFileContents::SingleKey(key) => Some(std::mem::take(key)),
+            KeyFileContents::Mnemonic(_) | KeyFileContents::Unknown => None,
+        }
+    }
+
+    /// 清除文件中的私钥
+    pub fn wipe(&mut self, memory_protection: bool) {
+        match &mut self.contents {
+            KeyFileContents::Keystore(keys) => keys.iter_mut().for_each(|key| wipe_secret(key, memory_protection)),
+            KeyFileContents::SingleKey(key) => wipe_secret(key, memory_protection),
+            KeyFileContents::Mnemonic(_) | KeyFileContents::Unknown => {}
+        }
     }
 }
 
 /// 用户界面状态
 #[derive(Debug, Clone)]
 pub struct UiState {
-    /// 当前显示的面板
-    pub current_panel: Panel,
-    /// 侧边栏是否展开
-    pub sidebar_expanded: bool,
     /// 是否显示高级选项
     pub show_advanced_options: bool,
-    /// 选中的标签页
-    pub selected_tab: usize,
     /// 对话框状态
     pub dialog_state: DialogState,
+    /// 正在编辑的账户标签（None 表示未处于编辑状态）
+    pub label_edit_buffer: Option<String>,
+    /// 正在编辑备注的转账：`(交易摘要, 备注)`（None 表示未处于编辑状态）
+    pub note_edit_buffer: Option<(String, String)>,
+    /// 自定义网络编辑表单：名称
+    pub custom_network_name_input: String,
+    /// 自定义网络编辑表单：RPC URL
+    pub custom_network_url_input: String,
+    /// 自定义网络连接测试状态
+    pub custom_network_test: EndpointTestStatus,
+    /// 最近一次测试的 URL（输入变化后不再显示旧结果）
+    pub custom_network_tested_url: String,
+    /// 批量转账输入（CSV：地址,金额）
+    pub batch_input: String,
+    /// 备用 RPC 端点设置表单：各内置网络每行一个 URL
+    pub rpc_fallback_inputs: Vec<(Network, String)>,
+    /// 代币过滤设置表单：过滤方式
+    pub coin_filter_mode_input: CoinFilterMode,
+    /// 代币过滤设置表单：白名单（每行一个代币类型）
+    pub coin_allowlist_input: String,
+    /// 代币过滤设置表单：黑名单（每行一个片段）
+    pub coin_denylist_input: String,
+    /// 已应用到窗口的屏幕截图保护状态（None 表示尚未应用）
+    pub applied_screenshot_protection: Option<bool>,
+    /// 剪贴板警告对话框中的"不再提醒"选项
+    pub dont_warn_copy_again: bool,
+    /// 等待视图写入剪贴板的内容（可能是私钥，写入后清除）
+    pub pending_copy: Option<Zeroizing<String>>,
+    /// 敏感操作确认对话框中输入的密码
+    pub confirm_password_input: String,
+    /// 确认密码失败的原因
+    pub confirm_password_error: Option<String>,
+    /// 修改密码对话框中输入的新密码
+    pub new_password_input: String,
+    /// 修改密码对话框中再次输入的新密码
+    pub new_password_confirm: String,
+    /// 密码输入框是否需要在下一帧获取焦点
+    pub focus_password_input: bool,
+    /// 上一帧窗口是否有焦点（用于检测焦点变化）
+    pub window_focused: Option<bool>,
+    /// 上次因重新获得焦点而刷新余额的时间
+    pub last_focus_refresh: Option<Instant>,
+    /// 拖放文件中等待用户确认导入的私钥
+    pub pending_key_file: Option<PendingKeyFile>,
+    /// 地址列表导出：目标文件路径
+    pub export_path_input: String,
+    /// 地址列表导出格式
+    pub export_format: AddressExportFormat,
+    /// 已应用到界面的缩放比例（None 表示尚未应用）
+    pub applied_ui_scale: Option<f32>,
+    /// 正在拖动的缩放滑块值，松开后才应用到界面
+    pub ui_scale_draft: Option<f32>,
+    /// 已应用到界面的主题（None 表示尚未应用）
+    pub applied_theme: Option<AppTheme>,
 }
 
 impl UiState {
     pub fn new() -> Self {
         Self {
-            current_panel: Panel::Wallet,
-            sidebar_expanded: true,
             show_advanced_options: false,
-            selected_tab: 0,
             dialog_state: DialogState::None,
+            label_edit_buffer: None,
+            note_edit_buffer: None,
+            custom_network_name_input: String::new(),
+            custom_network_url_input: String::new(),
+            custom_network_test: EndpointTestStatus::Idle,
+            custom_network_tested_url: String::new(),
+            batch_input: String::new(),
+            rpc_fallback_inputs: Vec::new(),
+            coin_filter_mode_input: CoinFilterMode::default(),
+            coin_allowlist_input: String::new(),
+            coin_denylist_input: String::new(),
+            applied_screenshot_protection: None,
+            dont_warn_copy_again: false,
+            pending_copy: None,
+            confirm_password_input: String::new(),
+            confirm_password_error: None,
+            new_password_input: String::new(),
+            new_password_confirm: String::new(),
+            focus_password_input: true,
+            window_focused: None,
+            last_focus_refresh: None,
+            pending_key_file: None,
+            export_path_input: String::new(),
+            export_format: AddressExportFormat::Text,
+            applied_ui_scale: None,
+            ui_scale_draft: None,
+            applied_theme: None,
         }
     }
 
-    /// 切换面板
-    pub fn switch_panel(&mut self, panel: Panel) {
-        self.current_panel = panel;
+    /// 请求在下一帧聚焦密码输入框（密码面板重新显示或登录失败后）
+    pub fn request_password_focus(&mut self) {
+        self.focus_password_input = true;
     }
 
-    /// 切换侧边栏
-    pub fn toggle_sidebar(&mut self) {
-        self.sidebar_expanded = !self.sidebar_expanded;
+    /// 取出密码输入框的焦点请求，只在请求后的第一帧返回 true，避免每次重绘都抢占焦点
+    pub fn take_password_focus_request(&mut self) -> bool {
+        std::mem::take(&mut self.focus_password_input)
     }
 
     /// 显示对话框
@@ -366,6 +981,16 @@ impl UiState {
     pub fn close_dialog(&mut self) {
         self.dialog_state = DialogState::None;
     }
+
+    /// 按下 Esc 时关闭当前对话框，需要用户明确选择的对话框保持打开；返回是否关闭
+    pub fn dismiss_dialog(&mut self) -> bool {
+        if self.dialog_state.is_dismissible() {
+            self.close_dialog();
+            true
+        } else {
+            false
+        }
+    }
 }
 
 impl Default for UiState {
@@ -374,78 +999,371 @@ impl Default for UiState {
     }
 }
 
-/// 面板枚举
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum Panel {
-    Wallet,
-    Balance,
-    Transfer,
-    History,
-    Settings,
+/// 执行前需要重新输入密码确认的敏感操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PasswordProtectedAction {
+    /// 复制（显示）私钥
+    CopyPrivateKey,
+    /// 在主网上发送转账表单中的转账
+    SendTransfer,
+    /// 在主网上发送批量转账
+    SendBatchTransfer,
+    /// 重置钱包（删除密码和已保存的私钥）
+    ResetWallet,
+    /// 删除已保存的加密私钥
+    DeleteSavedKey,
+    /// 修改登录密码
+    ChangePassword,
 }
 
-impl Panel {
-    /// 获取面板名称
-    pub fn name(&self) -> &'static str {
+impl PasswordProtectedAction {
+    /// 确认对话框中说明该操作的翻译键
+    pub fn i18n_key(&self) -> &'static str {
         match self {
-            Panel::Wallet => "Wallet",
-            Panel::Balance => "Balance",
-            Panel::Transfer => "Transfer",
-            Panel::History => "History",
-            Panel::Settings => "Settings",
+            PasswordProtectedAction::CopyPrivateKey => "confirm_password_copy_private_key",
+            PasswordProtectedAction::SendTransfer => "confirm_password_send_transfer",
+            PasswordProtectedAction::SendBatchTransfer => "confirm_password_send_batch_transfer",
+            PasswordProtectedAction::ResetWallet => "confirm_password_reset_wallet",
+            PasswordProtectedAction::DeleteSavedKey => "confirm_password_delete_saved_key",
+            PasswordProtectedAction::ChangePassword => "confirm_password_change_password",
         }
     }
-
-    /// 获取所有面板
-    pub fn all() -> Vec<Panel> {
-        vec![
-            Panel::Wallet,
-            Panel::Balance,
-            Panel::Transfer,
-            Panel::History,
-            Panel::Settings,
-        ]
-    }
 }
 
 /// 对话框状态
 #[derive(Debug, Clone, PartialEq)]
 pub enum DialogState {
     None,
-    About,
-    Settings,
-    ConfirmLogout,
     ConfirmReset,
-    Error(String),
+    ConfirmDeleteKey,
+    OrphanedKey,
+    ConfirmReplaceWallet,
+    CustomNetwork,
+    BatchTransfer,
+    ConfirmBatchTransfer,
+    ClipboardWarning,
+    ConfirmKeyFileImport,
+    ExportAddresses,
+    /// 代币显示过滤（白名单/黑名单）设置
+    CoinFilter,
+    /// 各网络的备用 RPC 端点设置
+    RpcFallbacks,
+    /// 确认向自己的地址转账
+    ConfirmSelfSend,
+    /// 确认可疑的大额转账（附带警告原因）
+    ConfirmLargeAmount(String),
+    /// 重新输入密码以确认敏感操作
+    ConfirmPassword(PasswordProtectedAction),
+    /// 修改登录密码
+    ChangePassword,
     Info(String),
     Warning(String),
 }
 
 impl DialogState {
-    /// 检查是否有对话框显示
-    pub fn is_showing(&self) -> bool {
-        !matches!(self, DialogState::None)
-    }
 
-    /// 获取对话框标题
-    pub fn title(&self) -> &'static str {
-        match self {
-            DialogState::None => "",
-            DialogState::About => "About",
-            DialogState::Settings => "Settings",
-            DialogState::ConfirmLogout => "Confirm Logout",
-            DialogState::ConfirmReset => "Confirm Reset",
-            DialogState::Error(_) => "Error",
-            DialogState::Info(_) => "Information",
-            DialogState::Warning(_) => "Warning",
-        }
+    /// 是否可以通过 Esc 关闭（孤立私钥需要用户选择删除或保留）
+    pub fn is_dismissible(&self) -> bool {
+        !matches!(self, DialogState::None | DialogState::OrphanedKey)
     }
 
     /// 获取对话框消息
     pub fn message(&self) -> Option<&str> {
         match self {
-            DialogState::Error(msg) | DialogState::Info(msg) | DialogState::Warning(msg) => Some(msg),
+            DialogState::Info(msg) | DialogState::Warning(msg) => Some(msg),
             _ => None,
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Balance;
+
+    const SUI: u64 = 1_000_000_000;
+
+    #[test]
+    fn warns_for_key_copies_until_dismissed() {
+        let mut settings = AppSettings::default();
+        assert!(settings.should_warn_before_copy(CopyKind::PrivateKey));
+
+        settings.dismiss_copy_warning();
+        assert!(!settings.should_warn_before_copy(CopyKind::PrivateKey));
+    }
+
+    #[test]
+    fn detects_screenshot_protection_support_per_platform() {
+        assert!(SecuritySettings::screenshot_protection_supported_on("windows"));
+        assert!(SecuritySettings::screenshot_protection_supported_on("macos"));
+        assert!(!SecuritySettings::screenshot_protection_supported_on("linux"));
+        assert!(!SecuritySettings::screenshot_protection_supported_on("freebsd"));
+        assert_eq!(
+            SecuritySettings::screenshot_protection_supported(),
+            cfg!(any(target_os = "windows", target_os = "macos"))
+        );
+    }
+
+    #[test]
+    fn flags_amount_close_to_whole_balance() {
+        let guard = AmountGuardSettings::default();
+        assert_eq!(guard.check(95 * SUI, Some(100 * SUI), &[]), Some(SuspiciousAmount::LargeShareOfBalance));
+        assert_eq!(guard.check(50 * SUI, Some(100 * SUI), &[]), None);
+        // 余额未知时不做比例检查
+        assert_eq!(guard.check(95 * SUI, None, &[]), None);
+    }
+
+    #[test]
+    fn flags_amount_far_above_recent_transfers() {
+        let guard = AmountGuardSettings::default();
+        let recent = [SUI, 2 * SUI, SUI];
+        assert_eq!(guard.check(100 * SUI, None, &recent), Some(SuspiciousAmount::MuchLargerThanUsual));
+        assert_eq!(guard.check(10 * SUI, None, &recent), None);
+        // 历史记录不足时不比较
+        assert_eq!(guard.check(100 * SUI, None, &recent[..2]), None);
+    }
+
+    #[test]
+    fn password_focus_is_requested_once_per_show() {
+        let mut ui_state = UiState::new();
+        // 首次显示时请求一次焦点
+        assert!(ui_state.take_password_focus_request());
+        // 之后的重绘不再抢占焦点
+        assert!(!ui_state.take_password_focus_request());
+        assert!(!ui_state.take_password_focus_request());
+
+        // 登录失败或登出后再次请求
+        ui_state.request_password_focus();
+        assert!(ui_state.take_password_focus_request());
+        assert!(!ui_state.take_password_focus_request());
+    }
+
+    #[test]
+    fn locks_on_blur_only_when_enabled_and_focus_is_lost() {
+        let mut security = SecuritySettings::new();
+        assert!(!security.should_lock_on_blur(Some(true), Some(false), Some(false)));
+
+        security.lock_on_blur = true;
+        // 失去焦点或最小化时锁定
+        assert!(security.should_lock_on_blur(Some(true), Some(false), Some(false)));
+        assert!(security.should_lock_on_blur(Some(true), Some(true), Some(true)));
+        // 保持焦点、启动时尚未获得焦点或平台未报告时不锁定
+        assert!(!security.should_lock_on_blur(Some(true), Some(true), Some(false)));
+        assert!(!security.should_lock_on_blur(None, Some(false), None));
+        assert!(!security.should_lock_on_blur(Some(false), Some(false), Some(false)));
+        assert!(!security.should_lock_on_blur(Some(true), None, None));
+    }
+
+    #[test]
+    fn refreshes_on_focus_gain_with_debounce() {
+        let settings = AppSettings::new();
+        let now = Instant::now();
+
+        assert!(settings.should_refresh_on_focus(Some(false), Some(true), true, None, now));
+        // 仍在防抖间隔内
+        let recent = now - Duration::from_secs(5);
+        assert!(!settings.should_refresh_on_focus(Some(false), Some(true), true, Some(recent), now));
+        let old = now - FOCUS_REFRESH_DEBOUNCE;
+        assert!(settings.should_refresh_on_focus(Some(false), Some(true), true, Some(old), now));
+
+        // 没有获得焦点、未加载钱包或关闭设置时不刷新
+        assert!(!settings.should_refresh_on_focus(Some(true), Some(true), true, None, now));
+        assert!(!settings.should_refresh_on_focus(None, Some(true), true, None, now));
+        assert!(!settings.should_refresh_on_focus(Some(false), Some(true), false, None, now));
+        let disabled = AppSettings { refresh_on_focus: false, ..AppSettings::new() };
+        assert!(!disabled.should_refresh_on_focus(Some(false), Some(true), true, None, now));
+    }
+
+    #[test]
+    fn rejects_amount_above_known_balance_before_dry_run() {
+        let mut state = AppState::new();
+        state.transfer_amount = "2".to_string();
+        let balance = Balance::from_mist(SUI);
+
+        assert!(state.check_amount_within_balance(BalanceState::Known(balance)).is_err());
+        // 余额过期或未知时跳过检查，交给试运行
+        assert!(state.check_amount_within_balance(BalanceState::Stale(balance)).is_ok());
+        assert!(state.check_amount_within_balance(BalanceState::Unknown).is_ok());
+
+        state.transfer_amount = "1".to_string();
+        assert!(state.check_amount_within_balance(BalanceState::Known(balance)).is_ok());
+    }
+
+    #[test]
+    fn last_used_values_round_trip_through_settings() {
+        let mut settings = AppSettings::new();
+        settings.explorer_provider = ExplorerProvider::all()[1];
+        settings.last_used = LastUsedSettings {
+            network: Some(Network::Mainnet),
+            export_format: Some(AddressExportFormat::Json),
+            export_path: Some("/tmp/addresses.json".to_string()),
+            recent_networks: vec![Network::Mainnet, Network::Testnet],
+        };
+
+        let saved = serde_json::to_string(&settings).unwrap();
+        let loaded: AppSettings = serde_json::from_str(&saved).unwrap();
+        assert_eq!(loaded.last_used, settings.last_used);
+        assert_eq!(loaded.explorer_provider, settings.explorer_provider);
+
+        // 旧版本保存的设置没有该字段时使用默认值
+        let legacy: AppSettings = serde_json::from_str("{}").unwrap();
+        assert_eq!(legacy.last_used, LastUsedSettings::default());
+
+        // 旧版本保存的 auto_save 字段已由 security_settings.auto_save_keys 取代，读取时忽略
+        let legacy: AppSettings = serde_json::from_str(r#"{"auto_save": false}"#).unwrap();
+        assert!(legacy.security_settings.auto_save_keys);
+    }
+
+    #[test]
+    fn ui_scale_is_clamped_and_round_trips_through_settings() {
+        assert_eq!(AppSettings::clamp_ui_scale(0.1), UI_SCALE_MIN);
+        assert_eq!(AppSettings::clamp_ui_scale(10.0), UI_SCALE_MAX);
+        assert_eq!(AppSettings::clamp_ui_scale(1.5), 1.5);
+        assert_eq!(AppSettings::clamp_ui_scale(f32::NAN), UI_SCALE_DEFAULT);
+
+        let mut settings = AppSettings::new();
+        assert_eq!(settings.ui_scale, UI_SCALE_DEFAULT);
+        settings.set_ui_scale(1.25);
+        let saved = serde_json::to_string(&settings).unwrap();
+        let loaded: AppSettings = serde_json::from_str(&saved).unwrap();
+        assert_eq!(loaded.ui_scale, 1.25);
+
+        // 手动修改的超范围值在使用时被限制
+        let edited: AppSettings = serde_json::from_str(r#"{"ui_scale": 9.0}"#).unwrap();
+        assert_eq!(edited.effective_ui_scale(), UI_SCALE_MAX);
+        let legacy: AppSettings = serde_json::from_str("{}").unwrap();
+        assert_eq!(legacy.ui_scale, UI_SCALE_DEFAULT);
+    }
+
+    #[test]
+    fn acknowledged_security_warning_round_trips_and_hides_warning() {
+        let mut settings = AppSettings::new();
+        assert!(settings.should_show_security_warning());
+
+        settings.acknowledge_security_warning();
+        assert!(!settings.should_show_security_warning());
+        let saved = serde_json::to_string(&settings).unwrap();
+        let mut loaded: AppSettings = serde_json::from_str(&saved).unwrap();
+        assert!(!loaded.should_show_security_warning());
+
+        // 在设置中重新开启
+        loaded.security_warning_acknowledged = false;
+        assert!(loaded.should_show_security_warning());
+
+        // 旧版本的设置没有该字段时仍显示提醒
+        let legacy: AppSettings = serde_json::from_str("{}").unwrap();
+        assert!(legacy.should_show_security_warning());
+    }
+
+    #[test]
+    fn recent_networks_toggle_between_the_last_two() {
+        let mut last_used = LastUsedSettings::default();
+        assert_eq!(last_used.toggle_network_target(&Network::Testnet, None), None);
+
+        last_used.record_network(Network::Testnet);
+        last_used.record_network(Network::Mainnet);
+        assert_eq!(last_used.recent_networks, vec![Network::Mainnet, Network::Testnet]);
+        assert_eq!(last_used.network, Some(Network::Mainnet));
+        assert_eq!(last_used.toggle_network_target(&Network::Mainnet, None), Some(Network::Testnet));
+
+        // 切换回去后列表顺序更新，再次切换回到 Mainnet
+        last_used.record_network(Network::Testnet);
+        assert_eq!(last_used.recent_networks, vec![Network::Testnet, Network::Mainnet]);
+        assert_eq!(last_used.toggle_network_target(&Network::Testnet, None), Some(Network::Mainnet));
+
+        // 已删除的自定义网络不作为切换目标
+        let local = Network::custom("Local", "http://127.0.0.1:9000");
+        last_used.record_network(local.clone());
+        assert_eq!(last_used.toggle_network_target(&Network::Testnet, None), Some(Network::Mainnet));
+        assert_eq!(last_used.toggle_network_target(&Network::Testnet, Some(&local)), Some(local));
+
+        for _ in 0..3 {
+            last_used.record_network(Network::Devnet);
+        }
+        assert_eq!(last_used.recent_networks.iter().filter(|n| **n == Network::Devnet).count(), 1);
+        assert!(last_used.recent_networks.len() <= RECENT_NETWORKS_MAX);
+    }
+
+    #[test]
+    fn restores_custom_network_only_if_still_configured() {
+        let local = Network::custom("Local", "http://127.0.0.1:9000");
+        let last_used = LastUsedSettings { network: Some(local.clone()), ..LastUsedSettings::default() };
+        assert_eq!(last_used.network_to_restore(Some(&local)), Some(local));
+        assert_eq!(last_used.network_to_restore(None), None);
+    }
+
+    #[test]
+    fn loaded_security_settings_are_clamped() {
+        let mut settings = SecuritySettings { stay_signed_in_hours: u64::MAX, ..SecuritySettings::new() };
+        settings.clamp_to_limits();
+        assert_eq!(settings.stay_signed_in_hours, STAY_SIGNED_IN_HOURS_MAX);
+
+        settings.stay_signed_in_hours = 0;
+        settings.clamp_to_limits();
+        assert_eq!(settings.stay_signed_in_hours, 1);
+
+        settings.max_failed_login_attempts = u32::MAX;
+        settings.login_lockout_minutes = u64::MAX;
+        settings.clamp_to_limits();
+        assert_eq!(settings.max_failed_login_attempts, MAX_FAILED_LOGIN_ATTEMPTS_MAX);
+        assert_eq!(settings.login_lockout_minutes, LOGIN_LOCKOUT_MINUTES_MAX);
+
+        settings.min_password_length = 0;
+        settings.clamp_to_limits();
+        assert_eq!(settings.min_password_length, MIN_PASSWORD_LENGTH_FLOOR);
+        settings.min_password_length = usize::MAX;
+        settings.clamp_to_limits();
+        assert_eq!(settings.min_password_length, MIN_PASSWORD_LENGTH_MAX);
+
+        let preset = SecuritySettings::new();
+        let mut clamped = preset.clone();
+        clamped.clamp_to_limits();
+        assert_eq!(clamped.stay_signed_in_hours, preset.stay_signed_in_hours);
+        assert_eq!(clamped.max_failed_login_attempts, preset.max_failed_login_attempts);
+        assert_eq!(clamped.login_lockout_minutes, preset.login_lockout_minutes);
+        assert_eq!(clamped.min_password_length, preset.min_password_length);
+    }
+
+    #[test]
+    fn dialogs_open_close_and_dismiss_on_escape() {
+        let mut ui_state = UiState::new();
+        assert_eq!(ui_state.dialog_state, DialogState::None);
+        assert!(!ui_state.dismiss_dialog());
+
+        ui_state.show_dialog(DialogState::ExportAddresses);
+        assert_eq!(ui_state.dialog_state, DialogState::ExportAddresses);
+        ui_state.close_dialog();
+        assert_eq!(ui_state.dialog_state, DialogState::None);
+
+        // 打开新对话框会替换当前对话框，Esc 关闭
+        ui_state.show_dialog(DialogState::BatchTransfer);
+        ui_state.show_dialog(DialogState::ConfirmBatchTransfer);
+        assert!(ui_state.dismiss_dialog());
+        assert_eq!(ui_state.dialog_state, DialogState::None);
+
+        ui_state.show_dialog(DialogState::Warning("boom".to_string()));
+        assert!(ui_state.dismiss_dialog());
+
+        // 需要用户明确选择的对话框不会被 Esc 关闭
+        ui_state.show_dialog(DialogState::OrphanedKey);
+        assert!(!ui_state.dismiss_dialog());
+        assert_eq!(ui_state.dialog_state, DialogState::OrphanedKey);
+    }
+
+    #[test]
+    fn pasted_payment_uri_fills_recipient_and_amount() {
+        let address = "0xa2d14fad60c56049ecf75246a481934691214ce413e6a8ae2fe6834c173a6133";
+        let mut state = AppState::new();
+        state.recipient_address = format!("sui:{}?amount=1500000000", address);
+        assert_eq!(state.apply_payment_uri(), Ok(true));
+        assert_eq!(state.recipient_address, address);
+        assert_eq!(state.transfer_amount, "1.5");
+        assert_eq!(state.transfer_amount_mist(), Ok(1_500_000_000));
+
+        // 普通地址不受影响，无效 URI 保持原样
+        assert_eq!(state.apply_payment_uri(), Ok(false));
+        state.recipient_address = "sui:not-an-address".to_string();
+        assert_eq!(state.apply_payment_uri(), Err(PaymentUriError::InvalidAddress));
+        assert_eq!(state.recipient_address, "sui:not-an-address");
+    }
+}
@@ -1,4 +1,4 @@
-use std::{fs, path::PathBuf};
+use std::{fs, io::{self, Write}, path::{Path, PathBuf}};
 use argon2::{
     password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
     Argon2,
@@ -9,55 +9,230 @@ use aes_gcm::{
     Aes256Gcm, Nonce, Key
 };
 use base64::{Engine as _, engine::general_purpose};
+use zeroize::{Zeroize, Zeroizing};
+use super::{log_error, unix_now, wipe_secret, AccountScope, LoginLockout, LoginLockoutStore, SessionSecret, SessionSecretBackend, SessionTokenStore};
+
+/// 原子写入文件：先写入同目录下的临时文件并同步到磁盘，再重命名覆盖目标文件
+/// 写入中断时原文件保持不变
+pub fn write_file_atomic(path: &Path, data: &[u8]) -> io::Result<()> {
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "invalid file path"))?;
+    let mut tmp_name = file_name.to_os_string();
+    tmp_name.push(".tmp");
+    let tmp_path = path.with_file_name(tmp_name);
+
+    let result = (|| {
+        let mut file = fs::File::create(&tmp_path)?;
+        file.write_all(data)?;
+        file.sync_all()?;
+        fs::rename(&tmp_path, path)
+    })();
+
+    if result.is_err() {
+        let _ = fs::remove_file(&tmp_path);
+    }
+    result
+}
+
+/// 内置的常见弱密码列表（每行一个，`#` 开头为注释），离线检查，不查询任何在线服务
+static COMMON_PASSWORDS: &str = include_str!("../../assets/common_passwords.txt");
+/// 常见密码变体在结尾附加的数字和符号
+const COMMON_PASSWORD_SUFFIX_CHARS: &str = "0123456789!@#$%^&*?._-";
+
+/// 与常见密码列表比较的候选项：依次去掉结尾附加的数字和符号得到各个前缀，
+/// 每个前缀再还原字符替换（1 可能代替 i 或 l，分别尝试）
+fn common_password_candidates(password: &str) -> impl Iterator<Item = String> + '_ {
+    let stem_len = password.trim_end_matches(|c| COMMON_PASSWORD_SUFFIX_CHARS.contains(c)).len();
+    (stem_len.max(1)..=password.len()).rev().flat_map(move |end| {
+        let prefix = &password[..end];
+        [prefix.to_string(), undo_leetspeak(prefix, 'i'), undo_leetspeak(prefix, 'l')]
+    })
+}
+
+/// 把常见的数字和符号替换还原为字母
+fn undo_leetspeak(text: &str, one: char) -> String {
+    text.chars()
+        .map(|c| match c {
+            '0' => 'o',
+            '1' => one,
+            '3' => 'e',
+            '4' | '@' => 'a',
+            '5' | '$' => 's',
+            '7' => 't',
+            _ => c,
+        })
+        .collect()
+}
+
+/// 获取钱包配置目录
+pub fn wallet_config_dir() -> PathBuf {
+    let mut cfg_dir = dirs::config_dir().unwrap_or_else(|| std::env::current_dir().unwrap());
+    cfg_dir.push("sui_rust_wallet");
+    cfg_dir
+}
+
+/// 当前平台打开文件夹所用的文件管理器命令，不支持的平台返回 None
+pub fn file_manager_command(target_os: &str) -> Option<&'static str> {
+    match target_os {
+        "macos" => Some("open"),
+        "windows" => Some("explorer"),
+        "linux" | "freebsd" | "openbsd" | "netbsd" => Some("xdg-open"),
+        _ => None,
+    }
+}
+
+/// 在系统文件管理器中打开目录
+pub fn open_in_file_manager(dir: &Path) -> Result<(), String> {
+    let command = file_manager_command(std::env::consts::OS)
+        .ok_or_else(|| "Opening folders is not supported on this platform".to_string())?;
+    std::process::Command::new(command)
+        .arg(dir)
+        .spawn()
+        .map(|_| ())
+        .map_err(|e| format!("Failed to run {}: {}", command, e))
+}
+
+/// 会话即将过期的提醒阈值
+pub const SESSION_EXPIRY_WARNING: std::time::Duration = std::time::Duration::from_secs(120);
+
+/// 空闲锁定的阶段
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdleLockPhase {
+    /// 未接近锁定（或会话永不超时）
+    Active,
+    /// 即将锁定，显示倒计时，值为剩余时间
+    Warning(std::time::Duration),
+    /// 应立即锁定
+    Lock,
+}
+
+/// 格式化会话剩余时间：不足一小时显示 "分:秒"，否则显示 "时:分:秒"
+pub fn format_session_remaining(remaining: std::time::Duration) -> String {
+    let total = remaining.as_secs();
+    let (hours, minutes, seconds) = (total / 3600, total % 3600 / 60, total % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{}:{:02}", minutes, seconds)
+    }
+}
 
 /// 认证状态
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct AuthState {
     pub is_authenticated: bool,
+    /// 已锁定：需要重新输入密码，但已加载的钱包保持不变
+    pub is_locked: bool,
+    /// 临时会话：未登录，只能临时导入不保存的钱包，登出时清除
+    pub temporary_session: bool,
     pub is_first_run: bool,
     pub password_input: String,
     pub password_confirm: String,
     pub password_hash: Option<String>,
     pub password_file: PathBuf,
+    /// 密码文件存在但无法解析（已损坏）
+    pub password_file_corrupt: bool,
     pub session_timeout: Option<std::time::Instant>,
+    /// 新会话的超时时长（分钟），None 表示永不超时
+    pub session_timeout_minutes: Option<u64>,
     // 私钥加密存储相关
     pub encrypted_private_key_file: PathBuf,
-    // 会话中的临时密码（仅用于私钥加密保存）
-    session_password: Option<String>,
+    // 账户标签，与加密私钥保存在同一目录
+    pub account_label_file: PathBuf,
+    // "保持登录"会话令牌
+    pub session_store: SessionTokenStore,
+    /// 登录时是否勾选"保持登录"
+    pub stay_signed_in: bool,
+    /// 清除敏感输入时覆盖内存（见 `SecuritySettings::memory_protection`）
+    pub memory_protection: bool,
+    /// 登录失败次数和锁定状态，保存在配置目录中
+    pub lockout: LoginLockout,
+    pub lockout_store: LoginLockoutStore,
+    /// 设置密码时使用的密码策略
+    pub password_policy: PasswordPolicy,
+    /// 锁定前允许的连续登录失败次数，0 表示不限制
+    pub max_failed_attempts: u32,
+    /// 登录锁定时长（分钟）
+    pub lockout_duration_minutes: u64,
+    // 会话中的临时密码（仅用于私钥加密保存），可存放在操作系统钥匙串中
+    session_password: SessionSecret,
 }
 
 impl AuthState {
     /// 创建新的认证状态
     pub fn new() -> Self {
-        let mut cfg_dir = dirs::config_dir().unwrap_or_else(|| std::env::current_dir().unwrap());
-        cfg_dir.push("sui_rust_wallet");
-        let mut password_file = cfg_dir.clone();
-        password_file.push("password.hash");
-        
-        let mut encrypted_private_key_file = cfg_dir.clone();
-        encrypted_private_key_file.push("private_key.enc");
+        Self::with_config_dir(&wallet_config_dir())
+    }
 
-        let (is_first_run, password_hash) = match fs::read_to_string(&password_file) {
-            Ok(s) if !s.trim().is_empty() => (false, Some(s)),
-            _ => (true, None),
+    /// 使用指定配置目录下的文件创建认证状态
+    pub fn with_config_dir(cfg_dir: &Path) -> Self {
+        let password_file = cfg_dir.join("password.hash");
+
+        let encrypted_private_key_file = AccountScope::Shared.private_key_file(cfg_dir);
+        let account_label_file = AccountScope::Shared.label_file(cfg_dir);
+        let lockout_store = LoginLockoutStore::new(cfg_dir);
+
+        // 文件损坏时不能视为首次运行，否则任何人都可以覆盖密码
+        let (is_first_run, password_hash, password_file_corrupt) = match fs::read(&password_file) {
+            Ok(bytes) => match String::from_utf8(bytes) {
+                Ok(s) if s.trim().is_empty() => (true, None, false),
+                Ok(s) => {
+                    let corrupt = PasswordHash::new(&s).is_err();
+                    (false, Some(s), corrupt)
+                }
+                Err(_) => (false, None, true),
+            },
+            Err(_) => (true, None, false),
         };
 
         Self {
             is_authenticated: false,
+            is_locked: false,
+            temporary_session: false,
             is_first_run,
             password_input: String::new(),
             password_confirm: String::new(),
             password_hash,
             password_file,
+            password_file_corrupt,
             session_timeout: None,
+            session_timeout_minutes: Some(30),
             encrypted_private_key_file,
-            session_password: None,
+            account_label_file,
+            session_store: SessionTokenStore::new(cfg_dir),
+            stay_signed_in: false,
+            memory_protection: true,
+            lockout: lockout_store.load(),
+            lockout_store,
+            password_policy: PasswordPolicy::default(),
+            max_failed_attempts: 5,
+            lockout_duration_minutes: 15,
+            session_password: SessionSecret::new(false, cfg_dir),
         }
     }
 
-    /// 检查是否需要首次设置
-    pub fn needs_setup(&self) -> bool {
-        self.is_first_run
+    /// 切换账户范围（共用或按网络区分），返回私钥文件路径是否发生变化
+    pub fn set_account_scope(&mut self, scope: &AccountScope) -> bool {
+        let cfg_dir = self.config_dir().to_path_buf();
+        let private_key_file = scope.private_key_file(&cfg_dir);
+        if private_key_file == self.encrypted_private_key_file {
+            return false;
+        }
+        self.encrypted_private_key_file = private_key_file;
+        self.account_label_file = scope.label_file(&cfg_dir);
+        true
+    }
+
+    /// 检查是否存在孤立的加密私钥（私钥文件存在但密码文件缺失）
+    /// 此时新设置的密码无法解密已保存的私钥
+    pub fn has_orphaned_private_key(&self) -> bool {
+        self.is_first_run && !self.password_file_corrupt && self.encrypted_private_key_file.exists()
+    }
+
+    /// 检查密码文件是否损坏，需要进入恢复流程
+    pub fn needs_recovery(&self) -> bool {
+        self.password_file_corrupt
     }
 
     /// 检查是否已认证
@@ -78,6 +253,10 @@ impl AuthState {
             return Err(i18n.tr("password_mismatch_error"));
         }
 
+        if let Some(issue) = self.password_policy.check(pw) {
+            return Err(issue.message(|key| i18n.tr(key)));
+        }
+
         // 生成 salt 并计算 hash（argon2）
         let salt = SaltString::generate(&mut OsRng);
         let argon2 = Argon2::default();
@@ -87,25 +266,67 @@ impl AuthState {
             .to_string();
 
         // 确保存储目录存在并写入
-        if let Some(parent) = self.password_file.parent() {
-            if let Err(e) = fs::create_dir_all(parent) {
-                return Err(i18n.tr("create_dir_error").replace("{}", &e.to_string()));
-            }
+        if let Some(parent) = self.password_file.parent()
+            && let Err(e) = fs::create_dir_all(parent)
+        {
+            return Err(i18n.tr("create_dir_error").replace("{}", &e.to_string()));
         }
         
-        fs::write(&self.password_file, &password_hash)
+        write_file_atomic(&self.password_file, password_hash.as_bytes())
             .map_err(|e| i18n.tr("write_error").replace("{}", &e.to_string()))?;
 
         self.password_hash = Some(password_hash);
         self.is_first_run = false;
         self.is_authenticated = true;
-        self.password_input.clear();
-        self.password_confirm.clear();
+        self.clear_password_inputs();
+        self.start_session();
+        Ok(())
+    }
+
+    /// 写回之前的密码哈希（修改密码中途失败时回滚）
+    pub fn restore_password_hash(&mut self, password_hash: String) -> Result<(), String> {
+        write_file_atomic(&self.password_file, password_hash.as_bytes())
+            .map_err(|e| format!("Failed to restore password file: {}", e))?;
+        self.password_hash = Some(password_hash);
         Ok(())
     }
 
+    /// 设置登录锁定策略
+    pub fn set_lockout_policy(&mut self, max_failed_attempts: u32, lockout_duration_minutes: u64) {
+        self.max_failed_attempts = max_failed_attempts;
+        self.lockout_duration_minutes = lockout_duration_minutes;
+    }
+
+    /// 登录锁定时长
+    fn lockout_duration(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.lockout_duration_minutes.saturating_mul(60))
+    }
+
+    /// 登录锁定的剩余时间，未锁定时为 None
+    pub fn lockout_remaining(&self) -> Option<std::time::Duration> {
+        self.lockout.remaining(unix_now(), self.lockout_duration())
+    }
+
+    /// 锁定提示，剩余时间按分钟向上取整
+    fn lockout_message(i18n: &crate::i18n::I18nManager, remaining: std::time::Duration) -> String {
+        let minutes = remaining.as_secs().div_ceil(60);
+        i18n.tr("login_locked_out").replace("{}", &minutes.to_string())
+    }
+
+    /// 保存锁定状态，失败时只记录错误
+    fn persist_lockout(&self) {
+        if let Err(e) = self.lockout_store.save(&self.lockout) {
+            log_error(&e);
+        }
+    }
+
     /// 验证密码
+    /// 锁定期间直接拒绝；连续失败达到上限后锁定，锁定状态保存到磁盘，登录成功时清除
     pub fn verify_password(&mut self, attempt: &str, i18n: &crate::i18n::I18nManager) -> Result<bool, String> {
+        if let Some(remaining) = self.lockout_remaining() {
+            return Err(Self::lockout_message(i18n, remaining));
+        }
+
         let stored = match &self.password_hash {
             Some(h) => h.clone(),
             None => {
@@ -120,33 +341,60 @@ impl AuthState {
             }
         };
 
-        let parsed = PasswordHash::new(&stored)
-            .map_err(|e| i18n.tr("parse_hash_error").replace("{}", &e.to_string()))?;
+        let parsed = match PasswordHash::new(&stored) {
+            Ok(parsed) => parsed,
+            Err(_) => {
+                self.password_file_corrupt = true;
+                return Err(i18n.tr("password_file_corrupt"));
+            }
+        };
         let argon2 = Argon2::default();
         match argon2.verify_password(attempt.as_bytes(), &parsed) {
             Ok(()) => {
                 self.is_authenticated = true;
+                self.start_session();
                 // 保存会话密码用于私钥加密
-                self.session_password = Some(attempt.to_string());
+                self.session_password.set(attempt.to_string());
+                if !self.lockout.is_clear() {
+                    self.lockout = LoginLockout::default();
+                    self.persist_lockout();
+                }
                 Ok(true)
             }
-            Err(_) => Ok(false),
+            Err(_) => {
+                let locked = self.lockout.record_failure(self.max_failed_attempts, self.lockout_duration(), unix_now());
+                self.persist_lockout();
+                match self.lockout_remaining() {
+                    Some(remaining) if locked => Err(Self::lockout_message(i18n, remaining)),
+                    _ => Ok(false),
+                }
+            }
         }
     }
 
-    /// 获取会话密码（用于私钥加密）
-    pub fn get_session_password(&self) -> Option<&str> {
-        self.session_password.as_deref()
+    /// 获取会话密码（用于私钥加密），副本释放时清零
+    pub fn get_session_password(&self) -> Option<Zeroizing<String>> {
+        self.session_password.get()
     }
 
     /// 设置会话密码
     pub fn set_session_password(&mut self, password: String) {
-        self.session_password = Some(password);
+        self.session_password.set(password);
     }
 
     /// 清除会话密码
     pub fn clear_session_password(&mut self) {
-        self.session_password = None;
+        self.session_password.clear();
+    }
+
+    /// 设置是否将会话密码保存在操作系统钥匙串中（不可用时回退到内存）
+    pub fn set_use_os_keyring(&mut self, enabled: bool) {
+        self.session_password.set_prefer_keyring(enabled);
+    }
+
+    /// 会话密码当前的存储后端
+    pub fn session_secret_backend(&self) -> SessionSecretBackend {
+        self.session_password.backend()
     }
 
     /// 检查会话是否过期
@@ -160,9 +408,29 @@ impl AuthState {
 
     /// 设置会话超时（分钟）
     pub fn set_session_timeout(&mut self, minutes: u64) {
-        self.session_timeout = Some(
-            std::time::Instant::now() + std::time::Duration::from_secs(minutes * 60)
-        );
+        // 超出 Instant 可表示范围时视为永不超时
+        self.session_timeout = std::time::Instant::now()
+            .checked_add(std::time::Duration::from_secs(minutes.saturating_mul(60)));
+    }
+
+    /// 按配置的超时时长开始新会话
+    pub fn start_session(&mut self) {
+        match self.session_timeout_minutes {
+            Some(minutes) => self.set_session_timeout(minutes),
+            None => self.clear_session_timeout(),
+        }
+    }
+
+    /// 设置新会话的超时时长（None 表示永不超时）
+    pub fn set_session_timeout_minutes(&mut self, minutes: Option<u64>) {
+        self.session_timeout_minutes = minutes;
+    }
+
+    /// 应用认证配置
+    pub fn apply_config(&mut self, config: &AuthConfig) {
+        self.session_timeout_minutes = config.session_timeout_minutes;
+        self.set_lockout_policy(config.max_failed_attempts, config.lockout_duration_minutes);
+        self.password_policy = config.password_policy.clone();
     }
 
     /// 清除会话超时
@@ -175,43 +443,97 @@ impl AuthState {
         self.set_session_timeout(minutes);
     }
 
+    /// 会话剩余时间，永不超时时返回 None
+    pub fn session_remaining(&self, now: std::time::Instant) -> Option<std::time::Duration> {
+        self.session_timeout
+            .map(|timeout| timeout.saturating_duration_since(now))
+    }
+
+    /// 当前空闲锁定所处的阶段：剩余时间不超过 `grace` 时进入倒计时，到期后锁定
+    pub fn idle_lock_phase(&self, now: std::time::Instant, grace: std::time::Duration) -> IdleLockPhase {
+        match self.session_remaining(now) {
+            None => IdleLockPhase::Active,
+            Some(remaining) if remaining.is_zero() => IdleLockPhase::Lock,
+            Some(remaining) if remaining <= grace => IdleLockPhase::Warning(remaining),
+            Some(_) => IdleLockPhase::Active,
+        }
+    }
+
+    /// 记录用户活动：已认证（或处于临时会话）且会话未过期时，有真实交互才按配置的超时时长延长会话
+    /// 返回是否延长了会话
+    pub fn record_activity(&mut self, interacted: bool) -> bool {
+        if !interacted || !self.has_wallet_access() || self.is_session_expired() {
+            return false;
+        }
+        match self.session_timeout_minutes {
+            Some(minutes) => {
+                self.extend_session(minutes);
+                true
+            }
+            None => false,
+        }
+    }
+
     /// 清除密码输入
     pub fn clear_password_inputs(&mut self) {
-        self.password_input.clear();
-        self.password_confirm.clear();
+        wipe_secret(&mut self.password_input, self.memory_protection);
+        wipe_secret(&mut self.password_confirm, self.memory_protection);
+    }
+
+    /// 设置是否启用内存保护
+    pub fn set_memory_protection(&mut self, enabled: bool) {
+        self.memory_protection = enabled;
+        self.session_password.set_memory_protection(enabled);
     }
 
     /// 登出
     pub fn logout(&mut self) {
         self.is_authenticated = false;
+        self.is_locked = false;
+        self.temporary_session = false;
         self.clear_password_inputs();
         self.clear_session_timeout();
         self.clear_session_password();
     }
 
-    /// 获取密码文件路径
-    pub fn password_file_path(&self) -> &PathBuf {
-        &self.password_file
+    /// 是否可以进入钱包界面：已登录，或处于临时会话中
+    pub fn has_wallet_access(&self) -> bool {
+        self.is_authenticated || self.temporary_session
     }
 
-    /// 检查密码文件是否存在
-    pub fn password_file_exists(&self) -> bool {
-        self.password_file.exists()
+    /// 锁定：要求重新输入密码，与登出不同，不清除已加载的钱包
+    pub fn lock(&mut self) {
+        self.is_authenticated = false;
+        self.is_locked = true;
+        self.clear_password_inputs();
+        self.clear_session_timeout();
+        self.clear_session_password();
+    }
+
+    /// 配置目录（密码文件所在目录，共用账户的加密私钥也保存在这里）
+    pub fn config_dir(&self) -> &Path {
+        self.password_file.parent().unwrap_or(Path::new("."))
     }
 
     /// 保存加密的私钥
     pub fn save_encrypted_private_key(&self, private_key: &str, password: &str) -> Result<(), String> {
+        Self::write_encrypted_private_key(&self.encrypted_private_key_file, private_key, password)
+    }
+
+    /// 用密码加密私钥并写入指定文件
+    fn write_encrypted_private_key(path: &Path, private_key: &str, password: &str) -> Result<(), String> {
         // 使用密码生成加密密钥
         let salt = SaltString::generate(&mut OsRng);
         let argon2 = Argon2::default();
         
         // 生成 32 字节的密钥用于 AES-256
-        let mut key_bytes = [0u8; 32];
-        argon2.hash_password_into(password.as_bytes(), salt.as_str().as_bytes(), &mut key_bytes)
+        let mut key_bytes = Zeroizing::new([0u8; 32]);
+        argon2.hash_password_into(password.as_bytes(), salt.as_str().as_bytes(), &mut *key_bytes)
             .map_err(|e| format!("Failed to derive key: {}", e))?;
         
-        let key = Key::<Aes256Gcm>::from_slice(&key_bytes);
-        let cipher = Aes256Gcm::new(key);
+        let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&*key_bytes));
+        // 密钥已复制进 cipher，立即清零
+        drop(key_bytes);
         
         // 生成随机 nonce
         let nonce = Aes256Gcm::generate_nonce(&mut AesOsRng);
@@ -230,26 +552,31 @@ impl AuthState {
         let data = format!("{}|{}", salt_str, payload_b64);
         
         // 确保存储目录存在并写入
-        if let Some(parent) = self.encrypted_private_key_file.parent() {
+        if let Some(parent) = path.parent() {
             fs::create_dir_all(parent)
                 .map_err(|e| format!("Failed to create directory: {}", e))?;
         }
         
-        fs::write(&self.encrypted_private_key_file, data)
+        write_file_atomic(path, data.as_bytes())
             .map_err(|e| format!("Failed to write encrypted private key: {}", e))?;
         
         Ok(())
     }
 
     /// 加载并解密私钥
-    pub fn load_encrypted_private_key(&self, password: &str) -> Result<Option<String>, String> {
+    pub fn load_encrypted_private_key(&self, password: &str) -> Result<Option<Zeroizing<String>>, String> {
+        Self::read_encrypted_private_key(&self.encrypted_private_key_file, password)
+    }
+
+    /// 读取并解密指定文件中的私钥，文件不存在时返回 None
+    fn read_encrypted_private_key(path: &Path, password: &str) -> Result<Option<Zeroizing<String>>, String> {
         // 检查文件是否存在
-        if !self.encrypted_private_key_file.exists() {
+        if !path.exists() {
             return Ok(None);
         }
         
         // 读取文件
-        let file_data = fs::read_to_string(&self.encrypted_private_key_file)
+        let file_data = fs::read_to_string(path)
             .map_err(|e| format!("Failed to read encrypted private key file: {}", e))?;
         
         // 解析格式：salt|payload_b64
@@ -283,21 +610,82 @@ impl AuthState {
         
         // 使用密码和 salt 重新生成密钥
         let argon2 = Argon2::default();
-        let mut key_bytes = [0u8; 32];
-        argon2.hash_password_into(password.as_bytes(), salt.as_str().as_bytes(), &mut key_bytes)
+        let mut key_bytes = Zeroizing::new([0u8; 32]);
+        argon2.hash_password_into(password.as_bytes(), salt.as_str().as_bytes(), &mut *key_bytes)
             .map_err(|e| format!("Failed to derive key: {}", e))?;
         
-        let key = Key::<Aes256Gcm>::from_slice(&key_bytes);
-        let cipher = Aes256Gcm::new(key);
+        let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&*key_bytes));
+        // 密钥已复制进 cipher，立即清零
+        drop(key_bytes);
         
         // 解密
         let plaintext = cipher.decrypt(nonce, ciphertext)
             .map_err(|_| "Failed to decrypt private key (wrong password?)".to_string())?;
         
-        let private_key = String::from_utf8(plaintext)
-            .map_err(|e| format!("Invalid UTF-8 in decrypted data: {}", e))?;
+        let private_key = String::from_utf8(plaintext).map_err(|e| {
+            let message = format!("Invalid UTF-8 in decrypted data: {}", e.utf8_error());
+            e.into_bytes().zeroize();
+            message
+        })?;
         
-        Ok(Some(private_key))
+        Ok(Some(Zeroizing::new(private_key)))
+    }
+
+    /// 用新密码重新加密所有账户范围（共用及每个网络）中已保存的私钥
+    /// 先用旧密码解密全部私钥，任何一个失败都不写入；写入中途失败时恢复已写入的文件
+    pub fn reencrypt_private_keys(&self, old_password: &str, new_password: &str) -> Result<(), String> {
+        let cfg_dir = self.config_dir().to_path_buf();
+        let mut saved = Vec::new();
+        let mut result = Ok(());
+        for path in AccountScope::all(&cfg_dir).iter().map(|scope| scope.private_key_file(&cfg_dir)) {
+            let original = match fs::read(&path) {
+                Ok(original) => original,
+                Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+                Err(e) => {
+                    result = Err(format!("Failed to read encrypted private key file: {}", e));
+                    break;
+                }
+            };
+            match Self::read_encrypted_private_key(&path, old_password) {
+                Ok(Some(key)) => saved.push((path, original, key)),
+                Ok(None) => {}
+                Err(e) => {
+                    result = Err(e);
+                    break;
+                }
+            }
+        }
+
+        if result.is_ok() {
+            for (written, (path, _, key)) in saved.iter().enumerate() {
+                if let Err(e) = Self::write_encrypted_private_key(path, key, new_password) {
+                    for (path, original, _) in &saved[..written] {
+                        if let Err(restore) = write_file_atomic(path, original) {
+                            log_error(&format!("Failed to restore {}: {}", path.display(), restore));
+                        }
+                    }
+                    result = Err(e);
+                    break;
+                }
+            }
+        }
+
+        // 解密出的私钥为 Zeroizing，随 saved 释放时清零
+        result
+    }
+
+    /// 删除所有账户范围（共用及每个网络）中已保存的私钥和账户标签
+    pub fn delete_all_private_keys(&self) -> Result<(), String> {
+        let cfg_dir = self.config_dir().to_path_buf();
+        for scope in AccountScope::all(&cfg_dir) {
+            for path in [scope.private_key_file(&cfg_dir), scope.label_file(&cfg_dir)] {
+                if path.exists() {
+                    fs::remove_file(&path)
+                        .map_err(|e| format!("Failed to delete {}: {}", path.display(), e))?;
+                }
+            }
+        }
+        Ok(())
     }
 
     /// 检查是否有保存的加密私钥
@@ -311,6 +699,34 @@ impl AuthState {
             fs::remove_file(&self.encrypted_private_key_file)
                 .map_err(|e| format!("Failed to delete encrypted private key: {}", e))?;
         }
+        self.delete_account_label()
+    }
+
+    /// 保存账户标签
+    pub fn save_account_label(&self, label: &str) -> Result<(), String> {
+        if let Some(parent) = self.account_label_file.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create directory: {}", e))?;
+        }
+
+        write_file_atomic(&self.account_label_file, label.as_bytes())
+            .map_err(|e| format!("Failed to write account label: {}", e))
+    }
+
+    /// 加载账户标签
+    pub fn load_account_label(&self) -> Option<String> {
+        fs::read_to_string(&self.account_label_file)
+            .ok()
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+    }
+
+    /// 删除账户标签
+    pub fn delete_account_label(&self) -> Result<(), String> {
+        if self.account_label_file.exists() {
+            fs::remove_file(&self.account_label_file)
+                .map_err(|e| format!("Failed to delete account label: {}", e))?;
+        }
         Ok(())
     }
 }
@@ -325,134 +741,19 @@ impl Default for AuthState {
 pub struct AuthManager;
 
 impl AuthManager {
-    /// 设置密码
-    pub fn set_password(auth_state: &mut AuthState, error_handler: impl Fn(&str) -> String) -> Result<(), String> {
-        let pw = auth_state.password_input.trim();
-        let pwc = auth_state.password_confirm.trim();
-        
-        if pw.is_empty() {
-            return Err(error_handler("password_empty_error"));
-        }
-        
-        if pw != pwc {
-            return Err(error_handler("password_mismatch_error"));
-        }
-
-        // 检查密码强度
-        if let Some(weakness) = Self::check_password_strength(pw) {
-            return Err(format!("Password weakness: {}", weakness));
-        }
-
-        // 生成 salt 并计算 hash（argon2）
-        let salt = SaltString::generate(&mut OsRng);
-        let argon2 = Argon2::default();
-        let password_hash = argon2
-            .hash_password(pw.as_bytes(), &salt)
-            .map_err(|e| error_handler("hash_error").replace("{}", &e.to_string()))?
-            .to_string();
-
-        // 确保存储目录存在并写入
-        if let Some(parent) = auth_state.password_file.parent() {
-            if let Err(e) = fs::create_dir_all(parent) {
-                return Err(error_handler("create_dir_error").replace("{}", &e.to_string()));
-            }
-        }
-        
-        fs::write(&auth_state.password_file, &password_hash)
-            .map_err(|e| error_handler("write_error").replace("{}", &e.to_string()))?;
-
-        auth_state.password_hash = Some(password_hash);
-        auth_state.is_first_run = false;
-        auth_state.is_authenticated = true;
-        auth_state.clear_password_inputs();
-        
-        // 设置默认会话超时（30分钟）
-        auth_state.set_session_timeout(30);
-        
-        Ok(())
-    }
-
-    /// 验证密码
-    pub fn verify_password(auth_state: &mut AuthState, attempt: &str, error_handler: impl Fn(&str) -> String) -> Result<bool, String> {
-        let stored = match &auth_state.password_hash {
-            Some(h) => h.clone(),
-            None => {
-                // 尝试从文件读取（兜底）
-                match fs::read_to_string(&auth_state.password_file) {
-                    Ok(s) if !s.trim().is_empty() => {
-                        auth_state.password_hash = Some(s.clone());
-                        s
-                    }
-                    _ => return Err(error_handler("password_not_found_error")),
-                }
-            }
-        };
-
-        let parsed = PasswordHash::new(&stored)
-            .map_err(|e| error_handler("parse_hash_error").replace("{}", &e.to_string()))?;
-        let argon2 = Argon2::default();
-        
-        match argon2.verify_password(attempt.as_bytes(), &parsed) {
-            Ok(()) => {
-                auth_state.is_authenticated = true;
-                auth_state.set_session_timeout(30); // 30分钟会话
-                Ok(true)
-            }
-            Err(_) => Ok(false),
-        }
-    }
-
-    /// 检查密码强度
-    pub fn check_password_strength(password: &str) -> Option<&'static str> {
-        if password.len() < 8 {
-            return Some("Password must be at least 8 characters long");
-        }
-        
-        let has_lowercase = password.chars().any(|c| c.is_lowercase());
-        let has_uppercase = password.chars().any(|c| c.is_uppercase());
-        let has_digit = password.chars().any(|c| c.is_numeric());
-        let has_special = password.chars().any(|c| !c.is_alphanumeric());
-        
-        if !has_lowercase {
-            return Some("Password must contain at least one lowercase letter");
-        }
-        if !has_uppercase {
-            return Some("Password must contain at least one uppercase letter");
-        }
-        if !has_digit {
-            return Some("Password must contain at least one digit");
-        }
-        if !has_special {
-            return Some("Password must contain at least one special character");
-        }
-        
-        None
-    }
-
-    /// 计算密码强度分数 (0-5)
-    pub fn calculate_password_score(password: &str) -> u8 {
-        let mut score = 0;
-        
-        if password.len() >= 8 { score += 1; }
-        if password.len() >= 12 { score += 1; }
-        if password.chars().any(|c| c.is_lowercase()) { score += 1; }
-        if password.chars().any(|c| c.is_uppercase()) { score += 1; }
-        if password.chars().any(|c| c.is_numeric()) { score += 1; }
-        if password.chars().any(|c| !c.is_alphanumeric()) { score += 1; }
-        
-        score.min(5)
-    }
-
-    /// 获取密码强度描述
-    pub fn get_password_strength_description(score: u8) -> (&'static str, PasswordStrength) {
-        match score {
-            0..=1 => ("Very Weak", PasswordStrength::VeryWeak),
-            2 => ("Weak", PasswordStrength::Weak),
-            3 => ("Fair", PasswordStrength::Fair),
-            4 => ("Good", PasswordStrength::Good),
-            5 => ("Strong", PasswordStrength::Strong),
-            _ => ("Strong", PasswordStrength::Strong),
-        }
+    /// 密码是否在内置的常见弱密码列表中（不区分大小写）
+    /// 比较前还原常见变体：去掉结尾附加的数字和符号（如 sunshine123!），并还原字符替换（如 p@ssw0rd）
+    pub fn is_common_password(password: &str) -> bool {
+        static DENYLIST: std::sync::LazyLock<std::collections::HashSet<&'static str>> =
+            std::sync::LazyLock::new(|| {
+                COMMON_PASSWORDS
+                    .lines()
+                    .map(str::trim)
+                    .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                    .collect()
+            });
+        let password = password.trim().to_lowercase();
+        common_password_candidates(&password).any(|candidate| DENYLIST.contains(candidate.as_str()))
     }
 
     /// 重置密码
@@ -465,6 +766,7 @@ impl AuthManager {
 
         // 重置状态
         auth_state.password_hash = None;
+        auth_state.password_file_corrupt = false;
         auth_state.is_first_run = true;
         auth_state.is_authenticated = false;
         auth_state.clear_password_inputs();
@@ -472,98 +774,432 @@ impl AuthManager {
 
         Ok(())
     }
+}
 
-    /// 更改密码
-    pub fn change_password(
-        auth_state: &mut AuthState, 
-        old_password: &str, 
-        new_password: &str, 
-        confirm_password: &str,
-        error_handler: impl Fn(&str) -> String
-    ) -> Result<(), String> {
-        // 验证旧密码
-        if !Self::verify_password(auth_state, old_password, &error_handler)? {
-            return Err("Current password is incorrect".to_string());
-        }
+/// 密码不符合密码策略的原因
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PasswordIssue {
+    /// 短于最小长度
+    TooShort(usize),
+    MissingLowercase,
+    MissingUppercase,
+    MissingDigit,
+    MissingSpecial,
+    /// 在常见弱密码列表中
+    TooCommon,
+}
 
-        // 临时保存新密码到输入字段
-        auth_state.password_input = new_password.to_string();
-        auth_state.password_confirm = confirm_password.to_string();
+impl PasswordIssue {
+    /// 获取原因的翻译键
+    pub fn i18n_key(&self) -> &'static str {
+        match self {
+            PasswordIssue::TooShort(_) => "password_too_short",
+            PasswordIssue::MissingLowercase => "password_missing_lowercase",
+            PasswordIssue::MissingUppercase => "password_missing_uppercase",
+            PasswordIssue::MissingDigit => "password_missing_digit",
+            PasswordIssue::MissingSpecial => "password_missing_special",
+            PasswordIssue::TooCommon => "password_too_common",
+        }
+    }
 
-        // 设置新密码
-        Self::set_password(auth_state, error_handler)
+    /// 用 `tr` 翻译后的提示文本
+    pub fn message(&self, tr: impl Fn(&str) -> String) -> String {
+        let text = tr(self.i18n_key());
+        match self {
+            PasswordIssue::TooShort(min_length) => text.replace("{}", &min_length.to_string()),
+            _ => text,
+        }
     }
 }
 
-/// 密码强度枚举
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum PasswordStrength {
-    VeryWeak,
-    Weak,
-    Fair,
-    Good,
-    Strong,
+/// 密码策略：最小长度、必须包含的字符类别、是否拒绝常见弱密码
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswordPolicy {
+    /// 最小长度（字符数）
+    pub min_length: usize,
+    pub require_lowercase: bool,
+    pub require_uppercase: bool,
+    pub require_digit: bool,
+    pub require_special: bool,
+    /// 拒绝内置列表中的常见弱密码
+    pub reject_common: bool,
 }
 
-impl PasswordStrength {
-    /// 获取对应的颜色
-    #[cfg(feature = "egui")]
-    pub fn to_egui_color(&self) -> eframe::egui::Color32 {
-        match self {
-            PasswordStrength::VeryWeak => eframe::egui::Color32::RED,
-            PasswordStrength::Weak => eframe::egui::Color32::from_rgb(255, 100, 100),
-            PasswordStrength::Fair => eframe::egui::Color32::YELLOW,
-            PasswordStrength::Good => eframe::egui::Color32::from_rgb(100, 200, 100),
-            PasswordStrength::Strong => eframe::egui::Color32::GREEN,
+impl PasswordPolicy {
+    /// 检查密码，返回第一个不符合的原因
+    pub fn check(&self, password: &str) -> Option<PasswordIssue> {
+        if password.chars().count() < self.min_length {
+            return Some(PasswordIssue::TooShort(self.min_length));
         }
+        if self.require_lowercase && !password.chars().any(|c| c.is_lowercase()) {
+            return Some(PasswordIssue::MissingLowercase);
+        }
+        if self.require_uppercase && !password.chars().any(|c| c.is_uppercase()) {
+            return Some(PasswordIssue::MissingUppercase);
+        }
+        if self.require_digit && !password.chars().any(|c| c.is_numeric()) {
+            return Some(PasswordIssue::MissingDigit);
+        }
+        if self.require_special && !password.chars().any(|c| !c.is_alphanumeric()) {
+            return Some(PasswordIssue::MissingSpecial);
+        }
+        if self.reject_common && AuthManager::is_common_password(password) {
+            return Some(PasswordIssue::TooCommon);
+        }
+        None
     }
+}
 
-    /// 获取 RGB 值
-    pub fn to_rgb(&self) -> (u8, u8, u8) {
-        match self {
-            PasswordStrength::VeryWeak => (255, 0, 0),
-            PasswordStrength::Weak => (255, 100, 100),
-            PasswordStrength::Fair => (255, 255, 0),
-            PasswordStrength::Good => (100, 200, 100),
-            PasswordStrength::Strong => (0, 255, 0),
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        Self {
+            min_length: 8,
+            require_lowercase: true,
+            require_uppercase: true,
+            require_digit: true,
+            require_special: true,
+            reject_common: true,
         }
     }
 }
 
+/// 首次设置密码表单的实时校验结果（与提交时一样，两端空白不计入密码）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PasswordSetupValidation {
+    /// 确认密码是否与密码一致，确认框为空时为 None（不显示提示）
+    pub confirm_matches: Option<bool>,
+    /// 密码不符合密码策略的原因
+    pub strength_issue: Option<PasswordIssue>,
+}
+
+impl PasswordSetupValidation {
+    pub fn check(password: &str, confirm: &str, policy: &PasswordPolicy) -> Self {
+        let (password, confirm) = (password.trim(), confirm.trim());
+        Self {
+            confirm_matches: (!confirm.is_empty()).then(|| password == confirm),
+            strength_issue: policy.check(password),
+        }
+    }
+
+    /// 两次输入一致且密码符合密码策略时才允许创建
+    pub fn can_submit(&self) -> bool {
+        self.confirm_matches == Some(true) && self.strength_issue.is_none()
+    }
+}
+
 /// 认证配置
 #[derive(Debug, Clone)]
 pub struct AuthConfig {
-    pub session_timeout_minutes: u64,
-    pub require_password_change: bool,
+    /// 会话超时（分钟），None 表示永不超时
+    pub session_timeout_minutes: Option<u64>,
     pub max_failed_attempts: u32,
     pub lockout_duration_minutes: u64,
-    pub auto_logout_on_idle: bool,
+    /// 设置密码时使用的密码策略
+    pub password_policy: PasswordPolicy,
 }
 
 impl AuthConfig {
     pub fn new() -> Self {
         Self {
-            session_timeout_minutes: 30,
-            require_password_change: false,
+            session_timeout_minutes: Some(30),
             max_failed_attempts: 5,
             lockout_duration_minutes: 15,
-            auto_logout_on_idle: true,
+            password_policy: PasswordPolicy::default(),
         }
     }
-
-    pub fn with_session_timeout(mut self, minutes: u64) -> Self {
-        self.session_timeout_minutes = minutes;
-        self
-    }
-
-    pub fn with_max_failed_attempts(mut self, attempts: u32) -> Self {
-        self.max_failed_attempts = attempts;
-        self
-    }
 }
 
 impl Default for AuthConfig {
     fn default() -> Self {
         Self::new()
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::TestDir;
+    use std::time::{Duration, Instant};
+
+    fn authenticated_state(dir: &Path) -> AuthState {
+        let mut state = AuthState::with_config_dir(dir);
+        state.is_authenticated = true;
+        state.session_timeout_minutes = Some(30);
+        state.session_timeout = Some(Instant::now() + Duration::from_secs(60));
+        state
+    }
+
+    #[test]
+    fn formats_remaining_time_from_timeout() {
+        let dir = TestDir::new("formats_remaining_time_from_timeout");
+        let now = Instant::now();
+        let mut state = authenticated_state(&dir);
+
+        state.session_timeout = Some(now + Duration::from_secs(12 * 60 + 30));
+        assert_eq!(state.session_remaining(now).map(format_session_remaining).as_deref(), Some("12:30"));
+
+        state.session_timeout = Some(now + Duration::from_secs(3600 + 5));
+        assert_eq!(state.session_remaining(now).map(format_session_remaining).as_deref(), Some("1:00:05"));
+
+        // 已过期时显示 0:00
+        state.session_timeout = Some(now - Duration::from_secs(5));
+        assert_eq!(state.session_remaining(now).map(format_session_remaining).as_deref(), Some("0:00"));
+
+        state.session_timeout = None;
+        assert_eq!(state.session_remaining(now), None);
+    }
+
+    #[test]
+    fn interaction_extends_session() {
+        let dir = TestDir::new("interaction_extends_session");
+        let mut state = authenticated_state(&dir);
+        let before = state.session_timeout;
+
+        assert!(state.record_activity(true));
+        assert!(state.session_timeout > before);
+    }
+
+    #[test]
+    fn repaint_without_interaction_does_not_extend_session() {
+        let dir = TestDir::new("repaint_without_interaction_does_not_extend_session");
+        let mut state = authenticated_state(&dir);
+        let before = state.session_timeout;
+
+        assert!(!state.record_activity(false));
+        assert_eq!(state.session_timeout, before);
+    }
+
+    #[test]
+    fn expired_or_logged_out_session_is_not_extended() {
+        let dir = TestDir::new("expired_or_logged_out_session_is_not_extended");
+        let mut expired = authenticated_state(&dir);
+        expired.session_timeout = Some(Instant::now() - Duration::from_secs(1));
+        assert!(!expired.record_activity(true));
+
+        let mut logged_out = authenticated_state(&dir);
+        logged_out.is_authenticated = false;
+        assert!(!logged_out.record_activity(true));
+    }
+
+    #[test]
+    fn idle_lock_counts_down_then_locks_unless_dismissed() {
+        let dir = TestDir::new("idle_lock_counts_down_then_locks_unless_dismissed");
+        let mut state = authenticated_state(&dir);
+        let start = Instant::now();
+        state.session_timeout = Some(start + Duration::from_secs(60));
+        let grace = Duration::from_secs(10);
+
+        assert_eq!(state.idle_lock_phase(start, grace), IdleLockPhase::Active);
+        assert_eq!(
+            state.idle_lock_phase(start + Duration::from_secs(52), grace),
+            IdleLockPhase::Warning(Duration::from_secs(8))
+        );
+        assert_eq!(state.idle_lock_phase(start + Duration::from_secs(60), grace), IdleLockPhase::Lock);
+
+        // "保持解锁"重新开始计时
+        state.start_session();
+        assert_eq!(state.idle_lock_phase(start + Duration::from_secs(60), grace), IdleLockPhase::Active);
+
+        // 永不超时时不会进入倒计时
+        state.clear_session_timeout();
+        assert_eq!(state.idle_lock_phase(start + Duration::from_secs(3600), grace), IdleLockPhase::Active);
+    }
+
+    #[test]
+    fn account_scope_files_stay_in_the_config_dir() {
+        let dir = TestDir::new("account_scope");
+        let mut state = AuthState::with_config_dir(&dir);
+
+        assert!(state.set_account_scope(&AccountScope::Network("testnet".to_string())));
+        assert!(state.encrypted_private_key_file.starts_with(&*dir));
+        assert!(state.account_label_file.starts_with(&*dir));
+        assert!(!state.set_account_scope(&AccountScope::Network("testnet".to_string())));
+    }
+
+    #[test]
+    fn persisted_lockout_survives_restart_and_clears_on_login() {
+        let dir = TestDir::new("lockout");
+        let i18n = crate::i18n::I18nManager::new();
+
+        let mut state = AuthState::with_config_dir(&dir);
+        state.password_input = "Correct-horse-1".to_string();
+        state.password_confirm = "Correct-horse-1".to_string();
+        state.set_password(&i18n).unwrap();
+        state.set_lockout_policy(3, 15);
+
+        assert_eq!(state.verify_password("wrong", &i18n), Ok(false));
+        assert_eq!(state.verify_password("wrong", &i18n), Ok(false));
+        assert!(state.verify_password("wrong", &i18n).is_err());
+
+        // 重启后锁定仍然有效，即使密码正确也被拒绝
+        let mut restarted = AuthState::with_config_dir(&dir);
+        assert!(restarted.lockout_remaining().is_some());
+        assert!(restarted.verify_password("Correct-horse-1", &i18n).is_err());
+        assert!(!restarted.is_authenticated);
+
+        // 锁定到期后登录成功，清除失败记录
+        restarted.lockout.locked_until = Some(0);
+        restarted.lockout.failed_attempts = 1;
+        assert_eq!(restarted.verify_password("Correct-horse-1", &i18n), Ok(true));
+        assert!(AuthState::with_config_dir(&dir).lockout.is_clear());
+    }
+
+    #[test]
+    fn verifying_uses_the_configured_session_timeout() {
+        let dir = TestDir::new("session_timeout");
+        let i18n = crate::i18n::I18nManager::new();
+        let mut state = AuthState::with_config_dir(&dir);
+        state.password_input = "Correct-horse-1".to_string();
+        state.password_confirm = "Correct-horse-1".to_string();
+        state.set_password(&i18n).unwrap();
+
+        state.set_session_timeout_minutes(Some(5));
+        assert_eq!(state.verify_password("Correct-horse-1", &i18n), Ok(true));
+        let remaining = state.session_remaining(Instant::now()).unwrap();
+        assert!(remaining <= Duration::from_secs(5 * 60));
+        assert!(remaining > Duration::from_secs(4 * 60));
+
+        // None 表示永不超时，登录后不设置截止时间
+        state.set_session_timeout_minutes(None);
+        assert_eq!(state.verify_password("Correct-horse-1", &i18n), Ok(true));
+        assert_eq!(state.session_remaining(Instant::now()), None);
+        assert!(!state.is_session_expired());
+    }
+
+    #[test]
+    fn huge_session_timeout_does_not_overflow() {
+        let dir = TestDir::new("huge_session_timeout");
+        let mut state = AuthState::with_config_dir(&dir);
+        state.set_session_timeout(u64::MAX);
+        assert!(!state.is_session_expired());
+    }
+
+    #[test]
+    fn failed_atomic_write_keeps_the_previous_file() {
+        let dir = TestDir::new("atomic_write");
+        let target = dir.join("password.hash");
+        let tmp = dir.join("password.hash.tmp");
+        write_file_atomic(&target, b"old").unwrap();
+
+        // 临时文件路径被目录占用，写入在重命名之前失败
+        fs::create_dir(&tmp).unwrap();
+        assert!(write_file_atomic(&target, b"new").is_err());
+        assert_eq!(fs::read(&target).unwrap(), b"old");
+        fs::remove_dir(&tmp).unwrap();
+
+        // 目标被目录占用时重命名失败，不留下临时文件
+        let blocked = dir.join("blocked");
+        fs::create_dir_all(blocked.join("child")).unwrap();
+        assert!(write_file_atomic(&blocked, b"new").is_err());
+        assert!(!dir.join("blocked.tmp").exists());
+
+        write_file_atomic(&target, b"new").unwrap();
+        assert_eq!(fs::read(&target).unwrap(), b"new");
+        assert!(!tmp.exists());
+    }
+
+    #[test]
+    fn corrupt_password_file_is_not_treated_as_first_run() {
+        let i18n = crate::i18n::I18nManager::new();
+        for (name, contents) in [("corrupt_text", b"not-a-hash".to_vec()), ("corrupt_binary", vec![0xff, 0xfe, 0x00])] {
+            let dir = TestDir::new(name);
+            fs::write(dir.join("password.hash"), contents).unwrap();
+
+            let mut state = AuthState::with_config_dir(&dir);
+            assert!(!state.is_first_run);
+            assert!(state.needs_recovery());
+            assert!(!state.has_orphaned_private_key());
+            assert!(state.verify_password("anything", &i18n).is_err());
+            assert!(!state.is_authenticated);
+        }
+
+        // 空文件仍视为首次运行
+        let dir = TestDir::new("empty_password_file");
+        fs::write(dir.join("password.hash"), "  ").unwrap();
+        let state = AuthState::with_config_dir(&dir);
+        assert!(state.is_first_run);
+        assert!(!state.needs_recovery());
+    }
+
+    #[test]
+    fn setup_is_enabled_only_for_matching_strong_passwords() {
+        let policy = PasswordPolicy::default();
+        let check = |password, confirm| PasswordSetupValidation::check(password, confirm, &policy);
+
+        let empty = check("", "");
+        assert_eq!(empty.confirm_matches, None);
+        assert!(!empty.can_submit());
+
+        // 还未输入确认密码
+        assert_eq!(check("Str0ng!pass", "").confirm_matches, None);
+        assert!(!check("Str0ng!pass", "").can_submit());
+
+        let mismatch = check("Str0ng!pass", "Str0ng!pas");
+        assert_eq!(mismatch.confirm_matches, Some(false));
+        assert!(!mismatch.can_submit());
+
+        let weak = check("weakpass", "weakpass");
+        assert_eq!(weak.confirm_matches, Some(true));
+        assert!(weak.strength_issue.is_some());
+        assert!(!weak.can_submit());
+
+        assert!(check("Str0ng!pass", "Str0ng!pass").can_submit());
+        // 与提交时一致，忽略两端空白
+        assert!(check(" Str0ng!pass", "Str0ng!pass ").can_submit());
+    }
+
+    #[test]
+    fn common_passwords_are_rejected_when_enabled() {
+        assert!(AuthManager::is_common_password("password123"));
+        assert!(AuthManager::is_common_password("P@ssw0rd"));
+        assert!(!AuthManager::is_common_password("Str0ng!pass-Horse"));
+        // 满足字符类要求的常见变体同样被拒绝
+        assert!(AuthManager::is_common_password("Sunshine123!"));
+        assert!(AuthManager::is_common_password("M0nk3y!"));
+        assert!(AuthManager::is_common_password("Dr@g0n2024!"));
+        assert!(AuthManager::is_common_password("h3ll0123"));
+        assert!(AuthManager::is_common_password("Footba11#"));
+        assert!(!AuthManager::is_common_password("Sunshine-Tractor-42"));
+        assert!(!AuthManager::is_common_password(""));
+
+        let dir = TestDir::new("common_password");
+        let i18n = crate::i18n::I18nManager::new();
+        let mut state = AuthState::with_config_dir(&dir);
+        state.password_input = "P@ssw0rd".to_string();
+        state.password_confirm = "P@ssw0rd".to_string();
+        assert_eq!(state.set_password(&i18n), Err(i18n.tr("password_too_common")));
+        assert!(!state.password_file.exists());
+
+        state.password_input = "Str0ng!pass-Horse".to_string();
+        state.password_confirm = "Str0ng!pass-Horse".to_string();
+        assert!(state.set_password(&i18n).is_ok());
+
+        // 关闭检查后允许常见密码
+        let allow_common = PasswordPolicy { reject_common: false, ..PasswordPolicy::default() };
+        assert!(PasswordSetupValidation::check("P@ssw0rd", "P@ssw0rd", &allow_common).can_submit());
+        assert!(!PasswordSetupValidation::check("P@ssw0rd", "P@ssw0rd", &PasswordPolicy::default()).can_submit());
+    }
+
+    #[test]
+    fn stricter_policy_rejects_borderline_passwords() {
+        let default = PasswordPolicy::default();
+        let strict = PasswordPolicy { min_length: 12, ..PasswordPolicy::default() };
+        // 恰好 8 个字符且包含所有字符类别
+        assert_eq!(default.check("Ab1!cdef"), None);
+        assert_eq!(strict.check("Ab1!cdef"), Some(PasswordIssue::TooShort(12)));
+        assert_eq!(strict.check("Ab1!cdefghij"), None);
+
+        let relaxed = PasswordPolicy { require_special: false, ..PasswordPolicy::default() };
+        assert_eq!(default.check("Tulipvane7"), Some(PasswordIssue::MissingSpecial));
+        assert_eq!(relaxed.check("Tulipvane7"), None);
+
+        let dir = TestDir::new("password_policy");
+        let i18n = crate::i18n::I18nManager::new();
+        let mut state = AuthState::with_config_dir(&dir);
+        state.apply_config(&AuthConfig { password_policy: strict, ..AuthConfig::default() });
+        state.password_input = "Ab1!cdef".to_string();
+        state.password_confirm = "Ab1!cdef".to_string();
+        let expected = i18n.tr("password_too_short").replace("{}", "12");
+        assert_eq!(state.set_password(&i18n), Err(expected));
+        assert!(!state.password_file.exists());
+    }
+}
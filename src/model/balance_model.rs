@@ -0,0 +1,485 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+use sui_sdk::types::base_types::SuiAddress;
+use super::{Network, MIST_PER_SUI, SUI_COIN_TYPE};
+
+/// MIST 的小数位数（1 SUI = 10^9 MIST）
+const MIST_DECIMALS: usize = 9;
+/// 格式化时至少保留的小数位数
+const MIN_DISPLAY_DECIMALS: usize = 2;
+
+/// 将 MIST 金额格式化为 SUI 十进制字符串，只使用整数运算
+/// 最多保留 `decimals` 位小数（多余位数截断，不进位），末尾的 0 去掉但至少保留 2 位，
+/// 例如 `1.50`、`0.000000001`、`123456789.123456789`
+pub fn format_mist(mist: u64, decimals: u32) -> String {
+    let decimals = (decimals as usize).min(MIST_DECIMALS);
+    let whole = mist / MIST_PER_SUI;
+    let fraction = format!("{:0width$}", mist % MIST_PER_SUI, width = MIST_DECIMALS);
+    let fraction = fraction[..decimals].trim_end_matches('0');
+    let min_decimals = MIN_DISPLAY_DECIMALS.min(decimals);
+    format!("{}.{:0<width$}", whole, fraction, width = min_decimals)
+        .trim_end_matches('.')
+        .to_string()
+}
+
+/// SUI 余额，内部以 MIST 整数保存
+/// 所有换算和格式化都使用整数运算，不经过 f64，避免大额余额的舍入误差
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Balance {
+    mist: u64,
+}
+
+impl Balance {
+    /// 从 MIST 创建余额
+    pub const fn from_mist(mist: u64) -> Self {
+        Self { mist }
+    }
+
+    /// 从 RPC 返回的 u128 总余额创建，超出 u64 范围时取最大值
+    pub fn from_total_balance(total_balance: u128) -> Self {
+        Self::from_mist(u64::try_from(total_balance).unwrap_or(u64::MAX))
+    }
+
+    /// 余额（MIST）
+    pub const fn mist(&self) -> u64 {
+        self.mist
+    }
+
+    /// 是否为零
+    pub const fn is_zero(&self) -> bool {
+        self.mist == 0
+    }
+
+    /// 预留 Gas 后可以转出的最大金额，余额不足以支付 Gas 时为零
+    pub const fn spendable_after_gas(&self, gas_mist: u64) -> Self {
+        Self::from_mist(self.mist.saturating_sub(gas_mist))
+    }
+
+    /// 整数部分（SUI）
+    pub const fn whole_sui(&self) -> u64 {
+        self.mist / MIST_PER_SUI
+    }
+
+    /// 小数部分（MIST）
+    pub const fn fraction_mist(&self) -> u64 {
+        self.mist % MIST_PER_SUI
+    }
+
+    /// 精确的 SUI 十进制字符串，去掉末尾多余的 0，例如 `1.5`、`2`
+    pub fn to_sui_string(self) -> String {
+        let fraction = format!("{:0width$}", self.fraction_mist(), width = MIST_DECIMALS);
+        let fraction = fraction.trim_end_matches('0');
+        if fraction.is_empty() {
+            self.whole_sui().to_string()
+        } else {
+            format!("{}.{}", self.whole_sui(), fraction)
+        }
+    }
+}
+
+/// 地址持有代币的情况，用于区分"地址为空"和"没有 SUI 但有其他代币"
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Holdings {
+    /// 地址没有任何代币
+    Empty,
+    /// 没有 SUI，但持有其他代币
+    NoSui { other_coins: usize },
+    /// 持有 SUI（可能还有其他代币）
+    HasSui { other_coins: usize },
+}
+
+/// 一次余额查询的结果：SUI 余额及持有代币的概况
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BalanceSnapshot {
+    pub sui: Balance,
+    pub holdings: Holdings,
+}
+
+/// 发回 UI 线程的余额查询结果：`(请求代次, 余额概况及提供结果的端点)`
+pub type BalanceUpdate = (u64, Result<(BalanceSnapshot, String), String>);
+
+impl BalanceSnapshot {
+    /// 从 RPC 返回的 `(代币类型, 总余额)` 列表汇总，余额为 0 的代币不计入
+    pub fn from_coin_balances<'a>(balances: impl IntoIterator<Item = (&'a str, u128)>) -> Self {
+        let mut sui_mist: Option<u128> = None;
+        let mut other_coins = 0;
+        for (coin_type, total_balance) in balances {
+            if total_balance == 0 {
+                continue;
+            }
+            if coin_type == SUI_COIN_TYPE {
+                sui_mist = Some(sui_mist.unwrap_or(0).saturating_add(total_balance));
+            } else {
+                other_coins += 1;
+            }
+        }
+
+        let holdings = match (sui_mist, other_coins) {
+            (Some(_), other_coins) => Holdings::HasSui { other_coins },
+            (None, 0) => Holdings::Empty,
+            (None, other_coins) => Holdings::NoSui { other_coins },
+        };
+        Self {
+            sui: Balance::from_total_balance(sui_mist.unwrap_or(0)),
+            holdings,
+        }
+    }
+}
+
+/// 余额超过该时长未刷新即视为过期
+pub const BALANCE_STALE_AFTER: Duration = Duration::from_secs(120);
+
+/// 转账前快速检查使用的余额状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BalanceState {
+    /// 尚未查询到余额
+    Unknown,
+    /// 余额可能已过期（正在刷新，或超过 `BALANCE_STALE_AFTER` 未刷新）
+    Stale(Balance),
+    /// 最近查询到的余额
+    Known(Balance),
+}
+
+impl BalanceState {
+    /// 根据最近一次查询的结果、查询时间和是否正在刷新确定余额状态
+    pub fn from_last_fetch(balance: Option<Balance>, fetched_at: Option<Instant>, refreshing: bool, now: Instant) -> Self {
+        match (balance, fetched_at) {
+            (Some(balance), Some(at)) if !refreshing && now.saturating_duration_since(at) < BALANCE_STALE_AFTER => {
+                BalanceState::Known(balance)
+            }
+            (Some(balance), _) => BalanceState::Stale(balance),
+            (None, _) => BalanceState::Unknown,
+        }
+    }
+
+    /// 可信的余额（MIST），过期或未知时返回 None
+    pub fn known_mist(&self) -> Option<u64> {
+        match self {
+            BalanceState::Known(balance) => Some(balance.mist()),
+            BalanceState::Stale(_) | BalanceState::Unknown => None,
+        }
+    }
+}
+
+/// 余额变化高亮显示的时长
+pub const BALANCE_CHANGE_HIGHLIGHT: Duration = Duration::from_secs(4);
+
+/// 刷新后余额的变化，短暂高亮显示（如转账或领取测试币之后）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BalanceChange {
+    /// 带符号的变化量（MIST），正数为增加
+    pub delta_mist: i128,
+    /// 收到新余额的时间
+    pub at: Instant,
+}
+
+impl BalanceChange {
+    /// 两次余额之间带符号的变化量（MIST）
+    pub fn signed_delta(previous: Balance, current: Balance) -> i128 {
+        i128::from(current.mist()) - i128::from(previous.mist())
+    }
+
+    /// 余额有变化时返回变化记录
+    pub fn between(previous: Balance, current: Balance, at: Instant) -> Option<Self> {
+        let delta_mist = Self::signed_delta(previous, current);
+        (delta_mist != 0).then_some(Self { delta_mist, at })
+    }
+
+    /// 是否增加
+    pub fn is_increase(&self) -> bool {
+        self.delta_mist > 0
+    }
+
+    /// 是否仍在高亮显示时间内
+    pub fn is_visible(&self, now: Instant) -> bool {
+        now.saturating_duration_since(self.at) < BALANCE_CHANGE_HIGHLIGHT
+    }
+
+    /// 变化量的绝对值
+    pub fn magnitude(&self) -> Balance {
+        Balance::from_mist(u64::try_from(self.delta_mist.unsigned_abs()).unwrap_or(u64::MAX))
+    }
+
+    /// 显示用的符号（减号使用 U+2212）
+    pub fn sign(&self) -> char {
+        if self.is_increase() { '+' } else { '−' }
+    }
+
+    /// 带符号的精确 SUI 数值，例如 `+5`、`−0.25`
+    pub fn signed_sui_string(&self) -> String {
+        format!("{}{}", self.sign(), self.magnitude().to_sui_string())
+    }
+}
+
+/// 进行中的余额查询
+/// 每次发起查询时递增 `generation`，结果带回同一编号，UI 只接受最新一次查询的结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct BalanceRequest {
+    pub generation: u64,
+    pub address: SuiAddress,
+    pub network_url: String,
+}
+
+impl BalanceRequest {
+    /// 是否是对同一地址、同一网络的查询
+    pub fn is_same_query(&self, address: SuiAddress, network_url: &str) -> bool {
+        self.address == address && self.network_url == network_url
+    }
+}
+
+/// 同一地址在各网络上的余额
+/// Sui 地址与网络无关，用户常把在测试网使用过的私钥导入后期待主网资金，这里汇总各网络的查询结果
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NetworkBalances {
+    address: Option<SuiAddress>,
+    /// 每个网络的查询结果，None 表示仍在查询
+    entries: Vec<(Network, Option<Result<Balance, String>>)>,
+}
+
+impl NetworkBalances {
+    /// 开始查询指定地址在各网络上的余额
+    pub fn start(address: SuiAddress, networks: Vec<Network>) -> Self {
+        Self {
+            address: Some(address),
+            entries: networks.into_iter().map(|network| (network, None)).collect(),
+        }
+    }
+
+    /// 记录某个网络的查询结果，地址或网络不匹配时忽略并返回 false
+    pub fn record(&mut self, address: SuiAddress, network: &Network, result: Result<Balance, String>) -> bool {
+        if self.address != Some(address) {
+            return false;
+        }
+        match self.entries.iter_mut().find(|(entry_network, _)| entry_network == network) {
+            Some((_, entry)) => {
+                *entry = Some(result);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// 各网络的查询结果
+    pub fn entries(&self) -> &[(Network, Option<Result<Balance, String>>)] {
+        &self.entries
+    }
+
+    /// 是否已开始查询
+    pub fn is_started(&self) -> bool {
+        !self.entries.is_empty()
+    }
+
+    /// 是否仍有网络在查询中
+    pub fn is_checking(&self) -> bool {
+        self.entries.iter().any(|(_, result)| result.is_none())
+    }
+
+    /// 有余额的网络
+    pub fn funded_networks(&self) -> Vec<&Network> {
+        self.entries
+            .iter()
+            .filter(|(_, result)| matches!(result, Some(Ok(balance)) if !balance.is_zero()))
+            .map(|(network, _)| network)
+            .collect()
+    }
+
+    /// 汇总各网络的查询状态，部分网络失败时其余结果仍然有效
+    pub fn summary(&self) -> NetworkBalanceSummary {
+        let mut summary = NetworkBalanceSummary::default();
+        for (_, result) in &self.entries {
+            match result {
+                None => summary.pending += 1,
+                Some(Ok(_)) => summary.loaded += 1,
+                Some(Err(_)) => summary.failed += 1,
+            }
+        }
+        summary.funded = self.funded_networks().into_iter().cloned().collect();
+        summary
+    }
+}
+
+/// 多网络余额查询的汇总
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NetworkBalanceSummary {
+    /// 查询成功的网络数量
+    pub loaded: usize,
+    /// 查询失败的网络数量
+    pub failed: usize,
+    /// 仍在查询的网络数量
+    pub pending: usize,
+    /// 有余额的网络
+    pub funded: Vec<Network>,
+}
+
+impl NetworkBalanceSummary {
+    /// 是否有网络查询失败
+    pub fn is_partial(&self) -> bool {
+        self.failed > 0
+    }
+}
+
+impl fmt::Display for Balance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} SUI", self.to_sui_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::WalletUtils;
+
+    #[test]
+    fn balance_state_is_known_only_when_fresh() {
+        let now = Instant::now();
+        let balance = Some(Balance::from_mist(5));
+        assert_eq!(BalanceState::from_last_fetch(balance, Some(now), false, now), BalanceState::Known(Balance::from_mist(5)));
+        assert_eq!(
+            BalanceState::from_last_fetch(balance, Some(now - BALANCE_STALE_AFTER), false, now),
+            BalanceState::Stale(Balance::from_mist(5))
+        );
+        assert_eq!(BalanceState::from_last_fetch(balance, Some(now), true, now).known_mist(), None);
+        assert_eq!(BalanceState::from_last_fetch(None, Some(now), false, now), BalanceState::Unknown);
+    }
+
+    #[test]
+    fn formats_large_balance_exactly() {
+        let balance = Balance::from_mist(123_456_789_123_456_789);
+        assert_eq!(balance.to_sui_string(), "123456789.123456789");
+        assert_eq!(format_mist(balance.mist(), 4), "123456789.1234");
+        assert_eq!(balance.to_string(), "123456789.123456789 SUI");
+        // f64 无法精确表示该值
+        assert_ne!(format!("{:.9}", 123_456_789_123_456_789u64 as f64 / 1e9), "123456789.123456789");
+    }
+
+    #[test]
+    fn formats_small_and_whole_balances() {
+        assert_eq!(Balance::from_mist(0).to_sui_string(), "0");
+        assert_eq!(Balance::from_mist(1).to_sui_string(), "0.000000001");
+        assert_eq!(Balance::from_mist(1_500_000_000).to_sui_string(), "1.5");
+    }
+
+    #[test]
+    fn format_mist_handles_zero() {
+        assert_eq!(format_mist(0, 9), "0.00");
+        assert_eq!(format_mist(0, 0), "0");
+    }
+
+    #[test]
+    fn format_mist_keeps_dust_exact() {
+        assert_eq!(format_mist(1, 9), "0.000000001");
+        assert_eq!(format_mist(1_000, 9), "0.000001");
+        // 超出显示精度的部分截断，不会四舍五入成更大的金额
+        assert_eq!(format_mist(1, 4), "0.00");
+        assert_eq!(format_mist(1_999_999_999, 4), "1.9999");
+    }
+
+    #[test]
+    fn format_mist_pads_whole_numbers_to_two_places() {
+        assert_eq!(format_mist(2 * MIST_PER_SUI, 9), "2.00");
+        assert_eq!(format_mist(1_500_000_000, 4), "1.50");
+        assert_eq!(format_mist(1_250_000_000, 9), "1.25");
+        assert_eq!(format_mist(1_500_000_000, 1), "1.5");
+        assert_eq!(format_mist(7 * MIST_PER_SUI, 0), "7");
+    }
+
+    #[test]
+    fn format_mist_is_exact_for_very_large_values() {
+        assert_eq!(format_mist(123_456_789_123_456_789, 9), "123456789.123456789");
+        assert_eq!(format_mist(u64::MAX, 9), "18446744073.709551615");
+        assert_eq!(format_mist(u64::MAX, 2), "18446744073.70");
+    }
+
+    #[test]
+    fn sui_strings_parse_back_to_the_same_balance() {
+        let balance = Balance::from_mist(123_456_789_123_456_789);
+        assert_eq!(WalletUtils::parse_sui_amount(&balance.to_sui_string()).map(Balance::from_mist), Ok(balance));
+    }
+
+    #[test]
+    fn saturates_out_of_range_total_balance() {
+        assert_eq!(Balance::from_total_balance(u128::MAX).mist(), u64::MAX);
+        assert_eq!(Balance::from_total_balance(42).mist(), 42);
+    }
+
+    #[test]
+    fn aggregates_per_network_balances_for_one_address() {
+        let address = SuiAddress::from_bytes([1; 32]).unwrap();
+        let other = SuiAddress::from_bytes([2; 32]).unwrap();
+        let mut balances = NetworkBalances::start(address, Network::all());
+        assert!(balances.is_checking());
+
+        assert!(balances.record(address, &Network::Testnet, Ok(Balance::from_mist(5 * MIST_PER_SUI))));
+        assert!(balances.record(address, &Network::Mainnet, Ok(Balance::from_mist(0))));
+        // 其他地址的结果（如切换钱包前发起的查询）被忽略
+        assert!(!balances.record(other, &Network::Devnet, Ok(Balance::from_mist(1))));
+        assert!(balances.is_checking());
+
+        assert!(balances.record(address, &Network::Devnet, Err("timeout".to_string())));
+        assert!(!balances.is_checking());
+        assert_eq!(balances.funded_networks(), vec![&Network::Testnet]);
+        assert_eq!(balances.entries()[2], (Network::Mainnet, Some(Ok(Balance::from_mist(0)))));
+    }
+
+    #[test]
+    fn summarizes_partial_failures_across_networks() {
+        let address = SuiAddress::from_bytes([3; 32]).unwrap();
+        let mut balances = NetworkBalances::start(address, Network::all());
+        assert_eq!(balances.summary().pending, 3);
+
+        balances.record(address, &Network::Devnet, Ok(Balance::from_mist(MIST_PER_SUI)));
+        balances.record(address, &Network::Testnet, Err("connection refused".to_string()));
+        balances.record(address, &Network::Mainnet, Ok(Balance::from_mist(250_000_000)));
+
+        let summary = balances.summary();
+        assert_eq!(
+            summary,
+            NetworkBalanceSummary {
+                loaded: 2,
+                failed: 1,
+                pending: 0,
+                funded: vec![Network::Devnet, Network::Mainnet],
+            }
+        );
+        assert!(summary.is_partial());
+    }
+
+    const USDC: &str = "0xdba3::usdc::USDC";
+    const CETUS: &str = "0xc0ff::cetus::CETUS";
+
+    #[test]
+    fn distinguishes_empty_address_from_no_sui_with_other_coins() {
+        let no_sui = BalanceSnapshot::from_coin_balances([(USDC, 5_000_000), (CETUS, 1), (SUI_COIN_TYPE, 0)]);
+        assert_eq!(no_sui.sui, Balance::from_mist(0));
+        assert_eq!(no_sui.holdings, Holdings::NoSui { other_coins: 2 });
+
+        let empty = BalanceSnapshot::from_coin_balances(Vec::<(&str, u128)>::new());
+        assert_eq!(empty.sui, Balance::from_mist(0));
+        assert_eq!(empty.holdings, Holdings::Empty);
+        assert_ne!(no_sui, empty);
+
+        let funded = BalanceSnapshot::from_coin_balances([(SUI_COIN_TYPE, 42), (USDC, 1)]);
+        assert_eq!(funded.sui, Balance::from_mist(42));
+        assert_eq!(funded.holdings, Holdings::HasSui { other_coins: 1 });
+    }
+
+    #[test]
+    fn computes_signed_delta_between_snapshots() {
+        let before = BalanceSnapshot::from_coin_balances([(SUI_COIN_TYPE, 3 * MIST_PER_SUI as u128)]);
+        let after_faucet = BalanceSnapshot::from_coin_balances([(SUI_COIN_TYPE, 8 * MIST_PER_SUI as u128)]);
+        let after_transfer = BalanceSnapshot::from_coin_balances([(SUI_COIN_TYPE, 6 * MIST_PER_SUI as u128 - 1)]);
+
+        assert_eq!(BalanceChange::signed_delta(before.sui, after_faucet.sui), 5 * MIST_PER_SUI as i128);
+        assert_eq!(BalanceChange::signed_delta(after_faucet.sui, after_transfer.sui), -(2 * MIST_PER_SUI as i128) - 1);
+        assert_eq!(BalanceChange::signed_delta(Balance::from_mist(u64::MAX), Balance::from_mist(0)), -(u64::MAX as i128));
+
+        let now = Instant::now();
+        let gained = BalanceChange::between(before.sui, after_faucet.sui, now).unwrap();
+        assert!(gained.is_increase());
+        assert_eq!(gained.signed_sui_string(), "+5");
+        let spent = BalanceChange::between(after_faucet.sui, after_transfer.sui, now).unwrap();
+        assert_eq!(spent.signed_sui_string(), "−2.000000001");
+        assert!(spent.is_visible(now) && !spent.is_visible(now + BALANCE_CHANGE_HIGHLIGHT));
+        assert_eq!(BalanceChange::between(before.sui, before.sui, now), None);
+    }
+}
@@ -0,0 +1,254 @@
+use std::str::FromStr;
+use sui_sdk::types::base_types::SuiAddress;
+use super::{Balance, WalletUtils};
+use crate::i18n::I18nManager;
+
+/// 每笔转账的预估 Gas 费用（MIST），与余额面板显示的 ~0.001 SUI 一致
+pub const ESTIMATED_GAS_PER_TRANSFER_MIST: u64 = 1_000_000;
+
+/// 批量转账行的错误类型
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BatchRowError {
+    /// 缺少金额列
+    MissingAmount,
+    /// 地址无效
+    InvalidAddress,
+    /// 金额无效或不大于 0
+    InvalidAmount,
+}
+
+impl BatchRowError {
+    /// 获取错误的翻译键
+    pub fn i18n_key(&self) -> &'static str {
+        match self {
+            BatchRowError::MissingAmount => "batch_missing_amount",
+            BatchRowError::InvalidAddress => "invalid_recipient",
+            BatchRowError::InvalidAmount => "invalid_amount",
+        }
+    }
+}
+
+/// 批量转账中的一行
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchTransferRow {
+    /// 输入中的行号（从 1 开始）
+    pub line: usize,
+    pub recipient: SuiAddress,
+    /// 转账金额（MIST）
+    pub amount_mist: u64,
+}
+
+/// 解析失败的行
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchRowIssue {
+    /// 输入中的行号（从 1 开始）
+    pub line: usize,
+    /// 原始内容
+    pub content: String,
+    pub error: BatchRowError,
+}
+
+/// 批量转账（空投）列表
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BatchTransfer {
+    pub rows: Vec<BatchTransferRow>,
+    pub issues: Vec<BatchRowIssue>,
+}
+
+impl BatchTransfer {
+    /// 解析粘贴的 CSV：每行 `地址,金额(SUI)`，也接受分号或制表符分隔
+    /// 空行、`#` 开头的注释行和 `address,amount` 表头会被跳过
+    pub fn parse_csv(input: &str) -> Self {
+        let mut batch = Self::default();
+
+        for (index, raw) in input.lines().enumerate() {
+            let line = index + 1;
+            let content = raw.trim();
+            if content.is_empty() || content.starts_with('#') || Self::is_header(content) {
+                continue;
+            }
+
+            match Self::parse_row(content) {
+                Ok((recipient, amount_mist)) => batch.rows.push(BatchTransferRow {
+                    line,
+                    recipient,
+                    amount_mist,
+                }),
+                Err(error) => batch.issues.push(BatchRowIssue {
+                    line,
+                    content: content.to_string(),
+                    error,
+                }),
+            }
+        }
+
+        batch
+    }
+
+    /// 解析单行
+    fn parse_row(content: &str) -> Result<(SuiAddress, u64), BatchRowError> {
+        let mut fields = content.split([',', ';', '\t']);
+        let address = WalletUtils::normalize_input(fields.next().unwrap_or_default());
+        let amount = fields
+            .next()
+            .map(WalletUtils::normalize_input)
+            .filter(|a| !a.is_empty())
+            .ok_or(BatchRowError::MissingAmount)?;
+
+        if !WalletUtils::is_valid_address_format(&address) {
+            return Err(BatchRowError::InvalidAddress);
+        }
+        let recipient = SuiAddress::from_str(&address).map_err(|_| BatchRowError::InvalidAddress)?;
+
+        let amount_mist = WalletUtils::parse_sui_amount(&amount)
+            .ok()
+            .filter(|mist| *mist > 0)
+            .ok_or(BatchRowError::InvalidAmount)?;
+
+        Ok((recipient, amount_mist))
+    }
+
+    /// 检查是否为表头行
+    fn is_header(content: &str) -> bool {
+        content
+            .split([',', ';', '\t'])
+            .next()
+            .is_some_and(|first| first.trim().eq_ignore_ascii_case("address"))
+    }
+
+    /// 所有行都有效、至少有一行，且总花费没有溢出
+    pub fn is_valid(&self) -> bool {
+        !self.rows.is_empty() && self.issues.is_empty() && self.estimated_total_mist(self.estimated_gas_mist()).is_some()
+    }
+
+    /// 转账总金额（MIST），超出 u64 范围时为 None
+    pub fn total_amount_mist(&self) -> Option<u64> {
+        self.rows
+            .iter()
+            .try_fold(0u64, |total, row| total.checked_add(row.amount_mist))
+    }
+
+    /// 预估 Gas 预算（MIST）：所有行在同一笔交易中执行，按收款人数（新建的 Coin 对象数）估算
+    /// 界面显示和提交时都应通过 `Model::batch_gas_budget` 使用同一个数值
+    pub fn estimated_gas_mist(&self) -> u64 {
+        (self.rows.len() as u64).saturating_mul(ESTIMATED_GAS_PER_TRANSFER_MIST)
+    }
+
+    /// 预估总花费（金额 + Gas 预算），超出 u64 范围时为 None
+    pub fn estimated_total_mist(&self, gas_budget_mist: u64) -> Option<u64> {
+        self.total_amount_mist()?.checked_add(gas_budget_mist)
+    }
+
+    /// 签名前确认用的完整摘要：逐行列出完整的收款地址和金额，以及收款人数、总金额、Gas 预算和总花费
+    /// 总金额或总花费溢出时返回错误，此时不能继续签名
+    pub fn confirmation_summary(&self, gas_budget_mist: u64, i18n: &I18nManager) -> Result<String, String> {
+        let overflow = || i18n.tr("batch_total_overflow");
+        let total = self.total_amount_mist().ok_or_else(overflow)?;
+        let estimated_total = self.estimated_total_mist(gas_budget_mist).ok_or_else(overflow)?;
+
+        let mut summary = String::new();
+        for (index, row) in self.rows.iter().enumerate() {
+            summary.push_str(&format!("{}. {} → {}\n", index + 1, row.recipient, Balance::from_mist(row.amount_mist)));
+        }
+
+        summary.push_str(&format!("\n{}: {}\n", i18n.tr("batch_recipients"), self.rows.len()));
+        summary.push_str(&format!("{}: {}\n", i18n.tr("batch_total_amount"), Balance::from_mist(total)));
+        summary.push_str(&format!("{}: ~{}\n", i18n.tr("network_fee"), Balance::from_mist(gas_budget_mist)));
+        summary.push_str(&format!(
+            "{}: {}",
+            i18n.tr("batch_estimated_total"),
+            Balance::from_mist(estimated_total)
+        ));
+        Ok(summary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADDR_A: &str = "0x0000000000000000000000000000000000000000000000000000000000000a11";
+    const ADDR_B: &str = "0x0000000000000000000000000000000000000000000000000000000000000b22";
+
+    #[test]
+    fn parses_valid_rows_and_skips_header_and_comments() {
+        let input = format!("address,amount\n# airdrop\n{},1.5\n\n{}; 0.25\n", ADDR_A, ADDR_B);
+        let batch = BatchTransfer::parse_csv(&input);
+
+        assert!(batch.is_valid());
+        assert_eq!(batch.rows.len(), 2);
+        assert_eq!(batch.rows[0].line, 3);
+        assert_eq!(batch.rows[0].recipient, SuiAddress::from_str(ADDR_A).unwrap());
+        assert_eq!(batch.rows[0].amount_mist, 1_500_000_000);
+        assert_eq!(batch.rows[1].amount_mist, 250_000_000);
+        assert_eq!(batch.total_amount_mist(), Some(1_750_000_000));
+        assert_eq!(batch.estimated_gas_mist(), 2 * ESTIMATED_GAS_PER_TRANSFER_MIST);
+        assert_eq!(batch.estimated_total_mist(batch.estimated_gas_mist()), Some(1_750_000_000 + 2 * ESTIMATED_GAS_PER_TRANSFER_MIST));
+    }
+
+    #[test]
+    fn reports_invalid_rows_with_line_numbers() {
+        let input = format!("{}\nnot-an-address,1\n{},abc\n{},0\n{},2", ADDR_A, ADDR_B, ADDR_B, ADDR_A);
+        let batch = BatchTransfer::parse_csv(&input);
+
+        assert!(!batch.is_valid());
+        assert_eq!(batch.rows.len(), 1);
+        assert_eq!(batch.rows[0].line, 5);
+        let errors: Vec<(usize, BatchRowError)> = batch.issues.iter().map(|i| (i.line, i.error)).collect();
+        assert_eq!(
+            errors,
+            vec![
+                (1, BatchRowError::MissingAmount),
+                (2, BatchRowError::InvalidAddress),
+                (3, BatchRowError::InvalidAmount),
+                (4, BatchRowError::InvalidAmount),
+            ]
+        );
+    }
+
+    #[test]
+    fn confirmation_summary_lists_every_row_and_totals() {
+        let input = format!("{},1.5\n{},0.25\n{},0.000000001", ADDR_A, ADDR_B, ADDR_A);
+        let batch = BatchTransfer::parse_csv(&input);
+        let mut i18n = I18nManager::new();
+        i18n.set_language(crate::i18n::Language::English);
+
+        let summary = batch.confirmation_summary(batch.estimated_gas_mist(), &i18n).unwrap();
+        let expected = format!(
+            "1. {} → 1.5 SUI\n2. {} → 0.25 SUI\n3. {} → 0.000000001 SUI\n\n\
+             Recipients: 3\n\
+             Total Amount: 1.750000001 SUI\n\
+             Network Fee: ~0.003 SUI\n\
+             Estimated Total: 1.753000001 SUI",
+            ADDR_A, ADDR_B, ADDR_A
+        );
+        assert_eq!(summary, expected);
+
+        // 手动 Gas 预算计入总花费
+        assert!(batch.confirmation_summary(10_000_000, &i18n).unwrap().ends_with("Estimated Total: 1.760000001 SUI"));
+    }
+
+    #[test]
+    fn overflowing_total_rejects_the_batch() {
+        let half = u64::MAX / 2 + 1;
+        let row = |line| BatchTransferRow {
+            line,
+            recipient: SuiAddress::from_str(ADDR_A).unwrap(),
+            amount_mist: half,
+        };
+        let batch = BatchTransfer { rows: vec![row(1), row(2)], issues: Vec::new() };
+        let mut i18n = I18nManager::new();
+        i18n.set_language(crate::i18n::Language::English);
+
+        assert_eq!(batch.total_amount_mist(), None);
+        assert_eq!(batch.estimated_total_mist(0), None);
+        assert!(!batch.is_valid());
+        assert_eq!(batch.confirmation_summary(0, &i18n), Err(i18n.tr("batch_total_overflow")));
+
+        // 金额本身不溢出，但加上 Gas 后溢出
+        let batch = BatchTransfer { rows: vec![BatchTransferRow { amount_mist: u64::MAX, ..row(1) }], issues: Vec::new() };
+        assert_eq!(batch.total_amount_mist(), Some(u64::MAX));
+        assert_eq!(batch.estimated_total_mist(batch.estimated_gas_mist()), None);
+        assert!(!batch.is_valid());
+    }
+}
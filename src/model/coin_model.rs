@@ -0,0 +1,126 @@
+use serde::{Deserialize, Serialize};
+
+/// SUI 代币类型，始终显示
+pub const SUI_COIN_TYPE: &str = "0x2::sui::SUI";
+
+/// 默认隐藏的代币类型片段（常见的空投诈骗代币会在模块名或代币名中诱导用户访问网站或"领取"奖励）
+const DEFAULT_SPAM_PATTERNS: &[&str] = &["http", "www.", ".com", ".io", ".xyz", "claim", "airdrop", "reward", "voucher"];
+
+/// 代币过滤方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum CoinFilterMode {
+    /// 隐藏与黑名单匹配的代币，其他全部显示
+    #[default]
+    Denylist,
+    /// 只显示白名单中的代币
+    Allowlist,
+}
+
+/// 多代币余额的显示过滤设置
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CoinFilterSettings {
+    pub mode: CoinFilterMode,
+    /// 白名单：完整的代币类型（如 `0x...::usdc::USDC`），不区分大小写
+    pub allowlist: Vec<String>,
+    /// 黑名单：代币类型中包含的片段，不区分大小写
+    pub denylist: Vec<String>,
+}
+
+impl CoinFilterSettings {
+    /// 检查代币类型是否应显示，SUI 在任何模式下都显示
+    pub fn is_visible(&self, coin_type: &str) -> bool {
+        let coin_type = coin_type.trim().to_lowercase();
+        if coin_type == SUI_COIN_TYPE.to_lowercase() {
+            return true;
+        }
+
+        match self.mode {
+            CoinFilterMode::Allowlist => self
+                .allowlist
+                .iter()
+                .any(|allowed| allowed.trim().to_lowercase() == coin_type),
+            CoinFilterMode::Denylist => !self
+                .denylist
+                .iter()
+                .map(|pattern| pattern.trim().to_lowercase())
+                .any(|pattern| !pattern.is_empty() && coin_type.contains(&pattern)),
+        }
+    }
+
+    /// 解析设置中每行一个的代币类型或片段，去除空行和重复项（不区分大小写）
+    pub fn parse_list(input: &str) -> Vec<String> {
+        let mut list: Vec<String> = Vec::new();
+        for entry in input.lines().map(str::trim).filter(|entry| !entry.is_empty()) {
+            if !list.iter().any(|existing| existing.eq_ignore_ascii_case(entry)) {
+                list.push(entry.to_string());
+            }
+        }
+        list
+    }
+
+    /// 将列表转换为每行一个的文本，用于设置中编辑
+    pub fn list_text(list: &[String]) -> String {
+        list.join("\n")
+    }
+}
+
+impl Default for CoinFilterSettings {
+    fn default() -> Self {
+        Self {
+            mode: CoinFilterMode::Denylist,
+            allowlist: Vec::new(),
+            denylist: DEFAULT_SPAM_PATTERNS.iter().map(|p| p.to_string()).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const USDC: &str = "0xdba3::usdc::USDC";
+    const SPAM: &str = "0xbad1::claim_reward::VISIT_SUIDROP_XYZ";
+    const OTHER: &str = "0xc0ff::cetus::CETUS";
+
+    /// 过滤后仍显示的代币类型，保持原有顺序
+    fn visible(filter: &CoinFilterSettings) -> Vec<&'static str> {
+        [SUI_COIN_TYPE, USDC, SPAM, OTHER]
+            .into_iter()
+            .filter(|coin_type| filter.is_visible(coin_type))
+            .collect()
+    }
+
+    #[test]
+    fn default_denylist_hides_spam_patterns() {
+        let filter = CoinFilterSettings::default();
+        assert_eq!(visible(&filter), vec![SUI_COIN_TYPE, USDC, OTHER]);
+    }
+
+    #[test]
+    fn allowlist_shows_only_listed_coins_and_sui() {
+        let filter = CoinFilterSettings {
+            mode: CoinFilterMode::Allowlist,
+            allowlist: vec!["0xDBA3::usdc::usdc".to_string()],
+            ..CoinFilterSettings::default()
+        };
+        assert_eq!(visible(&filter), vec![SUI_COIN_TYPE, USDC]);
+    }
+
+    #[test]
+    fn custom_denylist_matches_case_insensitively_and_ignores_blank_patterns() {
+        let filter = CoinFilterSettings {
+            denylist: vec!["CETUS".to_string(), "  ".to_string()],
+            ..CoinFilterSettings::default()
+        };
+        assert_eq!(visible(&filter), vec![SUI_COIN_TYPE, USDC, SPAM]);
+    }
+
+    #[test]
+    fn edited_lists_round_trip_without_blanks_or_duplicates() {
+        let parsed = CoinFilterSettings::parse_list("  claim \n\nAIRDROP\n0xdba3::usdc::USDC\nClaim\n");
+        assert_eq!(parsed, vec!["claim", "AIRDROP", "0xdba3::usdc::USDC"]);
+        assert_eq!(CoinFilterSettings::parse_list(&CoinFilterSettings::list_text(&parsed)), parsed);
+        assert!(CoinFilterSettings::parse_list(" \n ").is_empty());
+    }
+}
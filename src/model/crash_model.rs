@@ -0,0 +1,214 @@
+use std::{fs, path::{Path, PathBuf}};
+use std::sync::atomic::{AtomicBool, Ordering};
+use super::{log_error, mask_secrets, write_file_atomic};
+
+/// 崩溃报告所在的子目录（位于配置目录）
+pub const CRASH_REPORTS_DIR: &str = "crash_reports";
+/// 记录尚未提示给用户的崩溃报告路径，下次启动时读取并删除
+const PENDING_CRASH_REPORT_FILE: &str = "last_crash_report";
+/// 崩溃报告中包含的最近日志行数
+pub const CRASH_REPORT_LOG_LINES: usize = 50;
+/// 最多保留的崩溃报告数量，写入新报告时删除更早的报告
+pub const MAX_CRASH_REPORTS: usize = 20;
+
+/// 是否在 panic 时写入崩溃报告，由设置控制；panic hook 中只能读取全局状态
+static CRASH_REPORTS_ENABLED: AtomicBool = AtomicBool::new(true);
+
+pub fn set_crash_reports_enabled(enabled: bool) {
+    CRASH_REPORTS_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+pub fn crash_reports_enabled() -> bool {
+    CRASH_REPORTS_ENABLED.load(Ordering::Relaxed)
+}
+
+/// 本地崩溃报告：只写入配置目录，不会发送到任何地方
+/// 内容经过与日志相同的脱敏处理，用户可以直接附加到问题反馈中
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrashReport {
+    /// 崩溃时间（Unix 秒）
+    pub timestamp: u64,
+    pub version: &'static str,
+    pub os: &'static str,
+    pub arch: &'static str,
+    /// 已脱敏的 panic 消息
+    pub message: String,
+    /// 已脱敏的最近日志行
+    pub log_tail: Vec<String>,
+}
+
+impl CrashReport {
+    /// 创建崩溃报告，消息和日志都会再次脱敏，只保留最后 `CRASH_REPORT_LOG_LINES` 行日志
+    pub fn new(timestamp: u64, message: &str, log_lines: &[String]) -> Self {
+        let start = log_lines.len().saturating_sub(CRASH_REPORT_LOG_LINES);
+        Self {
+            timestamp,
+            version: env!("CARGO_PKG_VERSION"),
+            os: std::env::consts::OS,
+            arch: std::env::consts::ARCH,
+            message: mask_secrets(message),
+            log_tail: log_lines[start..]
+                .iter()
+                .map(|line| mask_secrets(line))
+                .collect(),
+        }
+    }
+
+    /// 报告的文本内容
+    pub fn render(&self) -> String {
+        let mut text = format!(
+            "Sui Rust Wallet crash report\nversion: {}\nos: {}\narch: {}\ntimestamp: {}\npanic: {}\n\nrecent log ({} lines):\n",
+            self.version,
+            self.os,
+            self.arch,
+            self.timestamp,
+            self.message,
+            self.log_tail.len()
+        );
+        for line in &self.log_tail {
+            text.push_str(line);
+            text.push('\n');
+        }
+        text
+    }
+
+    /// 写入 `<dir>/crash_reports/crash-<时间>.txt` 并记录为待提示的报告，返回报告路径
+    /// 同一秒内的多次崩溃写入 `crash-<时间>-<序号>.txt`，不会覆盖之前的报告；
+    /// 写入后只保留最新的 `MAX_CRASH_REPORTS` 份报告
+    pub fn write_to(&self, dir: &Path) -> std::io::Result<PathBuf> {
+        let reports_dir = dir.join(CRASH_REPORTS_DIR);
+        fs::create_dir_all(&reports_dir)?;
+        let path = Self::unused_path(&reports_dir, self.timestamp);
+        write_file_atomic(&path, self.render().as_bytes())?;
+        write_file_atomic(&dir.join(PENDING_CRASH_REPORT_FILE), path.to_string_lossy().as_bytes())?;
+        Self::prune(&reports_dir, MAX_CRASH_REPORTS);
+        Ok(path)
+    }
+
+    /// 该时间的第一个尚不存在的报告路径
+    fn unused_path(reports_dir: &Path, timestamp: u64) -> PathBuf {
+        let first = reports_dir.join(format!("crash-{}.txt", timestamp));
+        if !first.exists() {
+            return first;
+        }
+        (1..)
+            .map(|n| reports_dir.join(format!("crash-{}-{}.txt", timestamp, n)))
+            .find(|path| !path.exists())
+            .expect("unbounded sequence")
+    }
+
+    /// 从文件名解析 (时间, 序号)，不是崩溃报告的文件返回 None
+    fn report_order(name: &str) -> Option<(u64, u64)> {
+        let stem = name.strip_prefix("crash-")?.strip_suffix(".txt")?;
+        match stem.split_once('-') {
+            Some((timestamp, n)) => Some((timestamp.parse().ok()?, n.parse().ok()?)),
+            None => Some((stem.parse().ok()?, 0)),
+        }
+    }
+
+    /// 删除最新 `keep` 份以外的崩溃报告，失败时只记录日志
+    fn prune(reports_dir: &Path, keep: usize) {
+        let Ok(entries) = fs::read_dir(reports_dir) else {
+            return;
+        };
+        let mut reports: Vec<((u64, u64), PathBuf)> = entries
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| {
+                let order = Self::report_order(&entry.file_name().to_string_lossy())?;
+                Some((order, entry.path()))
+            })
+            .collect();
+        reports.sort_by_key(|(order, _)| std::cmp::Reverse(*order));
+        for (_, path) in reports.into_iter().skip(keep) {
+            if let Err(e) = fs::remove_file(&path) {
+                log_error(&format!("Failed to remove old crash report {}: {}", path.display(), e));
+            }
+        }
+    }
+
+    /// 取出上次崩溃时写入、尚未提示给用户的报告路径（取出后不再提示），报告已被删除时为 None
+    pub fn take_pending(dir: &Path) -> Option<PathBuf> {
+        let pointer = dir.join(PENDING_CRASH_REPORT_FILE);
+        let path = fs::read_to_string(&pointer).ok()?;
+        let _ = fs::remove_file(&pointer);
+        let path = PathBuf::from(path.trim());
+        path.is_file().then_some(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::TestDir;
+
+    const PRIVATE_KEY: &str = "suiprivkey1qzdlfxn2qa2lj5uprl8pyhexs02sg2wrhdy7qaq50cqgnffw4c2477kg9h3";
+    const HEX_KEY: &str = "0x8f3c1e6a9b2d4f7085e1c3a5b7d9f0e2c4a6b8d0f1e3c5a7b9d1f3e5c7a9b0d2";
+    const BASE64_KEY: &str = "AKxw5pQ9Yc8oGv3JmL1dTzR7bWnE2sFhUiXkP4qV6yC0";
+
+    #[test]
+    fn report_contains_required_fields_and_no_secrets() {
+        let logs = vec![
+            "[1] INFO: Wallet loaded".to_string(),
+            format!("[2] ERROR: import failed for {}", PRIVATE_KEY),
+            format!("[3] WARN: raw key {} / {}", HEX_KEY, BASE64_KEY),
+        ];
+        let report = CrashReport::new(1_700_000_000, &format!("boom with {}", PRIVATE_KEY), &logs);
+        let text = report.render();
+
+        assert!(text.contains(&format!("version: {}", env!("CARGO_PKG_VERSION"))));
+        assert!(text.contains(&format!("os: {}", std::env::consts::OS)));
+        assert!(text.contains("timestamp: 1700000000"));
+        assert!(text.contains("panic: boom with"));
+        assert!(text.contains("Wallet loaded"));
+        assert_eq!(report.log_tail.len(), 3);
+
+        for secret in [PRIVATE_KEY, "suiprivkey", HEX_KEY, &HEX_KEY[2..], BASE64_KEY] {
+            assert!(!text.contains(secret), "crash report leaked {}", secret);
+        }
+    }
+
+    #[test]
+    fn written_report_is_pending_until_taken() {
+        let dir = TestDir::new("crash");
+        let report = CrashReport::new(42, "boom", &[]);
+        let path = report.write_to(&dir).unwrap();
+        assert!(fs::read_to_string(&path).unwrap().contains("panic: boom"));
+
+        assert_eq!(CrashReport::take_pending(&dir), Some(path));
+        assert_eq!(CrashReport::take_pending(&dir), None);
+    }
+
+    #[test]
+    fn crashes_in_the_same_second_keep_separate_reports() {
+        let dir = TestDir::new("crash_same_second");
+        let first = CrashReport::new(42, "first", &[]).write_to(&dir).unwrap();
+        let second = CrashReport::new(42, "second", &[]).write_to(&dir).unwrap();
+
+        assert_ne!(first, second);
+        assert!(fs::read_to_string(&first).unwrap().contains("panic: first"));
+        assert!(fs::read_to_string(&second).unwrap().contains("panic: second"));
+        assert_eq!(CrashReport::take_pending(&dir), Some(second));
+    }
+
+    #[test]
+    fn old_reports_are_pruned() {
+        let dir = TestDir::new("crash_prune");
+        let reports_dir = dir.join(CRASH_REPORTS_DIR);
+        fs::create_dir_all(&reports_dir).unwrap();
+        fs::write(reports_dir.join("notes.txt"), "keep me").unwrap();
+        for timestamp in 1..=MAX_CRASH_REPORTS as u64 {
+            CrashReport::new(timestamp, "old", &[]).write_to(&dir).unwrap();
+        }
+        let newest = CrashReport::new(1_000, "new", &[]).write_to(&dir).unwrap();
+
+        let count = fs::read_dir(&reports_dir)
+            .unwrap()
+            .filter(|entry| entry.as_ref().unwrap().file_name().to_string_lossy().starts_with("crash-"))
+            .count();
+        assert_eq!(count, MAX_CRASH_REPORTS);
+        assert!(newest.is_file());
+        assert!(!reports_dir.join("crash-1.txt").exists());
+        assert!(reports_dir.join("crash-2.txt").exists());
+        assert!(reports_dir.join("notes.txt").exists());
+    }
+}
@@ -0,0 +1,166 @@
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+use sui_sdk::types::base_types::SuiAddress;
+
+/// 地址列表导出的默认文件名（不含扩展名）
+const DEFAULT_EXPORT_FILE_STEM: &str = "sui_addresses";
+
+/// 地址列表的导出格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AddressExportFormat {
+    /// 纯文本，每行 `标签<Tab>地址`（无标签时只有地址）
+    Text,
+    /// JSON 数组：`[{"label": ..., "address": ...}]`
+    Json,
+}
+
+impl AddressExportFormat {
+    pub fn all() -> [Self; 2] {
+        [AddressExportFormat::Text, AddressExportFormat::Json]
+    }
+
+    /// 文件扩展名
+    pub fn extension(&self) -> &'static str {
+        match self {
+            AddressExportFormat::Text => "txt",
+            AddressExportFormat::Json => "json",
+        }
+    }
+
+    /// 显示名称
+    pub fn name(&self) -> &'static str {
+        match self {
+            AddressExportFormat::Text => "Text",
+            AddressExportFormat::Json => "JSON",
+        }
+    }
+}
+
+/// 导出的一条地址记录，只包含公开信息
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AddressEntry {
+    pub label: Option<String>,
+    pub address: SuiAddress,
+}
+
+/// 导出路径的校验错误
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExportPathError {
+    /// 未填写路径
+    Empty,
+    /// 路径指向已存在的目录
+    IsDirectory,
+    /// 所在目录不存在
+    MissingParent,
+}
+
+impl ExportPathError {
+    /// 获取错误的翻译键
+    pub fn i18n_key(&self) -> &'static str {
+        match self {
+            ExportPathError::Empty => "export_path_empty",
+            ExportPathError::IsDirectory => "export_path_is_directory",
+            ExportPathError::MissingParent => "export_path_missing_parent",
+        }
+    }
+}
+
+/// 地址列表导出
+pub struct AddressExport;
+
+impl AddressExport {
+    /// 默认导出路径：用户主目录下的 `sui_addresses.<扩展名>`
+    pub fn default_path(format: AddressExportFormat) -> String {
+        let file_name = format!("{}.{}", DEFAULT_EXPORT_FILE_STEM, format.extension());
+        dirs::home_dir()
+            .map(|home| home.join(&file_name))
+            .unwrap_or_else(|| PathBuf::from(&file_name))
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    /// 切换格式时同步修改路径的扩展名（仅当原扩展名是另一种导出格式时）
+    pub fn switch_extension(path: &str, format: AddressExportFormat) -> String {
+        let current = Path::new(path.trim());
+        let is_export_extension = current
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| AddressExportFormat::all().iter().any(|f| f.extension() == ext));
+        if is_export_extension {
+            current.with_extension(format.extension()).to_string_lossy().into_owned()
+        } else {
+            path.to_string()
+        }
+    }
+
+    /// 校验导出路径：不能为空、不能是目录，所在目录必须已存在
+    pub fn validate_path(input: &str) -> Result<PathBuf, ExportPathError> {
+        let trimmed = input.trim();
+        if trimmed.is_empty() {
+            return Err(ExportPathError::Empty);
+        }
+
+        let path = PathBuf::from(trimmed);
+        if path.is_dir() {
+            return Err(ExportPathError::IsDirectory);
+        }
+        match path.parent() {
+            // 相对路径的文件名（如 "addresses.txt"）写入当前目录
+            Some(parent) if parent.as_os_str().is_empty() => Ok(path),
+            Some(parent) if parent.is_dir() => Ok(path),
+            _ => Err(ExportPathError::MissingParent),
+        }
+    }
+
+    /// 生成导出内容，只使用地址和标签，不接触任何私钥数据
+    pub fn render(entries: &[AddressEntry], format: AddressExportFormat) -> Result<String, String> {
+        match format {
+            AddressExportFormat::Text => Ok(entries
+                .iter()
+                .map(|entry| match &entry.label {
+                    Some(label) => format!("{}\t{}\n", label, entry.address),
+                    None => format!("{}\n", entry.address),
+                })
+                .collect()),
+            AddressExportFormat::Json => serde_json::to_string_pretty(entries).map_err(|e| e.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::WalletUtils;
+    use sui_sdk::types::crypto::EncodeDecodeBase64;
+
+    /// 测试用 Ed25519 私钥（字节 0x01..=0x20）
+    const TEST_KEY: &str = "suiprivkey1qqqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0jqa4ffsr";
+
+    #[test]
+    fn exported_addresses_contain_no_private_key_material() {
+        let (address, keypair) = WalletUtils::import_private_key(TEST_KEY).unwrap();
+        let entries = vec![AddressEntry { label: Some("Cold".to_string()), address }];
+        let private_hex: String = (1u8..=32).map(|b| format!("{:02x}", b)).collect();
+        let private_base64 = keypair.encode_base64();
+
+        for format in AddressExportFormat::all() {
+            let content = AddressExport::render(&entries, format).unwrap();
+            assert!(content.contains(&address.to_string()));
+            assert!(content.contains("Cold"));
+            assert!(!content.contains(TEST_KEY));
+            assert!(!content.contains(&private_hex));
+            assert!(!content.contains(&private_base64));
+        }
+    }
+
+    #[test]
+    fn validates_export_path() {
+        assert_eq!(AddressExport::validate_path("  "), Err(ExportPathError::Empty));
+        let dir = std::env::temp_dir();
+        assert_eq!(AddressExport::validate_path(&dir.to_string_lossy()), Err(ExportPathError::IsDirectory));
+        let missing = dir.join("sui_wallet_missing_dir").join("addresses.txt");
+        assert_eq!(AddressExport::validate_path(&missing.to_string_lossy()), Err(ExportPathError::MissingParent));
+        let ok = dir.join("addresses.txt");
+        assert_eq!(AddressExport::validate_path(&ok.to_string_lossy()), Ok(ok));
+    }
+}
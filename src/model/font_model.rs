@@ -0,0 +1,73 @@
+use std::path::{Path, PathBuf};
+use super::log_warn;
+
+/// 中文字体在 egui 字体表中的名称
+pub const CJK_FONT_NAME: &str = "noto_sans_sc";
+/// 编译时嵌入的中文字体（Noto Sans SC），不依赖工作目录，开箱即可显示中文
+pub static EMBEDDED_CJK_FONT: &[u8] = include_bytes!("../../assets/NotoSansSC-Regular.ttf");
+/// 指定自定义字体文件的环境变量，设置后优先于内置字体
+pub const CUSTOM_FONT_ENV: &str = "SUI_WALLET_FONT";
+
+/// 使用的中文字体来源
+#[derive(Debug, Clone, PartialEq)]
+pub enum CjkFontSource {
+    /// 内置字体
+    Embedded,
+    /// 用户指定的字体文件
+    Custom { path: PathBuf, data: Vec<u8> },
+}
+
+/// 中文字体选择
+pub struct CjkFont;
+
+impl CjkFont {
+    /// 环境变量中指定的自定义字体路径（未设置或为空时为 None）
+    pub fn override_path() -> Option<PathBuf> {
+        std::env::var_os(CUSTOM_FONT_ENV)
+            .filter(|value| !value.is_empty())
+            .map(PathBuf::from)
+    }
+
+    /// 优先使用自定义字体；自定义字体无法读取时记录警告并回退到内置字体
+    pub fn resolve(override_path: Option<&Path>) -> CjkFontSource {
+        let Some(path) = override_path else {
+            return CjkFontSource::Embedded;
+        };
+        match std::fs::read(path) {
+            Ok(data) if !data.is_empty() => CjkFontSource::Custom { path: path.to_path_buf(), data },
+            Ok(_) => {
+                log_warn(&format!("Custom font {} is empty, using the bundled font", path.display()));
+                CjkFontSource::Embedded
+            }
+            Err(e) => {
+                log_warn(&format!("Failed to read custom font {}: {}, using the bundled font", path.display(), e));
+                CjkFontSource::Embedded
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn embedded_font_is_bundled() {
+        assert!(!EMBEDDED_CJK_FONT.is_empty());
+        assert_eq!(CjkFont::resolve(None), CjkFontSource::Embedded);
+    }
+
+    #[test]
+    fn custom_font_overrides_embedded_font() {
+        let path = std::env::temp_dir().join(format!("sui_wallet_font_test_{}.ttf", std::process::id()));
+        std::fs::write(&path, b"font-bytes").unwrap();
+        assert_eq!(
+            CjkFont::resolve(Some(&path)),
+            CjkFontSource::Custom { path: path.clone(), data: b"font-bytes".to_vec() }
+        );
+        std::fs::remove_file(&path).unwrap();
+
+        // 自定义字体不存在时回退到内置字体
+        assert_eq!(CjkFont::resolve(Some(&path)), CjkFontSource::Embedded);
+    }
+}
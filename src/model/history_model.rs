@@ -0,0 +1,308 @@
+use std::{collections::HashSet, fs, path::{Path, PathBuf}, time::{Duration, SystemTime, UNIX_EPOCH}};
+use serde::{Deserialize, Serialize};
+use super::{log_error, wall_clock_elapsed, write_file_atomic, Balance};
+
+/// 转账备注的最大字符数
+pub const TRANSFER_NOTE_MAX_CHARS: usize = 200;
+
+/// 规范化备注：去除首尾空白，超长时按字符截断，空备注返回 None
+pub fn normalize_transfer_note(note: &str) -> Option<String> {
+    let trimmed = note.trim();
+    (!trimmed.is_empty()).then(|| trimmed.chars().take(TRANSFER_NOTE_MAX_CHARS).collect())
+}
+
+/// 转账状态
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum TransferStatus {
+    /// 已提交，等待确认
+    Pending,
+    /// 执行成功
+    Success,
+    /// 执行失败
+    Failed,
+}
+
+impl TransferStatus {
+    /// 获取状态的翻译键
+    pub fn i18n_key(&self) -> &'static str {
+        match self {
+            TransferStatus::Pending => "transfer_status_pending",
+            TransferStatus::Success => "transfer_status_success",
+            TransferStatus::Failed => "transfer_status_failed",
+        }
+    }
+}
+
+/// 单条转账记录
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransferRecord {
+    /// 发起时间（Unix 秒）
+    pub timestamp: u64,
+    /// 发送方地址
+    pub sender: String,
+    /// 接收方地址
+    pub recipient: String,
+    /// 转账金额（MIST）
+    pub amount_mist: u64,
+    /// 交易摘要，提交前失败时为空
+    pub digest: Option<String>,
+    pub status: TransferStatus,
+    /// 网络名称
+    pub network: String,
+    /// 用户添加的本地备注（Sui 转账不在链上携带备注，只保存在本地）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+impl TransferRecord {
+    /// 创建以当前时间为时间戳的记录
+    pub fn new(sender: String, recipient: String, amount_mist: u64, network: String) -> Self {
+        Self {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+            sender,
+            recipient,
+            amount_mist,
+            digest: None,
+            status: TransferStatus::Pending,
+            network,
+            note: None,
+        }
+    }
+
+    /// 记录距今的时间（`now` 为当前 Unix 秒），系统时钟异常时为 None
+    pub fn age(&self, now: u64) -> Option<Duration> {
+        wall_clock_elapsed(self.timestamp, now)
+    }
+
+    /// 附加备注（发送时填写），空备注忽略
+    pub fn with_note(mut self, note: &str) -> Self {
+        self.note = normalize_transfer_note(note);
+        self
+    }
+
+    /// 转账金额
+    pub fn amount(&self) -> Balance {
+        Balance::from_mist(self.amount_mist)
+    }
+}
+
+/// 本地转账历史
+/// 记录用户发起的每一笔转账，无需查询链上即可立即显示
+#[derive(Debug, Clone)]
+pub struct TransferHistory {
+    file: PathBuf,
+    records: Vec<TransferRecord>,
+}
+
+impl TransferHistory {
+    /// 从指定文件加载历史，文件不存在时返回空历史
+    pub fn load(file: PathBuf) -> Result<Self, String> {
+        let records = match fs::read_to_string(&file) {
+            Ok(content) if content.trim().is_empty() => Vec::new(),
+            Ok(content) => serde_json::from_str(&content)
+                .map_err(|e| format!("Failed to parse transfer history: {}", e))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(format!("Failed to read transfer history: {}", e)),
+        };
+        Ok(Self { file, records })
+    }
+
+    /// 从配置目录加载历史，失败时返回空历史（不覆盖原文件直到下次写入）
+    pub fn load_from_dir(config_dir: &Path) -> Self {
+        let file = config_dir.join("transfer_history.json");
+        Self::load(file.clone()).unwrap_or_else(|e| {
+            log_error(&e);
+            Self { file, records: Vec::new() }
+        })
+    }
+
+    /// 所有记录（按添加顺序）
+    #[cfg(test)]
+    pub fn records(&self) -> &[TransferRecord] {
+        &self.records
+    }
+
+    /// 指定网络上最近成功转账的金额（MIST），最新的在前
+    pub fn recent_amounts(&self, network: &str, limit: usize) -> Vec<u64> {
+        self.records
+            .iter()
+            .rev()
+            .filter(|r| r.network == network && r.status == TransferStatus::Success)
+            .take(limit)
+            .map(|r| r.amount_mist)
+            .collect()
+    }
+
+    /// 追加一条记录并保存
+    pub fn append(&mut self, record: TransferRecord) -> Result<(), String> {
+        self.records.push(record);
+        self.save()
+    }
+
+    /// 更新指定交易的状态并保存，找不到交易时返回 false
+    /// 批量转账的所有收款人在同一笔交易中，共用一个摘要，一起更新
+    pub fn update_status(&mut self, digest: &str, status: TransferStatus) -> Result<bool, String> {
+        let mut found = false;
+        for record in self.records.iter_mut().filter(|r| r.digest.as_deref() == Some(digest)) {
+            record.status = status;
+            found = true;
+        }
+        if !found {
+            return Ok(false);
+        }
+        self.save().map(|_| true)
+    }
+
+    /// 设置指定交易的备注并保存（空备注表示删除），找不到交易时返回 false
+    pub fn set_note(&mut self, digest: &str, note: &str) -> Result<bool, String> {
+        let note = normalize_transfer_note(note);
+        let mut found = false;
+        for record in self.records.iter_mut().filter(|r| r.digest.as_deref() == Some(digest)) {
+            record.note = note.clone();
+            found = true;
+        }
+        if !found {
+            return Ok(false);
+        }
+        self.save().map(|_| true)
+    }
+
+    /// 指定交易的备注
+    pub fn note_for(&self, digest: &str) -> Option<&str> {
+        self.records
+            .iter()
+            .find(|r| r.digest.as_deref() == Some(digest))
+            .and_then(|r| r.note.as_deref())
+    }
+
+    /// 与链上历史合并：相同摘要的记录以链上为准（保留本地备注），结果按时间倒序排列
+    pub fn merged_with(&self, on_chain: &[TransferRecord]) -> Vec<TransferRecord> {
+        let chain_digests: HashSet<&str> = on_chain
+            .iter()
+            .filter_map(|r| r.digest.as_deref())
+            .collect();
+
+        let mut merged: Vec<TransferRecord> = on_chain
+            .iter()
+            .map(|record| {
+                let mut record = record.clone();
+                if record.note.is_none() {
+                    record.note = record.digest.as_deref().and_then(|d| self.note_for(d)).map(str::to_string);
+                }
+                record
+            })
+            .collect();
+        merged.extend(
+            self.records
+                .iter()
+                .filter(|r| !r.digest.as_deref().is_some_and(|d| chain_digests.contains(d)))
+                .cloned(),
+        );
+        merged.sort_by_key(|record| std::cmp::Reverse(record.timestamp));
+        merged
+    }
+
+    /// 保存历史到文件
+    fn save(&self) -> Result<(), String> {
+        if let Some(parent) = self.file.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create directory: {}", e))?;
+        }
+        let json = serde_json::to_string_pretty(&self.records)
+            .map_err(|e| format!("Failed to serialize transfer history: {}", e))?;
+        write_file_atomic(&self.file, json.as_bytes())
+            .map_err(|e| format!("Failed to write transfer history: {}", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::{I18nManager, Language};
+    use crate::model::TestDir;
+
+    #[test]
+    fn relative_time_is_sane_under_clock_skew() {
+        let mut i18n = I18nManager::new();
+        i18n.set_language(Language::English);
+        let now = 1_700_000_000;
+        let mut record = TransferRecord::new("0x1".to_string(), "0x2".to_string(), 1, "Testnet".to_string());
+
+        record.timestamp = now - 120;
+        assert_eq!(i18n.format_relative_age(record.age(now)), "2 minutes ago");
+
+        // 记录时间在未来（时钟回拨）：显示"刚刚"而不是负数或极大的时间
+        record.timestamp = now + 3_600;
+        assert_eq!(record.age(now), Some(Duration::ZERO));
+        assert_eq!(i18n.format_relative_age(record.age(now)), i18n.tr("time_just_now"));
+
+        // 时钟不可用或跳变过大：显示"未知"
+        assert_eq!(i18n.format_relative_age(record.age(0)), i18n.tr("time_unknown"));
+        record.timestamp = 1;
+        assert_eq!(i18n.format_relative_age(record.age(now)), i18n.tr("time_unknown"));
+    }
+
+    #[test]
+    fn appended_record_survives_reload() {
+        let dir = TestDir::new("history");
+        let file = dir.join("transfer_history.json");
+
+        let mut history = TransferHistory::load(file.clone()).unwrap();
+        assert!(history.records().is_empty());
+
+        let mut record = TransferRecord::new("0xa".to_string(), "0xb".to_string(), 1_500_000_000, "testnet".to_string());
+        record.digest = Some("digest1".to_string());
+        history.append(record.clone()).unwrap();
+
+        let reloaded = TransferHistory::load(file).unwrap();
+        assert_eq!(reloaded.records(), &[record]);
+    }
+
+    #[test]
+    fn status_update_covers_every_recipient_of_the_transaction() {
+        let dir = TestDir::new("history_batch_status");
+        let file = dir.join("transfer_history.json");
+        let mut history = TransferHistory::load(file.clone()).unwrap();
+        for recipient in ["0xb", "0xc"] {
+            let mut record = TransferRecord::new("0xa".to_string(), recipient.to_string(), 1, "testnet".to_string());
+            record.digest = Some("batch".to_string());
+            history.append(record).unwrap();
+        }
+
+        assert!(history.update_status("batch", TransferStatus::Success).unwrap());
+        assert!(!history.update_status("missing", TransferStatus::Failed).unwrap());
+        let reloaded = TransferHistory::load(file).unwrap();
+        assert!(reloaded.records().iter().all(|r| r.status == TransferStatus::Success));
+    }
+
+    #[test]
+    fn note_persists_with_its_digest() {
+        let dir = TestDir::new("history_notes");
+        let file = dir.join("transfer_history.json");
+        let mut history = TransferHistory::load(file.clone()).unwrap();
+
+        let mut rent = TransferRecord::new("0xa".to_string(), "0xb".to_string(), 1, "testnet".to_string())
+            .with_note("  Rent for March  ");
+        rent.digest = Some("digest_rent".to_string());
+        let mut other = TransferRecord::new("0xa".to_string(), "0xc".to_string(), 2, "testnet".to_string());
+        other.digest = Some("digest_other".to_string());
+        history.append(rent).unwrap();
+        history.append(other).unwrap();
+        assert!(history.set_note("digest_other", "Coffee").unwrap());
+        assert!(!history.set_note("missing", "x").unwrap());
+
+        let reloaded = TransferHistory::load(file).unwrap();
+        assert_eq!(reloaded.note_for("digest_rent"), Some("Rent for March"));
+        assert_eq!(reloaded.note_for("digest_other"), Some("Coffee"));
+
+        // 链上记录没有备注，合并时使用本地备注
+        let mut on_chain = TransferRecord::new("0xa".to_string(), "0xb".to_string(), 1, "testnet".to_string());
+        on_chain.digest = Some("digest_rent".to_string());
+        let merged = reloaded.merged_with(&[on_chain]);
+        let rent = merged.iter().find(|r| r.digest.as_deref() == Some("digest_rent")).unwrap();
+        assert_eq!(rent.note.as_deref(), Some("Rent for March"));
+    }
+}
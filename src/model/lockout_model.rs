@@ -0,0 +1,136 @@
+use std::{fs, path::{Path, PathBuf}, time::Duration};
+use serde::{Deserialize, Serialize};
+use super::{log_error, write_file_atomic};
+
+/// 登录失败次数和锁定截止时间
+/// 保存在配置目录中，重启应用不能绕过锁定
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LoginLockout {
+    /// 连续登录失败次数
+    pub failed_attempts: u32,
+    /// 锁定截止时间（Unix 秒），None 表示未锁定
+    pub locked_until: Option<u64>,
+}
+
+impl LoginLockout {
+    /// 距离解除锁定的剩余时间，未锁定或已到期时为 None
+    /// 剩余时间不超过 `lockout`（当前设置的锁定时长），系统时钟回拨或修改了锁定文件时也不会被锁得更久
+    pub fn remaining(&self, now: u64, lockout: Duration) -> Option<Duration> {
+        self.locked_until
+            .filter(|until| *until > now)
+            .map(|until| Duration::from_secs(until - now).min(lockout))
+            .filter(|remaining| !remaining.is_zero())
+    }
+
+    /// 记录一次登录失败，达到 `max_attempts` 次时锁定 `lockout` 时长并重新计数，返回是否因此被锁定
+    /// `max_attempts` 为 0 表示不限制尝试次数
+    pub fn record_failure(&mut self, max_attempts: u32, lockout: Duration, now: u64) -> bool {
+        if self.locked_until.is_some_and(|until| until <= now) {
+            self.locked_until = None;
+        }
+        self.failed_attempts = self.failed_attempts.saturating_add(1);
+        if max_attempts == 0 || self.failed_attempts < max_attempts {
+            return false;
+        }
+        self.failed_attempts = 0;
+        self.locked_until = Some(now.saturating_add(lockout.as_secs()));
+        true
+    }
+
+    /// 没有失败记录也没有锁定（无需保存到磁盘）
+    pub fn is_clear(&self) -> bool {
+        self.failed_attempts == 0 && self.locked_until.is_none()
+    }
+}
+
+/// 登录锁定状态的存储文件
+#[derive(Debug, Clone)]
+pub struct LoginLockoutStore {
+    file: PathBuf,
+}
+
+impl LoginLockoutStore {
+    /// 在指定目录下创建锁定状态存储
+    pub fn new(dir: &Path) -> Self {
+        Self {
+            file: dir.join("login.lockout"),
+        }
+    }
+
+    /// 读取锁定状态；文件不存在时为空状态，无法解析时记录错误并视为空状态
+    pub fn load(&self) -> LoginLockout {
+        match fs::read(&self.file) {
+            Ok(bytes) => serde_json::from_slice(&bytes).unwrap_or_else(|e| {
+                log_error(&format!("Ignoring unreadable login lockout file: {}", e));
+                LoginLockout::default()
+            }),
+            Err(_) => LoginLockout::default(),
+        }
+    }
+
+    /// 保存锁定状态，状态为空时删除文件
+    pub fn save(&self, lockout: &LoginLockout) -> Result<(), String> {
+        if lockout.is_clear() {
+            return self.clear();
+        }
+        if let Some(parent) = self.file.parent() {
+            fs::create_dir_all(parent).map_err(|e| format!("Failed to create config dir: {}", e))?;
+        }
+        let data = serde_json::to_vec(lockout).map_err(|e| format!("Failed to serialize login lockout: {}", e))?;
+        write_file_atomic(&self.file, &data).map_err(|e| format!("Failed to write login lockout: {}", e))
+    }
+
+    /// 删除锁定状态文件
+    pub fn clear(&self) -> Result<(), String> {
+        match fs::remove_file(&self.file) {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(format!("Failed to remove login lockout: {}", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LOCKOUT: Duration = Duration::from_secs(15 * 60);
+
+    #[test]
+    fn locks_after_max_failed_attempts() {
+        let mut lockout = LoginLockout::default();
+        for _ in 0..4 {
+            assert!(!lockout.record_failure(5, LOCKOUT, 1_000));
+        }
+        assert!(lockout.remaining(1_000, LOCKOUT).is_none());
+        assert!(lockout.record_failure(5, LOCKOUT, 1_000));
+        assert_eq!(lockout.remaining(1_000, LOCKOUT), Some(LOCKOUT));
+        assert!(lockout.remaining(1_000 + LOCKOUT.as_secs() - 1, LOCKOUT).is_some());
+        assert!(lockout.remaining(1_000 + LOCKOUT.as_secs(), LOCKOUT).is_none());
+    }
+
+    #[test]
+    fn remaining_time_never_exceeds_the_lockout_duration() {
+        let lockout = LoginLockout { failed_attempts: 0, locked_until: Some(1_000 + 7 * 86_400) };
+        assert_eq!(lockout.remaining(1_000, LOCKOUT), Some(LOCKOUT));
+        // 时钟回拨到很久以前也只锁定设置的时长
+        assert_eq!(lockout.remaining(0, LOCKOUT), Some(LOCKOUT));
+        assert!(lockout.remaining(1_000, Duration::ZERO).is_none());
+    }
+
+    #[test]
+    fn huge_lockout_duration_does_not_overflow() {
+        let mut lockout = LoginLockout::default();
+        assert!(lockout.record_failure(1, Duration::MAX, 1_000));
+        assert_eq!(lockout.locked_until, Some(u64::MAX));
+        assert!(lockout.remaining(1_000, Duration::MAX).is_some());
+    }
+
+    #[test]
+    fn zero_max_attempts_never_locks() {
+        let mut lockout = LoginLockout::default();
+        for _ in 0..100 {
+            assert!(!lockout.record_failure(0, LOCKOUT, 1_000));
+        }
+    }
+}
@@ -0,0 +1,220 @@
+use std::collections::VecDeque;
+use std::sync::{Mutex, TryLockError};
+
+use zeroize::Zeroize;
+
+/// 日志中替换疑似密钥内容的占位符
+const MASKED: &str = "[MASKED]";
+/// Bech32 编码私钥的前缀（含分隔符 '1'）
+const BECH32_PRIVATE_KEY_PREFIX: &str = "suiprivkey1";
+/// 十六进制私钥的长度（不含 0x 前缀）
+const HEX_KEY_LEN: usize = 64;
+/// Base64 私钥的长度（1 字节签名方案标志 + 32 字节私钥；不带标志的 32 字节私钥含一个填充字符，长度相同）
+const BASE64_KEY_LEN: usize = 44;
+/// 内存中保留的日志行数
+pub const LOG_BUFFER_CAPACITY: usize = 500;
+
+/// 日志级别
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogLevel {
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    fn label(&self) -> &'static str {
+        match self {
+            LogLevel::Info => "INFO",
+            LogLevel::Warn => "WARN",
+            LogLevel::Error => "ERROR",
+        }
+    }
+}
+
+/// 单个词是否像私钥：`suiprivkey1` 开头的 Bech32、64 位十六进制（可带 0x 前缀）或 44 位 Base64（可带填充）
+/// 只匹配这三种格式，路径、RPC 地址和较长的错误信息不受影响
+fn looks_like_key(token: &str) -> bool {
+    if token.to_ascii_lowercase().starts_with(BECH32_PRIVATE_KEY_PREFIX) {
+        return true;
+    }
+
+    let hex = token.strip_prefix("0x").or_else(|| token.strip_prefix("0X")).unwrap_or(token);
+    if hex.len() == HEX_KEY_LEN && hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return true;
+    }
+
+    token.len() == BASE64_KEY_LEN
+        && token
+            .trim_end_matches('=')
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '/'))
+}
+
+/// 脱敏单个词：整体像私钥时整体替换；否则把 '/' 视为分隔符（路径、URL），只替换其中像私钥的片段
+fn push_masked_token(masked: &mut String, token: &str) {
+    if looks_like_key(token) {
+        masked.push_str(MASKED);
+        return;
+    }
+    for (index, part) in token.split('/').enumerate() {
+        if index > 0 {
+            masked.push('/');
+        }
+        masked.push_str(if looks_like_key(part) { MASKED } else { part });
+    }
+}
+
+/// 将文本中所有像私钥的词替换为 `[MASKED]`，其余内容保持不变
+/// 日志、panic 消息和崩溃报告共用这一个脱敏函数
+pub fn mask_secrets(line: &str) -> String {
+    // '/' 是 Base64 字符，先保留在词中，由 push_masked_token 决定是否作为分隔符
+    let is_token_char = |c: char| c.is_ascii_alphanumeric() || matches!(c, '+' | '/');
+    let mut masked = String::with_capacity(line.len());
+    let mut token = String::new();
+    for c in line.chars().chain(std::iter::once('\n')) {
+        // Base64 私钥末尾的填充字符属于同一个词；其他位置的 '=' 仍是分隔符（如 `key=value`）
+        if is_token_char(c) || (c == '=' && token.len() == BASE64_KEY_LEN - 1) {
+            token.push(c);
+            continue;
+        }
+        if !token.is_empty() {
+            push_masked_token(&mut masked, &token);
+            token.zeroize();
+        }
+        masked.push(c);
+    }
+    masked.pop();
+    masked
+}
+
+/// 最近的日志行，写入前已脱敏；超过容量时丢弃最早的行
+#[derive(Debug)]
+pub struct LogBuffer {
+    lines: VecDeque<String>,
+    capacity: usize,
+}
+
+impl LogBuffer {
+    pub const fn new(capacity: usize) -> Self {
+        Self { lines: VecDeque::new(), capacity }
+    }
+
+    /// 脱敏后保存一行日志，返回实际保存的内容
+    pub fn push(&mut self, level: LogLevel, message: &str) -> String {
+        let line = format!("[{}] {}", level.label(), mask_secrets(message));
+        if self.lines.len() >= self.capacity {
+            self.lines.pop_front();
+        }
+        self.lines.push_back(line.clone());
+        line
+    }
+
+    /// 已保存的日志行（从旧到新）
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.lines.iter().map(String::as_str)
+    }
+}
+
+/// 进程内的日志缓冲区
+static LOG_BUFFER: Mutex<LogBuffer> = Mutex::new(LogBuffer::new(LOG_BUFFER_CAPACITY));
+
+/// 写入一行日志：先脱敏，再保存到日志缓冲区并输出到标准错误
+/// 涉及私钥、密码的代码路径都应使用该函数而不是直接 `eprintln!`
+pub fn log_message(level: LogLevel, message: &str) {
+    let line = LOG_BUFFER
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .push(level, message);
+    eprintln!("{}", line);
+}
+
+/// panic hook 中使用的非阻塞版本：日志缓冲区正被占用时只输出到标准错误
+pub fn try_log_error(message: &str) {
+    let line = match LOG_BUFFER.try_lock() {
+        Ok(mut buffer) => buffer.push(LogLevel::Error, message),
+        Err(TryLockError::Poisoned(poisoned)) => poisoned.into_inner().push(LogLevel::Error, message),
+        Err(TryLockError::WouldBlock) => format!("[{}] {}", LogLevel::Error.label(), mask_secrets(message)),
+    };
+    eprintln!("{}", line);
+}
+
+pub fn log_info(message: &str) {
+    log_message(LogLevel::Info, message);
+}
+
+pub fn log_warn(message: &str) {
+    log_message(LogLevel::Warn, message);
+}
+
+pub fn log_error(message: &str) {
+    log_message(LogLevel::Error, message);
+}
+
+/// 不阻塞地读取最近的日志行（供 panic hook 使用），日志缓冲区正被占用时返回 None
+pub fn try_recent_log_lines() -> Option<Vec<String>> {
+    let buffer = match LOG_BUFFER.try_lock() {
+        Ok(buffer) => buffer,
+        Err(TryLockError::Poisoned(poisoned)) => poisoned.into_inner(),
+        Err(TryLockError::WouldBlock) => return None,
+    };
+    Some(buffer.lines().map(str::to_string).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 测试用 Ed25519 私钥（字节 0x01..=0x20）
+    const TEST_KEY: &str = "suiprivkey1qqqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0jqa4ffsr";
+
+    #[test]
+    fn fake_private_key_is_masked_before_storage() {
+        let mut buffer = LogBuffer::new(10);
+        let hex_key: String = (1u8..=32).map(|b| format!("{:02x}", b)).collect();
+        let base64_key = "AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8g";
+
+        let stored = buffer.push(LogLevel::Error, &format!("Failed to save key {}", TEST_KEY));
+        buffer.push(LogLevel::Info, &format!("raw=0x{} b64={}", hex_key, base64_key));
+
+        assert_eq!(stored, "[ERROR] Failed to save key [MASKED]");
+        let lines: Vec<&str> = buffer.lines().collect();
+        assert_eq!(lines[1], "[INFO] raw=[MASKED] b64=[MASKED]");
+        assert!(lines.iter().all(|line| !line.contains(TEST_KEY) && !line.contains(&hex_key)));
+    }
+
+    #[test]
+    fn panic_messages_are_masked() {
+        let hex_key: String = (1u8..=32).map(|b| format!("{:02x}", b)).collect();
+        let message = format!("failed to sign with {}: bad key '{}'", TEST_KEY, hex_key);
+        assert_eq!(mask_secrets(&message), "failed to sign with [MASKED]: bad key '[MASKED]'");
+        assert_eq!(mask_secrets("index out of bounds: len 3"), "index out of bounds: len 3");
+    }
+
+    #[test]
+    fn paths_urls_and_long_tokens_are_not_masked() {
+        let crash = "Crash report written to /home/user/.config/sui_rust_wallet/crash_reports/crash-1700000000123.json";
+        assert_eq!(mask_secrets(crash), crash);
+        let rpc = "RPC error from https://fullnode.mainnet.sui.io:443/v1/ExecuteTransactionBlockWithEffects: request_timeout_exceeded_after_retrying";
+        assert_eq!(mask_secrets(rpc), rpc);
+
+        // 路径中的私钥片段和含 '/' 的 Base64 私钥仍会被替换
+        let hex_key: String = (1u8..=32).map(|b| format!("{:02x}", b)).collect();
+        assert_eq!(mask_secrets(&format!("/tmp/{}/key", hex_key)), "/tmp/[MASKED]/key");
+        assert_eq!(mask_secrets("key AP/////////////////////////////////////////+"), "key [MASKED]");
+        let padded = format!("{}=", "A/".repeat(21) + "A");
+        assert_eq!(mask_secrets(&format!("key={}.", padded)), "key=[MASKED].");
+    }
+
+    #[test]
+    fn ordinary_messages_are_unchanged_and_buffer_is_bounded() {
+        assert_eq!(mask_secrets("Private key saved successfully"), "Private key saved successfully");
+        assert_eq!(mask_secrets("timeout after 30s: connection refused"), "timeout after 30s: connection refused");
+
+        let mut buffer = LogBuffer::new(2);
+        for i in 0..3 {
+            buffer.push(LogLevel::Info, &format!("line {}", i));
+        }
+        assert_eq!(buffer.lines().collect::<Vec<_>>(), vec!["[INFO] line 1", "[INFO] line 2"]);
+    }
+}
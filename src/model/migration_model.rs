@@ -0,0 +1,130 @@
+use std::{fs, io, path::Path};
+use super::{log_warn, write_file_atomic};
+
+/// 当前配置目录结构版本
+pub const CURRENT_SCHEMA_VERSION: u32 = 2;
+
+/// 版本标记文件名
+const SCHEMA_VERSION_FILE: &str = "schema_version";
+
+/// 单个迁移步骤：将配置目录从 `version - 1` 升级到 `version`
+/// 每个步骤都必须是幂等的，中途失败后可以安全地重新执行
+struct Migration {
+    version: u32,
+    description: &'static str,
+    apply: fn(&Path) -> io::Result<()>,
+}
+
+/// 按版本顺序排列的迁移步骤
+const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "create accounts and logs directories",
+        apply: create_base_dirs,
+    },
+    Migration {
+        version: 2,
+        description: "create empty address book",
+        apply: create_address_book,
+    },
+];
+
+/// 配置目录迁移
+pub struct ConfigMigrator;
+
+impl ConfigMigrator {
+    /// 将配置目录升级到当前版本，返回迁移后的版本号
+    /// 目录版本比程序更新时不做任何修改（避免旧版本程序破坏新格式）
+    pub fn run(dir: &Path) -> Result<u32, String> {
+        fs::create_dir_all(dir)
+            .map_err(|e| format!("Failed to create config directory: {}", e))?;
+
+        let mut version = Self::read_version(dir)?;
+        if version > CURRENT_SCHEMA_VERSION {
+            log_warn(&format!(
+                "Config directory schema version {} is newer than supported version {}",
+                version, CURRENT_SCHEMA_VERSION
+            ));
+            return Ok(version);
+        }
+
+        let pending: Vec<_> = MIGRATIONS.iter().filter(|m| m.version > version).collect();
+        for migration in pending {
+            (migration.apply)(dir).map_err(|e| {
+                format!("Migration to version {} ({}) failed: {}", migration.version, migration.description, e)
+            })?;
+            // 每一步完成后立即记录版本，中断后从下一步继续
+            Self::write_version(dir, migration.version)?;
+            version = migration.version;
+        }
+
+        Ok(version)
+    }
+
+    /// 读取配置目录版本，标记文件不存在时视为版本 0
+    pub fn read_version(dir: &Path) -> Result<u32, String> {
+        match fs::read_to_string(dir.join(SCHEMA_VERSION_FILE)) {
+            Ok(content) => content
+                .trim()
+                .parse()
+                .map_err(|e| format!("Invalid schema version marker: {}", e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(0),
+            Err(e) => Err(format!("Failed to read schema version: {}", e)),
+        }
+    }
+
+    /// 写入配置目录版本
+    fn write_version(dir: &Path, version: u32) -> Result<(), String> {
+        write_file_atomic(&dir.join(SCHEMA_VERSION_FILE), version.to_string().as_bytes())
+            .map_err(|e| format!("Failed to write schema version: {}", e))
+    }
+}
+
+/// 版本 1：创建账户和日志子目录
+fn create_base_dirs(dir: &Path) -> io::Result<()> {
+    fs::create_dir_all(dir.join("accounts"))?;
+    fs::create_dir_all(dir.join("logs"))
+}
+
+/// 版本 2：创建空地址簿
+fn create_address_book(dir: &Path) -> io::Result<()> {
+    let path = dir.join("address_book.json");
+    if path.exists() {
+        return Ok(());
+    }
+    write_file_atomic(&path, b"[]")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::TestDir;
+
+    fn assert_current_layout(dir: &Path) {
+        assert_eq!(ConfigMigrator::read_version(dir), Ok(CURRENT_SCHEMA_VERSION));
+        assert!(dir.join("accounts").is_dir());
+        assert!(dir.join("logs").is_dir());
+        assert_eq!(fs::read_to_string(dir.join("address_book.json")).unwrap(), "[]");
+    }
+
+    #[test]
+    fn migrates_empty_dir_to_current_layout() {
+        let dir = TestDir::new("migrate_empty");
+        assert_eq!(ConfigMigrator::run(&dir), Ok(CURRENT_SCHEMA_VERSION));
+        assert_current_layout(&dir);
+    }
+
+    #[test]
+    fn migration_is_idempotent_on_current_dir() {
+        let dir = TestDir::new("migrate_current");
+        ConfigMigrator::run(&dir).unwrap();
+        fs::write(dir.join("address_book.json"), "[{\"name\":\"Alice\"}]").unwrap();
+
+        assert_eq!(ConfigMigrator::run(&dir), Ok(CURRENT_SCHEMA_VERSION));
+        assert_eq!(ConfigMigrator::read_version(&dir), Ok(CURRENT_SCHEMA_VERSION));
+        assert!(dir.join("accounts").is_dir());
+        assert!(dir.join("logs").is_dir());
+        // 已有数据不会被覆盖
+        assert_eq!(fs::read_to_string(dir.join("address_book.json")).unwrap(), "[{\"name\":\"Alice\"}]");
+    }
+}
@@ -0,0 +1,177 @@
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
+use sui_sdk::types::{base_types::SuiAddress, crypto::SuiKeyPair};
+use zeroize::{Zeroize, Zeroizing};
+use super::{Balance, Network};
+
+/// 助记词导入预览中显示的账户数量
+pub const MNEMONIC_PREVIEW_ACCOUNTS: u32 = 5;
+/// BIP-39 助记词允许的单词数
+const MNEMONIC_WORD_COUNTS: [usize; 5] = [12, 15, 18, 21, 24];
+/// SLIP-0010 Ed25519 主密钥的 HMAC 密钥
+const ED25519_SEED_KEY: &[u8] = b"ed25519 seed";
+/// Sui 的 BIP-44 币种编号
+const SUI_COIN_TYPE_INDEX: u32 = 784;
+/// 强化派生的索引偏移
+const HARDENED_OFFSET: u32 = 0x8000_0000;
+/// Ed25519 私钥在 SuiKeyPair 字节表示中的签名方案标志
+const ED25519_FLAG: u8 = 0x00;
+
+/// 助记词相关工具函数
+pub struct MnemonicUtils;
+
+impl MnemonicUtils {
+    /// 输入看起来是否为助记词（12/15/18/21/24 个小写英文单词），用于决定是否显示预览
+    pub fn looks_like_mnemonic(input: &str) -> Option<usize> {
+        let words: Vec<&str> = input.split_whitespace().collect();
+        let is_mnemonic = MNEMONIC_WORD_COUNTS.contains(&words.len())
+            && words.iter().all(|w| w.chars().all(|c| c.is_ascii_lowercase()));
+        is_mnemonic.then_some(words.len())
+    }
+
+    /// 派生路径 m/44'/784'/{account}'/0'/0'（与 Sui 钱包和 CLI 一致，只支持 Ed25519）
+    fn derivation_path(account: u32) -> [u32; 5] {
+        [44, SUI_COIN_TYPE_INDEX, account, 0, 0]
+    }
+
+    /// SLIP-0010 Ed25519 派生：所有层级都是强化派生，返回 (私钥, 链码)
+    fn slip10_derive(seed: &[u8], path: &[u32]) -> ([u8; 32], [u8; 32]) {
+        let split = |bytes: &[u8]| {
+            let mut key = [0u8; 32];
+            let mut chain_code = [0u8; 32];
+            key.copy_from_slice(&bytes[..32]);
+            chain_code.copy_from_slice(&bytes[32..]);
+            (key, chain_code)
+        };
+
+        let (mut key, mut chain_code) = split(&Self::hmac_sha512(ED25519_SEED_KEY, &[seed]));
+        for index in path {
+            let hardened = (index | HARDENED_OFFSET).to_be_bytes();
+            (key, chain_code) = split(&Self::hmac_sha512(&chain_code, &[&[0u8], &key, &hardened]));
+        }
+        (key, chain_code)
+    }
+
+    fn hmac_sha512(key: &[u8], parts: &[&[u8]]) -> Zeroizing<Vec<u8>> {
+        let mut mac = Hmac::<Sha512>::new_from_slice(key).expect("HMAC accepts keys of any length");
+        for part in parts {
+            mac.update(part);
+        }
+        Zeroizing::new(mac.finalize().into_bytes().to_vec())
+    }
+
+    /// 从助记词派生指定账户的私钥和地址，种子和中间私钥用完即清零
+    pub fn derive_account(phrase: &str, account: u32) -> Result<(SuiAddress, SuiKeyPair), String> {
+        let mnemonic = bip39::Mnemonic::parse_in_normalized(bip39::Language::English, phrase.trim())
+            .map_err(|e| format!("Invalid recovery phrase: {}", e))?;
+        let seed = Zeroizing::new(mnemonic.to_seed_normalized(""));
+        let (mut key, mut chain_code) = Self::slip10_derive(&*seed, &Self::derivation_path(account));
+
+        let mut bytes = Zeroizing::new(Vec::with_capacity(33));
+        bytes.push(ED25519_FLAG);
+        bytes.extend_from_slice(&key);
+        key.zeroize();
+        chain_code.zeroize();
+        let keypair = SuiKeyPair::from_bytes(&bytes).map_err(|e| e.to_string())?;
+        let address: SuiAddress = (&keypair.public()).into();
+        Ok((address, keypair))
+    }
+
+    /// 派生前 `count` 个账户的地址（不保留私钥）
+    pub fn derive_addresses(phrase: &str, count: u32) -> Result<Vec<SuiAddress>, String> {
+        (0..count)
+            .map(|account| Self::derive_account(phrase, account).map(|(address, _)| address))
+            .collect()
+    }
+}
+
+/// 助记词导入预览：前几个账户的地址及其在当前网络上的余额，帮助用户选择有资金的账户
+#[derive(Debug, Clone, PartialEq)]
+pub struct MnemonicPreview {
+    /// 查询余额的网络
+    pub network: Network,
+    /// 按账户索引排列的地址和余额查询结果（None 表示仍在查询）
+    pub accounts: Vec<(SuiAddress, Option<Result<Balance, String>>)>,
+    /// 选中的账户索引
+    pub selected: u32,
+}
+
+impl MnemonicPreview {
+    pub fn new(network: Network, addresses: Vec<SuiAddress>) -> Self {
+        Self {
+            network,
+            accounts: addresses.into_iter().map(|address| (address, None)).collect(),
+            selected: 0,
+        }
+    }
+
+    /// 预览中某个账户的地址
+    pub fn address(&self, account: u32) -> Option<SuiAddress> {
+        self.accounts.get(account as usize).map(|(address, _)| *address)
+    }
+
+    /// 是否仍有账户的余额在查询中
+    pub fn is_fetching(&self) -> bool {
+        self.accounts.iter().any(|(_, balance)| balance.is_none())
+    }
+
+    /// 记录某个地址的余额，地址不在预览中时忽略并返回 false
+    pub fn record(&mut self, address: SuiAddress, result: Result<Balance, String>) -> bool {
+        match self.accounts.iter_mut().find(|(entry, _)| *entry == address) {
+            Some((_, balance)) => {
+                *balance = Some(result);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    /// Sui CLI 测试中使用的助记词及其第一个 Ed25519 地址
+    const TEST_MNEMONIC: &str = "film crazy soon outside stand loop subway crumble thrive popular green nuclear struggle pistol arm wife phrase warfare march wheat nephew ask sunny firm";
+    const TEST_ADDRESS_0: &str = "0xa2d14fad60c56049ecf75246a481934691214ce413e6a8ae2fe6834c173a6133";
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    #[test]
+    fn follows_slip10_ed25519_test_vector() {
+        let seed: Vec<u8> = (0u8..16).collect();
+        let (key, chain_code) = MnemonicUtils::slip10_derive(&seed, &[]);
+        assert_eq!(hex(&key), "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7");
+        assert_eq!(hex(&chain_code), "90046a93de5380a72b5e45010748567d5ea02bbf6522f979e05c0d8d8ca9fffb");
+
+        let (key, chain_code) = MnemonicUtils::slip10_derive(&seed, &[0]);
+        assert_eq!(hex(&key), "68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3");
+        assert_eq!(hex(&chain_code), "8b59aa11380b624e81507a27fedda59fea6d0b779a778918a2fd3590e16e9c69");
+    }
+
+    #[test]
+    fn derives_first_accounts_from_known_mnemonic() {
+        let addresses = MnemonicUtils::derive_addresses(TEST_MNEMONIC, MNEMONIC_PREVIEW_ACCOUNTS).unwrap();
+        assert_eq!(addresses.len(), MNEMONIC_PREVIEW_ACCOUNTS as usize);
+        assert_eq!(addresses[0], SuiAddress::from_str(TEST_ADDRESS_0).unwrap());
+
+        // 每个账户的地址不同，且与单独派生的结果一致
+        for (index, address) in addresses.iter().enumerate() {
+            assert_eq!(addresses.iter().filter(|a| *a == address).count(), 1);
+            let (derived, _) = MnemonicUtils::derive_account(TEST_MNEMONIC, index as u32).unwrap();
+            assert_eq!(derived, *address);
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_phrases() {
+        assert_eq!(MnemonicUtils::looks_like_mnemonic(TEST_MNEMONIC), Some(24));
+        assert_eq!(MnemonicUtils::looks_like_mnemonic("suiprivkey1abc"), None);
+        // 单词数正确但校验和错误
+        let bad = "abandon ".repeat(12);
+        assert!(MnemonicUtils::derive_account(&bad, 0).is_err());
+    }
+}
@@ -1,29 +1,52 @@
+use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::future::Future;
+use std::time::{Duration, Instant};
+use std::str::FromStr;
+use sui_sdk::SuiClientBuilder;
+use sui_sdk::types::{base_types::SuiAddress, digests::TransactionDigest};
+use super::{log_warn, Balance, WalletUtils};
+
+/// 余额不足以支付这么多笔交易时显示低余额提醒
+pub const LOW_BALANCE_TX_COUNT: u64 = 100;
+
+/// 健康检查超时时间
+const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(10);
+/// 响应慢于此阈值视为性能下降
+const HEALTH_CHECK_SLOW_THRESHOLD: Duration = Duration::from_secs(3);
+/// 两次手动检查之间的最小间隔
+const HEALTH_CHECK_DEBOUNCE: Duration = Duration::from_secs(5);
+/// 自动选择端点时重新探测所有端点的间隔
+pub const ENDPOINT_PROBE_INTERVAL: Duration = Duration::from_secs(60);
 
 /// 支持的网络类型
-#[derive(Debug, PartialEq, Clone, Copy)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub enum Network {
     Devnet,
     Testnet,
     Mainnet,
+    /// 自定义 RPC 端点（如本地节点 http://127.0.0.1:9000）
+    Custom { name: String, url: String },
 }
 
 impl Network {
     /// 获取网络的 RPC URL
-    pub fn url(&self) -> &'static str {
+    pub fn url(&self) -> &str {
         match self {
             Network::Devnet => "https://fullnode.devnet.sui.io:443",
             Network::Testnet => "https://fullnode.testnet.sui.io:443",
             Network::Mainnet => "https://fullnode.mainnet.sui.io:443",
+            Network::Custom { url, .. } => url,
         }
     }
 
     /// 获取网络名称
-    pub fn name(&self) -> &'static str {
+    pub fn name(&self) -> &str {
         match self {
             Network::Devnet => "Devnet",
             Network::Testnet => "Testnet",
             Network::Mainnet => "Mainnet",
+            Network::Custom { name, .. } => name,
         }
     }
 
@@ -33,46 +56,53 @@ impl Network {
             Network::Devnet => "DEV",
             Network::Testnet => "TEST",
             Network::Mainnet => "MAIN",
+            Network::Custom { .. } => "CUSTOM",
         }
     }
 
-    /// 获取网络描述
-    pub fn description(&self) -> &'static str {
+    /// 网络名称的翻译键；自定义网络没有固定名称，使用通用的"自定义网络"
+    pub fn i18n_key(&self) -> &'static str {
         match self {
-            Network::Devnet => "Development network for testing",
-            Network::Testnet => "Test network with reset cycles",
-            Network::Mainnet => "Production network",
+            Network::Devnet => "devnet",
+            Network::Testnet => "testnet",
+            Network::Mainnet => "mainnet",
+            Network::Custom { .. } => "custom_network",
         }
     }
 
-    /// 获取区块链浏览器 URL
-    pub fn explorer_url(&self) -> &'static str {
+    /// 获取浏览器 URL 中使用的网络标识（自定义网络没有公共浏览器，返回 None）
+    pub fn explorer_slug(&self) -> Option<&'static str> {
         match self {
-            Network::Devnet => "https://suiexplorer.com/?network=devnet",
-            Network::Testnet => "https://suiexplorer.com/?network=testnet",
-            Network::Mainnet => "https://suiexplorer.com",
+            Network::Devnet => Some("devnet"),
+            Network::Testnet => Some("testnet"),
+            Network::Mainnet => Some("mainnet"),
+            Network::Custom { .. } => None,
         }
     }
 
-    /// 获取地址的浏览器链接
-    pub fn address_explorer_url(&self, address: &str) -> String {
-        match self {
-            Network::Devnet => format!("https://suiexplorer.com/address/{}?network=devnet", address),
-            Network::Testnet => format!("https://suiexplorer.com/address/{}?network=testnet", address),
-            Network::Mainnet => format!("https://suiexplorer.com/address/{}", address),
+    /// 创建自定义网络
+    pub fn custom(name: impl Into<String>, url: impl Into<String>) -> Network {
+        Network::Custom {
+            name: name.into(),
+            url: url.into(),
         }
     }
 
-    /// 获取交易的浏览器链接
-    pub fn transaction_explorer_url(&self, tx_hash: &str) -> String {
-        match self {
-            Network::Devnet => format!("https://suiexplorer.com/txblock/{}?network=devnet", tx_hash),
-            Network::Testnet => format!("https://suiexplorer.com/txblock/{}?network=testnet", tx_hash),
-            Network::Mainnet => format!("https://suiexplorer.com/txblock/{}", tx_hash),
-        }
+    /// 检查是否为自定义网络
+    pub fn is_custom(&self) -> bool {
+        matches!(self, Network::Custom { .. })
+    }
+
+    /// 检查 RPC URL 格式是否有效
+    pub fn is_valid_rpc_url(url: &str) -> bool {
+        let url = url.trim();
+        let rest = url
+            .strip_prefix("http://")
+            .or_else(|| url.strip_prefix("https://"));
+        matches!(rest, Some(host) if !host.is_empty() && !host.contains(char::is_whitespace))
     }
 
-    /// 获取所有可用网络
+    /// 获取所有内置网络
     pub fn all() -> Vec<Network> {
         vec![Network::Devnet, Network::Testnet, Network::Mainnet]
     }
@@ -93,6 +123,7 @@ impl Network {
             Network::Devnet => NetworkColor::Blue,
             Network::Testnet => NetworkColor::Yellow,
             Network::Mainnet => NetworkColor::Green,
+            Network::Custom { .. } => NetworkColor::Gray,
         }
     }
 
@@ -106,33 +137,30 @@ impl Network {
         }
     }
 
-    /// 获取默认网络（通常是 Devnet 用于开发）
-    pub fn default() -> Network {
-        Network::Devnet
-    }
-
-    /// 获取建议的最小余额（用于交易费用）
-    pub fn minimum_balance(&self) -> f64 {
+    /// 获取预估的交易费用（MIST）
+    pub fn estimated_tx_fee_mist(&self) -> u64 {
         match self {
-            Network::Devnet => 0.001,
-            Network::Testnet => 0.001,
-            Network::Mainnet => 0.01,
+            Network::Devnet => 100_000,
+            Network::Testnet => 100_000,
+            Network::Mainnet => 1_000_000,
+            Network::Custom { .. } => 100_000,
         }
     }
 
-    /// 获取预估的交易费用
-    pub fn estimated_tx_fee(&self) -> f64 {
-        match self {
-            Network::Devnet => 0.0001,
-            Network::Testnet => 0.0001,
-            Network::Mainnet => 0.001,
-        }
+    /// 低余额提醒阈值：足够支付 `LOW_BALANCE_TX_COUNT` 笔交易的预估费用
+    pub fn low_balance_threshold(&self) -> Balance {
+        Balance::from_mist(self.estimated_tx_fee_mist() * LOW_BALANCE_TX_COUNT)
     }
 }
 
+/// 内置的默认网络：首次运行、没有用户选择时使用
+/// `Network::default()`、`NetworkConfig::new()` 和 `Model::default()` 都以此为准；
+/// 用户在首次设置中选择的默认网络保存在 `AppSettings::default_network`
+pub const DEFAULT_NETWORK: Network = Network::Testnet;
+
 impl Default for Network {
     fn default() -> Self {
-        Network::default()
+        DEFAULT_NETWORK
     }
 }
 
@@ -142,40 +170,145 @@ impl fmt::Display for Network {
     }
 }
 
+/// 区块链浏览器提供方
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum ExplorerProvider {
+    #[default]
+    Suiscan,
+    SuiVision,
+    SuiExplorer,
+}
+
+impl ExplorerProvider {
+    /// 获取所有浏览器提供方
+    pub fn all() -> Vec<ExplorerProvider> {
+        vec![ExplorerProvider::Suiscan, ExplorerProvider::SuiVision, ExplorerProvider::SuiExplorer]
+    }
+
+    /// 获取显示名称
+    pub fn name(&self) -> &'static str {
+        match self {
+            ExplorerProvider::Suiscan => "Suiscan",
+            ExplorerProvider::SuiVision => "SuiVision",
+            ExplorerProvider::SuiExplorer => "Sui Explorer",
+        }
+    }
+
+    /// 获取指定网络的浏览器首页
+    fn base_url(&self, network: &Network) -> Option<String> {
+        let slug = network.explorer_slug()?;
+        Some(match self {
+            ExplorerProvider::Suiscan => format!("https://suiscan.xyz/{}", slug),
+            ExplorerProvider::SuiVision if network.is_mainnet() => "https://suivision.xyz".to_string(),
+            ExplorerProvider::SuiVision => format!("https://{}.suivision.xyz", slug),
+            ExplorerProvider::SuiExplorer => "https://suiexplorer.com".to_string(),
+        })
+    }
+
+    /// Sui Explorer 通过查询参数区分网络
+    fn network_query(&self, network: &Network) -> String {
+        match (self, network) {
+            (ExplorerProvider::SuiExplorer, network) if network.is_testnet() => {
+                format!("?network={}", network.explorer_slug().unwrap_or_default())
+            }
+            _ => String::new(),
+        }
+    }
+
+    /// 获取浏览器首页链接
+    pub fn home_url(&self, network: &Network) -> Option<String> {
+        let base = self.base_url(network)?;
+        let query = self.network_query(network);
+        Some(if query.is_empty() { base } else { format!("{}/{}", base, query) })
+    }
+
+    /// 获取地址的浏览器链接，地址无效时返回 None
+    pub fn address_url(&self, network: &Network, address: &str) -> Option<String> {
+        let address = SuiAddress::from_str(WalletUtils::normalize_input(address).as_str()).ok()?;
+        let base = self.base_url(network)?;
+        let path = match self {
+            ExplorerProvider::SuiExplorer => "address",
+            ExplorerProvider::Suiscan | ExplorerProvider::SuiVision => "account",
+        };
+        Some(format!("{}/{}/{}{}", base, path, address, self.network_query(network)))
+    }
+
+    /// 获取交易的浏览器链接，交易摘要无效时返回 None
+    pub fn transaction_url(&self, network: &Network, tx_hash: &str) -> Option<String> {
+        let tx_hash = TransactionDigest::from_str(WalletUtils::normalize_input(tx_hash).as_str()).ok()?;
+        let base = self.base_url(network)?;
+        let path = match self {
+            ExplorerProvider::Suiscan => "tx",
+            ExplorerProvider::SuiVision | ExplorerProvider::SuiExplorer => "txblock",
+        };
+        Some(format!("{}/{}/{}{}", base, path, tx_hash, self.network_query(network)))
+    }
+}
+
+
 /// 网络配色枚举
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum NetworkColor {
     Blue,
     Yellow,
     Green,
+    Gray,
 }
 
 impl NetworkColor {
-    /// 获取对应的 egui 颜色
-    #[cfg(feature = "egui")]
-    pub fn to_egui_color(&self) -> eframe::egui::Color32 {
-        match self {
-            NetworkColor::Blue => eframe::egui::Color32::BLUE,
-            NetworkColor::Yellow => eframe::egui::Color32::YELLOW,
-            NetworkColor::Green => eframe::egui::Color32::GREEN,
-        }
-    }
-
     /// 获取 RGB 值
-    pub fn to_rgb(&self) -> (u8, u8, u8) {
+    pub fn to_rgb(self) -> (u8, u8, u8) {
         match self {
             NetworkColor::Blue => (0, 100, 255),
             NetworkColor::Yellow => (255, 200, 0),
             NetworkColor::Green => (0, 150, 0),
+            NetworkColor::Gray => (128, 128, 128),
+        }
+    }
+
+    /// 高对比度主题下的 RGB 值：在黑色背景上足够明亮，且彼此容易区分
+    pub fn to_high_contrast_rgb(self) -> (u8, u8, u8) {
+        match self {
+            NetworkColor::Blue => (90, 200, 255),
+            NetworkColor::Yellow => (255, 235, 0),
+            NetworkColor::Green => (0, 255, 110),
+            NetworkColor::Gray => (255, 255, 255),
         }
     }
 }
 
+/// 单个端点的探测结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct EndpointProbe {
+    pub url: String,
+    pub healthy: bool,
+    pub latency: Duration,
+}
+
+impl EndpointProbe {
+    /// 从探测结果中选出延迟最低的健康端点，延迟相同时保留配置中靠前的端点
+    pub fn select_best(probes: &[EndpointProbe]) -> Option<&EndpointProbe> {
+        probes
+            .iter()
+            .filter(|probe| probe.healthy)
+            .min_by_key(|probe| probe.latency)
+    }
+}
+
 /// 网络配置管理
+/// `auto_switch` 开启时当前网络不可达会切换到首选网络；
+/// `auto_select_endpoint` 开启时定期探测当前网络的所有端点（主端点和备用端点），自动优先使用延迟最低的健康端点
 pub struct NetworkConfig {
     pub current_network: Network,
     pub auto_switch: bool,
     pub preferred_network: Network,
+    pub auto_select_endpoint: bool,
+    /// 自动选择的当前网络端点，None 表示使用配置顺序
+    pub active_endpoint: Option<String>,
+    /// 是否正在探测端点
+    pub probing: bool,
+    /// 最近一次发起探测的时间
+    pub last_probe: Option<Instant>,
 }
 
 impl NetworkConfig {
@@ -184,13 +317,62 @@ impl NetworkConfig {
             current_network: Network::default(),
             auto_switch: false,
             preferred_network: Network::default(),
+            auto_select_endpoint: false,
+            active_endpoint: None,
+            probing: false,
+            last_probe: None,
         }
     }
 
+    /// 切换网络，之前选出的端点不再适用
     pub fn switch_to(&mut self, network: Network) {
+        if self.current_network != network {
+            self.active_endpoint = None;
+            self.last_probe = None;
+        }
         self.current_network = network;
     }
 
+    /// 是否到了重新探测端点的时间
+    pub fn should_probe(&self, now: Instant) -> bool {
+        self.auto_select_endpoint
+            && !self.probing
+            && self
+                .last_probe
+                .is_none_or(|at| now.saturating_duration_since(at) >= ENDPOINT_PROBE_INTERVAL)
+    }
+
+    /// 应用探测结果；没有健康端点时回到配置顺序
+    pub fn apply_probes(&mut self, network: &Network, probes: &[EndpointProbe]) {
+        self.probing = false;
+        if *network != self.current_network {
+            return;
+        }
+        self.active_endpoint = EndpointProbe::select_best(probes).map(|probe| probe.url.clone());
+    }
+
+    /// 按尝试顺序排列的端点：启用自动选择时，选出的端点排在最前面
+    pub fn ordered_endpoints(&self, network: &Network, fallbacks: &RpcFallbackSettings) -> Vec<String> {
+        let mut endpoints = fallbacks.endpoints(network);
+        if self.auto_select_endpoint
+            && *network == self.current_network
+            && let Some(active) = &self.active_endpoint
+            && let Some(index) = endpoints.iter().position(|e| e == active)
+        {
+            let active = endpoints.remove(index);
+            endpoints.insert(0, active);
+        }
+        endpoints
+    }
+
+    /// 当前实际优先使用的端点
+    pub fn active_endpoint_for(&self, network: &Network, fallbacks: &RpcFallbackSettings) -> String {
+        self.ordered_endpoints(network, fallbacks)
+            .into_iter()
+            .next()
+            .unwrap_or_else(|| network.url().to_string())
+    }
+
     pub fn set_preferred(&mut self, network: Network) {
         self.preferred_network = network;
     }
@@ -203,6 +385,20 @@ impl NetworkConfig {
         self.auto_switch = false;
     }
 
+    /// 端点列表变化后在下一帧重新探测
+    pub fn request_probe(&mut self) {
+        self.last_probe = None;
+    }
+
+    /// 开启或关闭自动选择端点，关闭时回到配置顺序
+    pub fn set_auto_select_endpoint(&mut self, enabled: bool) {
+        self.auto_select_endpoint = enabled;
+        if !enabled {
+            self.active_endpoint = None;
+            self.last_probe = None;
+        }
+    }
+
     pub fn should_auto_switch(&self) -> bool {
         self.auto_switch && self.current_network != self.preferred_network
     }
@@ -214,36 +410,175 @@ impl Default for NetworkConfig {
     }
 }
 
+/// 各内置网络的备用 RPC 端点，按顺序在主端点不可用时依次尝试
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RpcFallbackSettings {
+    pub devnet: Vec<String>,
+    pub testnet: Vec<String>,
+    pub mainnet: Vec<String>,
+}
+
+impl RpcFallbackSettings {
+    /// 指定网络的备用端点，自定义网络没有备用端点
+    pub fn for_network(&self, network: &Network) -> &[String] {
+        match network {
+            Network::Devnet => &self.devnet,
+            Network::Testnet => &self.testnet,
+            Network::Mainnet => &self.mainnet,
+            Network::Custom { .. } => &[],
+        }
+    }
+
+    /// 设置指定网络的备用端点，自定义网络没有备用端点
+    pub fn set_for_network(&mut self, network: &Network, urls: Vec<String>) {
+        match network {
+            Network::Devnet => self.devnet = urls,
+            Network::Testnet => self.testnet = urls,
+            Network::Mainnet => self.mainnet = urls,
+            Network::Custom { .. } => {}
+        }
+    }
+
+    /// 解析设置中每行一个的备用端点，去除空行和重复项；有无效 URL 时返回这些行
+    pub fn parse_urls(input: &str) -> Result<Vec<String>, Vec<String>> {
+        let mut urls: Vec<String> = Vec::new();
+        let mut invalid = Vec::new();
+        for line in input.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            if !Network::is_valid_rpc_url(line) {
+                invalid.push(line.to_string());
+            } else if !urls.iter().any(|url| url == line) {
+                urls.push(line.to_string());
+            }
+        }
+        if invalid.is_empty() {
+            Ok(urls)
+        } else {
+            Err(invalid)
+        }
+    }
+
+    /// 按尝试顺序排列的端点：主端点在前，随后是去重后的有效备用端点
+    pub fn endpoints(&self, network: &Network) -> Vec<String> {
+        let mut endpoints = vec![network.url().to_string()];
+        for url in self.for_network(network) {
+            let url = url.trim();
+            if Network::is_valid_rpc_url(url) && !endpoints.iter().any(|e| e == url) {
+                endpoints.push(url.to_string());
+            }
+        }
+        endpoints
+    }
+}
+
 /// 网络工具函数
 pub struct NetworkUtils;
 
 impl NetworkUtils {
     /// 检查网络连接状态
     pub async fn check_network_health(network: Network) -> NetworkHealthStatus {
-        // 这里可以实现实际的网络健康检查
-        // 目前返回假设的状态
-        NetworkHealthStatus::Healthy
-    }
-
-    /// 获取推荐的网络
-    pub fn get_recommended_network() -> Network {
-        // 对于开发环境，推荐使用 Devnet
-        // 对于生产环境，可以根据需要调整
-        #[cfg(debug_assertions)]
-        return Network::Devnet;
-        
-        #[cfg(not(debug_assertions))]
-        return Network::Testnet;
-    }
-
-    /// 比较网络性能
-    pub fn compare_networks(net1: Network, net2: Network) -> NetworkComparison {
-        if net1.is_mainnet() && !net2.is_mainnet() {
-            NetworkComparison::FirstBetter
-        } else if !net1.is_mainnet() && net2.is_mainnet() {
-            NetworkComparison::SecondBetter
+        let started = Instant::now();
+        let request = SuiClientBuilder::default().build(network.url());
+
+        match tokio::time::timeout(HEALTH_CHECK_TIMEOUT, request).await {
+            Ok(Ok(_)) if started.elapsed() > HEALTH_CHECK_SLOW_THRESHOLD => NetworkHealthStatus::Degraded,
+            Ok(Ok(_)) => NetworkHealthStatus::Healthy,
+            Ok(Err(_)) | Err(_) => NetworkHealthStatus::Unhealthy,
+        }
+    }
+
+    /// 测试 RPC 端点：调用 `sui_getChainIdentifier`，成功时返回链 ID
+    pub async fn test_endpoint(url: String) -> Result<String, String> {
+        let request = async {
+            let client = SuiClientBuilder::default()
+                .build(&url)
+                .await
+                .map_err(|e| e.to_string())?;
+            client
+                .read_api()
+                .get_chain_identifier()
+                .await
+                .map_err(|e| e.to_string())
+        };
+
+        match tokio::time::timeout(HEALTH_CHECK_TIMEOUT, request).await {
+            Ok(result) => result,
+            Err(_) => Err(format!("Request timed out after {}s", HEALTH_CHECK_TIMEOUT.as_secs())),
+        }
+    }
+
+    /// 探测单个端点的健康状态和延迟
+    pub async fn probe_endpoint(url: String) -> EndpointProbe {
+        let started = Instant::now();
+        let healthy = Self::test_endpoint(url.clone()).await.is_ok();
+        EndpointProbe {
+            url,
+            healthy,
+            latency: started.elapsed(),
+        }
+    }
+
+    /// 按顺序尝试各端点，返回第一个成功的结果及提供结果的端点
+    /// 全部失败时返回包含每个端点错误的汇总信息
+    pub async fn try_endpoints_in_order<T, F, Fut>(endpoints: &[String], mut attempt: F) -> Result<(String, T), String>
+    where
+        F: FnMut(String) -> Fut,
+        Fut: Future<Output = Result<T, String>>,
+    {
+        let mut errors = Vec::new();
+        for endpoint in endpoints {
+            match attempt(endpoint.clone()).await {
+                Ok(value) => return Ok((endpoint.clone(), value)),
+                Err(e) => {
+                    log_warn(&format!("RPC endpoint {} failed: {}", endpoint, e));
+                    errors.push(format!("{}: {}", endpoint, e));
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Err("No RPC endpoint configured".to_string())
         } else {
-            NetworkComparison::Equal
+            Err(errors.join("; "))
+        }
+    }
+}
+
+/// 自定义端点连接测试状态
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum EndpointTestStatus {
+    /// 尚未测试
+    #[default]
+    Idle,
+    /// 正在测试
+    Testing,
+    /// 连接成功，返回链 ID
+    Connected { chain_id: String },
+    /// 连接失败，包含具体错误
+    Failed(String),
+}
+
+impl EndpointTestStatus {
+    /// 将测试结果转换为界面状态
+    pub fn from_result(result: Result<String, String>) -> Self {
+        match result {
+            Ok(chain_id) if chain_id.trim().is_empty() => {
+                EndpointTestStatus::Failed("Empty chain identifier".to_string())
+            }
+            Ok(chain_id) => EndpointTestStatus::Connected { chain_id: chain_id.trim().to_string() },
+            Err(error) => EndpointTestStatus::Failed(error),
+        }
+    }
+
+    /// 根据链 ID 识别已知网络
+    pub fn known_network(&self) -> Option<&'static str> {
+        match self {
+            EndpointTestStatus::Connected { chain_id } => match chain_id.as_str() {
+                "35834a8a" => Some("Mainnet"),
+                "4c78adac" => Some("Testnet"),
+                _ => None,
+            },
+            _ => None,
         }
     }
 }
@@ -257,10 +592,338 @@ pub enum NetworkHealthStatus {
     Unknown,
 }
 
-/// 网络比较结果
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum NetworkComparison {
-    FirstBetter,
-    SecondBetter,
-    Equal,
-}
\ No newline at end of file
+/// 网络健康检查状态（用于连接指示器）
+#[derive(Debug, Clone)]
+pub struct NetworkHealthState {
+    /// 最近一次检查结果
+    pub status: NetworkHealthStatus,
+    /// 是否正在检查
+    pub checking: bool,
+    /// 最近一次发起检查的时间
+    pub last_checked: Option<Instant>,
+    /// 当前检查是否为启动时的检查（失败时需要提醒用户）
+    pub startup_check: bool,
+}
+
+impl NetworkHealthState {
+    pub fn new() -> Self {
+        Self {
+            status: NetworkHealthStatus::Unknown,
+            checking: false,
+            last_checked: None,
+            startup_check: false,
+        }
+    }
+
+    /// 是否允许发起新的检查（防抖，避免频繁请求）
+    pub fn can_refresh(&self) -> bool {
+        !self.checking
+            && self
+                .last_checked
+                .is_none_or(|at| at.elapsed() >= HEALTH_CHECK_DEBOUNCE)
+    }
+
+    /// 标记开始检查
+    pub fn begin_check(&mut self) {
+        self.checking = true;
+        self.last_checked = Some(Instant::now());
+    }
+
+    /// 标记开始启动时检查
+    pub fn begin_startup_check(&mut self) {
+        self.begin_check();
+        self.startup_check = true;
+    }
+
+    /// 应用检查结果，返回是否需要提醒用户端点不可达
+    pub fn apply_result(&mut self, status: NetworkHealthStatus) -> bool {
+        let warn = Self::should_warn_unreachable(self.startup_check, status);
+        self.status = status;
+        self.checking = false;
+        self.startup_check = false;
+        warn
+    }
+
+    /// 取消进行中的检查，保留上一次的结果
+    pub fn cancel_check(&mut self) {
+        self.checking = false;
+        self.startup_check = false;
+    }
+
+    /// 判断是否需要提醒：仅启动检查发现端点不可达时提醒，手动检查由指示器显示
+    pub fn should_warn_unreachable(startup_check: bool, status: NetworkHealthStatus) -> bool {
+        startup_check && status == NetworkHealthStatus::Unhealthy
+    }
+
+    /// 切换网络后重置状态
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl Default for NetworkHealthState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADDRESS: &str = "0x0000000000000000000000000000000000000000000000000000000000000a11";
+
+    #[test]
+    fn networks_map_to_i18n_keys() {
+        assert_eq!(Network::Devnet.i18n_key(), "devnet");
+        assert_eq!(Network::Testnet.i18n_key(), "testnet");
+        assert_eq!(Network::Mainnet.i18n_key(), "mainnet");
+        let custom = Network::Custom { name: "Local".to_string(), url: "http://127.0.0.1:9000".to_string() };
+        assert_eq!(custom.i18n_key(), "custom_network");
+
+        let i18n = crate::i18n::I18nManager::default();
+        for network in Network::all().into_iter().chain(std::iter::once(custom)) {
+            assert_ne!(i18n.tr(network.i18n_key()), network.i18n_key(), "missing translation for {:?}", network);
+        }
+    }
+
+    fn local() -> Network {
+        Network::custom("Local", "http://127.0.0.1:9000")
+    }
+
+    #[test]
+    fn parses_network_aliases_case_insensitively() {
+        for (input, expected) in [
+            ("devnet", Network::Devnet),
+            ("dev", Network::Devnet),
+            ("DEVNET", Network::Devnet),
+            ("testnet", Network::Testnet),
+            ("Test", Network::Testnet),
+            ("mainnet", Network::Mainnet),
+            ("MAIN", Network::Mainnet),
+        ] {
+            assert_eq!(Network::from_str(input), Some(expected), "input {:?}", input);
+        }
+        for input in ["", "localnet", "main net", " mainnet", "custom"] {
+            assert_eq!(Network::from_str(input), None, "input {:?}", input);
+        }
+    }
+
+    #[test]
+    fn builtin_networks_have_fixed_urls_and_custom_uses_its_own() {
+        assert_eq!(Network::Devnet.url(), "https://fullnode.devnet.sui.io:443");
+        assert_eq!(Network::Testnet.url(), "https://fullnode.testnet.sui.io:443");
+        assert_eq!(Network::Mainnet.url(), "https://fullnode.mainnet.sui.io:443");
+        assert_eq!(local().url(), "http://127.0.0.1:9000");
+        assert_eq!(local().name(), "Local");
+        assert_eq!(local().to_string(), "Local");
+        assert_eq!(Network::all(), vec![Network::Devnet, Network::Testnet, Network::Mainnet]);
+    }
+
+    #[test]
+    fn classifies_mainnet_and_test_networks() {
+        assert!(Network::Mainnet.is_mainnet());
+        assert!(!Network::Mainnet.is_testnet());
+        assert!(Network::Testnet.is_testnet() && Network::Devnet.is_testnet());
+        assert!(!Network::Testnet.is_mainnet() && !Network::Devnet.is_mainnet());
+        // 自定义网络既不算生产网络也不算测试网络
+        assert!(!local().is_mainnet() && !local().is_testnet());
+        assert!(local().is_custom() && !Network::Mainnet.is_custom());
+    }
+
+    #[test]
+    fn colors_and_fees_per_network() {
+        assert_eq!(Network::Devnet.color(), NetworkColor::Blue);
+        assert_eq!(Network::Testnet.color(), NetworkColor::Yellow);
+        assert_eq!(Network::Mainnet.color(), NetworkColor::Green);
+        assert_eq!(local().color(), NetworkColor::Gray);
+
+        assert_eq!(Network::Mainnet.estimated_tx_fee_mist(), 1_000_000);
+        assert_eq!(Network::Devnet.estimated_tx_fee_mist(), 100_000);
+    }
+
+    #[test]
+    fn default_explorer_urls_for_each_network() {
+        let provider = ExplorerProvider::default();
+        assert_eq!(provider.home_url(&Network::Devnet).as_deref(), Some("https://suiscan.xyz/devnet"));
+        assert_eq!(provider.home_url(&Network::Testnet).as_deref(), Some("https://suiscan.xyz/testnet"));
+        assert_eq!(provider.home_url(&Network::Mainnet).as_deref(), Some("https://suiscan.xyz/mainnet"));
+        for network in Network::all() {
+            let slug = network.explorer_slug().unwrap();
+            assert_eq!(
+                provider.address_url(&network, ADDRESS),
+                Some(format!("https://suiscan.xyz/{}/account/{}", slug, ADDRESS))
+            );
+        }
+
+        // 自定义网络没有公共浏览器
+        assert_eq!(local().explorer_slug(), None);
+        assert_eq!(provider.home_url(&local()), None);
+        assert_eq!(provider.address_url(&local(), ADDRESS), None);
+    }
+
+    #[test]
+    fn explorer_providers_build_network_specific_urls() {
+        assert_eq!(ExplorerProvider::SuiVision.home_url(&Network::Mainnet).as_deref(), Some("https://suivision.xyz"));
+        assert_eq!(
+            ExplorerProvider::SuiVision.home_url(&Network::Testnet).as_deref(),
+            Some("https://testnet.suivision.xyz")
+        );
+        assert_eq!(ExplorerProvider::SuiExplorer.home_url(&Network::Mainnet).as_deref(), Some("https://suiexplorer.com"));
+        assert_eq!(
+            ExplorerProvider::SuiExplorer.address_url(&Network::Devnet, ADDRESS),
+            Some(format!("https://suiexplorer.com/address/{}?network=devnet", ADDRESS))
+        );
+    }
+
+    #[test]
+    fn explorer_links_require_valid_input() {
+        let provider = ExplorerProvider::Suiscan;
+        assert_eq!(provider.address_url(&Network::Testnet, ""), None);
+        assert_eq!(provider.address_url(&Network::Testnet, "0xnot-an-address"), None);
+        assert_eq!(
+            provider.address_url(&Network::Testnet, ADDRESS),
+            Some(format!("https://suiscan.xyz/testnet/account/{}", ADDRESS))
+        );
+        assert_eq!(provider.transaction_url(&Network::Testnet, ""), None);
+    }
+
+    #[test]
+    fn interprets_endpoint_test_results() {
+        let connected = EndpointTestStatus::from_result(Ok("35834a8a".to_string()));
+        assert_eq!(connected, EndpointTestStatus::Connected { chain_id: "35834a8a".to_string() });
+        assert_eq!(connected.known_network(), Some("Mainnet"));
+
+        let local = EndpointTestStatus::from_result(Ok("deadbeef\n".to_string()));
+        assert_eq!(local, EndpointTestStatus::Connected { chain_id: "deadbeef".to_string() });
+        assert_eq!(local.known_network(), None);
+
+        assert_eq!(
+            EndpointTestStatus::from_result(Err("connection refused".to_string())),
+            EndpointTestStatus::Failed("connection refused".to_string())
+        );
+        assert!(matches!(EndpointTestStatus::from_result(Ok(String::new())), EndpointTestStatus::Failed(_)));
+    }
+
+    #[test]
+    fn low_balance_threshold_depends_on_network() {
+        // Mainnet：100 笔 × 0.001 SUI = 0.1 SUI；测试网络：100 笔 × 0.0001 SUI = 0.01 SUI
+        assert_eq!(Network::Mainnet.low_balance_threshold(), Balance::from_mist(100_000_000));
+        assert_eq!(Network::Testnet.low_balance_threshold(), Balance::from_mist(10_000_000));
+        assert_eq!(Network::Devnet.low_balance_threshold(), Balance::from_mist(10_000_000));
+
+        // Devnet 上 0.05 SUI 足够，Mainnet 上则偏低
+        let balance = Balance::from_mist(50_000_000);
+        assert!(balance >= Network::Devnet.low_balance_threshold());
+        assert!(balance < Network::Mainnet.low_balance_threshold());
+    }
+
+    #[test]
+    fn tries_endpoints_in_order_until_one_succeeds() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let endpoints = vec!["https://a".to_string(), "https://b".to_string(), "https://c".to_string()];
+
+        let mut attempted = Vec::new();
+        let result = rt.block_on(NetworkUtils::try_endpoints_in_order(&endpoints, |url| {
+            attempted.push(url.clone());
+            async move {
+                match url.as_str() {
+                    "https://a" => Err("connection refused".to_string()),
+                    _ => Ok(url.len()),
+                }
+            }
+        }));
+        assert_eq!(result, Ok(("https://b".to_string(), 9)));
+        // 成功后不再尝试后面的端点
+        assert_eq!(attempted, vec!["https://a", "https://b"]);
+
+        let all_failed = rt.block_on(NetworkUtils::try_endpoints_in_order(&endpoints, |url| async move {
+            Err::<(), _>(format!("{} down", url))
+        }));
+        let error = all_failed.unwrap_err();
+        assert!(error.contains("https://a") && error.contains("https://c"));
+    }
+
+    #[test]
+    fn fallback_endpoints_follow_primary_without_duplicates() {
+        let fallbacks = RpcFallbackSettings {
+            testnet: vec![
+                "https://rpc.example.org".to_string(),
+                Network::Testnet.url().to_string(),
+                "not a url".to_string(),
+            ],
+            ..RpcFallbackSettings::default()
+        };
+        assert_eq!(
+            fallbacks.endpoints(&Network::Testnet),
+            vec![Network::Testnet.url().to_string(), "https://rpc.example.org".to_string()]
+        );
+        assert_eq!(fallbacks.endpoints(&Network::Mainnet), vec![Network::Mainnet.url().to_string()]);
+    }
+
+    fn probe(url: &str, healthy: bool, latency_ms: u64) -> EndpointProbe {
+        EndpointProbe {
+            url: url.to_string(),
+            healthy,
+            latency: Duration::from_millis(latency_ms),
+        }
+    }
+
+    #[test]
+    fn selects_lowest_latency_healthy_endpoint() {
+        let probes = [
+            probe("https://primary", true, 900),
+            probe("https://fast-but-down", false, 50),
+            probe("https://backup-a", true, 200),
+            probe("https://backup-b", true, 200),
+        ];
+        assert_eq!(EndpointProbe::select_best(&probes).map(|p| p.url.as_str()), Some("https://backup-a"));
+        assert_eq!(EndpointProbe::select_best(&[probe("https://primary", false, 10)]), None);
+        assert_eq!(EndpointProbe::select_best(&[]), None);
+    }
+
+    #[test]
+    fn auto_selected_endpoint_is_tried_first() {
+        let fallbacks = RpcFallbackSettings {
+            testnet: vec!["https://backup.example.org".to_string()],
+            ..RpcFallbackSettings::default()
+        };
+        let mut config = NetworkConfig::new();
+        config.switch_to(Network::Testnet);
+        config.apply_probes(&Network::Testnet, &[
+            probe(Network::Testnet.url(), true, 500),
+            probe("https://backup.example.org", true, 100),
+        ]);
+
+        // 未开启自动选择时保持配置顺序
+        assert_eq!(config.active_endpoint_for(&Network::Testnet, &fallbacks), Network::Testnet.url());
+        // 自动切换网络不影响端点顺序
+        config.enable_auto_switch();
+        assert_eq!(config.active_endpoint_for(&Network::Testnet, &fallbacks), Network::Testnet.url());
+        config.auto_select_endpoint = true;
+        assert_eq!(config.active_endpoint_for(&Network::Testnet, &fallbacks), "https://backup.example.org");
+
+        config.switch_to(Network::Mainnet);
+        assert_eq!(config.active_endpoint, None);
+    }
+
+    #[test]
+    fn parses_fallback_urls_per_line() {
+        let input = " https://rpc-a.example.com \n\nhttp://127.0.0.1:9000\nhttps://rpc-a.example.com\n";
+        assert_eq!(
+            RpcFallbackSettings::parse_urls(input),
+            Ok(vec!["https://rpc-a.example.com".to_string(), "http://127.0.0.1:9000".to_string()])
+        );
+        assert_eq!(RpcFallbackSettings::parse_urls(""), Ok(Vec::new()));
+        assert_eq!(
+            RpcFallbackSettings::parse_urls("https://ok.example.com\nrpc.example.com\nftp://x"),
+            Err(vec!["rpc.example.com".to_string(), "ftp://x".to_string()])
+        );
+
+        let mut fallbacks = RpcFallbackSettings::default();
+        fallbacks.set_for_network(&Network::Testnet, vec!["https://rpc-a.example.com".to_string()]);
+        fallbacks.set_for_network(&Network::custom("Local".to_string(), "http://127.0.0.1:9000".to_string()), vec!["https://ignored.example.com".to_string()]);
+        assert_eq!(fallbacks.for_network(&Network::Testnet), ["https://rpc-a.example.com".to_string()]);
+        assert!(fallbacks.devnet.is_empty() && fallbacks.mainnet.is_empty());
+    }
+}
@@ -0,0 +1,139 @@
+use std::collections::BTreeMap;
+use tokio::task::AbortHandle;
+
+/// 在后台执行的异步操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AsyncOperation {
+    /// 刷新当前网络的余额
+    RefreshBalance,
+    /// 查询当前地址在其他网络上的余额
+    CheckOtherNetworks,
+    /// 查询助记词预览中各账户的余额
+    MnemonicPreview,
+    /// 检查当前 RPC 端点是否可达
+    HealthCheck,
+    /// 测试自定义网络端点
+    EndpointTest,
+    /// 签名并提交转账
+    SendTransfer,
+}
+
+impl AsyncOperation {
+    /// 获取操作名称的翻译键
+    pub fn i18n_key(&self) -> &'static str {
+        match self {
+            AsyncOperation::RefreshBalance => "operation_refresh_balance",
+            AsyncOperation::CheckOtherNetworks => "operation_check_other_networks",
+            AsyncOperation::MnemonicPreview => "operation_mnemonic_preview",
+            AsyncOperation::HealthCheck => "operation_health_check",
+            AsyncOperation::EndpointTest => "operation_endpoint_test",
+            AsyncOperation::SendTransfer => "operation_send_transfer",
+        }
+    }
+
+    /// 进行中时是否禁止其他会改变钱包、账户或余额的操作（例如发送、导入）
+    pub fn blocks_wallet_actions(&self) -> bool {
+        matches!(self, AsyncOperation::RefreshBalance | AsyncOperation::SendTransfer)
+    }
+
+    /// 是否可以取消（已提交的转账无法撤回）
+    pub fn is_cancellable(&self) -> bool {
+        !matches!(self, AsyncOperation::SendTransfer)
+    }
+}
+
+/// 进行中的异步操作集合，界面据此显示正在执行的操作并禁用冲突的操作
+/// 每个操作记录其后台任务的中止句柄，取消时中止这些任务
+#[derive(Debug, Clone, Default)]
+pub struct InFlightOperations {
+    operations: BTreeMap<AsyncOperation, Vec<AbortHandle>>,
+}
+
+impl InFlightOperations {
+    /// 记录操作的后台任务，取消操作时中止该任务（操作未开始时同时开始）
+    pub fn track(&mut self, operation: AsyncOperation, task: AbortHandle) {
+        self.operations.entry(operation).or_default().push(task);
+    }
+
+    /// 标记操作结束，返回该操作之前是否在进行
+    pub fn finish(&mut self, operation: AsyncOperation) -> bool {
+        self.operations.remove(&operation).is_some()
+    }
+
+    /// 取消操作并中止其后台任务，返回该操作之前是否在进行
+    pub fn cancel(&mut self, operation: AsyncOperation) -> bool {
+        match self.operations.remove(&operation) {
+            Some(tasks) => {
+                tasks.iter().for_each(AbortHandle::abort);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn is_running(&self, operation: AsyncOperation) -> bool {
+        self.operations.contains_key(&operation)
+    }
+
+    /// 没有进行中的操作
+    pub fn is_idle(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    /// 是否有进行中的操作需要禁止钱包相关操作
+    pub fn blocks_wallet_actions(&self) -> bool {
+        self.operations.keys().any(AsyncOperation::blocks_wallet_actions)
+    }
+
+    /// 进行中的操作（按固定顺序）
+    pub fn iter(&self) -> impl Iterator<Item = AsyncOperation> + '_ {
+        self.operations.keys().copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn track_and_finish_update_in_flight_set() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let mut operations = InFlightOperations::default();
+        assert!(operations.is_idle());
+
+        operations.track(AsyncOperation::HealthCheck, rt.spawn(std::future::pending::<()>()).abort_handle());
+        operations.track(AsyncOperation::RefreshBalance, rt.spawn(std::future::pending::<()>()).abort_handle());
+        // 同一操作的多个任务只记录一次操作
+        operations.track(AsyncOperation::RefreshBalance, rt.spawn(std::future::pending::<()>()).abort_handle());
+        assert!(operations.is_running(AsyncOperation::RefreshBalance));
+        assert!(operations.blocks_wallet_actions());
+        assert_eq!(
+            operations.iter().collect::<Vec<_>>(),
+            vec![AsyncOperation::RefreshBalance, AsyncOperation::HealthCheck]
+        );
+
+        assert!(operations.finish(AsyncOperation::RefreshBalance));
+        assert!(!operations.finish(AsyncOperation::RefreshBalance));
+        assert!(!operations.is_running(AsyncOperation::RefreshBalance));
+        // 健康检查仍在进行，但不阻止钱包操作
+        assert!(!operations.blocks_wallet_actions());
+        assert!(!operations.is_idle());
+
+        operations.finish(AsyncOperation::HealthCheck);
+        assert!(operations.is_idle());
+    }
+
+    #[test]
+    fn cancel_aborts_tracked_tasks() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let task = rt.spawn(std::future::pending::<()>());
+        let mut operations = InFlightOperations::default();
+        operations.track(AsyncOperation::RefreshBalance, task.abort_handle());
+        assert!(operations.is_running(AsyncOperation::RefreshBalance));
+
+        assert!(operations.cancel(AsyncOperation::RefreshBalance));
+        assert!(operations.is_idle());
+        assert!(rt.block_on(task).unwrap_err().is_cancelled());
+        assert!(!operations.cancel(AsyncOperation::RefreshBalance));
+    }
+}
@@ -0,0 +1,83 @@
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use shared_crypto::intent::{Intent, IntentMessage, PersonalMessage};
+use sui_sdk::types::{
+    base_types::SuiAddress,
+    crypto::{Signature, SuiKeyPair, SuiSignature},
+};
+
+/// 自检挑战消息的前缀
+const CHALLENGE_PREFIX: &str = "Sui Rust Wallet ownership check";
+/// 挑战中随机数的字节数
+const CHALLENGE_NONCE_BYTES: usize = 16;
+
+/// 地址所有权自检：用已加载的私钥签名随机挑战，再验证签名确实属于该地址
+/// 签名使用 Sui 个人消息（PersonalMessage）意图，不可能被当作交易提交
+pub struct OwnershipProof;
+
+impl OwnershipProof {
+    /// 生成包含地址和随机数的挑战
+    pub fn challenge(address: &SuiAddress) -> Vec<u8> {
+        let mut nonce = [0u8; CHALLENGE_NONCE_BYTES];
+        OsRng.fill_bytes(&mut nonce);
+        let nonce_hex: String = nonce.iter().map(|b| format!("{:02x}", b)).collect();
+        format!("{}\naddress: {}\nnonce: {}", CHALLENGE_PREFIX, address, nonce_hex).into_bytes()
+    }
+
+    fn intent_message(challenge: &[u8]) -> IntentMessage<PersonalMessage> {
+        IntentMessage::new(
+            Intent::personal_message(),
+            PersonalMessage { message: challenge.to_vec() },
+        )
+    }
+
+    /// 用私钥签名挑战
+    pub fn sign(keypair: &SuiKeyPair, challenge: &[u8]) -> Signature {
+        Signature::new_secure(&Self::intent_message(challenge), keypair)
+    }
+
+    /// 验证签名是否由指定地址对应的私钥生成
+    pub fn verify(signature: &Signature, challenge: &[u8], address: SuiAddress) -> bool {
+        signature
+            .verify_secure(&Self::intent_message(challenge), address, signature.scheme())
+            .is_ok()
+    }
+
+    /// 完整的自检：生成挑战、签名并验证
+    pub fn check(keypair: &SuiKeyPair, address: SuiAddress) -> bool {
+        let challenge = Self::challenge(&address);
+        Self::verify(&Self::sign(keypair, &challenge), &challenge, address)
+    }
+}
+
+/// 最近一次所有权自检的结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OwnershipCheck {
+    pub address: SuiAddress,
+    pub verified: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::WalletUtils;
+
+    /// 测试用 Ed25519 私钥（字节 0x01..=0x20）
+    const TEST_KEY: &str = "suiprivkey1qqqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0jqa4ffsr";
+
+    #[test]
+    fn signed_challenge_proves_ownership_only_for_matching_address() {
+        let (address, keypair) = WalletUtils::import_private_key(TEST_KEY).unwrap();
+        assert!(OwnershipProof::check(&keypair, address));
+
+        let challenge = OwnershipProof::challenge(&address);
+        let signature = OwnershipProof::sign(&keypair, &challenge);
+        assert!(OwnershipProof::verify(&signature, &challenge, address));
+
+        // 其他地址、被篡改的挑战都无法通过验证
+        let other = SuiAddress::from_bytes([9; 32]).unwrap();
+        assert!(!OwnershipProof::check(&keypair, other));
+        assert!(!OwnershipProof::verify(&signature, &challenge, other));
+        assert!(!OwnershipProof::verify(&signature, b"tampered", address));
+        assert_ne!(OwnershipProof::challenge(&address), challenge);
+    }
+}
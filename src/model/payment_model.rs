@@ -0,0 +1,101 @@
+use std::str::FromStr;
+use sui_sdk::types::base_types::SuiAddress;
+use super::WalletUtils;
+
+/// 支付 URI 的协议前缀
+pub const PAYMENT_URI_SCHEME: &str = "sui:";
+
+/// 支付 URI 的解析错误
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaymentUriError {
+    /// 不是 `sui:` 开头的支付 URI
+    NotPaymentUri,
+    /// 地址无效
+    InvalidAddress,
+    /// `amount` 不是有效的 MIST 整数
+    InvalidAmount,
+}
+
+impl PaymentUriError {
+    /// 获取错误的翻译键
+    pub fn i18n_key(&self) -> &'static str {
+        match self {
+            PaymentUriError::NotPaymentUri => "invalid_recipient",
+            PaymentUriError::InvalidAddress => "payment_uri_invalid_address",
+            PaymentUriError::InvalidAmount => "payment_uri_invalid_amount",
+        }
+    }
+}
+
+/// 支付 URI：`sui:<地址>?amount=<MIST>`，与收款二维码的格式一致
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaymentUri {
+    pub address: SuiAddress,
+    /// 请求的金额（MIST），URI 中未指定时为 None
+    pub amount_mist: Option<u64>,
+}
+
+impl PaymentUri {
+    /// 输入是否为支付 URI（协议前缀不区分大小写）
+    pub fn is_payment_uri(input: &str) -> bool {
+        Self::strip_scheme(&WalletUtils::normalize_input(input)).is_some()
+    }
+
+    fn strip_scheme(input: &str) -> Option<&str> {
+        let prefix = input.get(..PAYMENT_URI_SCHEME.len())?;
+        prefix
+            .eq_ignore_ascii_case(PAYMENT_URI_SCHEME)
+            .then(|| &input[PAYMENT_URI_SCHEME.len()..])
+    }
+
+    /// 解析支付 URI，忽略未知的查询参数
+    pub fn parse(input: &str) -> Result<Self, PaymentUriError> {
+        let input = WalletUtils::normalize_input(input);
+        let rest = Self::strip_scheme(&input).ok_or(PaymentUriError::NotPaymentUri)?;
+        let (address, query) = rest.split_once('?').unwrap_or((rest, ""));
+
+        if !WalletUtils::is_valid_address_format(address) {
+            return Err(PaymentUriError::InvalidAddress);
+        }
+        let address = SuiAddress::from_str(address).map_err(|_| PaymentUriError::InvalidAddress)?;
+
+        let mut amount_mist = None;
+        for (key, value) in query.split('&').filter_map(|pair| pair.split_once('=')) {
+            if key == "amount" {
+                amount_mist = Some(value.parse::<u64>().map_err(|_| PaymentUriError::InvalidAmount)?);
+            }
+        }
+
+        Ok(Self { address, amount_mist })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADDRESS: &str = "0xa2d14fad60c56049ecf75246a481934691214ce413e6a8ae2fe6834c173a6133";
+
+    #[test]
+    fn parses_address_and_amount_ignoring_unknown_params() {
+        let uri = format!("sui:{}?label=coffee&amount=1500000000&memo=x", ADDRESS);
+        assert!(PaymentUri::is_payment_uri(&uri));
+        let parsed = PaymentUri::parse(&uri).unwrap();
+        assert_eq!(parsed.address, SuiAddress::from_str(ADDRESS).unwrap());
+        assert_eq!(parsed.amount_mist, Some(1_500_000_000));
+
+        let no_amount = PaymentUri::parse(&format!("SUI:{}", ADDRESS)).unwrap();
+        assert_eq!(no_amount.amount_mist, None);
+    }
+
+    #[test]
+    fn rejects_malformed_uris() {
+        assert_eq!(PaymentUri::parse(ADDRESS), Err(PaymentUriError::NotPaymentUri));
+        assert!(!PaymentUri::is_payment_uri(ADDRESS));
+        assert_eq!(PaymentUri::parse("sui:0x123?amount=1"), Err(PaymentUriError::InvalidAddress));
+        assert_eq!(
+            PaymentUri::parse(&format!("sui:{}?amount=1.5", ADDRESS)),
+            Err(PaymentUriError::InvalidAmount)
+        );
+    }
+}
@@ -0,0 +1,59 @@
+use std::time::{Duration, Instant};
+
+/// SUI 美元价格的查询地址（CoinGecko 简单价格接口，无需 API 密钥）
+pub const SUI_PRICE_URL: &str = "https://api.coingecko.com/api/v3/simple/price?ids=sui&vs_currencies=usd";
+/// 价格刷新间隔
+pub const PRICE_REFRESH_INTERVAL: Duration = Duration::from_secs(300);
+/// 单次价格查询的超时时间
+pub const PRICE_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// SUI 美元价格的查询状态（价格本身保存在 `AppState::sui_price_usd`）
+#[derive(Debug, Clone, Default)]
+pub struct PriceFeed {
+    /// 是否有进行中的查询
+    pub fetching: bool,
+    /// 最近一次发起查询的时间
+    pub last_fetch: Option<Instant>,
+}
+
+impl PriceFeed {
+    /// 是否到了重新查询价格的时间
+    pub fn should_fetch(&self, now: Instant) -> bool {
+        !self.fetching
+            && self
+                .last_fetch
+                .is_none_or(|at| now.saturating_duration_since(at) >= PRICE_REFRESH_INTERVAL)
+    }
+
+    /// 从 CoinGecko 的响应 `{"sui":{"usd":1.23}}` 中取出价格，价格无效时为 None
+    pub fn parse_response(body: &serde_json::Value) -> Option<f64> {
+        body.get("sui")?
+            .get("usd")?
+            .as_f64()
+            .filter(|price| price.is_finite() && *price > 0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn price_is_read_from_the_response_and_refreshed_periodically() {
+        assert_eq!(PriceFeed::parse_response(&json!({"sui": {"usd": 3.21}})), Some(3.21));
+        assert_eq!(PriceFeed::parse_response(&json!({"sui": {"usd": 0}})), None);
+        assert_eq!(PriceFeed::parse_response(&json!({"sui": {}})), None);
+        assert_eq!(PriceFeed::parse_response(&json!({"error": "rate limited"})), None);
+
+        let now = Instant::now();
+        let mut feed = PriceFeed::default();
+        assert!(feed.should_fetch(now));
+        feed.fetching = true;
+        feed.last_fetch = Some(now);
+        assert!(!feed.should_fetch(now + PRICE_REFRESH_INTERVAL));
+        feed.fetching = false;
+        assert!(!feed.should_fetch(now + Duration::from_secs(1)));
+        assert!(feed.should_fetch(now + PRICE_REFRESH_INTERVAL));
+    }
+}
@@ -0,0 +1,622 @@
+use std::fmt;
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard, TryLockError, Weak};
+use aes_gcm::aead::{rand_core::RngCore, OsRng as AesOsRng};
+use base64::{Engine as _, engine::general_purpose};
+use sui_sdk::types::crypto::SuiKeyPair;
+use zeroize::{Zeroize, Zeroizing};
+use super::{crash_reports_enabled, log_error, mask_secrets, try_log_error, try_recent_log_lines, wallet_config_dir, CrashReport};
+
+/// 操作系统钥匙串中的服务名
+pub(crate) const KEYRING_SERVICE: &str = "sui_rust_wallet";
+/// 会话密码钥匙串条目的锁文件目录
+const KEYRING_SESSION_DIR: &str = "keyring_sessions";
+
+/// 清除保存敏感数据的字符串
+/// 开启内存保护时先将整个缓冲区（包括未使用的容量）覆盖为 0，再清空
+pub fn wipe_secret(buffer: &mut String, memory_protection: bool) {
+    if memory_protection {
+        buffer.zeroize();
+    } else {
+        buffer.clear();
+    }
+}
+
+
+/// panic 时仍能找到并清除的敏感字符串登记表
+/// 只保存弱引用，条目释放后自动失效
+pub struct PanicWipeRegistry {
+    slots: Mutex<Vec<Weak<dyn PanicWipe>>>,
+}
+
+/// panic 时可以清除的敏感数据
+pub trait PanicWipe: Send + Sync {
+    /// 立即清除内容；数据正被占用时跳过并返回 false（panic hook 中不能阻塞）
+    fn try_wipe(&self) -> bool;
+}
+
+impl PanicWipe for Mutex<String> {
+    fn try_wipe(&self) -> bool {
+        match try_lock_for_wipe(self) {
+            Some(mut secret) => {
+                secret.zeroize();
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl PanicWipe for Mutex<Option<SuiKeyPair>> {
+    // 私钥对象在释放时由 fastcrypto 自行清零，置空即可释放
+    fn try_wipe(&self) -> bool {
+        match try_lock_for_wipe(self) {
+            Some(mut keypair) => {
+                *keypair = None;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// 进程内唯一的登记表，由 panic hook 使用
+static PANIC_WIPE_REGISTRY: PanicWipeRegistry = PanicWipeRegistry::new();
+
+/// 获取锁；panic 时不能阻塞，锁被占用时返回 None，锁中毒时仍然取得数据
+fn try_lock_for_wipe<T>(mutex: &Mutex<T>) -> Option<MutexGuard<'_, T>> {
+    match mutex.try_lock() {
+        Ok(guard) => Some(guard),
+        Err(TryLockError::Poisoned(poisoned)) => Some(poisoned.into_inner()),
+        Err(TryLockError::WouldBlock) => None,
+    }
+}
+
+impl PanicWipeRegistry {
+    pub const fn new() -> Self {
+        Self { slots: Mutex::new(Vec::new()) }
+    }
+
+    /// 登记一项敏感数据，返回共享的存储位置
+    pub fn register<T: PanicWipe + 'static>(&self, secret: T) -> Arc<T> {
+        let slot = Arc::new(secret);
+        let weak: Weak<dyn PanicWipe> = Arc::downgrade(&slot) as Weak<dyn PanicWipe>;
+        let mut slots = self.slots.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        slots.retain(|weak| weak.strong_count() > 0);
+        slots.push(weak);
+        slot
+    }
+
+    /// 清除所有仍存活的敏感数据（字符串覆盖为 0，私钥对象释放），返回清除的数量
+    /// 在 panic hook 中调用，不会阻塞：正被其他线程持有的条目会被跳过
+    pub fn wipe_all(&self) -> usize {
+        let Some(slots) = try_lock_for_wipe(&self.slots) else {
+            return 0;
+        };
+        slots
+            .iter()
+            .filter_map(Weak::upgrade)
+            .filter(|slot| slot.try_wipe())
+            .count()
+    }
+}
+
+impl Default for PanicWipeRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 登记到全局表的敏感字符串，panic 时由 hook 清除，正常释放时同样清除
+pub struct PanicWipeSecret {
+    slot: Arc<Mutex<String>>,
+    memory_protection: bool,
+}
+
+impl PanicWipeSecret {
+    pub fn new(secret: String, memory_protection: bool) -> Self {
+        Self {
+            slot: PANIC_WIPE_REGISTRY.register(Mutex::new(secret)),
+            memory_protection,
+        }
+    }
+
+    /// 读取内容的副本
+    pub fn get(&self) -> String {
+        self.lock().clone()
+    }
+
+    /// 直接访问内容（供输入框原地编辑），持有期间 panic hook 会跳过该条目
+    pub fn lock(&self) -> MutexGuard<'_, String> {
+        self.slot.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// 清除旧内容后替换为新内容
+    pub fn set(&self, secret: String) {
+        let mut current = self.lock();
+        wipe_secret(&mut current, self.memory_protection);
+        *current = secret;
+    }
+}
+
+impl Default for PanicWipeSecret {
+    fn default() -> Self {
+        Self::new(String::new(), true)
+    }
+}
+
+impl fmt::Debug for PanicWipeSecret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PanicWipeSecret([REDACTED])")
+    }
+}
+
+impl Clone for PanicWipeSecret {
+    // 复制内容并单独登记，两个副本互不影响
+    fn clone(&self) -> Self {
+        Self::new(self.get(), self.memory_protection)
+    }
+}
+
+impl Drop for PanicWipeSecret {
+    fn drop(&mut self) {
+        let mut secret = self.slot.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        wipe_secret(&mut secret, self.memory_protection);
+    }
+}
+
+/// 登记到全局表的私钥对象，panic 时由 hook 释放（释放时清零）
+pub struct PanicWipeKeyPair {
+    slot: Arc<Mutex<Option<SuiKeyPair>>>,
+}
+
+impl PanicWipeKeyPair {
+    pub fn new(keypair: SuiKeyPair) -> Self {
+        Self { slot: PANIC_WIPE_REGISTRY.register(Mutex::new(Some(keypair))) }
+    }
+
+    /// 使用私钥对象；已被 panic hook 清除时返回 None
+    pub fn with<R>(&self, f: impl FnOnce(&SuiKeyPair) -> R) -> Option<R> {
+        self.slot
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .as_ref()
+            .map(f)
+    }
+}
+
+impl fmt::Debug for PanicWipeKeyPair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PanicWipeKeyPair([REDACTED])")
+    }
+}
+
+/// 清除 panic 登记表中的所有敏感数据（会话密码及其钥匙串条目、私钥输入、已加载的私钥）
+pub fn wipe_registered_secrets() -> usize {
+    PANIC_WIPE_REGISTRY.wipe_all()
+}
+
+/// 安装 panic hook：先清除登记的敏感数据（会话密码、私钥输入、已加载的私钥），
+/// 再将经过脱敏的 panic 消息写入日志，最后调用原有的 hook
+pub fn install_panic_hook() {
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let wiped = wipe_registered_secrets();
+
+        let payload = info
+            .payload()
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| info.payload().downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "Box<dyn Any>".to_string());
+        let location = info
+            .location()
+            .map(|l| format!("{}:{}", l.file(), l.line()))
+            .unwrap_or_else(|| "unknown location".to_string());
+        let message = format!(
+            "panic at {}: {} (wiped {} secret(s))",
+            location,
+            mask_secrets(&payload),
+            wiped
+        );
+        try_log_error(&message);
+        if crash_reports_enabled() {
+            write_crash_report(&message);
+        }
+
+        // 原有的 hook 会再次输出 panic 消息，只在调试构建中保留，避免发布版本输出未脱敏的内容
+        if cfg!(debug_assertions) {
+            previous(info);
+        }
+    }));
+}
+
+/// 写入本地崩溃报告并输出其路径，下次启动时也会提示该路径
+fn write_crash_report(message: &str) {
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let log_lines = try_recent_log_lines().unwrap_or_default();
+    match CrashReport::new(timestamp, message, &log_lines).write_to(&wallet_config_dir()) {
+        Ok(path) => try_log_error(&format!("Crash report written to {}", path.display())),
+        Err(e) => try_log_error(&format!("Failed to write crash report: {}", e)),
+    }
+}
+
+/// 会话密码的存储后端
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SessionSecretBackend {
+    /// 操作系统钥匙串（macOS Keychain、Windows 凭据管理器、Linux 内核密钥环）
+    Keyring,
+    /// 进程内存
+    InMemory,
+}
+
+impl SessionSecretBackend {
+    /// 选择存储后端：仅在用户启用且钥匙串可用时使用钥匙串，否则回退到内存
+    pub fn select(prefer_keyring: bool, keyring_available: bool) -> Self {
+        if prefer_keyring && keyring_available {
+            SessionSecretBackend::Keyring
+        } else {
+            SessionSecretBackend::InMemory
+        }
+    }
+}
+
+/// 钥匙串中的会话密码条目，panic 时由 hook 删除
+struct KeyringSessionEntry {
+    id: String,
+}
+
+impl PanicWipe for KeyringSessionEntry {
+    fn try_wipe(&self) -> bool {
+        delete_session_entry(&self.id)
+    }
+}
+
+/// 会话密码存储
+/// 钥匙串模式下会话密码只保存在操作系统钥匙串中，每次使用时读取，进程内存中不保留副本。
+/// 每个实例使用独立的钥匙串条目，并对配置目录中同名的锁文件持有排他锁；
+/// 进程退出后锁由系统释放，下次启动时只清理这类条目，不会删除仍在运行的实例的条目
+pub struct SessionSecret {
+    backend: SessionSecretBackend,
+    memory: Option<PanicWipeSecret>,
+    /// 本实例钥匙串条目的随机 ID
+    keyring_id: String,
+    /// 钥匙串条目锁文件所在目录
+    lock_dir: PathBuf,
+    /// 已写入钥匙串时登记到 panic 清除表（崩溃时删除条目），并持有锁文件表明所有者仍在运行
+    keyring_entry: Option<(Arc<KeyringSessionEntry>, File)>,
+    /// 清除或释放时是否覆盖内存中的副本
+    memory_protection: bool,
+}
+
+impl SessionSecret {
+    /// 创建会话密码存储，钥匙串条目的锁文件保存在指定配置目录下
+    pub fn new(prefer_keyring: bool, cfg_dir: &Path) -> Self {
+        let mut id = [0u8; 16];
+        AesOsRng.fill_bytes(&mut id);
+        let mut secret = Self {
+            backend: SessionSecretBackend::InMemory,
+            memory: None,
+            keyring_id: general_purpose::URL_SAFE_NO_PAD.encode(id),
+            lock_dir: cfg_dir.join(KEYRING_SESSION_DIR),
+            keyring_entry: None,
+            memory_protection: true,
+        };
+        secret.set_prefer_keyring(prefer_keyring);
+        secret
+    }
+
+    /// 设置是否在清除时覆盖内存中的副本
+    pub fn set_memory_protection(&mut self, enabled: bool) {
+        self.memory_protection = enabled;
+        if let Some(memory) = &mut self.memory {
+            memory.memory_protection = enabled;
+        }
+    }
+
+    /// 当前使用的存储后端
+    pub fn backend(&self) -> SessionSecretBackend {
+        self.backend
+    }
+
+    /// 切换存储后端，已保存的会话密码会迁移到新后端
+    pub fn set_prefer_keyring(&mut self, prefer_keyring: bool) {
+        let available = prefer_keyring && self.keyring_available();
+        let backend = SessionSecretBackend::select(prefer_keyring, available);
+        if backend == self.backend {
+            return;
+        }
+
+        let current = self.get();
+        self.clear();
+        self.backend = backend;
+        if let Some(mut secret) = current {
+            self.set(std::mem::take(&mut *secret));
+        }
+    }
+
+    /// 保存会话密码，钥匙串写入失败时回退到内存
+    pub fn set(&mut self, secret: String) {
+        if self.backend == SessionSecretBackend::Keyring {
+            match self.store_in_keyring(&secret) {
+                Ok(()) => {
+                    // 钥匙串模式下不保留内存副本
+                    let mut secret = secret;
+                    wipe_secret(&mut secret, self.memory_protection);
+                    return;
+                }
+                Err(e) => {
+                    log_error(&format!("OS keyring unavailable, keeping session password in memory: {}", e));
+                    self.backend = SessionSecretBackend::InMemory;
+                }
+            }
+        }
+        self.wipe_memory();
+        // 登记到 panic 清除表，崩溃时也能清除内存中的副本
+        self.memory = Some(PanicWipeSecret::new(secret, self.memory_protection));
+    }
+
+    /// 写入本实例的钥匙串条目，首次写入前先取得锁文件
+    fn store_in_keyring(&mut self, secret: &str) -> Result<(), String> {
+        if self.keyring_entry.is_none() {
+            let lock = self.lock_keyring_entry()?;
+            let entry = PANIC_WIPE_REGISTRY.register(KeyringSessionEntry { id: self.keyring_id.clone() });
+            self.keyring_entry = Some((entry, lock));
+        }
+        session_entry(&self.keyring_id)
+            .and_then(|entry| entry.set_password(secret))
+            .map_err(|e| e.to_string())
+    }
+
+    /// 创建并锁定本实例的锁文件，进程存活期间一直持有
+    fn lock_keyring_entry(&self) -> Result<File, String> {
+        fs::create_dir_all(&self.lock_dir)
+            .map_err(|e| format!("Failed to create directory: {}", e))?;
+        let lock = File::create(lock_path(&self.lock_dir, &self.keyring_id))
+            .map_err(|e| format!("Failed to create keyring lock file: {}", e))?;
+        lock.try_lock()
+            .map_err(|e| format!("Failed to lock keyring lock file: {}", e))?;
+        Ok(lock)
+    }
+
+    /// 清除内存中的副本（释放时覆盖）
+    fn wipe_memory(&mut self) {
+        self.memory = None;
+    }
+
+    /// 读取会话密码，钥匙串模式下每次从钥匙串读取；返回的副本释放时清零
+    pub fn get(&self) -> Option<Zeroizing<String>> {
+        match self.backend {
+            SessionSecretBackend::Keyring => {
+                self.keyring_entry.as_ref()?;
+                match session_entry(&self.keyring_id).and_then(|entry| entry.get_password()) {
+                    Ok(secret) => Some(Zeroizing::new(secret)),
+                    Err(keyring::Error::NoEntry) => None,
+                    Err(e) => {
+                        log_error(&format!("Failed to read session password from OS keyring: {}", e));
+                        None
+                    }
+                }
+            }
+            SessionSecretBackend::InMemory => self.memory.as_ref().map(|secret| Zeroizing::new(secret.get())),
+        }
+    }
+
+    /// 清除会话密码
+    pub fn clear(&mut self) {
+        self.wipe_memory();
+        // 删除失败时保留锁文件，释放锁后由下次启动时清理
+        if self.keyring_entry.take().is_some() && delete_session_entry(&self.keyring_id) {
+            remove_lock_file(&self.lock_dir, &self.keyring_id);
+        }
+    }
+
+    /// 启动时删除上次运行残留的钥匙串条目（例如进程被强制结束时）
+    /// 只处理锁文件未被持有、即所有者已退出的条目
+    pub fn clear_stale_keyring_entries(cfg_dir: &Path) {
+        let lock_dir = cfg_dir.join(KEYRING_SESSION_DIR);
+        for (id, _lock) in stale_keyring_entries(&lock_dir) {
+            if delete_session_entry(&id) {
+                remove_lock_file(&lock_dir, &id);
+            }
+        }
+    }
+
+    /// 检查操作系统钥匙串是否可用：实际读取一次条目，只创建条目对象不会访问钥匙串
+    fn keyring_available(&self) -> bool {
+        match session_entry(&self.keyring_id).and_then(|entry| entry.get_password()) {
+            Ok(mut existing) => {
+                existing.zeroize();
+                true
+            }
+            Err(keyring::Error::NoEntry) => true,
+            Err(e) => {
+                log_error(&format!("OS keyring probe failed: {}", e));
+                false
+            }
+        }
+    }
+}
+
+/// 会话密码的钥匙串条目，每个实例使用独立的条目
+fn session_entry(id: &str) -> keyring::Result<keyring::Entry> {
+    keyring::Entry::new(KEYRING_SERVICE, &format!("session_password_{}", id))
+}
+
+/// 删除会话密码的钥匙串条目，条目不存在也视为成功
+fn delete_session_entry(id: &str) -> bool {
+    match session_entry(id).and_then(|entry| entry.delete_credential()) {
+        Ok(()) | Err(keyring::Error::NoEntry) => true,
+        Err(e) => {
+            try_log_error(&format!("Failed to delete session password from OS keyring: {}", e));
+            false
+        }
+    }
+}
+
+/// 钥匙串条目对应的锁文件
+fn lock_path(lock_dir: &Path, id: &str) -> PathBuf {
+    lock_dir.join(format!("{}.lock", id))
+}
+
+/// 删除钥匙串条目对应的锁文件
+fn remove_lock_file(lock_dir: &Path, id: &str) {
+    if let Err(e) = fs::remove_file(lock_path(lock_dir, id)) {
+        log_error(&format!("Failed to delete keyring lock file: {}", e));
+    }
+}
+
+/// 找出所有者已退出（锁文件可以加锁）的钥匙串条目
+/// 返回条目 ID 及取得的锁，清理完成前一直持有
+fn stale_keyring_entries(lock_dir: &Path) -> Vec<(String, File)> {
+    let Ok(entries) = fs::read_dir(lock_dir) else {
+        return Vec::new();
+    };
+    entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let path = entry.path();
+            if path.extension()? != "lock" {
+                return None;
+            }
+            let id = path.file_stem()?.to_str()?.to_string();
+            let lock = File::open(&path).ok()?;
+            lock.try_lock().ok()?;
+            Some((id, lock))
+        })
+        .collect()
+}
+
+impl Drop for SessionSecret {
+    // 释放时同时删除钥匙串中的条目，不让会话密码在进程结束后残留
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl fmt::Debug for SessionSecret {
+    // 不输出会话密码
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SessionSecret")
+            .field("backend", &self.backend)
+            .field("has_memory_copy", &self.memory.is_some())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{use_test_keyring, TestDir};
+
+    #[test]
+    fn selects_keyring_only_when_enabled_and_available() {
+        assert_eq!(SessionSecretBackend::select(true, true), SessionSecretBackend::Keyring);
+        assert_eq!(SessionSecretBackend::select(true, false), SessionSecretBackend::InMemory);
+        assert_eq!(SessionSecretBackend::select(false, true), SessionSecretBackend::InMemory);
+        assert_eq!(SessionSecretBackend::select(false, false), SessionSecretBackend::InMemory);
+    }
+
+    #[test]
+    fn wipe_zeroizes_buffer_when_memory_protection_is_on() {
+        let mut secret = String::with_capacity(32);
+        secret.push_str("correct horse battery staple");
+
+        wipe_secret(&mut secret, true);
+
+        // zeroize 保留原缓冲区（先覆盖为 0 再清空），不会重新分配
+        assert!(secret.is_empty());
+        assert!(secret.capacity() >= 28);
+    }
+
+    #[test]
+    fn wipe_still_clears_when_memory_protection_is_off() {
+        let mut secret = "hunter2".to_string();
+        wipe_secret(&mut secret, false);
+        assert!(secret.is_empty());
+    }
+
+    #[test]
+    fn in_memory_backend_round_trips_and_clears() {
+        let dir = TestDir::new("in_memory_session_secret");
+        let mut secret = SessionSecret::new(false, &dir);
+        assert_eq!(secret.backend(), SessionSecretBackend::InMemory);
+        assert_eq!(secret.get(), None);
+
+        secret.set("hunter2".to_string());
+        assert_eq!(secret.get().as_deref().map(String::as_str), Some("hunter2"));
+
+        secret.clear();
+        assert_eq!(secret.get(), None);
+    }
+
+    #[test]
+    fn keyring_entries_are_per_instance_and_only_stale_ones_are_swept() {
+        use_test_keyring();
+        let dir = TestDir::new("keyring_session_entries");
+        let mut first = SessionSecret::new(true, &dir);
+        let second = {
+            let mut second = SessionSecret::new(true, &dir);
+            second.set("second".to_string());
+            second
+        };
+        assert_eq!(first.backend(), SessionSecretBackend::Keyring);
+        first.set("first".to_string());
+
+        // 进程内存中不保留副本，两个实例的条目互不覆盖
+        assert!(first.memory.is_none());
+        assert_eq!(first.get().as_deref().map(String::as_str), Some("first"));
+        assert_eq!(second.get().as_deref().map(String::as_str), Some("second"));
+
+        // 已退出的实例只留下未被持有的锁文件和钥匙串条目
+        let lock_dir = dir.join(KEYRING_SESSION_DIR);
+        let exited = format!("exited_{}", std::process::id());
+        fs::write(lock_path(&lock_dir, &exited), "").unwrap();
+        session_entry(&exited).unwrap().set_password("stale").unwrap();
+
+        SessionSecret::clear_stale_keyring_entries(&dir);
+
+        assert!(matches!(session_entry(&exited).unwrap().get_password(), Err(keyring::Error::NoEntry)));
+        assert!(!lock_path(&lock_dir, &exited).exists());
+        assert_eq!(first.get().as_deref().map(String::as_str), Some("first"));
+        assert_eq!(second.get().as_deref().map(String::as_str), Some("second"));
+
+        first.clear();
+        assert_eq!(first.get(), None);
+        assert!(!lock_path(&lock_dir, &first.keyring_id).exists());
+        assert_eq!(second.get().as_deref().map(String::as_str), Some("second"));
+    }
+
+    #[test]
+    fn panic_wipe_routine_clears_registered_buffers() {
+        let registry = PanicWipeRegistry::new();
+        let mut secret = String::with_capacity(32);
+        secret.push_str("correct horse battery staple");
+        let slot = registry.register(Mutex::new(secret));
+        let released = registry.register(Mutex::new("released".to_string()));
+        drop(released);
+
+        assert_eq!(registry.wipe_all(), 1);
+
+        let secret = slot.lock().unwrap();
+        assert!(secret.is_empty());
+        assert!(secret.capacity() >= 28);
+    }
+
+    #[test]
+    fn panic_wipe_routine_releases_registered_keypairs() {
+        let registry = PanicWipeRegistry::new();
+        let (_, keypair) = super::super::WalletUtils::import_private_key(
+            "suiprivkey1qqqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0jqa4ffsr",
+        )
+        .unwrap();
+        let slot = registry.register(Mutex::new(Some(keypair)));
+
+        assert_eq!(registry.wipe_all(), 1);
+        assert!(slot.lock().unwrap().is_none());
+    }
+}
@@ -0,0 +1,380 @@
+use std::{fs, path::{Path, PathBuf}, time::{Duration, SystemTime, UNIX_EPOCH}};
+use aes_gcm::{
+    aead::{rand_core::RngCore, Aead, AeadCore, KeyInit, OsRng as AesOsRng},
+    Aes256Gcm, Key, Nonce,
+};
+use base64::{Engine as _, engine::general_purpose};
+use serde::{Deserialize, Serialize};
+use super::{log_error, wallet_config_dir, write_file_atomic, KEYRING_SERVICE};
+
+/// device.key 文件中钥匙串引用的前缀
+const DEVICE_KEY_KEYRING_REF: &str = "keyring:";
+
+/// 会话令牌内容（加密后保存）
+#[derive(Debug, Serialize, Deserialize)]
+struct SessionTokenPayload {
+    /// 会话密码，用于自动登录后解密私钥
+    password: String,
+    /// 过期时间（Unix 秒）
+    expires_at: u64,
+}
+
+/// "保持登录"会话令牌存储
+/// 令牌使用保存在操作系统钥匙串中的设备密钥加密，任何缺失、过期或篡改都会拒绝自动登录
+#[derive(Debug, Clone)]
+pub struct SessionTokenStore {
+    token_file: PathBuf,
+    device_key_file: PathBuf,
+}
+
+impl SessionTokenStore {
+    /// 在指定目录下创建令牌存储
+    pub fn new(dir: &Path) -> Self {
+        Self {
+            token_file: dir.join("session.token"),
+            device_key_file: dir.join("device.key"),
+        }
+    }
+
+    /// 在默认配置目录下创建令牌存储
+    pub fn default_location() -> Self {
+        Self::new(&wallet_config_dir())
+    }
+
+    /// 创建会话令牌，在 `valid_for` 时间内有效；操作系统钥匙串不可用时拒绝创建
+    pub fn create(&self, password: &str, valid_for: Duration) -> Result<(), String> {
+        let payload = SessionTokenPayload {
+            password: password.to_string(),
+            expires_at: unix_now().saturating_add(valid_for.as_secs()),
+        };
+        let plaintext = serde_json::to_vec(&payload)
+            .map_err(|e| format!("Failed to serialize session token: {}", e))?;
+
+        let cipher = Aes256Gcm::new(&self.create_device_key()?);
+        let nonce = Aes256Gcm::generate_nonce(&mut AesOsRng);
+        let ciphertext = cipher.encrypt(&nonce, plaintext.as_slice())
+            .map_err(|e| format!("Encryption failed: {}", e))?;
+
+        let mut data = Vec::with_capacity(nonce.len() + ciphertext.len());
+        data.extend_from_slice(&nonce);
+        data.extend_from_slice(&ciphertext);
+
+        write_file_atomic(&self.token_file, general_purpose::STANDARD.encode(data).as_bytes())
+            .map_err(|e| format!("Failed to write session token: {}", e))
+    }
+
+    /// 加载会话令牌，返回会话密码
+    /// 令牌不存在或已过期返回 `Ok(None)`；令牌被篡改，或过期时间比现在晚 `max_valid_for` 以上
+    /// （有效期超过当前设置，或系统时钟被回拨）返回错误。这些情况都会删除令牌
+    pub fn load(&self, max_valid_for: Duration) -> Result<Option<String>, String> {
+        if !self.token_file.exists() {
+            return Ok(None);
+        }
+
+        let now = unix_now();
+        match self.decrypt_token() {
+            Ok(payload) if payload.expires_at > now.saturating_add(max_valid_for.as_secs()) => {
+                self.clear()?;
+                Err("Session token outlives the allowed stay signed in period".to_string())
+            }
+            Ok(payload) if payload.expires_at > now => Ok(Some(payload.password)),
+            Ok(_) => {
+                self.clear()?;
+                Ok(None)
+            }
+            Err(e) => {
+                self.clear()?;
+                Err(e)
+            }
+        }
+    }
+
+    /// 删除会话令牌和对应的设备密钥
+    pub fn clear(&self) -> Result<(), String> {
+        if self.token_file.exists() {
+            fs::remove_file(&self.token_file)
+                .map_err(|e| format!("Failed to delete session token: {}", e))?;
+        }
+        self.clear_device_key()
+    }
+
+    /// 解密并解析令牌
+    fn decrypt_token(&self) -> Result<SessionTokenPayload, String> {
+        // 设备密钥缺失时无法验证令牌，按失败处理
+        let key = self.load_device_key()?.ok_or("Device key missing")?;
+
+        let encoded = fs::read_to_string(&self.token_file)
+            .map_err(|e| format!("Failed to read session token: {}", e))?;
+        let data = general_purpose::STANDARD.decode(encoded.trim())
+            .map_err(|e| format!("Failed to decode session token: {}", e))?;
+        if data.len() < 28 {
+            return Err("Invalid session token format".to_string());
+        }
+
+        let (nonce_bytes, ciphertext) = data.split_at(12);
+        let cipher = Aes256Gcm::new(&key);
+        let plaintext = cipher.decrypt(Nonce::from_slice(nonce_bytes), ciphertext)
+            .map_err(|_| "Session token has been tampered with".to_string())?;
+
+        serde_json::from_slice(&plaintext)
+            .map_err(|e| format!("Invalid session token payload: {}", e))
+    }
+
+    /// 读取设备密钥的保存位置
+    fn read_device_key_ref(&self) -> Result<Option<DeviceKeyRef>, String> {
+        if !self.device_key_file.exists() {
+            return Ok(None);
+        }
+
+        let contents = fs::read_to_string(&self.device_key_file)
+            .map_err(|e| format!("Failed to read device key: {}", e))?;
+        Ok(Some(DeviceKeyRef::parse(&contents)))
+    }
+
+    /// 读取设备密钥
+    fn load_device_key(&self) -> Result<Option<Key<Aes256Gcm>>, String> {
+        let encoded = match self.read_device_key_ref()? {
+            None => return Ok(None),
+            Some(DeviceKeyRef::File(encoded)) => encoded,
+            Some(DeviceKeyRef::Keyring(id)) => match device_key_entry(&id).and_then(|entry| entry.get_password()) {
+                Ok(encoded) => encoded,
+                Err(keyring::Error::NoEntry) => return Ok(None),
+                Err(e) => return Err(format!("Failed to read device key from OS keyring: {}", e)),
+            },
+        };
+
+        let bytes = general_purpose::STANDARD.decode(encoded.trim())
+            .map_err(|e| format!("Failed to decode device key: {}", e))?;
+        if bytes.len() != 32 {
+            return Err("Invalid device key length".to_string());
+        }
+
+        Ok(Some(*Key::<Aes256Gcm>::from_slice(&bytes)))
+    }
+
+    /// 为新令牌生成设备密钥，替换旧的密钥
+    /// 密钥保存在操作系统钥匙串中，磁盘上只保留条目引用；钥匙串不可用时不创建密钥，
+    /// 避免密钥和令牌同时保存在配置目录中
+    fn create_device_key(&self) -> Result<Key<Aes256Gcm>, String> {
+        self.clear_device_key()?;
+
+        if let Some(parent) = self.device_key_file.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create directory: {}", e))?;
+        }
+
+        let key = Aes256Gcm::generate_key(&mut AesOsRng);
+        let encoded = general_purpose::STANDARD.encode(key);
+        let mut id = [0u8; 16];
+        AesOsRng.fill_bytes(&mut id);
+        let id = general_purpose::URL_SAFE_NO_PAD.encode(id);
+
+        device_key_entry(&id)
+            .and_then(|entry| entry.set_password(&encoded))
+            .map_err(|e| format!("OS keyring unavailable, cannot store session device key: {}", e))?;
+        let device_key_ref = DeviceKeyRef::Keyring(id);
+
+        write_file_atomic(&self.device_key_file, device_key_ref.to_file_contents().as_bytes())
+            .map_err(|e| format!("Failed to write device key: {}", e))?;
+        restrict_to_owner(&self.device_key_file);
+
+        Ok(key)
+    }
+
+    /// 删除设备密钥（包括钥匙串中的条目）
+    fn clear_device_key(&self) -> Result<(), String> {
+        if let Ok(Some(DeviceKeyRef::Keyring(id))) = self.read_device_key_ref() {
+            match device_key_entry(&id).and_then(|entry| entry.delete_credential()) {
+                Ok(()) | Err(keyring::Error::NoEntry) => {}
+                Err(e) => log_error(&format!("Failed to delete session device key from OS keyring: {}", e)),
+            }
+        }
+
+        if self.device_key_file.exists() {
+            fs::remove_file(&self.device_key_file)
+                .map_err(|e| format!("Failed to delete device key: {}", e))?;
+        }
+        Ok(())
+    }
+}
+
+/// device.key 文件记录的设备密钥位置
+#[derive(Debug, PartialEq)]
+enum DeviceKeyRef {
+    /// 密钥保存在操作系统钥匙串中，文件只保存条目 ID
+    Keyring(String),
+    /// 旧版本直接保存在文件中的密钥（Base64），只用于读取
+    File(String),
+}
+
+impl DeviceKeyRef {
+    /// 解析 device.key 文件内容，没有钥匙串引用前缀的视为直接保存的密钥（兼容旧版本）
+    fn parse(contents: &str) -> Self {
+        let contents = contents.trim();
+        match contents.strip_prefix(DEVICE_KEY_KEYRING_REF) {
+            Some(id) => DeviceKeyRef::Keyring(id.to_string()),
+            None => DeviceKeyRef::File(contents.to_string()),
+        }
+    }
+
+    /// 写入 device.key 文件的内容
+    fn to_file_contents(&self) -> String {
+        match self {
+            DeviceKeyRef::Keyring(id) => format!("{}{}", DEVICE_KEY_KEYRING_REF, id),
+            DeviceKeyRef::File(encoded) => encoded.clone(),
+        }
+    }
+}
+
+/// 设备密钥的钥匙串条目，每个令牌使用独立的条目
+fn device_key_entry(id: &str) -> keyring::Result<keyring::Entry> {
+    keyring::Entry::new(KEYRING_SERVICE, &format!("session_device_key_{}", id))
+}
+
+impl Default for SessionTokenStore {
+    fn default() -> Self {
+        Self::default_location()
+    }
+}
+
+/// 当前 Unix 时间（秒）
+pub(crate) fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// 超过此时长的相对时间视为时钟异常，显示为"未知"
+pub const MAX_RELATIVE_AGE: Duration = Duration::from_secs(10 * 365 * 86_400);
+
+/// 从 `timestamp` 到 `now`（均为 Unix 秒）经过的时间，容忍系统时钟调整：
+/// 时间戳在未来（时钟回拨或不同设备的时钟偏差）时视为刚刚发生；
+/// 任一时间未知（为 0）或相差超过 `MAX_RELATIVE_AGE` 时返回 None
+pub fn wall_clock_elapsed(timestamp: u64, now: u64) -> Option<Duration> {
+    if timestamp == 0 || now == 0 {
+        return None;
+    }
+    let elapsed = Duration::from_secs(now.saturating_sub(timestamp));
+    (elapsed <= MAX_RELATIVE_AGE).then_some(elapsed)
+}
+
+/// 将文件权限限制为仅所有者可读写
+#[cfg(unix)]
+fn restrict_to_owner(path: &Path) {
+    use std::os::unix::fs::PermissionsExt;
+    if let Err(e) = fs::set_permissions(path, fs::Permissions::from_mode(0o600)) {
+        log_error(&format!("Failed to restrict permissions on {}: {}", path.display(), e));
+    }
+}
+
+#[cfg(not(unix))]
+fn restrict_to_owner(_path: &Path) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{use_test_keyring, TestDir};
+
+    const HOUR: Duration = Duration::from_secs(3600);
+
+    #[test]
+    fn rejects_tokens_valid_longer_than_the_stay_signed_in_period() {
+        use_test_keyring();
+        let dir = TestDir::new("rejects_tokens_valid_longer_than_the_stay_signed_in_period");
+        let store = SessionTokenStore::new(&dir);
+
+        store.create("password", 24 * HOUR).unwrap();
+        assert_eq!(store.load(24 * HOUR), Ok(Some("password".to_string())));
+
+        // 设置改短后，按原有效期创建的令牌不再被接受
+        assert!(store.load(8 * HOUR).is_err());
+        assert!(!store.token_file.exists());
+
+        // 超长有效期不会溢出成已过期的令牌
+        store.create("password", Duration::MAX).unwrap();
+        assert!(store.load(24 * HOUR).is_err());
+    }
+
+    #[test]
+    fn created_token_resumes_the_session_until_cleared() {
+        use_test_keyring();
+        let dir = TestDir::new("created_token_resumes_the_session_until_cleared");
+        let store = SessionTokenStore::new(&dir);
+        assert_eq!(store.load(HOUR), Ok(None));
+
+        store.create("correct horse", HOUR).unwrap();
+        assert!(store.token_file.exists());
+        // 令牌中不包含明文密码
+        assert!(!fs::read_to_string(&store.token_file).unwrap().contains("correct horse"));
+        assert_eq!(store.load(HOUR), Ok(Some("correct horse".to_string())));
+
+        store.clear().unwrap();
+        assert!(!store.token_file.exists());
+        assert!(!store.device_key_file.exists());
+        assert_eq!(store.load(HOUR), Ok(None));
+    }
+
+    #[test]
+    fn expired_token_is_rejected_and_removed() {
+        use_test_keyring();
+        let dir = TestDir::new("expired_token_is_rejected_and_removed");
+        let store = SessionTokenStore::new(&dir);
+
+        store.create("correct horse", Duration::ZERO).unwrap();
+        assert_eq!(store.load(HOUR), Ok(None));
+        assert!(!store.token_file.exists());
+    }
+
+    #[test]
+    fn tampered_token_or_missing_device_key_fails_closed() {
+        use_test_keyring();
+        let dir = TestDir::new("tampered_token_or_missing_device_key_fails_closed");
+        let store = SessionTokenStore::new(&dir);
+
+        store.create("correct horse", HOUR).unwrap();
+        let encoded = fs::read_to_string(&store.token_file).unwrap();
+        let mut data = general_purpose::STANDARD.decode(encoded.trim()).unwrap();
+        let last = data.len() - 1;
+        data[last] ^= 0x01;
+        fs::write(&store.token_file, general_purpose::STANDARD.encode(&data)).unwrap();
+        assert!(store.load(HOUR).is_err());
+        assert!(!store.token_file.exists());
+
+        fs::write(&store.token_file, "not base64!").unwrap();
+        assert!(store.load(HOUR).is_err());
+        assert!(!store.token_file.exists());
+
+        // 设备密钥被删除后无法解密令牌
+        store.create("correct horse", HOUR).unwrap();
+        store.clear_device_key().unwrap();
+        assert!(store.load(HOUR).is_err());
+        assert!(!store.token_file.exists());
+    }
+
+    #[test]
+    fn device_key_file_holds_either_a_keyring_reference_or_the_key() {
+        let reference = DeviceKeyRef::Keyring("abc123".to_string());
+        assert_eq!(reference.to_file_contents(), "keyring:abc123");
+        assert_eq!(DeviceKeyRef::parse("keyring:abc123\n"), reference);
+
+        // 旧版本直接保存在文件中的密钥仍可读取
+        let encoded = general_purpose::STANDARD.encode([7u8; 32]);
+        assert_eq!(DeviceKeyRef::parse(&encoded), DeviceKeyRef::File(encoded.clone()));
+
+        use_test_keyring();
+        let dir = TestDir::new("device_key_file_holds_either_a_keyring_reference_or_the_key");
+        let store = SessionTokenStore::new(&dir);
+        fs::write(&store.device_key_file, &encoded).unwrap();
+        assert_eq!(store.load_device_key(), Ok(Some(*Key::<Aes256Gcm>::from_slice(&[7u8; 32]))));
+
+        // 新密钥只保存在钥匙串中，磁盘上只保留引用
+        store.create("correct horse", HOUR).unwrap();
+        let Some(DeviceKeyRef::Keyring(id)) = store.read_device_key_ref().unwrap() else {
+            panic!("device key should be stored in the keyring");
+        };
+        assert!(device_key_entry(&id).and_then(|entry| entry.get_password()).is_ok());
+        store.clear().unwrap();
+        assert!(matches!(device_key_entry(&id).and_then(|entry| entry.get_password()), Err(keyring::Error::NoEntry)));
+    }
+}
@@ -0,0 +1,212 @@
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+
+/// 状态消息显示的最大字符数，超出部分以省略号代替（完整内容可通过提示或复制查看）
+pub const STATUS_DISPLAY_MAX_CHARS: usize = 200;
+
+/// 按字符截断文本，超出 `max_chars` 时保留前面的部分并加上省略号（结果共 `max_chars` 个字符）
+/// 按字符而非字节截断，不会切断多字节字符
+pub fn truncate_with_ellipsis(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    let kept: String = text.chars().take(max_chars.saturating_sub(1)).collect();
+    format!("{}…", kept)
+}
+
+/// 状态消息的类型，决定显示的颜色和图标
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusKind {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl StatusKind {
+    /// 获取显示用的图标
+    pub fn icon(&self) -> &'static str {
+        match self {
+            StatusKind::Info => "ℹ️",
+            StatusKind::Success => "✅",
+            StatusKind::Warning => "⚠️",
+            StatusKind::Error => "❌",
+        }
+    }
+}
+
+/// 临时状态消息（操作结果、错误、进度提示）
+/// 与余额等持久状态分开保存，视图按类型显示，无需解析文本
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusMessage {
+    pub kind: StatusKind,
+    pub text: String,
+    /// 底层错误的完整内容（如 RPC 返回的错误），复制时优先使用
+    pub detail: Option<String>,
+    /// 消息产生的时间
+    pub at: Instant,
+}
+
+impl StatusMessage {
+    /// 创建指定类型的状态消息
+    pub fn new(kind: StatusKind, text: impl Into<String>) -> Self {
+        Self {
+            kind,
+            text: text.into(),
+            detail: None,
+            at: Instant::now(),
+        }
+    }
+
+    pub fn info(text: impl Into<String>) -> Self {
+        Self::new(StatusKind::Info, text)
+    }
+
+    pub fn success(text: impl Into<String>) -> Self {
+        Self::new(StatusKind::Success, text)
+    }
+
+    pub fn warning(text: impl Into<String>) -> Self {
+        Self::new(StatusKind::Warning, text)
+    }
+
+    pub fn error(text: impl Into<String>) -> Self {
+        Self::new(StatusKind::Error, text)
+    }
+
+    /// 附加底层错误的完整内容
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+
+    /// 复制到剪贴板的内容：有底层错误时为完整的底层错误，否则为完整的消息文本（不截断）
+    pub fn copy_text(&self) -> &str {
+        self.detail.as_deref().unwrap_or(&self.text)
+    }
+
+    /// 用于显示的文本，过长时截断；完整内容仍保存在 `text` 中
+    pub fn display_text(&self) -> String {
+        truncate_with_ellipsis(&self.text, STATUS_DISPLAY_MAX_CHARS)
+    }
+
+    /// 显示时是否被截断
+    pub fn is_truncated(&self) -> bool {
+        self.text.chars().count() > STATUS_DISPLAY_MAX_CHARS
+    }
+
+    /// 消息已显示的时长
+    pub fn age(&self) -> Duration {
+        self.at.elapsed()
+    }
+
+    /// 按配置的显示时长判断现在是否应自动关闭
+    pub fn is_expired(&self, ttl: &StatusTtlSettings) -> bool {
+        ttl.should_dismiss(self.kind, self.age())
+    }
+}
+
+/// 各类型状态消息的自动关闭时间（秒），None 表示一直显示到被新消息替换或用户下一次操作
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StatusTtlSettings {
+    pub info_seconds: Option<u64>,
+    pub success_seconds: Option<u64>,
+    pub warning_seconds: Option<u64>,
+    pub error_seconds: Option<u64>,
+}
+
+impl StatusTtlSettings {
+    /// 指定类型的显示时长
+    pub fn ttl(&self, kind: StatusKind) -> Option<Duration> {
+        let seconds = match kind {
+            StatusKind::Info => self.info_seconds,
+            StatusKind::Success => self.success_seconds,
+            StatusKind::Warning => self.warning_seconds,
+            StatusKind::Error => self.error_seconds,
+        };
+        seconds.map(Duration::from_secs)
+    }
+
+    /// 指定类型、已显示指定时长的消息现在是否应关闭
+    pub fn should_dismiss(&self, kind: StatusKind, age: Duration) -> bool {
+        self.ttl(kind).is_some_and(|ttl| age >= ttl)
+    }
+}
+
+impl Default for StatusTtlSettings {
+    fn default() -> Self {
+        Self {
+            info_seconds: Some(5),
+            success_seconds: Some(5),
+            warning_seconds: Some(10),
+            // 错误一直显示，直到用户下一次操作
+            error_seconds: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transient_messages_are_dismissed_after_their_ttl() {
+        let ttl = StatusTtlSettings::default();
+        assert!(!ttl.should_dismiss(StatusKind::Info, Duration::from_secs(4)));
+        assert!(ttl.should_dismiss(StatusKind::Info, Duration::from_secs(5)));
+        assert!(ttl.should_dismiss(StatusKind::Success, Duration::from_secs(6)));
+        assert!(!ttl.should_dismiss(StatusKind::Warning, Duration::from_secs(6)));
+        assert!(ttl.should_dismiss(StatusKind::Warning, Duration::from_secs(10)));
+    }
+
+    #[test]
+    fn errors_persist_by_default() {
+        let ttl = StatusTtlSettings::default();
+        assert!(!ttl.should_dismiss(StatusKind::Error, Duration::from_secs(3600)));
+    }
+
+    #[test]
+    fn ttl_is_configurable_per_kind() {
+        let ttl = StatusTtlSettings {
+            info_seconds: None,
+            error_seconds: Some(30),
+            ..StatusTtlSettings::default()
+        };
+        assert!(!ttl.should_dismiss(StatusKind::Info, Duration::from_secs(3600)));
+        assert!(ttl.should_dismiss(StatusKind::Error, Duration::from_secs(30)));
+        assert!(!ttl.should_dismiss(StatusKind::Error, Duration::from_secs(29)));
+    }
+
+    #[test]
+    fn truncates_long_text_and_keeps_full_text_for_copy() {
+        let long = "x".repeat(1_000);
+        let status = StatusMessage::error(long.clone());
+        let shown = status.display_text();
+        assert!(status.is_truncated());
+        assert_eq!(shown.chars().count(), STATUS_DISPLAY_MAX_CHARS);
+        assert!(shown.ends_with('…'));
+        assert_eq!(status.text, long);
+
+        let short = StatusMessage::info("done");
+        assert!(!short.is_truncated());
+        assert_eq!(short.display_text(), "done");
+    }
+
+    #[test]
+    fn truncation_respects_multibyte_characters() {
+        assert_eq!(truncate_with_ellipsis("余额查询失败", 4), "余额查…");
+        assert_eq!(truncate_with_ellipsis("余额", 2), "余额");
+    }
+
+    #[test]
+    fn copy_text_is_the_full_underlying_error() {
+        let error = format!("RPC error: {}", "timeout ".repeat(100));
+        let status = StatusMessage::error(format!("Async error: {}", error)).with_detail(error.clone());
+        assert!(status.is_truncated());
+        assert_eq!(status.copy_text(), error);
+
+        let plain = StatusMessage::error("x".repeat(300));
+        assert_eq!(plain.copy_text(), plain.text);
+    }
+}
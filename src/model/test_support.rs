@@ -0,0 +1,104 @@
+use std::any::Any;
+use std::collections::HashMap;
+use std::fs;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard, Once};
+use keyring::credential::{Credential, CredentialApi, CredentialBuilderApi, CredentialPersistence};
+
+/// 测试用的临时目录：创建时清空旧内容并新建，释放时删除（测试失败 panic 时同样删除）
+pub struct TestDir {
+    path: PathBuf,
+}
+
+impl TestDir {
+    /// 在系统临时目录下创建 `sui_rust_wallet_<name>_<进程 ID>`
+    pub fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("sui_rust_wallet_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).expect("failed to create test directory");
+        Self { path }
+    }
+}
+
+impl Deref for TestDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for TestDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+/// 测试钥匙串中的条目，按 (服务名, 条目名) 保存
+type TestKeyringEntries = HashMap<(String, String), Vec<u8>>;
+
+/// 进程内共享的测试钥匙串
+static TEST_KEYRING: Mutex<Option<TestKeyringEntries>> = Mutex::new(None);
+
+/// 测试用的钥匙串条目：同名条目在进程内共享，不访问操作系统钥匙串
+#[derive(Debug)]
+struct TestCredential {
+    key: (String, String),
+}
+
+impl TestCredential {
+    fn with_store<R>(f: impl FnOnce(&mut TestKeyringEntries) -> R) -> R {
+        let mut store = TEST_KEYRING.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        f(store.get_or_insert_with(HashMap::new))
+    }
+}
+
+impl CredentialApi for TestCredential {
+    fn set_secret(&self, secret: &[u8]) -> keyring::Result<()> {
+        Self::with_store(|store| store.insert(self.key.clone(), secret.to_vec()));
+        Ok(())
+    }
+
+    fn get_secret(&self) -> keyring::Result<Vec<u8>> {
+        Self::with_store(|store| store.get(&self.key).cloned()).ok_or(keyring::Error::NoEntry)
+    }
+
+    fn delete_credential(&self) -> keyring::Result<()> {
+        Self::with_store(|store| store.remove(&self.key)).map(|_| ()).ok_or(keyring::Error::NoEntry)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+struct TestCredentialBuilder;
+
+impl CredentialBuilderApi for TestCredentialBuilder {
+    fn build(&self, _target: Option<&str>, service: &str, user: &str) -> keyring::Result<Box<Credential>> {
+        Ok(Box::new(TestCredential { key: (service.to_string(), user.to_string()) }))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn persistence(&self) -> CredentialPersistence {
+        CredentialPersistence::ProcessOnly
+    }
+}
+
+/// 让本进程中的钥匙串访问改用内存中的条目，测试不依赖也不修改操作系统钥匙串
+pub fn use_test_keyring() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| keyring::set_default_credential_builder(Box::new(TestCredentialBuilder)));
+}
+
+/// 崩溃报告开关是进程内的全局状态，修改它的测试（包括调用 `Model::apply_settings` 的测试）先取得该锁
+static CRASH_REPORT_FLAG: Mutex<()> = Mutex::new(());
+
+/// 串行执行会修改崩溃报告开关的测试，返回的锁在测试结束前一直持有
+pub fn lock_crash_report_flag() -> MutexGuard<'static, ()> {
+    CRASH_REPORT_FLAG.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
@@ -0,0 +1,91 @@
+use sui_sdk::types::base_types::SuiAddress;
+use super::{normalize_transfer_note, TransferStatus};
+
+/// 转账的来源：转账表单或批量转账对话框
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TransferKind {
+    Single,
+    Batch,
+}
+
+/// 一次要提交的转账：收款人及金额（MIST）、Gas 预算和本地备注
+/// 单笔转账只有一个收款人，批量转账在同一笔交易中支付多个收款人
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransferPlan {
+    pub kind: TransferKind,
+    pub recipients: Vec<(SuiAddress, u64)>,
+    pub gas_budget_mist: u64,
+    /// 记录到本地转账历史的备注（不上链）
+    pub note: Option<String>,
+}
+
+impl TransferPlan {
+    /// 向单个收款人转账
+    pub fn single(recipient: SuiAddress, amount_mist: u64, gas_budget_mist: u64) -> Self {
+        Self {
+            kind: TransferKind::Single,
+            recipients: vec![(recipient, amount_mist)],
+            gas_budget_mist,
+            note: None,
+        }
+    }
+
+    /// 在同一笔交易中向多个收款人转账
+    pub fn batch(recipients: Vec<(SuiAddress, u64)>, gas_budget_mist: u64) -> Self {
+        Self {
+            kind: TransferKind::Batch,
+            recipients,
+            gas_budget_mist,
+            note: None,
+        }
+    }
+
+    /// 附加本地备注，空备注忽略
+    pub fn with_note(mut self, note: &str) -> Self {
+        self.note = normalize_transfer_note(note);
+        self
+    }
+
+    /// 所有收款金额之和（MIST），溢出时为 None
+    pub fn total_amount_mist(&self) -> Option<u64> {
+        self.recipients
+            .iter()
+            .try_fold(0u64, |total, (_, amount)| total.checked_add(*amount))
+    }
+
+    /// 需要从余额中选取的总额（转账金额加 Gas 预算），溢出时为 None
+    pub fn required_mist(&self) -> Option<u64> {
+        self.total_amount_mist()?.checked_add(self.gas_budget_mist)
+    }
+}
+
+/// 后台转账任务发回 UI 线程的进度
+#[derive(Debug, Clone, PartialEq)]
+pub enum TransferEvent {
+    /// 交易已签名并提交
+    Submitted { digest: String },
+    /// 交易已执行；没有收到执行结果时状态为 Pending，链上执行失败时附带原因
+    Executed { digest: String, status: TransferStatus, error: Option<String> },
+    /// 提交前失败（查询代币、构建交易或签名失败），或提交时出错
+    Failed { digest: Option<String>, error: String },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn address(byte: u8) -> SuiAddress {
+        SuiAddress::from_bytes([byte; 32]).unwrap()
+    }
+
+    #[test]
+    fn required_amount_includes_gas_and_rejects_overflow() {
+        let plan = TransferPlan::batch(vec![(address(1), 5), (address(2), 7)], 3);
+        assert_eq!(plan.total_amount_mist(), Some(12));
+        assert_eq!(plan.required_mist(), Some(15));
+
+        let plan = TransferPlan::single(address(1), u64::MAX, 1);
+        assert_eq!(plan.total_amount_mist(), Some(u64::MAX));
+        assert_eq!(plan.required_mist(), None);
+    }
+}
@@ -1,23 +1,43 @@
-use sui_sdk::types::{base_types::SuiAddress, crypto::SuiKeyPair};
+use sui_sdk::types::{base_types::SuiAddress, crypto::{EncodeDecodeBase64, SuiKeyPair}};
+use base64::{Engine as _, engine::general_purpose};
+use zeroize::{Zeroize, Zeroizing};
+use crate::i18n::I18nManager;
+use super::{AddressEntry, MnemonicUtils, PanicWipeKeyPair, PanicWipeSecret};
+
+/// Bech32 私钥的 HRP（人类可读前缀）
+const BECH32_KEY_HRP: &str = "suiprivkey";
+/// Bech32 私钥长度：HRP + 分隔符 + 33 字节数据（53 字符）+ 校验和（6 字符）
+const BECH32_KEY_LEN: usize = 70;
+/// Base64 私钥长度：33 字节（1 字节签名方案标志 + 32 字节私钥）
+const BASE64_KEY_LEN: usize = 44;
+/// 十六进制私钥长度：32 字节
+const HEX_KEY_LEN: usize = 64;
+/// 支持的签名方案标志：Ed25519、Secp256k1、Secp256r1
+const KNOWN_SCHEME_FLAGS: [u8; 3] = [0x00, 0x01, 0x02];
+/// 十六进制私钥不带签名方案标志，按 Ed25519 处理（与 Sui CLI 一致）
+const HEX_KEY_SCHEME_FLAG: u8 = 0x00;
 
 /// 钱包状态枚举
+/// 私钥输入和已加载的私钥都登记在 panic 清除表中，panic 时会被清除
 #[derive(Debug)]
 pub enum WalletState {
     /// 未导入钱包，存储用户输入的私钥字符串
     NoWallet { 
-        private_key_input: String 
+        private_key_input: PanicWipeSecret 
     },
     /// 已加载钱包
     Loaded {
         address: SuiAddress,
-        keypair: SuiKeyPair,
+        keypair: PanicWipeKeyPair,
+        /// 用户自定义的账户标签（如 "Trading"、"Cold"）
+        label: Option<String>,
     },
 }
 
 impl Default for WalletState {
     fn default() -> Self {
         WalletState::NoWallet {
-            private_key_input: String::new(),
+            private_key_input: PanicWipeSecret::default(),
         }
     }
 }
@@ -25,14 +45,12 @@ impl Default for WalletState {
 impl WalletState {
     /// 创建一个新的未导入钱包状态
     pub fn new_no_wallet() -> Self {
-        WalletState::NoWallet {
-            private_key_input: String::new(),
-        }
+        Self::default()
     }
 
     /// 创建一个已加载的钱包状态
     pub fn new_loaded(address: SuiAddress, keypair: SuiKeyPair) -> Self {
-        WalletState::Loaded { address, keypair }
+        WalletState::Loaded { address, keypair: PanicWipeKeyPair::new(keypair), label: None }
     }
 
     /// 检查钱包是否已加载
@@ -40,6 +58,11 @@ impl WalletState {
         matches!(self, WalletState::Loaded { .. })
     }
 
+    /// 可切换的账户数量（目前一次只加载一个账户）
+    pub fn account_count(&self) -> usize {
+        usize::from(self.is_loaded())
+    }
+
     /// 获取钱包地址（如果已加载）
     pub fn address(&self) -> Option<&SuiAddress> {
         match self {
@@ -48,54 +71,84 @@ impl WalletState {
         }
     }
 
-    /// 获取私钥对（如果已加载）
-    pub fn keypair(&self) -> Option<&SuiKeyPair> {
+    /// 使用已加载的私钥对（如果已加载且未被 panic hook 清除）
+    pub fn with_keypair<R>(&self, f: impl FnOnce(&SuiKeyPair) -> R) -> Option<R> {
         match self {
-            WalletState::Loaded { keypair, .. } => Some(keypair),
+            WalletState::Loaded { keypair, .. } => keypair.with(f),
             WalletState::NoWallet { .. } => None,
         }
     }
 
-    /// 获取私钥输入（如果未加载）
-    pub fn private_key_input(&self) -> Option<&str> {
-        match self {
-            WalletState::NoWallet { private_key_input } => Some(private_key_input),
-            WalletState::Loaded { .. } => None,
-        }
+    /// 是否持有可用的私钥对
+    pub fn has_keypair(&self) -> bool {
+        self.with_keypair(|_| ()).is_some()
     }
 
-    /// 获取可变的私钥输入（如果未加载）
-    pub fn private_key_input_mut(&mut self) -> Option<&mut String> {
+    /// 获取私钥输入的副本（如果未加载），副本释放时清零
+    pub fn private_key_input(&self) -> Option<Zeroizing<String>> {
         match self {
-            WalletState::NoWallet { private_key_input } => Some(private_key_input),
+            WalletState::NoWallet { private_key_input } => Some(Zeroizing::new(private_key_input.get())),
             WalletState::Loaded { .. } => None,
         }
     }
 
-    /// 清除私钥输入
-    pub fn clear_private_key_input(&mut self) {
+    /// 清除私钥输入，开启内存保护时先覆盖为 0
+    pub fn wipe_private_key_input(&mut self, memory_protection: bool) {
         if let WalletState::NoWallet { private_key_input } = self {
-            private_key_input.clear();
+            super::wipe_secret(&mut private_key_input.lock(), memory_protection);
         }
     }
 
-    /// 设置私钥输入
+    /// 设置私钥输入（旧内容会先被清除）
     pub fn set_private_key_input(&mut self, input: String) {
         if let WalletState::NoWallet { private_key_input } = self {
-            *private_key_input = input;
+            private_key_input.set(input);
         }
     }
 
-    /// 重置到未导入钱包状态
-    pub fn reset(&mut self) {
-        *self = WalletState::NoWallet {
-            private_key_input: String::new(),
-        };
+    /// 获取账户标签（如果已设置）
+    pub fn label(&self) -> Option<&str> {
+        match self {
+            WalletState::Loaded { label, .. } => label.as_deref(),
+            WalletState::NoWallet { .. } => None,
+        }
     }
 
-    /// 转换为已加载状态
-    pub fn load_wallet(&mut self, address: SuiAddress, keypair: SuiKeyPair) {
-        *self = WalletState::Loaded { address, keypair };
+    /// 设置账户标签，空白标签视为未设置
+    pub fn set_label(&mut self, new_label: Option<String>) {
+        if let WalletState::Loaded { label, .. } = self {
+            *label = new_label
+                .map(|l| l.trim().to_string())
+                .filter(|l| !l.is_empty());
+        }
+    }
+
+    /// 获取用于显示的账户名称：未设置标签时使用截断的地址
+    pub fn display_label(&self) -> Option<String> {
+        match self {
+            WalletState::Loaded { address, label, .. } => Some(
+                label
+                    .clone()
+                    .unwrap_or_else(|| WalletUtils::truncate_address(address, 6, 4)),
+            ),
+            WalletState::NoWallet { .. } => None,
+        }
+    }
+
+    /// 账户切换器中各账户的显示名称，顺序与 Ctrl/Cmd+N 快捷键一致
+    pub fn account_labels(&self) -> Vec<String> {
+        self.display_label().into_iter().collect()
+    }
+
+    /// 可导出的账户地址列表（标签 + 地址，不含私钥）
+    pub fn address_entries(&self) -> Vec<AddressEntry> {
+        match self {
+            WalletState::Loaded { address, label, .. } => vec![AddressEntry {
+                label: label.clone(),
+                address: *address,
+            }],
+            WalletState::NoWallet { .. } => Vec::new(),
+        }
     }
 }
 
@@ -103,27 +156,135 @@ impl WalletState {
 pub struct WalletUtils;
 
 impl WalletUtils {
-    /// 验证私钥格式
-    pub fn validate_private_key_format(private_key: &str) -> bool {
-        let trimmed = private_key.trim();
-        !trimmed.is_empty() && (
-            trimmed.starts_with("suiprivkey1") || // Bech32 format
-            (trimmed.len() == 44 && trimmed.chars().all(|c| c.is_alphanumeric() || c == '+' || c == '/' || c == '=')) || // Base64
-            (trimmed.len() == 64 && trimmed.chars().all(|c| c.is_ascii_hexdigit())) // Hex
-        )
-    }
-
-    /// 获取私钥格式类型
-    pub fn get_private_key_format(private_key: &str) -> Option<PrivateKeyFormat> {
-        let trimmed = private_key.trim();
-        if trimmed.starts_with("suiprivkey1") {
-            Some(PrivateKeyFormat::Bech32)
-        } else if trimmed.len() == 44 && trimmed.chars().all(|c| c.is_alphanumeric() || c == '+' || c == '/' || c == '=') {
-            Some(PrivateKeyFormat::Base64)
-        } else if trimmed.len() == 64 && trimmed.chars().all(|c| c.is_ascii_hexdigit()) {
-            Some(PrivateKeyFormat::Hex)
+    /// 规范化粘贴的地址或私钥：去除所有 Unicode 空白、零宽字符和控制字符
+    /// 地址和私钥本身不包含这些字符，复制粘贴时却经常混入
+    pub fn normalize_input(input: &str) -> String {
+        input
+            .chars()
+            .filter(|c| !c.is_whitespace() && !c.is_control() && !Self::is_zero_width(*c))
+            .collect()
+    }
+
+    /// 识别拖放文件的内容：keystore JSON、单个私钥或助记词
+    /// 只做格式检查，真正导入前仍需用户确认并经过完整的私钥校验
+    pub fn classify_key_file(contents: &str) -> KeyFileContents {
+        let trimmed = contents.trim().trim_start_matches('\u{FEFF}');
+
+        if trimmed.starts_with('[') {
+            let keys: Vec<String> = serde_json::from_str::<Vec<String>>(trimmed)
+                .unwrap_or_default()
+                .iter()
+                .map(|entry| Self::normalize_input(entry))
+                .filter(|key| Self::diagnose_private_key(key).is_ok())
+                .collect();
+            return if keys.is_empty() {
+                KeyFileContents::Unknown
+            } else {
+                KeyFileContents::Keystore(keys)
+            };
+        }
+
+        let key = Self::normalize_input(trimmed);
+        if !key.is_empty() && Self::diagnose_private_key(&key).is_ok() {
+            return KeyFileContents::SingleKey(key);
+        }
+
+        match MnemonicUtils::looks_like_mnemonic(trimmed) {
+            Some(words) => KeyFileContents::Mnemonic(words),
+            None => KeyFileContents::Unknown,
+        }
+    }
+
+    /// 检查是否为零宽字符（零宽空格、连接符、BOM 等）
+    fn is_zero_width(c: char) -> bool {
+        matches!(c, '\u{200B}' | '\u{200C}' | '\u{200D}' | '\u{2060}' | '\u{FEFF}')
+    }
+
+    /// 诊断私钥输入，返回识别出的格式或具体的错误原因
+    /// 只做结构检查，通过检查的输入仍可能因校验和等原因解码失败
+    pub fn diagnose_private_key(private_key: &str) -> Result<PrivateKeyFormat, KeyImportError> {
+        let input = Self::normalize_input(private_key);
+        if input.is_empty() {
+            return Err(KeyImportError::Empty);
+        }
+
+        // 十六进制（可带 0x 前缀）
+        let hex = Self::strip_address_prefix(&input);
+        if hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Self::check_length(PrivateKeyFormat::Hex, hex.len(), HEX_KEY_LEN);
+        }
+
+        // Bech32：小写字母开头的 HRP + 最后一个 '1' 分隔符 + 数据
+        if let Some((hrp, _)) = input.rsplit_once('1') {
+            let looks_like_bech32 = !hrp.is_empty()
+                && hrp.chars().all(|c| c.is_ascii_lowercase())
+                && input.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit());
+            if looks_like_bech32 {
+                if hrp != BECH32_KEY_HRP {
+                    return Err(KeyImportError::InvalidBech32Hrp);
+                }
+                return Self::check_length(PrivateKeyFormat::Bech32, input.len(), BECH32_KEY_LEN);
+            }
+        }
+
+        // Base64：1 字节签名方案标志 + 32 字节私钥
+        if input.chars().all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '/' || c == '=') {
+            let bytes = general_purpose::STANDARD
+                .decode(&input)
+                .map_err(|_| KeyImportError::InvalidBase64)?;
+            Self::check_length(PrivateKeyFormat::Base64, input.len(), BASE64_KEY_LEN)?;
+            if !KNOWN_SCHEME_FLAGS.contains(&bytes[0]) {
+                return Err(KeyImportError::UnknownScheme);
+            }
+            return Ok(PrivateKeyFormat::Base64);
+        }
+
+        Err(KeyImportError::Unrecognized)
+    }
+
+    /// 导入私钥：先做结构检查，再解码并推导地址
+    /// 与界面无关，便于测试各签名方案
+    pub fn import_private_key(private_key: &str) -> Result<(SuiAddress, SuiKeyPair), KeyImportError> {
+        let format = Self::diagnose_private_key(private_key)?;
+        let input = Self::normalize_input(private_key);
+        let keypair = match format {
+            PrivateKeyFormat::Bech32 => SuiKeyPair::decode(&input).map_err(|_| KeyImportError::DecodeFailed)?,
+            PrivateKeyFormat::Base64 => SuiKeyPair::decode_base64(&input).map_err(|_| KeyImportError::DecodeFailed)?,
+            PrivateKeyFormat::Hex => Self::decode_hex_key(Self::strip_address_prefix(&input))?,
+        };
+        let address: SuiAddress = (&keypair.public()).into();
+        Ok((address, keypair))
+    }
+
+    /// 解码十六进制私钥（已去除 0x 前缀），中间缓冲区使用后清零
+    fn decode_hex_key(hex: &str) -> Result<SuiKeyPair, KeyImportError> {
+        let mut bytes = Vec::with_capacity(1 + HEX_KEY_LEN / 2);
+        bytes.push(HEX_KEY_SCHEME_FLAG);
+        for pair in hex.as_bytes().chunks(2) {
+            let byte = std::str::from_utf8(pair)
+                .ok()
+                .and_then(|digits| u8::from_str_radix(digits, 16).ok());
+            match byte {
+                Some(byte) => bytes.push(byte),
+                None => {
+                    bytes.zeroize();
+                    return Err(KeyImportError::DecodeFailed);
+                }
+            }
+        }
+        let keypair = SuiKeyPair::from_bytes(&bytes).map_err(|_| KeyImportError::DecodeFailed);
+        bytes.zeroize();
+        keypair
+    }
+
+    /// 检查指定格式的长度
+    fn check_length(format: PrivateKeyFormat, len: usize, expected: usize) -> Result<PrivateKeyFormat, KeyImportError> {
+        if len < expected {
+            Err(KeyImportError::TooShort(format))
+        } else if len > expected {
+            Err(KeyImportError::TooLong(format))
         } else {
-            None
+            Ok(format)
         }
     }
 
@@ -140,10 +301,292 @@ impl WalletUtils {
     /// 检查地址是否有效
     pub fn is_valid_address_format(address: &str) -> bool {
         // 基本格式检查
+        let address = Self::normalize_input(address);
         address.len() >= 40 && address.starts_with("0x")
     }
+
+    /// 检查收款地址是否就是发送方地址（自转账）
+    /// 输入会先规范化，大小写和是否带 0x 前缀都不影响比较
+    pub fn is_self_send(recipient: &str, sender: &SuiAddress) -> bool {
+        let recipient = Self::normalize_input(recipient);
+        let sender = sender.to_string();
+        !recipient.is_empty()
+            && Self::strip_address_prefix(&recipient)
+                .eq_ignore_ascii_case(Self::strip_address_prefix(&sender))
+    }
+
+    /// 去除地址的 0x 前缀
+    pub fn strip_address_prefix(address: &str) -> &str {
+        let trimmed = address.trim();
+        trimmed
+            .strip_prefix("0x")
+            .or_else(|| trimmed.strip_prefix("0X"))
+            .unwrap_or(trimmed)
+    }
+
+    /// 将十进制金额字符串解析为最小单位的整数（如 SUI 的 `decimals` 为 9，结果为 MIST）
+    /// 只使用整数运算：按 `.` 拆分后将小数部分补齐到 `decimals` 位，不经过 f64，避免舍入误差
+    /// 小数位数超过 `decimals` 时拒绝（末尾多余的 0 除外），而不是静默截断
+    pub fn parse_amount(input: &str, decimals: u32) -> Result<u64, AmountParseError> {
+        let amount = Self::normalize_input(input);
+        if amount.is_empty() {
+            return Err(AmountParseError::Empty);
+        }
+
+        let (whole, fraction) = Self::split_decimal_amount(&amount)?;
+        let fraction = fraction.trim_end_matches('0');
+        if fraction.len() > decimals as usize {
+            return Err(AmountParseError::TooManyDecimals);
+        }
+
+        let scale = 10u64.checked_pow(decimals).ok_or(AmountParseError::TooLarge)?;
+        let whole: u64 = if whole.is_empty() {
+            0
+        } else {
+            whole.parse().map_err(|_| AmountParseError::TooLarge)?
+        };
+        let fraction_units: u64 = if fraction.is_empty() {
+            0
+        } else {
+            let padded = format!("{:0<width$}", fraction, width = decimals as usize);
+            padded.parse().map_err(|_| AmountParseError::Invalid)?
+        };
+
+        whole
+            .checked_mul(scale)
+            .and_then(|units| units.checked_add(fraction_units))
+            .ok_or(AmountParseError::TooLarge)
+    }
+
+    /// 将 SUI 金额字符串精确解析为 MIST
+    pub fn parse_sui_amount(input: &str) -> Result<u64, AmountParseError> {
+        Self::parse_amount(input, SUI_DECIMALS)
+    }
+
+    /// 按 `.` 拆分十进制金额：只接受数字和至多一个小数点，拒绝符号、指数、`inf` 等
+    fn split_decimal_amount(amount: &str) -> Result<(&str, &str), AmountParseError> {
+        let (whole, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+        let is_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+        if (whole.is_empty() && fraction.is_empty()) || !is_digits(whole) || !is_digits(fraction) {
+            return Err(AmountParseError::Invalid);
+        }
+        Ok((whole, fraction))
+    }
+
+    /// 解析美元金额，语法与 `parse_amount` 相同（只接受数字和小数点），之后再按价格换算
+    pub fn parse_usd_amount(input: &str) -> Result<f64, AmountParseError> {
+        let amount = Self::normalize_input(input);
+        if amount.is_empty() {
+            return Err(AmountParseError::Empty);
+        }
+        Self::split_decimal_amount(&amount)?;
+        amount.parse::<f64>().map_err(|_| AmountParseError::Invalid)
+    }
+
+    /// 将 SUI 金额转换为 MIST（四舍五入到最近的 MIST），金额无效时返回 None
+    pub fn sui_to_mist(sui: f64) -> Option<u64> {
+        if !sui.is_finite() || sui < 0.0 {
+            return None;
+        }
+        let mist = (sui * MIST_PER_SUI as f64).round();
+        (mist <= u64::MAX as f64).then_some(mist as u64)
+    }
+
+    /// 按 SUI 的美元价格将美元金额转换为 MIST（四舍五入到最近的 MIST）
+    pub fn usd_to_mist(usd: f64, sui_price_usd: f64) -> Option<u64> {
+        if !sui_price_usd.is_finite() || sui_price_usd <= 0.0 {
+            return None;
+        }
+        Self::sui_to_mist(usd / sui_price_usd)
+    }
+
+    /// 确定交易使用的 Gas 预算（MIST）：填写了手动预算时使用手动值，否则使用预估值
+    pub fn resolve_gas_budget(manual_input: &str, estimate_mist: u64) -> Result<u64, GasBudgetError> {
+        let input = Self::normalize_input(manual_input);
+        if input.is_empty() {
+            return Ok(estimate_mist);
+        }
+
+        let budget = input.parse::<u64>().map_err(|_| GasBudgetError::Invalid)?;
+        if (MIN_GAS_BUDGET_MIST..=MAX_GAS_BUDGET_MIST).contains(&budget) {
+            Ok(budget)
+        } else {
+            Err(GasBudgetError::OutOfBounds)
+        }
+    }
+
+    /// 按指定格式输出地址
+    pub fn format_address(address: &str, format: AddressFormat) -> String {
+        let bare = Self::strip_address_prefix(address);
+        match format {
+            AddressFormat::Prefixed => format!("0x{}", bare),
+            AddressFormat::Bare => bare.to_string(),
+        }
+    }
+
+    /// 按指定格式编码公钥：1 字节签名方案标志 + 公钥字节
+    pub fn encode_public_key(keypair: &SuiKeyPair, format: PublicKeyFormat) -> String {
+        let public = keypair.public();
+        let mut bytes = Vec::with_capacity(1 + public.as_ref().len());
+        bytes.push(public.flag());
+        bytes.extend_from_slice(public.as_ref());
+
+        match format {
+            PublicKeyFormat::Base64 => general_purpose::STANDARD.encode(&bytes),
+            PublicKeyFormat::Hex => {
+                let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+                format!("0x{}", hex)
+            }
+        }
+    }
+}
+
+/// 1 SUI = 10^9 MIST
+pub const MIST_PER_SUI: u64 = 1_000_000_000;
+/// SUI 的小数位数
+pub const SUI_DECIMALS: u32 = 9;
+
+/// 手动 Gas 预算下限（MIST）
+pub const MIN_GAS_BUDGET_MIST: u64 = 1_000_000;
+/// 手动 Gas 预算上限（MIST），即 50 SUI
+pub const MAX_GAS_BUDGET_MIST: u64 = 50_000_000_000;
+
+/// 手动 Gas 预算的错误类型
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GasBudgetError {
+    /// 不是有效的整数
+    Invalid,
+    /// 超出允许范围
+    OutOfBounds,
 }
 
+impl GasBudgetError {
+    /// 获取错误的翻译键
+    pub fn i18n_key(&self) -> &'static str {
+        match self {
+            GasBudgetError::Invalid => "gas_budget_invalid",
+            GasBudgetError::OutOfBounds => "gas_budget_out_of_bounds",
+        }
+    }
+}
+
+/// 金额解析的错误类型
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AmountParseError {
+    /// 未填写金额
+    Empty,
+    /// 不是有效的十进制数
+    Invalid,
+    /// 小数位数超过代币精度
+    TooManyDecimals,
+    /// 超出可表示的范围
+    TooLarge,
+}
+
+impl AmountParseError {
+    /// 获取错误的翻译键
+    pub fn i18n_key(&self) -> &'static str {
+        match self {
+            AmountParseError::Empty => "amount_required",
+            AmountParseError::Invalid | AmountParseError::TooLarge => "invalid_amount",
+            AmountParseError::TooManyDecimals => "amount_too_many_decimals",
+        }
+    }
+}
+
+/// 转账金额的输入单位
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AmountUnit {
+    Sui,
+    /// 美元，按当前价格换算为 SUI
+    Usd,
+}
+
+/// 地址复制格式
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AddressFormat {
+    /// 带 0x 前缀
+    Prefixed,
+    /// 不带前缀
+    Bare,
+}
+
+/// 公钥复制格式（均包含签名方案标志）
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PublicKeyFormat {
+    /// Base64，与 Sui CLI 的 `flag || pk` 格式一致
+    Base64,
+    /// 带 0x 前缀的十六进制
+    Hex,
+}
+
+/// 私钥导入失败的原因
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum KeyImportError {
+    /// 输入为空
+    Empty,
+    /// 对识别出的格式来说太短
+    TooShort(PrivateKeyFormat),
+    /// 对识别出的格式来说太长
+    TooLong(PrivateKeyFormat),
+    /// Bech32 前缀不是 suiprivkey
+    InvalidBech32Hrp,
+    /// 无法解码的 Base64
+    InvalidBase64,
+    /// 未知的签名方案标志
+    UnknownScheme,
+    /// 无法识别的格式
+    Unrecognized,
+    /// 结构正确但解码失败（例如校验和错误）
+    DecodeFailed,
+}
+
+impl KeyImportError {
+    /// 获取错误的翻译键
+    pub fn i18n_key(&self) -> &'static str {
+        match self {
+            KeyImportError::Empty => "key_error_empty",
+            KeyImportError::TooShort(_) => "key_error_too_short",
+            KeyImportError::TooLong(_) => "key_error_too_long",
+            KeyImportError::InvalidBech32Hrp => "key_error_invalid_hrp",
+            KeyImportError::InvalidBase64 => "key_error_invalid_base64",
+            KeyImportError::UnknownScheme => "key_error_unknown_scheme",
+            KeyImportError::Unrecognized => "unrecognized_key_format",
+            KeyImportError::DecodeFailed => "import_private_key_failed",
+        }
+    }
+
+    /// 获取本地化的错误消息
+    pub fn message(&self, i18n: &I18nManager) -> String {
+        let message = i18n.tr(self.i18n_key());
+        match self {
+            KeyImportError::TooShort(format) | KeyImportError::TooLong(format) => {
+                message.replace("{}", format.name())
+            }
+            _ => message,
+        }
+    }
+}
+
+/// 拖放导入时读取的最大文件大小，密钥文件不会超过这个大小
+pub const MAX_KEY_FILE_BYTES: u64 = 64 * 1024;
+
+/// 拖放文件内容的分类
+#[derive(Debug, Clone, PartialEq)]
+pub enum KeyFileContents {
+    /// Sui keystore JSON（私钥字符串数组），值为其中格式有效的私钥
+    Keystore(Vec<String>),
+    /// 只包含一个私钥的文本文件
+    SingleKey(String),
+    /// 助记词，值为单词数量（不保存助记词本身）
+    Mnemonic(usize),
+    /// 无法识别
+    Unknown,
+}
+
+/// Sui 官方文档中关于密钥和地址的说明
+pub const KEY_FORMAT_DOCS_URL: &str = "https://docs.sui.io/concepts/cryptography/transaction-auth/keys-addresses";
+
 /// 私钥格式枚举
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum PrivateKeyFormat {
@@ -153,12 +596,26 @@ pub enum PrivateKeyFormat {
 }
 
 impl PrivateKeyFormat {
-    /// 获取格式描述
-    pub fn description(&self) -> &'static str {
+    /// 所有支持的格式
+    pub fn all() -> [PrivateKeyFormat; 3] {
+        [PrivateKeyFormat::Bech32, PrivateKeyFormat::Base64, PrivateKeyFormat::Hex]
+    }
+
+    /// 帮助说明的翻译键
+    pub fn help_key(&self) -> &'static str {
+        match self {
+            PrivateKeyFormat::Bech32 => "format_help_bech32_detail",
+            PrivateKeyFormat::Base64 => "format_help_base64_detail",
+            PrivateKeyFormat::Hex => "format_help_hex_detail",
+        }
+    }
+
+    /// 获取格式名称
+    pub fn name(&self) -> &'static str {
         match self {
-            PrivateKeyFormat::Bech32 => "Bech32 format (suiprivkey1...)",
-            PrivateKeyFormat::Base64 => "Base64 format (44 characters)",
-            PrivateKeyFormat::Hex => "Hex format (64 characters)",
+            PrivateKeyFormat::Bech32 => "Bech32",
+            PrivateKeyFormat::Base64 => "Base64",
+            PrivateKeyFormat::Hex => "Hex",
         }
     }
 
@@ -172,26 +629,313 @@ impl PrivateKeyFormat {
     }
 }
 
-/// 钱包操作结果
-#[derive(Debug)]
-pub enum WalletOperationResult {
-    Success(String),
-    Error(String),
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+    use sui_sdk::types::crypto::SignatureScheme;
+    use crate::i18n::Language;
 
-impl WalletOperationResult {
-    pub fn is_success(&self) -> bool {
-        matches!(self, WalletOperationResult::Success(_))
+    fn address(byte: u8) -> SuiAddress {
+        SuiAddress::from_bytes([byte; 32]).unwrap()
     }
 
-    pub fn is_error(&self) -> bool {
-        matches!(self, WalletOperationResult::Error(_))
+    #[test]
+    fn binary_paste_is_rejected_as_unrecognized() {
+        let pasted = String::from_utf8_lossy(&[0x00, 0xff, 0x1b, 0xfe, b'\n', 0x07, 0x80]).into_owned();
+        let normalized = WalletUtils::normalize_input(&pasted);
+        assert!(normalized.chars().all(|c| !c.is_control()));
+
+        assert_eq!(WalletUtils::diagnose_private_key(&normalized), Err(KeyImportError::Unrecognized));
+        assert!(matches!(WalletUtils::import_private_key(&normalized), Err(KeyImportError::Unrecognized)));
+        // 只有控制字符的输入按空输入处理
+        assert_eq!(WalletUtils::diagnose_private_key("\u{0}\u{1b}\u{7f}"), Err(KeyImportError::Empty));
     }
 
-    pub fn message(&self) -> &str {
-        match self {
-            WalletOperationResult::Success(msg) => msg,
-            WalletOperationResult::Error(msg) => msg,
+    #[test]
+    fn formats_address_with_and_without_prefix() {
+        let hex = "ab".repeat(32);
+        let prefixed = format!("0x{}", hex);
+
+        assert_eq!(WalletUtils::strip_address_prefix(&prefixed), hex);
+        assert_eq!(WalletUtils::strip_address_prefix(&format!(" 0X{}\n", hex)), hex);
+        assert_eq!(WalletUtils::strip_address_prefix(&hex), hex);
+
+        for input in [&prefixed, &hex] {
+            assert_eq!(WalletUtils::format_address(input, AddressFormat::Prefixed), prefixed);
+            assert_eq!(WalletUtils::format_address(input, AddressFormat::Bare), hex);
+        }
+    }
+
+    #[test]
+    fn normalizes_unicode_whitespace_and_zero_width_characters() {
+        let recipient = address(0xab).to_string();
+        let pasted = format!("\u{FEFF}\u{00A0}{}\u{200B}\u{3000}\r\n", recipient);
+        assert_eq!(WalletUtils::normalize_input(&pasted), recipient);
+        assert_eq!(WalletUtils::normalize_input(&format!("{}\u{200C}{}", &recipient[..10], &recipient[10..])), recipient);
+        assert!(WalletUtils::is_valid_address_format(&pasted));
+
+        // 粘贴的私钥同样先规范化再导入
+        let key = format!("0x{}", "07".repeat(32));
+        let (expected, _) = WalletUtils::import_private_key(&key).unwrap();
+        let (imported, _) = WalletUtils::import_private_key(&format!("\u{00A0}{}\u{200B}\u{2060}\n", key)).unwrap();
+        assert_eq!(imported, expected);
+    }
+
+    #[test]
+    fn detects_self_send_only_for_matching_address() {
+        let sender = address(0xab);
+        let sender_str = sender.to_string();
+
+        assert!(WalletUtils::is_self_send(&sender_str, &sender));
+        // 粘贴时混入的空白、大写和缺少 0x 前缀不影响判断
+        assert!(WalletUtils::is_self_send(&format!(" {}\n", sender_str[2..].to_uppercase()), &sender));
+        assert!(!WalletUtils::is_self_send(&address(0xcd).to_string(), &sender));
+        assert!(!WalletUtils::is_self_send("", &sender));
+    }
+
+    /// 有效的 Base64 私钥：Ed25519 标志 + 32 字节
+    fn base64_key(flag: u8) -> String {
+        let mut bytes = vec![flag];
+        bytes.extend_from_slice(&[7u8; 32]);
+        general_purpose::STANDARD.encode(bytes)
+    }
+
+    #[test]
+    fn accepts_well_formed_keys() {
+        assert_eq!(WalletUtils::diagnose_private_key(&base64_key(0)), Ok(PrivateKeyFormat::Base64));
+        assert_eq!(WalletUtils::diagnose_private_key(&"ab".repeat(32)), Ok(PrivateKeyFormat::Hex));
+        let bech32 = format!("suiprivkey1{}", "q".repeat(59));
+        assert_eq!(WalletUtils::diagnose_private_key(&bech32), Ok(PrivateKeyFormat::Bech32));
+    }
+
+    #[test]
+    fn diagnoses_each_malformed_key_category() {
+        assert_eq!(WalletUtils::diagnose_private_key(" \u{200B} "), Err(KeyImportError::Empty));
+        assert_eq!(
+            WalletUtils::diagnose_private_key(&"ab".repeat(31)),
+            Err(KeyImportError::TooShort(PrivateKeyFormat::Hex))
+        );
+        assert_eq!(
+            WalletUtils::diagnose_private_key(&format!("suiprivkey1{}", "q".repeat(60))),
+            Err(KeyImportError::TooLong(PrivateKeyFormat::Bech32))
+        );
+        assert_eq!(
+            WalletUtils::diagnose_private_key(&format!("bc1{}", "q".repeat(59))),
+            Err(KeyImportError::InvalidBech32Hrp)
+        );
+        assert_eq!(WalletUtils::diagnose_private_key("abc=def"), Err(KeyImportError::InvalidBase64));
+        assert_eq!(WalletUtils::diagnose_private_key(&base64_key(9)), Err(KeyImportError::UnknownScheme));
+        assert_eq!(WalletUtils::diagnose_private_key("not a key!"), Err(KeyImportError::Unrecognized));
+    }
+
+    #[test]
+    fn each_key_error_has_specific_message() {
+        let i18n = I18nManager::new();
+        let errors = [
+            KeyImportError::Empty,
+            KeyImportError::TooShort(PrivateKeyFormat::Hex),
+            KeyImportError::TooLong(PrivateKeyFormat::Bech32),
+            KeyImportError::InvalidBech32Hrp,
+            KeyImportError::InvalidBase64,
+            KeyImportError::UnknownScheme,
+        ];
+        let messages: Vec<String> = errors.iter().map(|e| e.message(&i18n)).collect();
+
+        for (error, message) in errors.iter().zip(&messages) {
+            // 每个键都有翻译（缺失时 tr 会返回键本身）
+            assert_ne!(message, error.i18n_key());
+        }
+        for (i, message) in messages.iter().enumerate() {
+            assert!(!messages[i + 1..].contains(message), "duplicate message: {}", message);
+        }
+        assert!(messages[1].contains("Hex") && messages[2].contains("Bech32"));
+    }
+
+    /// 各签名方案的测试向量：私钥均为字节 0x01..=0x20，
+    /// 地址按 Sui 规范独立计算：blake2b256(方案标志 || 公钥)
+    const KEY_VECTORS: [(&str, &str, SignatureScheme); 3] = [
+        (
+            "suiprivkey1qqqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0jqa4ffsr",
+            "0x7573c697fa68450f04fa0dee2d39dcdc8a5ccf5db547f3e47638a6f8eeeec110",
+            SignatureScheme::ED25519,
+        ),
+        (
+            "suiprivkey1qyqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0jq82ukn5",
+            "0x888ccd887822e692bceebbd29743917e77932d72c7bd5da6a2a502ddef4f5837",
+            SignatureScheme::Secp256k1,
+        ),
+        (
+            "suiprivkey1qgqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0jqqz27ky",
+            "0xae4618a47eb09f9015de8028a5775f4349eb387f2081c596b14b7bbf7e5a7551",
+            SignatureScheme::Secp256r1,
+        ),
+    ];
+
+    #[test]
+    fn imports_each_signature_scheme_to_expected_address() {
+        for (key, expected_address, scheme) in KEY_VECTORS {
+            let (address, keypair) = WalletUtils::import_private_key(key)
+                .unwrap_or_else(|e| panic!("failed to import {:?} key: {:?}", scheme, e));
+            assert_eq!(address, SuiAddress::from_str(expected_address).unwrap(), "{:?}", scheme);
+            assert_eq!(keypair.public().scheme(), scheme);
+        }
+    }
+
+    #[test]
+    fn loaded_wallet_exposes_imported_keypair() {
+        for (key, _, scheme) in KEY_VECTORS {
+            let (address, keypair) = WalletUtils::import_private_key(key).unwrap();
+            let wallet = WalletState::new_loaded(address, keypair);
+
+            assert_eq!(wallet.address(), Some(&address));
+            let (loaded_scheme, encoded) = wallet
+                .with_keypair(|keypair| (keypair.public().scheme(), keypair.encode().unwrap()))
+                .expect("wallet should be loaded");
+            assert_eq!(loaded_scheme, scheme);
+            // 重新编码后与输入一致
+            assert_eq!(encoded, key);
+        }
+    }
+
+    #[test]
+    fn encodes_public_key_with_scheme_flag() {
+        let (_, ed25519) = WalletUtils::import_private_key(KEY_VECTORS[0].0).unwrap();
+        assert_eq!(
+            WalletUtils::encode_public_key(&ed25519, PublicKeyFormat::Base64),
+            "AHm1Vi6P5lT5QHixEuipi6eQH4U65pW+1+DjkQutBJZk"
+        );
+        assert_eq!(
+            WalletUtils::encode_public_key(&ed25519, PublicKeyFormat::Hex),
+            "0x0079b5562e8fe654f94078b112e8a98ba7901f853ae695bed7e0e3910bad049664"
+        );
+
+        let (_, secp256k1) = WalletUtils::import_private_key(KEY_VECTORS[1].0).unwrap();
+        assert_eq!(
+            WalletUtils::encode_public_key(&secp256k1, PublicKeyFormat::Base64),
+            "AQKEv3ViJiu9aUAIV0jzvmr6Uq4xcVUYHs4xtmNRzP+ksA=="
+        );
+    }
+
+    #[test]
+    fn uses_manual_gas_budget_within_bounds() {
+        assert_eq!(WalletUtils::resolve_gas_budget("", 3_000_000), Ok(3_000_000));
+        assert_eq!(WalletUtils::resolve_gas_budget(" 5000000 ", 3_000_000), Ok(5_000_000));
+        assert_eq!(WalletUtils::resolve_gas_budget(&MIN_GAS_BUDGET_MIST.to_string(), 3_000_000), Ok(MIN_GAS_BUDGET_MIST));
+    }
+
+    #[test]
+    fn rejects_out_of_bounds_gas_budget() {
+        assert_eq!(WalletUtils::resolve_gas_budget("999", 3_000_000), Err(GasBudgetError::OutOfBounds));
+        assert_eq!(
+            WalletUtils::resolve_gas_budget(&(MAX_GAS_BUDGET_MIST + 1).to_string(), 3_000_000),
+            Err(GasBudgetError::OutOfBounds)
+        );
+        assert_eq!(WalletUtils::resolve_gas_budget("0.5", 3_000_000), Err(GasBudgetError::Invalid));
+    }
+
+    #[test]
+    fn parses_sui_amounts_exactly() {
+        assert_eq!(WalletUtils::parse_sui_amount("1.5"), Ok(1_500_000_000));
+        assert_eq!(WalletUtils::parse_sui_amount("0.000000001"), Ok(1));
+        assert_eq!(WalletUtils::parse_sui_amount("0.3"), Ok(300_000_000));
+        assert_eq!(WalletUtils::parse_sui_amount(".25"), Ok(250_000_000));
+        assert_eq!(WalletUtils::parse_sui_amount("2."), Ok(2 * MIST_PER_SUI));
+        assert_eq!(WalletUtils::parse_sui_amount("1.5000000000"), Ok(1_500_000_000));
+        assert_eq!(WalletUtils::parse_amount("1.5", 6), Ok(1_500_000));
+    }
+
+    #[test]
+    fn rejects_over_precise_and_invalid_amounts() {
+        assert_eq!(WalletUtils::parse_sui_amount("1.0000000001"), Err(AmountParseError::TooManyDecimals));
+        assert_eq!(WalletUtils::parse_amount("0.1234567", 6), Err(AmountParseError::TooManyDecimals));
+        assert_eq!(WalletUtils::parse_sui_amount(""), Err(AmountParseError::Empty));
+        for invalid in [".", "-1", "1e9", "1.2.3", "abc", "1,5"] {
+            assert_eq!(WalletUtils::parse_sui_amount(invalid), Err(AmountParseError::Invalid), "{}", invalid);
+        }
+        assert_eq!(WalletUtils::parse_sui_amount("18446744074"), Err(AmountParseError::TooLarge));
+    }
+
+    #[test]
+    fn converts_usd_to_mist_at_current_rate() {
+        // 10 美元，价格 2.5 美元/SUI => 4 SUI
+        assert_eq!(WalletUtils::usd_to_mist(10.0, 2.5), Some(4 * MIST_PER_SUI));
+    }
+
+    #[test]
+    fn rounds_usd_conversion_to_nearest_mist() {
+        // 1 / 3 SUI = 333333333.33... MIST
+        assert_eq!(WalletUtils::usd_to_mist(1.0, 3.0), Some(333_333_333));
+        // 2 / 3 SUI = 666666666.66... MIST
+        assert_eq!(WalletUtils::usd_to_mist(2.0, 3.0), Some(666_666_667));
+    }
+
+    #[test]
+    fn rejects_unusable_usd_inputs() {
+        assert_eq!(WalletUtils::usd_to_mist(10.0, 0.0), None);
+        assert_eq!(WalletUtils::usd_to_mist(10.0, f64::NAN), None);
+        assert_eq!(WalletUtils::usd_to_mist(-1.0, 2.5), None);
+    }
+
+    #[test]
+    fn usd_amounts_use_the_decimal_grammar() {
+        assert_eq!(WalletUtils::parse_usd_amount("12.5"), Ok(12.5));
+        assert_eq!(WalletUtils::parse_usd_amount(".5"), Ok(0.5));
+        assert_eq!(WalletUtils::parse_usd_amount(" "), Err(AmountParseError::Empty));
+        for invalid in ["1e3", "+5", "-1", "inf", "NaN", "1.2.3", "."] {
+            assert_eq!(WalletUtils::parse_usd_amount(invalid), Err(AmountParseError::Invalid), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn format_help_text_is_translated_for_every_language() {
+        let mut i18n = I18nManager::new();
+        for language in [Language::English, Language::Chinese] {
+            i18n.set_language(language);
+            for format in PrivateKeyFormat::all() {
+                let help = i18n.tr(format.help_key());
+                assert_ne!(help, format.help_key(), "{:?} help missing for {:?}", format, language);
+            }
+            assert_ne!(i18n.tr("format_help_what_is_key"), "format_help_what_is_key");
+            assert_ne!(i18n.tr("format_help_safety_warning"), "format_help_safety_warning");
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn classifies_dropped_key_files() {
+        let bech32 = KEY_VECTORS[0].0;
+        let base64_key = format!("A{}", "Q".repeat(43));
+
+        let keystore = format!("[\"{}\", \"not a key\"]", base64_key);
+        assert_eq!(WalletUtils::classify_key_file(&keystore), KeyFileContents::Keystore(vec![base64_key]));
+        assert_eq!(
+            WalletUtils::classify_key_file(&format!("\n  {}\n", bech32)),
+            KeyFileContents::SingleKey(bech32.to_string())
+        );
+        let mnemonic = "abandon ".repeat(11) + "about";
+        assert_eq!(WalletUtils::classify_key_file(&mnemonic), KeyFileContents::Mnemonic(12));
+
+        assert_eq!(WalletUtils::classify_key_file(""), KeyFileContents::Unknown);
+        assert_eq!(WalletUtils::classify_key_file("[]"), KeyFileContents::Unknown);
+        assert_eq!(WalletUtils::classify_key_file("{\"name\": \"wallet\"}"), KeyFileContents::Unknown);
+        assert_eq!(WalletUtils::classify_key_file("hello world, this is a note"), KeyFileContents::Unknown);
+    }
+
+    #[test]
+    fn hex_keys_import_with_or_without_0x_prefix() {
+        // 与 Bech32 测试私钥相同的字节 0x01..=0x20
+        let bech32 = "suiprivkey1qqqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0jqa4ffsr";
+        let hex: String = (1u8..=32).map(|b| format!("{:02x}", b)).collect();
+        let (expected, _) = WalletUtils::import_private_key(bech32).unwrap();
+
+        for input in [hex.clone(), format!("0x{}", hex), format!(" 0X{}\n", hex.to_uppercase())] {
+            assert_eq!(WalletUtils::diagnose_private_key(&input), Ok(PrivateKeyFormat::Hex));
+            let (address, _) = WalletUtils::import_private_key(&input).unwrap();
+            assert_eq!(address, expected);
+        }
+        assert_eq!(
+            WalletUtils::diagnose_private_key(&format!("0x{}", "ab".repeat(33))),
+            Err(KeyImportError::TooLong(PrivateKeyFormat::Hex))
+        );
+    }
+}
@@ -1,47 +1,660 @@
 // 主视图 - 协调各个子视图模块
-use crate::model::Model;
-use crate::i18n::Language;
+use crate::model::{AppTheme, AsyncOperation, CjkFontSource, DialogState, IdleLockPhase, KeyFileContents, CJK_FONT_NAME, EMBEDDED_CJK_FONT, Model, Network, SecuritySettings, StatusKind, StatusMessage};
+use crate::i18n::{I18nManager, Language};
 use eframe::egui;
+use std::time::Instant;
 
 // 导入子视图模块
 pub mod auth_view;
 pub mod wallet_view;
 pub mod menu_view;
 pub mod balance_view;
+pub mod transfer_view;
+pub mod modal_view;
 
 // 重新导出视图组件以便外部使用
 pub use auth_view::AuthView;
 pub use wallet_view::WalletView;
 pub use menu_view::MenuView;
+pub use balance_view::BalanceView;
+pub use transfer_view::TransferView;
+pub use modal_view::ModalView;
 
 /// 视图动作枚举 - 定义用户可以触发的动作
 #[derive(Debug, Clone, PartialEq)]
 pub enum ViewAction {
     ImportKey,
+    /// 临时导入私钥（只保存在内存中）
+    ImportKeyTemporarily,
+    ConfirmReplaceWallet,
     RefreshBalance,
+    /// 收款地址栏内容改变（可能粘贴了支付 URI）
+    RecipientChanged,
+    /// 签名并提交转账表单中的转账
+    SendTransfer,
+    /// 签名并提交批量转账（同一笔交易）
+    SendBatchTransfer,
+    /// 确认向自己的地址转账后继续发送
+    ConfirmSelfSend,
+    /// 确认可疑的大额转账后继续发送
+    ConfirmLargeAmount,
+    /// 保存转账历史中某笔转账的本地备注
+    SaveTransferNote { digest: String, note: String },
+    /// 查询当前地址在所有内置网络上的余额
+    CheckOtherNetworks,
+    RefreshNetworkHealth,
+    OpenCustomNetwork,
+    /// 打开备用 RPC 端点设置
+    OpenRpcFallbacks,
+    /// 保存备用 RPC 端点设置
+    SaveRpcFallbacks,
+    SaveCustomNetwork { name: String, url: String },
+    SwitchNetwork(Network),
+    /// 启用或关闭按网络区分账户
+    SetSeparateAccountsPerNetwork(bool),
+    /// 启用或关闭按健康状态自动选择 RPC 端点
+    SetAutoSelectEndpoint(bool),
+    /// 启用或关闭当前网络不可达时自动切换到首选网络
+    SetAutoSwitchNetwork(bool),
+    /// 设置自动切换时使用的首选网络
+    SetPreferredNetwork(Network),
+    TestCustomNetwork(String),
+    CopyPrivateKey,
+    ConfirmCopyPrivateKey { dont_warn_again: bool },
+    /// 文件被拖放到窗口上（桌面端提供路径，Web 端提供内容）
+    KeyFileDropped { name: String, path: Option<std::path::PathBuf>, bytes: Option<Vec<u8>> },
+    /// 确认导入拖放文件中选中的私钥
+    ConfirmKeyFileImport,
+    CancelKeyFileImport,
+    /// 导出账户地址列表
+    ExportAddresses,
+    /// 打开代币显示过滤设置
+    OpenCoinFilter,
+    /// 保存代币显示过滤设置
+    SaveCoinFilter,
+    /// 验证已加载地址的所有权
+    VerifyOwnership,
+    /// 复制配置目录路径
+    CopyConfigPath,
+    /// 在文件管理器中打开配置目录
+    OpenConfigFolder,
+    /// 启用或关闭本地崩溃报告
+    SetWriteCrashReports(bool),
+    /// 请求删除已保存的加密私钥（已登录时先确认密码）
+    RequestDeleteSavedKey,
+    /// 确认删除已保存的加密私钥
+    ConfirmDeleteSavedKey,
+    /// 请求重置钱包（已登录时先确认密码）
+    RequestResetWallet,
+    /// 确认重置钱包，删除密码和已保存的私钥
+    ConfirmResetWallet,
+    /// 提交修改密码对话框中的新密码
+    SubmitChangePassword,
+    /// 取消修改密码
+    CancelChangePassword,
+    /// 锁定倒计时中选择保持解锁
+    StayUnlocked,
+    /// 取消进行中的异步操作
+    CancelOperation(AsyncOperation),
+    /// 提交敏感操作确认对话框中的密码
+    ConfirmWithPassword,
+    /// 取消敏感操作的密码确认
+    CancelPasswordConfirmation,
+    /// 预览助记词派生的账户
+    PreviewMnemonicAccounts,
+    /// 导入助记词派生的指定账户
+    ImportMnemonicAccount(u32),
     Logout,
+    RenameAccount(String),
+    /// 切换到指定索引的账户
+    SelectAccount(usize),
     LanguageChanged(Language),
     None,
 }
 
+impl ViewAction {
+    /// 临时会话中允许的动作：导入、查看余额和登出，不包括设置、导出和破坏性操作
+    pub fn allowed_in_temporary_session(&self) -> bool {
+        matches!(
+            self,
+            ViewAction::ImportKey
+                | ViewAction::ImportKeyTemporarily
+                | ViewAction::ConfirmReplaceWallet
+                | ViewAction::PreviewMnemonicAccounts
+                | ViewAction::ImportMnemonicAccount(_)
+                | ViewAction::RefreshBalance
+                | ViewAction::RefreshNetworkHealth
+                | ViewAction::VerifyOwnership
+                | ViewAction::StayUnlocked
+                | ViewAction::CancelOperation(_)
+                | ViewAction::Logout
+                | ViewAction::None
+        )
+    }
+
+    /// 会改变钱包、账户或网络的动作：发送、导入、刷新余额、切换网络或账户、删除私钥等
+    /// 转账或余额刷新进行中时不处理这些动作；登出、切换语言和对话框操作不受影响
+    pub fn is_wallet_mutation(&self) -> bool {
+        matches!(
+            self,
+            ViewAction::ImportKey
+                | ViewAction::ImportKeyTemporarily
+                | ViewAction::ConfirmReplaceWallet
+                | ViewAction::ConfirmKeyFileImport
+                | ViewAction::ImportMnemonicAccount(_)
+                | ViewAction::RefreshBalance
+                | ViewAction::SendTransfer
+                | ViewAction::SendBatchTransfer
+                | ViewAction::ConfirmSelfSend
+                | ViewAction::ConfirmLargeAmount
+                | ViewAction::SaveCustomNetwork { .. }
+                | ViewAction::SwitchNetwork(_)
+                | ViewAction::SetSeparateAccountsPerNetwork(_)
+                | ViewAction::SetAutoSwitchNetwork(_)
+                | ViewAction::SetPreferredNetwork(_)
+                | ViewAction::SelectAccount(_)
+                | ViewAction::RequestDeleteSavedKey
+                | ViewAction::ConfirmDeleteSavedKey
+                | ViewAction::RequestResetWallet
+                | ViewAction::ConfirmResetWallet
+                | ViewAction::SubmitChangePassword
+        )
+    }
+}
+
+/// 可通过回车键提交的表单输入框
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SubmitField {
+    /// 登录密码
+    LoginPassword,
+    /// 首次设置密码
+    SetupPassword,
+    /// 首次设置确认密码
+    SetupConfirm,
+    /// 多行私钥导入框（需要 Ctrl/Cmd+Enter，单独的 Enter 用于换行）
+    ImportKey,
+}
+
+/// 回车键触发的表单动作
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SubmitAction {
+    Login,
+    CreatePassword,
+    /// 移动焦点到确认密码框
+    FocusConfirm,
+    ImportKey,
+    None,
+}
+
+impl SubmitField {
+    /// 确定在该输入框按下回车时的动作
+    /// `command` 表示是否按住 Ctrl/Cmd，`form_complete` 表示表单必填项是否都已填写
+    pub fn on_enter(self, command: bool, form_complete: bool) -> SubmitAction {
+        match self {
+            SubmitField::LoginPassword if form_complete => SubmitAction::Login,
+            SubmitField::SetupPassword | SubmitField::SetupConfirm if form_complete => {
+                SubmitAction::CreatePassword
+            }
+            SubmitField::SetupPassword => SubmitAction::FocusConfirm,
+            SubmitField::ImportKey if command && form_complete => SubmitAction::ImportKey,
+            _ => SubmitAction::None,
+        }
+    }
+
+    /// 检查该输入框是否刚按下回车，返回按键时是否按住 Ctrl/Cmd
+    /// 单行输入框按回车会失去焦点，多行输入框按 Ctrl/Cmd+Enter 时仍保持焦点
+    pub fn enter_pressed(self, response: &egui::Response, ui: &egui::Ui) -> Option<bool> {
+        let focused = match self {
+            SubmitField::ImportKey => response.has_focus(),
+            _ => response.lost_focus(),
+        };
+        let (enter, command) = ui.input(|i| (i.key_pressed(egui::Key::Enter), i.modifiers.command));
+        (focused && enter).then_some(command)
+    }
+}
+
+/// Ctrl/Cmd+1..9 对应的账户快捷键
+const ACCOUNT_SHORTCUT_KEYS: [egui::Key; 9] = [
+    egui::Key::Num1,
+    egui::Key::Num2,
+    egui::Key::Num3,
+    egui::Key::Num4,
+    egui::Key::Num5,
+    egui::Key::Num6,
+    egui::Key::Num7,
+    egui::Key::Num8,
+    egui::Key::Num9,
+];
+
+/// 将快捷键映射为视图动作
+/// F5 刷新余额；Ctrl/Cmd+N 切换到第 N 个账户，超出 `account_count` 时不触发任何动作
+pub fn shortcut_action(key: egui::Key, modifiers: egui::Modifiers, account_count: usize) -> Option<ViewAction> {
+    if key == egui::Key::F5 && modifiers.is_none() {
+        return Some(ViewAction::RefreshBalance);
+    }
+    if !modifiers.command || modifiers.alt || modifiers.shift {
+        return None;
+    }
+    let index = ACCOUNT_SHORTCUT_KEYS.iter().position(|k| *k == key)?;
+    (index < account_count).then_some(ViewAction::SelectAccount(index))
+}
+
 /// 主视图协调器
 pub struct MainView;
 
+/// 字体设置：无条件注册中文字体（内置或用户指定）并设为首选
+pub fn font_definitions(source: CjkFontSource) -> egui::FontDefinitions {
+    let mut fonts = egui::FontDefinitions::default();
+    let font_data = match source {
+        CjkFontSource::Embedded => egui::FontData::from_static(EMBEDDED_CJK_FONT),
+        CjkFontSource::Custom { data, .. } => egui::FontData::from_owned(data),
+    };
+    fonts.font_data.insert(CJK_FONT_NAME.to_owned(), font_data.into());
+    for family in [egui::FontFamily::Proportional, egui::FontFamily::Monospace] {
+        fonts.families.entry(family).or_default().insert(0, CJK_FONT_NAME.to_owned());
+    }
+    fonts
+}
+
+/// 高对比度主题：纯黑背景、纯白文字、醒目的黄色焦点和选中边框
+pub fn high_contrast_visuals() -> egui::Visuals {
+    let mut visuals = egui::Visuals::dark();
+    let focus = egui::Stroke::new(2.0, egui::Color32::YELLOW);
+
+    visuals.override_text_color = Some(egui::Color32::WHITE);
+    visuals.panel_fill = egui::Color32::BLACK;
+    visuals.window_fill = egui::Color32::BLACK;
+    visuals.extreme_bg_color = egui::Color32::BLACK;
+    visuals.faint_bg_color = egui::Color32::from_gray(24);
+    visuals.window_stroke = egui::Stroke::new(2.0, egui::Color32::WHITE);
+    visuals.hyperlink_color = egui::Color32::from_rgb(90, 200, 255);
+    visuals.selection.bg_fill = egui::Color32::from_rgb(0, 70, 160);
+    visuals.selection.stroke = focus;
+
+    let widgets = &mut visuals.widgets;
+    widgets.noninteractive.fg_stroke = egui::Stroke::new(1.0, egui::Color32::WHITE);
+    widgets.noninteractive.bg_stroke = egui::Stroke::new(1.0, egui::Color32::WHITE);
+    for style in [&mut widgets.inactive, &mut widgets.hovered, &mut widgets.active, &mut widgets.open] {
+        style.bg_fill = egui::Color32::BLACK;
+        style.weak_bg_fill = egui::Color32::BLACK;
+        style.fg_stroke = egui::Stroke::new(1.5, egui::Color32::WHITE);
+        style.bg_stroke = egui::Stroke::new(1.0, egui::Color32::WHITE);
+    }
+    // 悬停和键盘焦点（egui 以 active 样式绘制获得焦点的控件）使用加粗的黄色边框
+    widgets.hovered.bg_stroke = focus;
+    widgets.active.bg_stroke = focus;
+    widgets.open.bg_stroke = focus;
+    visuals
+}
+
+/// 网络的显示名称：内置网络使用翻译，自定义网络使用其名称
+pub fn network_display_name(i18n: &I18nManager, network: &Network) -> String {
+    match network {
+        Network::Custom { name, .. } if !name.trim().is_empty() => name.clone(),
+        other => i18n.tr(other.i18n_key()),
+    }
+}
+
+/// 进行中的异步操作名称，用逗号分隔
+pub fn running_operations_text(model: &Model) -> String {
+    model
+        .operations
+        .iter()
+        .map(|operation| model.i18n.tr(operation.i18n_key()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// 当前网络标签的颜色，高对比度主题下使用更明亮的配色
+pub fn network_label_color(network: &Network, theme: AppTheme) -> egui::Color32 {
+    let color = network.color();
+    let (r, g, b) = if theme == AppTheme::HighContrast {
+        color.to_high_contrast_rgb()
+    } else {
+        color.to_rgb()
+    };
+    egui::Color32::from_rgb(r, g, b)
+}
+
 impl MainView {
     /// 显示主应用程序界面
     pub fn show(model: &mut Model, ctx: &egui::Context) -> ViewAction {
         let mut action = ViewAction::None;
 
-        // 首先显示菜单栏（如果已认证）
+        // 写入控制器准备好的剪贴板内容
+        if let Some(text) = model.app_state.ui_state.pending_copy.take() {
+            ctx.copy_text(text.to_string());
+        }
+
+        // 首先显示菜单栏；临时会话只显示会话提示和登出按钮，不提供设置和其他菜单
         if model.auth_state.is_authenticated {
             if let Some(menu_action) = Self::show_menu_bar(model, ctx) {
                 action = menu_action;
             }
+        } else if model.auth_state.temporary_session {
+            action = MenuView::show_temporary_session_bar(model, ctx);
         }
 
         // 显示主要内容区域
         action = Self::merge_actions(action, Self::show_main_content(model, ctx));
 
+        // 空闲锁定倒计时
+        action = Self::merge_actions(action, Self::show_idle_lock_countdown(model, ctx));
+
+        // 快捷键（对话框打开时不处理）
+        if model.app_state.ui_state.dialog_state == DialogState::None {
+            action = Self::merge_actions(action, Self::read_shortcuts(model, ctx));
+        }
+
+        // 拖放的密钥文件（已登录且没有打开其他对话框时处理，导入前需确认）
+        if model.auth_state.is_authenticated && model.app_state.ui_state.dialog_state == DialogState::None {
+            action = Self::merge_actions(action, Self::read_dropped_file(ctx));
+        }
+
+        // Esc 关闭当前对话框；需要清理状态的对话框转换为对应的取消动作
+        if ModalView::escape_pressed(ctx) {
+            action = Self::merge_actions(action, Self::dismiss_dialog(model));
+        }
+
+        // 显示对话框
+        match model.app_state.ui_state.dialog_state {
+            DialogState::ConfirmReplaceWallet => {
+                action = Self::merge_actions(action, Self::show_replace_wallet_dialog(model, ctx));
+            }
+            DialogState::CustomNetwork => {
+                action = Self::merge_actions(action, MenuView::show_custom_network_dialog(model, ctx));
+            }
+            DialogState::ClipboardWarning => {
+                action = Self::merge_actions(action, Self::show_clipboard_warning_dialog(model, ctx));
+            }
+            DialogState::ConfirmKeyFileImport => {
+                action = Self::merge_actions(action, Self::show_key_file_import_dialog(model, ctx));
+            }
+            DialogState::ConfirmDeleteKey => {
+                action = Self::merge_actions(action, AuthView::show_delete_key_dialog(model, ctx));
+            }
+            DialogState::ConfirmPassword(_) => {
+                action = Self::merge_actions(action, AuthView::show_confirm_password_dialog(model, ctx));
+            }
+            DialogState::ChangePassword => {
+                action = Self::merge_actions(action, AuthView::show_change_password_dialog(model, ctx));
+            }
+            DialogState::ConfirmReset => {
+                action = Self::merge_actions(action, AuthView::show_reset_dialog(model, ctx));
+            }
+            DialogState::ExportAddresses => {
+                action = Self::merge_actions(action, MenuView::show_export_addresses_dialog(model, ctx));
+            }
+            DialogState::RpcFallbacks => {
+                action = Self::merge_actions(action, MenuView::show_rpc_fallbacks_dialog(model, ctx));
+            }
+            DialogState::CoinFilter => {
+                action = Self::merge_actions(action, MenuView::show_coin_filter_dialog(model, ctx));
+            }
+            DialogState::BatchTransfer => {
+                TransferView::show_batch_transfer_dialog(model, ctx);
+            }
+            DialogState::ConfirmBatchTransfer => {
+                action = Self::merge_actions(action, TransferView::show_batch_confirm_dialog(model, ctx));
+            }
+            DialogState::ConfirmSelfSend => {
+                action = Self::merge_actions(action, TransferView::show_self_send_dialog(model, ctx));
+            }
+            DialogState::ConfirmLargeAmount(_) => {
+                action = Self::merge_actions(action, TransferView::show_large_amount_dialog(model, ctx));
+            }
+            DialogState::Info(_) | DialogState::Warning(_) => {
+                Self::show_message_dialog(model, ctx);
+            }
+            _ => {}
+        }
+
+        action
+    }
+
+    /// 即将因空闲而锁定时显示倒计时和"保持解锁"按钮
+    fn show_idle_lock_countdown(model: &Model, ctx: &egui::Context) -> ViewAction {
+        let grace = model.app_state.settings.security_settings.idle_lock_grace();
+        let IdleLockPhase::Warning(remaining) = model.auth_state.idle_lock_phase(Instant::now(), grace) else {
+            return ViewAction::None;
+        };
+
+        let mut action = ViewAction::None;
+        egui::Window::new(model.i18n.tr("idle_lock_title"))
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::RIGHT_BOTTOM, [-12.0, -12.0])
+            .show(ctx, |ui| {
+                let seconds = remaining.as_secs_f32().ceil() as u64;
+                ui.colored_label(
+                    egui::Color32::YELLOW,
+                    model.i18n.tr("idle_lock_countdown").replace("{}", &seconds.to_string()),
+                );
+                if ui.button(model.i18n.tr("stay_unlocked_button")).clicked() {
+                    action = ViewAction::StayUnlocked;
+                }
+            });
+
+        action
+    }
+
+    /// 读取本帧按下的快捷键
+    fn read_shortcuts(model: &Model, ctx: &egui::Context) -> ViewAction {
+        let account_count = model.wallet.account_count();
+        ctx.input(|i| {
+            i.events
+                .iter()
+                .find_map(|event| match event {
+                    egui::Event::Key { key, pressed: true, repeat: false, modifiers, .. } => {
+                        shortcut_action(*key, *modifiers, account_count)
+                    }
+                    _ => None,
+                })
+                .unwrap_or(ViewAction::None)
+        })
+    }
+
+    /// 读取本帧拖放的文件，一次只处理第一个
+    fn read_dropped_file(ctx: &egui::Context) -> ViewAction {
+        ctx.input(|i| {
+            i.raw
+                .dropped_files
+                .first()
+                .map(|file| ViewAction::KeyFileDropped {
+                    name: file
+                        .path
+                        .as_ref()
+                        .and_then(|p| p.file_name())
+                        .map(|n| n.to_string_lossy().into_owned())
+                        .unwrap_or_else(|| file.name.clone()),
+                    path: file.path.clone(),
+                    bytes: file.bytes.as_ref().map(|b| b.to_vec()),
+                })
+                .unwrap_or(ViewAction::None)
+        })
+    }
+
+    /// 关闭当前对话框（Esc）
+    fn dismiss_dialog(model: &mut Model) -> ViewAction {
+        match model.app_state.ui_state.dialog_state {
+            // 待导入的密钥文件需要由控制器清除
+            DialogState::ConfirmKeyFileImport => ViewAction::CancelKeyFileImport,
+            // 已输入的密码需要由控制器清除
+            DialogState::ConfirmPassword(_) => ViewAction::CancelPasswordConfirmation,
+            DialogState::ChangePassword => ViewAction::CancelChangePassword,
+            _ => {
+                model.app_state.ui_state.dismiss_dialog();
+                ViewAction::None
+            }
+        }
+    }
+
+    /// 显示拖放密钥文件的导入确认对话框
+    fn show_key_file_import_dialog(model: &mut Model, ctx: &egui::Context) -> ViewAction {
+        let mut action = ViewAction::None;
+        let wallet_loaded = model.wallet.is_loaded();
+        let i18n = &model.i18n;
+        let Some(pending) = model.app_state.ui_state.pending_key_file.as_mut() else {
+            return action;
+        };
+
+        ModalView::show(ctx, "key_file_import_title", &i18n.tr("key_file_import_title"), |ui| {
+            ui.label(format!("{}: {}", i18n.tr("key_file_name"), pending.file_name));
+            ui.add_space(4.0);
+
+            match &pending.contents {
+                KeyFileContents::Keystore(keys) => {
+                    ui.label(i18n.tr("key_file_keystore").replace("{}", &keys.len().to_string()));
+                    let count = keys.len();
+                    for index in 0..count {
+                        let label = i18n.tr("key_file_entry").replace("{}", &(index + 1).to_string());
+                        ui.radio_value(&mut pending.selected, index, label);
+                    }
+                }
+                KeyFileContents::SingleKey(_) => {
+                    ui.label(i18n.tr("key_file_single_key"));
+                }
+                KeyFileContents::Mnemonic(words) => {
+                    ui.colored_label(
+                        egui::Color32::YELLOW,
+                        i18n.tr("key_file_mnemonic_unsupported").replace("{}", &words.to_string()),
+                    );
+                }
+                KeyFileContents::Unknown => {}
+            }
+
+            if wallet_loaded && pending.selected_key().is_some() {
+                ui.add_space(4.0);
+                ui.colored_label(egui::Color32::YELLOW, i18n.tr("replace_wallet_message"));
+            }
+
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                let can_import = pending.selected_key().is_some();
+                if ui.add_enabled(can_import, egui::Button::new(i18n.tr("key_file_import_button"))).clicked() {
+                    action = ViewAction::ConfirmKeyFileImport;
+                }
+                if ui.button(i18n.tr("cancel_button")).clicked() {
+                    action = ViewAction::CancelKeyFileImport;
+                }
+            });
+        });
+
+        action
+    }
+
+    /// 将主题设置应用到界面（设置变化时立即生效）
+    pub fn sync_theme(model: &mut Model, ctx: &egui::Context) {
+        let theme = model.app_state.settings.theme;
+        let ui_state = &mut model.app_state.ui_state;
+        if ui_state.applied_theme == Some(theme) {
+            return;
+        }
+
+        // 先恢复默认配色，避免切换主题后残留高对比度配色
+        ctx.set_visuals_of(egui::Theme::Light, egui::Visuals::light());
+        ctx.set_visuals_of(egui::Theme::Dark, egui::Visuals::dark());
+        match theme {
+            AppTheme::Light => ctx.set_theme(egui::ThemePreference::Light),
+            AppTheme::Dark => ctx.set_theme(egui::ThemePreference::Dark),
+            AppTheme::Auto => ctx.set_theme(egui::ThemePreference::System),
+            AppTheme::HighContrast => {
+                ctx.set_visuals_of(egui::Theme::Dark, high_contrast_visuals());
+                ctx.set_theme(egui::ThemePreference::Dark);
+            }
+        }
+        ui_state.applied_theme = Some(theme);
+    }
+
+    /// 将界面缩放设置应用到界面（设置变化时立即生效）
+    pub fn sync_ui_scale(model: &mut Model, ctx: &egui::Context) {
+        let scale = model.app_state.settings.effective_ui_scale();
+        let ui_state = &mut model.app_state.ui_state;
+        if ui_state.applied_ui_scale != Some(scale) {
+            ctx.set_zoom_factor(scale);
+            ui_state.applied_ui_scale = Some(scale);
+        }
+    }
+
+    /// 将屏幕截图保护设置同步到窗口（设置变化时立即生效）
+    pub fn sync_screenshot_protection(model: &mut Model, ctx: &egui::Context) {
+        if !SecuritySettings::screenshot_protection_supported() {
+            return;
+        }
+
+        let enabled = model.app_state.settings.security_settings.screenshot_protection;
+        let ui_state = &mut model.app_state.ui_state;
+        if ui_state.applied_screenshot_protection != Some(enabled) {
+            ctx.send_viewport_cmd(egui::ViewportCommand::ContentProtected(enabled));
+            ui_state.applied_screenshot_protection = Some(enabled);
+        }
+    }
+
+    /// 显示通用消息对话框（信息、警告）
+    fn show_message_dialog(model: &mut Model, ctx: &egui::Context) {
+        let (title_key, color) = match &model.app_state.ui_state.dialog_state {
+            DialogState::Warning(_) => ("warning_title", egui::Color32::YELLOW),
+            _ => ("info_title", ctx.style().visuals.text_color()),
+        };
+        let message = model
+            .app_state
+            .ui_state
+            .dialog_state
+            .message()
+            .unwrap_or_default()
+            .to_string();
+
+        ModalView::show(ctx, "message", &model.i18n.tr(title_key), |ui| {
+            ui.colored_label(color, message);
+            ui.add_space(8.0);
+            if ui.button(model.i18n.tr("close")).clicked() {
+                model.app_state.ui_state.close_dialog();
+            }
+        });
+    }
+
+    /// 显示替换当前钱包的确认对话框
+    fn show_replace_wallet_dialog(model: &mut Model, ctx: &egui::Context) -> ViewAction {
+        let mut action = ViewAction::None;
+
+        ModalView::show(ctx, "replace_wallet_title", &model.i18n.tr("replace_wallet_title"), |ui| {
+            ui.label(model.i18n.tr("replace_wallet_message"));
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                if ui.button(model.i18n.tr("replace_wallet_confirm_button")).clicked() {
+                    action = ViewAction::ConfirmReplaceWallet;
+                }
+                if ui.button(model.i18n.tr("cancel_button")).clicked() {
+                    model.app_state.ui_state.close_dialog();
+                }
+            });
+        });
+
+        action
+    }
+
+    /// 显示复制敏感数据前的剪贴板历史警告
+    fn show_clipboard_warning_dialog(model: &mut Model, ctx: &egui::Context) -> ViewAction {
+        let mut action = ViewAction::None;
+
+        ModalView::show(ctx, "clipboard_warning_title", &model.i18n.tr("clipboard_warning_title"), |ui| {
+            ui.colored_label(egui::Color32::YELLOW, model.i18n.tr("clipboard_warning_message"));
+            ui.add_space(6.0);
+            let ui_state = &mut model.app_state.ui_state;
+            ui.checkbox(&mut ui_state.dont_warn_copy_again, model.i18n.tr("dont_warn_again"));
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                if ui.button(model.i18n.tr("copy_anyway_button")).clicked() {
+                    action = ViewAction::ConfirmCopyPrivateKey {
+                        dont_warn_again: ui_state.dont_warn_copy_again,
+                    };
+                }
+                if ui.button(model.i18n.tr("cancel_button")).clicked() {
+                    ui_state.close_dialog();
+                }
+            });
+        });
+
         action
     }
 
@@ -56,40 +669,100 @@ impl MainView {
 
     /// 显示主要内容区域
     fn show_main_content(model: &mut Model, ctx: &egui::Context) -> ViewAction {
-        let mut action = ViewAction::None;
-
         egui::CentralPanel::default().show(ctx, |ui| {
-            ui.heading(&model.i18n.tr("app_title"));
+            ui.heading(model.i18n.tr("app_title"));
             ui.add_space(10.0);
 
             // 根据钱包状态显示不同的视图
-            action = WalletView::show_wallet_content(model, ui);
+            let mut action = WalletView::show_wallet_content(model, ui);
+
+            // 已加载钱包时显示余额、各网络余额汇总和本地转账历史
+            if model.wallet.is_loaded() {
+                ui.add_space(10.0);
+                if let Some(balance_action) = BalanceView::show_balance_panel(model, ui) {
+                    action = Self::merge_actions(action, balance_action);
+                }
+                // 临时会话不能发送转账
+                if model.auth_state.is_authenticated {
+                    ui.add_space(10.0);
+                    action = Self::merge_actions(action, TransferView::show_transfer_panel(model, ui));
+                }
+                ui.add_space(10.0);
+                if let Some(summary_action) = BalanceView::show_network_summary_panel(model, ui) {
+                    action = Self::merge_actions(action, summary_action);
+                }
+                ui.add_space(10.0);
+                if let Some(history_action) = BalanceView::show_transaction_history(model, ui) {
+                    action = Self::merge_actions(action, history_action);
+                }
+            }
 
             ui.add_space(10.0);
             ui.separator();
 
             // 显示状态和加载信息
-            Self::show_status_section(model, ui);
-        });
+            if let Some(status_action) = Self::show_status_section(model, ui) {
+                action = Self::merge_actions(action, status_action);
+            }
 
-        action
+            action
+        })
+        .inner
     }
 
     /// 显示状态区域
-    fn show_status_section(model: &Model, ui: &mut egui::Ui) {
+    /// 进行中的操作各带一个取消按钮，返回用户取消的操作
+    fn show_status_section(model: &Model, ui: &mut egui::Ui) -> Option<ViewAction> {
+        let mut action = None;
         ui.horizontal(|ui| {
-            // 加载指示器
-            if model.is_loading {
+            // 加载指示器：显示正在进行的操作
+            if !model.operations.is_idle() {
                 ui.add(egui::Spinner::new());
-                ui.label(&model.i18n.tr("loading"));
+                for operation in model.operations.iter() {
+                    ui.label(model.i18n.tr(operation.i18n_key()));
+                    // 已提交的转账无法撤回，不显示取消按钮
+                    if operation.is_cancellable() && ui.small_button(model.i18n.tr("cancel_button")).clicked() {
+                        action = Some(ViewAction::CancelOperation(operation));
+                    }
+                }
             }
 
-            // 状态文本
-            if !model.result_text.is_empty() {
+            // 状态消息
+            if let Some(status) = &model.status {
                 ui.separator();
-                ui.label(&model.result_text);
+                Self::show_status_message(status, model, ui);
             }
         });
+        action
+    }
+
+    /// 按类型显示带图标和颜色的状态消息
+    /// 过长的消息截断显示，悬停查看完整内容，并可复制完整内容
+    pub fn show_status_message(status: &StatusMessage, model: &Model, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.label(status.kind.icon());
+            let text = status.display_text();
+            let response = match Self::status_color(status.kind) {
+                Some(color) => ui.colored_label(color, text),
+                None => ui.label(text),
+            };
+            if status.is_truncated() {
+                response.on_hover_text(&status.text);
+                if ui.small_button(model.i18n.tr("copy_full_message")).clicked() {
+                    ui.ctx().copy_text(status.text.clone());
+                }
+            }
+        });
+    }
+
+    /// 状态消息的颜色，普通信息使用默认文本颜色
+    fn status_color(kind: StatusKind) -> Option<egui::Color32> {
+        match kind {
+            StatusKind::Info => None,
+            StatusKind::Success => Some(egui::Color32::GREEN),
+            StatusKind::Warning => Some(egui::Color32::YELLOW),
+            StatusKind::Error => Some(egui::Color32::RED),
+        }
     }
 
     /// 合并多个动作，优先返回非 None 的动作
@@ -113,4 +786,113 @@ pub fn show_password_panel(model: &mut Model, ctx: &egui::Context) {
 /// 绘制主界面，并返回用户触发的动作（向后兼容）
 pub fn show(model: &mut Model, ctx: &egui::Context) -> ViewAction {
     MainView::show(model, ctx)
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn enter_submits_login_only_with_password() {
+        assert_eq!(SubmitField::LoginPassword.on_enter(false, true), SubmitAction::Login);
+        assert_eq!(SubmitField::LoginPassword.on_enter(true, true), SubmitAction::Login);
+        assert_eq!(SubmitField::LoginPassword.on_enter(false, false), SubmitAction::None);
+    }
+
+    #[test]
+    fn enter_in_setup_submits_only_when_both_fields_filled() {
+        assert_eq!(SubmitField::SetupConfirm.on_enter(false, true), SubmitAction::CreatePassword);
+        assert_eq!(SubmitField::SetupConfirm.on_enter(false, false), SubmitAction::None);
+        assert_eq!(SubmitField::SetupPassword.on_enter(false, true), SubmitAction::CreatePassword);
+        assert_eq!(SubmitField::SetupPassword.on_enter(false, false), SubmitAction::FocusConfirm);
+    }
+
+    #[test]
+    fn ctrl_number_selects_existing_account() {
+        let ctrl = egui::Modifiers::COMMAND;
+        assert_eq!(shortcut_action(egui::Key::Num1, ctrl, 1), Some(ViewAction::SelectAccount(0)));
+        assert_eq!(shortcut_action(egui::Key::Num3, ctrl, 3), Some(ViewAction::SelectAccount(2)));
+        assert_eq!(shortcut_action(egui::Key::Num9, ctrl, 9), Some(ViewAction::SelectAccount(8)));
+    }
+
+    #[test]
+    fn ctrl_number_beyond_account_count_is_ignored() {
+        let ctrl = egui::Modifiers::COMMAND;
+        assert_eq!(shortcut_action(egui::Key::Num2, ctrl, 1), None);
+        assert_eq!(shortcut_action(egui::Key::Num1, ctrl, 0), None);
+        // 没有按 Ctrl/Cmd 时不切换
+        assert_eq!(shortcut_action(egui::Key::Num1, egui::Modifiers::NONE, 1), None);
+        assert_eq!(shortcut_action(egui::Key::F5, egui::Modifiers::NONE, 0), Some(ViewAction::RefreshBalance));
+    }
+
+    #[test]
+    fn temporary_session_allows_only_import_view_and_logout() {
+        assert!(ViewAction::ImportKey.allowed_in_temporary_session());
+        assert!(ViewAction::RefreshBalance.allowed_in_temporary_session());
+        assert!(ViewAction::Logout.allowed_in_temporary_session());
+        assert!(!ViewAction::CopyPrivateKey.allowed_in_temporary_session());
+        assert!(!ViewAction::SendTransfer.allowed_in_temporary_session());
+        assert!(!ViewAction::OpenConfigFolder.allowed_in_temporary_session());
+        assert!(!ViewAction::SetWriteCrashReports(false).allowed_in_temporary_session());
+        assert!(!ViewAction::SetSeparateAccountsPerNetwork(true).allowed_in_temporary_session());
+        assert!(!ViewAction::RenameAccount("Main".to_string()).allowed_in_temporary_session());
+    }
+
+    #[test]
+    fn only_wallet_mutations_wait_for_in_flight_operations() {
+        assert!(ViewAction::SendTransfer.is_wallet_mutation());
+        assert!(ViewAction::ImportKey.is_wallet_mutation());
+        assert!(ViewAction::SwitchNetwork(Network::Testnet).is_wallet_mutation());
+        assert!(ViewAction::ConfirmDeleteSavedKey.is_wallet_mutation());
+        assert!(!ViewAction::Logout.is_wallet_mutation());
+        assert!(!ViewAction::LanguageChanged(Language::English).is_wallet_mutation());
+        assert!(!ViewAction::StayUnlocked.is_wallet_mutation());
+        assert!(!ViewAction::ConfirmWithPassword.is_wallet_mutation());
+        assert!(!ViewAction::CancelPasswordConfirmation.is_wallet_mutation());
+    }
+
+    #[test]
+    fn import_field_requires_command_enter() {
+        assert_eq!(SubmitField::ImportKey.on_enter(false, true), SubmitAction::None);
+        assert_eq!(SubmitField::ImportKey.on_enter(true, true), SubmitAction::ImportKey);
+        assert_eq!(SubmitField::ImportKey.on_enter(true, false), SubmitAction::None);
+    }
+
+    #[test]
+    fn embedded_cjk_font_is_registered_first() {
+        let fonts = font_definitions(CjkFontSource::Embedded);
+        let font = fonts.font_data.get(CJK_FONT_NAME).unwrap();
+        assert!(!font.font.is_empty());
+        assert_eq!(font.font.len(), EMBEDDED_CJK_FONT.len());
+        for family in [egui::FontFamily::Proportional, egui::FontFamily::Monospace] {
+            assert_eq!(fonts.families[&family].first().map(String::as_str), Some(CJK_FONT_NAME));
+        }
+    }
+
+    #[test]
+    fn high_contrast_theme_uses_strong_contrast_and_focus_outlines() {
+        let visuals = high_contrast_visuals();
+        assert!(visuals.dark_mode);
+        assert_eq!(visuals.override_text_color, Some(egui::Color32::WHITE));
+        assert_eq!(visuals.panel_fill, egui::Color32::BLACK);
+        assert_eq!(visuals.window_fill, egui::Color32::BLACK);
+        assert_eq!(visuals.widgets.active.bg_stroke, egui::Stroke::new(2.0, egui::Color32::YELLOW));
+        assert_eq!(visuals.selection.stroke.width, 2.0);
+
+        // 各网络的颜色在高对比度主题下互不相同，且不是背景色
+        let networks = [
+            Network::Devnet,
+            Network::Testnet,
+            Network::Mainnet,
+            Network::custom("Local", "http://127.0.0.1:9000"),
+        ];
+        let colors: Vec<egui::Color32> = networks
+            .iter()
+            .map(|network| network_label_color(network, AppTheme::HighContrast))
+            .collect();
+        for (i, color) in colors.iter().enumerate() {
+            assert_ne!(*color, visuals.panel_fill);
+            assert!(colors[i + 1..].iter().all(|other| other != color));
+        }
+    }
+}
@@ -1,5 +1,6 @@
-use crate::model::Model;
+use crate::model::{log_error, DialogState, Model, Network, PasswordSetupValidation};
 use crate::controller;
+use crate::view::{ModalView, SubmitAction, SubmitField, ViewAction};
 use eframe::egui;
 
 /// 认证视图 - 处理登录、密码设置相关的UI组件
@@ -10,62 +11,354 @@ impl AuthView {
     pub fn show_password_panel(model: &mut Model, ctx: &egui::Context) {
         egui::CentralPanel::default().show(ctx, |ui| {
             ui.vertical_centered(|ui| {
-                ui.heading(&model.i18n.tr("login_title"));
+                ui.heading(model.i18n.tr("login_title"));
                 ui.add_space(8.0);
 
-                if model.auth_state.is_first_run {
+                if model.auth_state.needs_recovery() {
+                    Self::show_corrupt_password_recovery(model, ui);
+                } else if model.is_first_run() {
                     Self::show_password_setup(model, ui);
                 } else {
                     Self::show_login_form(model, ui);
                 }
 
                 ui.add_space(12.0);
-                ui.label(&model.i18n.tr("password_info"));
+                ui.label(model.i18n.tr("password_info"));
             });
         });
+
+        // Esc 关闭可关闭的对话框
+        if ModalView::escape_pressed(ctx) {
+            model.app_state.ui_state.dismiss_dialog();
+        }
+
+        match model.app_state.ui_state.dialog_state {
+            DialogState::OrphanedKey => Self::show_orphaned_key_dialog(model, ctx),
+            DialogState::ConfirmDeleteKey => {
+                if Self::show_delete_key_dialog(model, ctx) == ViewAction::ConfirmDeleteSavedKey
+                    && let Err(err) = controller::AuthController::handle_confirm_delete_key(model)
+                {
+                    log_error(&format!("Failed to delete saved private key: {}", err));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// 显示删除已保存私钥的确认对话框，提醒之后需要重新导入私钥
+    pub fn show_delete_key_dialog(model: &mut Model, ctx: &egui::Context) -> ViewAction {
+        let mut action = ViewAction::None;
+
+        ModalView::show(ctx, "delete_key_confirm_title", &model.i18n.tr("delete_key_confirm_title"), |ui| {
+            ui.colored_label(egui::Color32::YELLOW, model.i18n.tr("delete_key_confirm_message"));
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                if ui.button(model.i18n.tr("delete_key_confirm_button")).clicked() {
+                    action = ViewAction::ConfirmDeleteSavedKey;
+                }
+                if ui.button(model.i18n.tr("cancel_button")).clicked() {
+                    model.app_state.ui_state.close_dialog();
+                }
+            });
+        });
+
+        action
+    }
+
+    /// 显示重置钱包的确认对话框
+    pub fn show_reset_dialog(model: &mut Model, ctx: &egui::Context) -> ViewAction {
+        let mut action = ViewAction::None;
+
+        ModalView::show(ctx, "reset_confirm_title", &model.i18n.tr("reset_confirm_title"), |ui| {
+            ui.colored_label(egui::Color32::YELLOW, model.i18n.tr("reset_confirm_message"));
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                if ui.button(model.i18n.tr("reset_confirm_button")).clicked() {
+                    action = ViewAction::ConfirmResetWallet;
+                }
+                if ui.button(model.i18n.tr("cancel_button")).clicked() {
+                    model.app_state.ui_state.close_dialog();
+                }
+            });
+        });
+
+        action
+    }
+
+    /// 修改密码对话框：输入两次新密码，实时提示是否一致以及强度是否达标
+    /// 提交后还需要重新输入当前密码确认
+    pub fn show_change_password_dialog(model: &mut Model, ctx: &egui::Context) -> ViewAction {
+        let mut action = ViewAction::None;
+
+        ModalView::show(ctx, "change_password_title", &model.i18n.tr("change_password_title"), |ui| {
+            let ui_state = &mut model.app_state.ui_state;
+            ui.add(
+                egui::TextEdit::singleline(&mut ui_state.new_password_input)
+                    .password(true)
+                    .hint_text(model.i18n.tr("new_password_hint")),
+            );
+            ui.add(
+                egui::TextEdit::singleline(&mut ui_state.new_password_confirm)
+                    .password(true)
+                    .hint_text(model.i18n.tr("confirm_new_password_hint")),
+            );
+
+            let validation = PasswordSetupValidation::check(
+                &ui_state.new_password_input,
+                &ui_state.new_password_confirm,
+                &model.auth_state.password_policy,
+            );
+            if let Some(issue) = validation.strength_issue.filter(|_| !ui_state.new_password_input.is_empty()) {
+                ui.colored_label(egui::Color32::from_rgb(255, 140, 0), issue.message(|key| model.i18n.tr(key)));
+            }
+            if validation.confirm_matches == Some(false) {
+                ui.colored_label(egui::Color32::RED, model.i18n.tr("passwords_do_not_match"));
+            }
+
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                let button = egui::Button::new(model.i18n.tr("change_password_button"));
+                if ui.add_enabled(validation.can_submit(), button).clicked() {
+                    action = ViewAction::SubmitChangePassword;
+                }
+                if ui.button(model.i18n.tr("cancel_button")).clicked() {
+                    action = ViewAction::CancelChangePassword;
+                }
+            });
+        });
+
+        action
+    }
+
+    /// 敏感操作前重新输入密码的确认对话框，回车或点击确认提交
+    pub fn show_confirm_password_dialog(model: &mut Model, ctx: &egui::Context) -> ViewAction {
+        let mut action = ViewAction::None;
+        let DialogState::ConfirmPassword(protected) = model.app_state.ui_state.dialog_state else {
+            return action;
+        };
+
+        ModalView::show(ctx, "confirm_password_title", &model.i18n.tr("confirm_password_title"), |ui| {
+            ui.label(model.i18n.tr(protected.i18n_key()));
+            ui.add_space(6.0);
+            let ui_state = &mut model.app_state.ui_state;
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut ui_state.confirm_password_input)
+                    .password(true)
+                    .hint_text(model.i18n.tr("enter_password")),
+            );
+            if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                action = ViewAction::ConfirmWithPassword;
+            }
+            if let Some(error) = &ui_state.confirm_password_error {
+                ui.colored_label(egui::Color32::RED, error);
+            }
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                let has_input = !ui_state.confirm_password_input.is_empty();
+                if ui.add_enabled(has_input, egui::Button::new(model.i18n.tr("confirm_button"))).clicked() {
+                    action = ViewAction::ConfirmWithPassword;
+                }
+                if ui.button(model.i18n.tr("cancel_button")).clicked() {
+                    action = ViewAction::CancelPasswordConfirmation;
+                }
+            });
+        });
+
+        action
+    }
+
+    /// 显示孤立私钥对话框（私钥文件存在但密码文件缺失）
+    fn show_orphaned_key_dialog(model: &mut Model, ctx: &egui::Context) {
+        ModalView::show(ctx, "orphaned_key_title", &model.i18n.tr("orphaned_key_title"), |ui| {
+            ui.label(model.i18n.tr("orphaned_key_message"));
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                if ui.button(model.i18n.tr("delete_orphaned_key_button")).clicked() {
+                    controller::AuthController::request_delete_key_confirmation(model);
+                }
+                if ui.button(model.i18n.tr("reimport_key_button")).clicked() {
+                    model.app_state.ui_state.close_dialog();
+                }
+            });
+        });
+    }
+
+    /// 显示密码文件损坏时的恢复界面
+    fn show_corrupt_password_recovery(model: &mut Model, ui: &mut egui::Ui) {
+        ui.colored_label(egui::Color32::RED, model.i18n.tr("password_file_corrupt"));
+        ui.label(model.i18n.tr("password_file_corrupt_hint"));
+        ui.add_space(6.0);
+
+        if model.app_state.ui_state.dialog_state == DialogState::ConfirmReset {
+            ui.colored_label(egui::Color32::YELLOW, model.i18n.tr("reset_confirm_message"));
+            ui.horizontal(|ui| {
+                if ui.button(model.i18n.tr("reset_confirm_button")).clicked()
+                    && let Err(err) = controller::handle_reset_wallet(model)
+                {
+                    log_error(&format!("Failed to reset wallet data: {}", err));
+                }
+                if ui.button(model.i18n.tr("cancel_button")).clicked() {
+                    model.app_state.ui_state.close_dialog();
+                }
+            });
+        } else if ui.button(model.i18n.tr("reset_wipe_data_button")).clicked() {
+            controller::AuthController::request_reset_confirmation(model);
+        }
     }
 
     /// 显示密码设置表单（首次运行）
     fn show_password_setup(model: &mut Model, ui: &mut egui::Ui) {
-        ui.label(&model.i18n.tr("first_run_message"));
+        ui.label(model.i18n.tr("first_run_message"));
         
-        ui.add(
+        let password_response = ui.add(
             egui::TextEdit::singleline(&mut model.auth_state.password_input)
                 .password(true)
-                .hint_text(&model.i18n.tr("enter_password"))
+                .hint_text(model.i18n.tr("enter_password"))
         );
+        if model.app_state.ui_state.take_password_focus_request() {
+            password_response.request_focus();
+        }
         
-        ui.add(
+        let confirm_response = ui.add(
             egui::TextEdit::singleline(&mut model.auth_state.password_confirm)
                 .password(true)
-                .hint_text(&model.i18n.tr("confirm_password"))
+                .hint_text(model.i18n.tr("confirm_password"))
         );
+
+        // 输入时实时提示两次密码是否一致以及强度是否达标
+        let validation = PasswordSetupValidation::check(
+            &model.auth_state.password_input,
+            &model.auth_state.password_confirm,
+            &model.auth_state.password_policy,
+        );
+        if let Some(issue) = validation.strength_issue.filter(|_| !model.auth_state.password_input.is_empty()) {
+            ui.colored_label(egui::Color32::from_rgb(255, 140, 0), issue.message(|key| model.i18n.tr(key)));
+        }
+        match validation.confirm_matches {
+            Some(true) => {
+                ui.colored_label(egui::Color32::from_rgb(0, 150, 0), model.i18n.tr("passwords_match"));
+            }
+            Some(false) => {
+                ui.colored_label(egui::Color32::RED, model.i18n.tr("passwords_do_not_match"));
+            }
+            None => {}
+        }
+
+        // 回车提交：两次输入一致且达到强度要求后才创建密码
+        let form_complete = validation.can_submit();
+        let mut submit = SubmitAction::None;
+        for (field, response) in [
+            (SubmitField::SetupPassword, &password_response),
+            (SubmitField::SetupConfirm, &confirm_response),
+        ] {
+            if let Some(command) = field.enter_pressed(response, ui) {
+                submit = field.on_enter(command, form_complete);
+            }
+        }
+        if submit == SubmitAction::FocusConfirm {
+            confirm_response.request_focus();
+        }
+
+        // 首次运行时选择默认网络，之后启动时没有最近使用的网络就使用它
+        ui.horizontal(|ui| {
+            ui.label(model.i18n.tr("default_network_label"));
+            let mut selected = model.app_state.settings.default_network.clone();
+            egui::ComboBox::from_id_salt("setup_default_network")
+                .selected_text(selected.name())
+                .show_ui(ui, |ui| {
+                    for network in Network::all() {
+                        let name = network.name().to_string();
+                        ui.selectable_value(&mut selected, network, name);
+                    }
+                });
+            if selected != model.app_state.settings.default_network {
+                model.app_state.settings.default_network = selected.clone();
+                model.set_network(selected);
+            }
+        });
+
+        // 首次运行时即可选择是否自动保存导入的私钥
+        ui.checkbox(
+            &mut model.app_state.settings.security_settings.auto_save_keys,
+            model.i18n.tr("auto_save_keys"),
+        )
+        .on_hover_text(model.i18n.tr("auto_save_keys_hint"));
         
         ui.add_space(6.0);
         
-        if ui.button(&model.i18n.tr("create_password_button")).clicked() {
-            if let Err(err) = controller::handle_set_password(model) {
-                model.auth_state.password_input.clear();
-                model.auth_state.password_confirm.clear();
-                eprintln!("Failed to set password: {}", err);
-            }
+        let create_button = egui::Button::new(model.i18n.tr("create_password_button"));
+        if ui.add_enabled(form_complete, create_button).clicked() || submit == SubmitAction::CreatePassword {
+            Self::submit_password_setup(model);
+        }
+    }
+
+    /// 提交首次密码设置
+    fn submit_password_setup(model: &mut Model) {
+        if let Err(err) = controller::handle_set_password(model) {
+            model.auth_state.clear_password_inputs();
+            log_error(&format!("Failed to set password: {}", err));
         }
+        // 失败后重新输入，成功后切换到登录表单，两种情况都聚焦密码框
+        model.app_state.ui_state.request_password_focus();
     }
 
     /// 显示登录表单
     fn show_login_form(model: &mut Model, ui: &mut egui::Ui) {
-        ui.label(&model.i18n.tr("login_message"));
+        if model.auth_state.is_locked {
+            ui.label(model.i18n.tr("wallet_locked_message"));
+        } else {
+            ui.label(model.i18n.tr("login_message"));
+        }
         
-        ui.add(
+        let password_response = ui.add(
             egui::TextEdit::singleline(&mut model.auth_state.password_input)
                 .password(true)
-                .hint_text(&model.i18n.tr("enter_password"))
+                .hint_text(model.i18n.tr("enter_password"))
         );
+        if model.app_state.ui_state.take_password_focus_request() {
+            password_response.request_focus();
+        }
+        let submit = SubmitField::LoginPassword
+            .enter_pressed(&password_response, ui)
+            .map(|command| {
+                SubmitField::LoginPassword.on_enter(command, !model.auth_state.password_input.is_empty())
+            });
+
+        let hours = model.app_state.settings.security_settings.stay_signed_in_hours;
+        let stay_label = model.i18n.tr("stay_signed_in").replace("{}", &hours.to_string());
+        ui.checkbox(&mut model.auth_state.stay_signed_in, stay_label)
+            .on_hover_text(model.i18n.tr("stay_signed_in_hint"));
         
         ui.add_space(6.0);
         
         // 居中对齐的按钮
         Self::show_centered_buttons(model, ui);
+
+        // 不登录，临时导入一个私钥（不会保存）；锁定时必须输入密码，不提供该入口
+        if model.can_start_temporary_session() {
+            ui.add_space(4.0);
+            if ui
+                .small_button(model.i18n.tr("temporary_session_button"))
+                .on_hover_text(model.i18n.tr("import_temporarily_hint"))
+                .clicked()
+            {
+                controller::handle_start_temporary_session(model);
+            }
+        }
+
+        if submit == Some(SubmitAction::Login) {
+            Self::submit_login(model);
+        }
+    }
+
+    /// 提交登录
+    fn submit_login(model: &mut Model) {
+        if let Err(err) = controller::handle_verify_password(model) {
+            log_error(&format!("Password verification failed: {}", err));
+            // 清空输入后重新聚焦，方便直接重试
+            model.app_state.ui_state.request_password_focus();
+        }
+        model.auth_state.clear_password_inputs();
     }
 
     /// 显示居中对齐的登录和退出按钮
@@ -82,58 +375,15 @@ impl AuthView {
                 ui.add_space(left_padding);
             }
             
-            if ui.button(&model.i18n.tr("login_button")).clicked() {
-                if let Err(err) = controller::handle_verify_password(model) {
-                    eprintln!("Password verification failed: {}", err);
-                }
-                model.auth_state.password_input.clear();
+            if ui.button(model.i18n.tr("login_button")).clicked() {
+                Self::submit_login(model);
             }
             
             ui.add_space(spacing);
             
-            if ui.button(&model.i18n.tr("exit_button")).clicked() {
+            if ui.button(model.i18n.tr("exit_button")).clicked() {
                 std::process::exit(0);
             }
         });
     }
-
-    /// 显示密码强度指示器（可选功能）
-    pub fn show_password_strength(password: &str, ui: &mut egui::Ui) {
-        let strength = Self::calculate_password_strength(password);
-        let (color, text) = match strength {
-            0..=2 => (egui::Color32::RED, "弱"),
-            3..=4 => (egui::Color32::YELLOW, "中等"),
-            5..=6 => (egui::Color32::GREEN, "强"),
-            _ => (egui::Color32::DARK_GREEN, "非常强"),
-        };
-        
-        ui.horizontal(|ui| {
-            ui.label("密码强度:");
-            ui.colored_label(color, text);
-        });
-    }
-
-    /// 计算密码强度分数
-    fn calculate_password_strength(password: &str) -> u8 {
-        let mut score = 0;
-        
-        if password.len() >= 8 { score += 1; }
-        if password.len() >= 12 { score += 1; }
-        if password.chars().any(|c| c.is_lowercase()) { score += 1; }
-        if password.chars().any(|c| c.is_uppercase()) { score += 1; }
-        if password.chars().any(|c| c.is_numeric()) { score += 1; }
-        if password.chars().any(|c| !c.is_alphanumeric()) { score += 1; }
-        
-        score
-    }
-
-    /// 显示错误消息（可选功能）
-    pub fn show_error_message(message: &str, ui: &mut egui::Ui) {
-        ui.colored_label(egui::Color32::RED, message);
-    }
-
-    /// 显示成功消息（可选功能）
-    pub fn show_success_message(message: &str, ui: &mut egui::Ui) {
-        ui.colored_label(egui::Color32::GREEN, message);
-    }
 }
\ No newline at end of file
@@ -1,243 +1,381 @@
-use crate::model::Model;
+use crate::model::{format_mist, AsyncOperation, truncate_with_ellipsis, Balance, Holdings, Model, StatusKind, StatusMessage, TransferRecord, TransferStatus, unix_now, TRANSFER_NOTE_MAX_CHARS};
+use crate::view::{network_display_name, ViewAction};
+use std::time::Instant;
 use eframe::egui;
 
 /// 余额视图 - 处理余额显示和操作相关的UI组件
 pub struct BalanceView;
 
 impl BalanceView {
-    /// 显示余额信息面板
-    pub fn show_balance_panel(model: &Model, ui: &mut egui::Ui) {
+    /// 显示余额信息面板，返回用户请求的重试
+    pub fn show_balance_panel(model: &Model, ui: &mut egui::Ui) -> Option<ViewAction> {
+        let mut action = None;
         ui.group(|ui| {
             ui.vertical(|ui| {
-                ui.heading(&model.i18n.tr("balance_title"));
+                ui.heading(model.i18n.tr("balance_title"));
                 ui.separator();
                 
-                if model.is_loading {
+                if model.operations.is_running(AsyncOperation::RefreshBalance) {
                     Self::show_loading_balance(model, ui);
                 } else {
-                    Self::show_balance_details(model, ui);
+                    action = Self::show_balance_details(model, ui);
                 }
             });
         });
+        action
+    }
+
+    /// 并排显示当前地址在 Devnet、Testnet、Mainnet 上的余额
+    /// 每个网络单独显示查询状态，某个网络不可用时不影响其他网络的结果
+    pub fn show_network_summary_panel(model: &Model, ui: &mut egui::Ui) -> Option<ViewAction> {
+        let mut action = None;
+        let balances = &model.network_balances;
+
+        ui.group(|ui| {
+            ui.horizontal(|ui| {
+                ui.strong(model.i18n.tr("network_summary_title"));
+                let label = if balances.is_started() {
+                    model.i18n.tr("refresh_balance_button")
+                } else {
+                    model.i18n.tr("check_other_networks")
+                };
+                if ui.add_enabled(!balances.is_checking(), egui::Button::new(label).small()).clicked() {
+                    action = Some(ViewAction::CheckOtherNetworks);
+                }
+            });
+
+            if !balances.is_started() {
+                return;
+            }
+
+            let entries = balances.entries();
+            ui.columns(entries.len(), |columns| {
+                for ((network, result), ui) in entries.iter().zip(columns.iter_mut()) {
+                    ui.vertical_centered(|ui| {
+                        ui.strong(network_display_name(&model.i18n, network));
+                        match result {
+                            None => {
+                                ui.add(egui::Spinner::new().size(14.0));
+                            }
+                            Some(Ok(balance)) => {
+                                ui.colored_label(
+                                    Self::get_balance_color(*balance),
+                                    format!("{} SUI", Self::format_sui(*balance, model)),
+                                );
+                            }
+                            Some(Err(e)) => {
+                                ui.colored_label(egui::Color32::RED, model.i18n.tr("network_unavailable"))
+                                    .on_hover_text(e);
+                            }
+                        }
+                    });
+                }
+            });
+
+            let summary = balances.summary();
+            if summary.pending == 0 && summary.is_partial() {
+                ui.small(
+                    model
+                        .i18n
+                        .tr("network_summary_partial")
+                        .replacen("{}", &summary.failed.to_string(), 1)
+                        .replacen("{}", &entries.len().to_string(), 1),
+                );
+            }
+        });
+
+        action
     }
 
     /// 显示余额加载状态
     fn show_loading_balance(model: &Model, ui: &mut egui::Ui) {
         ui.horizontal(|ui| {
             ui.add(egui::Spinner::new());
-            ui.label(&model.i18n.tr("refreshing_balance"));
+            ui.label(model.i18n.tr("refreshing_balance"));
         });
     }
 
-    /// 显示余额详细信息
-    fn show_balance_details(model: &Model, ui: &mut egui::Ui) {
-        let balance_text = &model.result_text;
-        
-        if balance_text.contains("SUI") {
-            Self::show_sui_balance(balance_text, model, ui);
-        } else if balance_text.contains(&model.i18n.tr("async_error")) {
-            Self::show_balance_error(balance_text, model, ui);
-        } else {
-            Self::show_general_status(balance_text, model, ui);
+    /// 显示余额详细信息；其他状态消息由主视图的状态区域显示
+    fn show_balance_details(model: &Model, ui: &mut egui::Ui) -> Option<ViewAction> {
+        match (model.balance, &model.status) {
+            (Some(balance), _) => {
+                Self::show_sui_balance(balance, model, ui);
+                None
+            }
+            (None, Some(status)) if status.kind == StatusKind::Error => {
+                Self::show_balance_error(status, model, ui)
+            }
+            (None, _) => {
+                ui.label(model.i18n.tr("balance_unknown"));
+                None
+            }
         }
     }
 
     /// 显示 SUI 余额
-    fn show_sui_balance(balance_text: &str, model: &Model, ui: &mut egui::Ui) {
-        // 解析余额数值
-        if let Some(amount) = Self::parse_sui_balance(balance_text) {
-            // 主余额显示
-            ui.horizontal(|ui| {
-                ui.heading("💰");
-                ui.vertical(|ui| {
-                    ui.heading(format!("{:.4}", amount));
-                    ui.label("SUI");
-                });
+    fn show_sui_balance(balance: Balance, model: &Model, ui: &mut egui::Ui) {
+        // 主余额显示
+        ui.horizontal(|ui| {
+            ui.heading("💰");
+            ui.vertical(|ui| {
+                ui.heading(Self::format_sui(balance, model))
+                    .on_hover_text(balance.to_string());
+                ui.label("SUI");
             });
-            
-            ui.add_space(8.0);
-            
-            // 余额统计
-            Self::show_balance_stats(amount, model, ui);
-            
-            ui.add_space(8.0);
-            
-            // 余额操作按钮
-            Self::show_balance_actions(model, ui);
-        } else {
-            ui.label(balance_text);
+            Self::show_balance_change(model, ui);
+        });
+
+        // 余额为 0 时说明地址是否持有其他代币
+        match model.holdings {
+            Some(Holdings::Empty) => {
+                ui.small(model.i18n.tr("address_empty"));
+            }
+            Some(Holdings::NoSui { other_coins }) => {
+                ui.small(model.i18n.tr("no_sui_other_coins").replace("{}", &other_coins.to_string()));
+            }
+            _ => {}
         }
+
+        // 主端点不可用、由备用端点提供结果时提示
+        if let Some(endpoint) = model.balance_endpoint.as_deref().filter(|e| *e != model.network.url()) {
+            ui.small(model.i18n.tr("served_by_fallback_endpoint").replace("{}", endpoint));
+        }
+        
+        ui.add_space(8.0);
+        
+        // 余额统计
+        Self::show_balance_stats(balance, model, ui);
+        Self::show_low_balance_warning(balance, model, ui);
     }
 
-    /// 显示余额错误信息
-    fn show_balance_error(error_text: &str, model: &Model, ui: &mut egui::Ui) {
+    /// 刷新后短暂显示余额变化量：增加为绿色，减少为红色
+    fn show_balance_change(model: &Model, ui: &mut egui::Ui) {
+        let Some(change) = model.balance_change.filter(|change| change.is_visible(Instant::now())) else {
+            return;
+        };
+        let color = if change.is_increase() { egui::Color32::GREEN } else { egui::Color32::RED };
+        let text = format!("{}{}", change.sign(), model.i18n.format_decimal(&change.magnitude().to_sui_string()));
+        ui.colored_label(color, egui::RichText::new(text).strong())
+            .on_hover_text(format!("{} SUI", change.signed_sui_string()));
+    }
+
+    /// 显示余额错误信息，点击重试时返回刷新动作
+    fn show_balance_error(status: &StatusMessage, model: &Model, ui: &mut egui::Ui) -> Option<ViewAction> {
         ui.horizontal(|ui| {
             ui.label("❌");
             ui.vertical(|ui| {
-                ui.colored_label(egui::Color32::RED, &model.i18n.tr("balance_error"));
-                ui.small(error_text);
+                ui.colored_label(egui::Color32::RED, model.i18n.tr("balance_error"));
+                let response = ui.small(status.display_text());
+                if status.is_truncated() {
+                    response.on_hover_text(&status.text);
+                }
+                // 始终可复制完整的底层错误，便于提交问题报告
+                if ui.small_button(model.i18n.tr("copy_error")).clicked() {
+                    ui.ctx().copy_text(status.copy_text().to_string());
+                }
             });
         });
         
         ui.add_space(8.0);
         
-        if ui.button(&model.i18n.tr("retry_button")).clicked() {
-            // 重试逻辑将在主视图中处理
-        }
-    }
-
-    /// 显示一般状态信息
-    fn show_general_status(status_text: &str, _model: &Model, ui: &mut egui::Ui) {
-        ui.horizontal(|ui| {
-            ui.label("ℹ️");
-            ui.label(status_text);
-        });
+        ui.button(model.i18n.tr("retry_button")).clicked().then_some(ViewAction::RefreshBalance)
     }
 
     /// 显示余额统计信息
-    fn show_balance_stats(amount: f64, model: &Model, ui: &mut egui::Ui) {
+    fn show_balance_stats(balance: Balance, model: &Model, ui: &mut egui::Ui) {
         egui::Grid::new("balance_stats")
             .num_columns(2)
             .spacing([20.0, 4.0])
             .show(ui, |ui| {
                 // 当前余额
-                ui.label(&model.i18n.tr("current_balance"));
-                ui.label(format!("{:.4} SUI", amount));
+                ui.label(model.i18n.tr("current_balance"));
+                ui.label(format!("{} SUI", Self::format_sui(balance, model)));
                 ui.end_row();
                 
-                // 估算USD价值（假设价格）
-                let estimated_usd = amount * 2.5; // 假设的SUI价格
-                ui.label(&model.i18n.tr("estimated_value"));
-                ui.label(format!("≈ ${:.2} USD", estimated_usd));
-                ui.end_row();
-                
-                // 网络费用估算
-                ui.label(&model.i18n.tr("network_fee"));
-                ui.label("~0.001 SUI");
+                // 当前网络的预估交易费用
+                let fee = Balance::from_mist(model.network.estimated_tx_fee_mist());
+                ui.label(model.i18n.tr("network_fee"));
+                ui.label(format!("~{} SUI", Self::format_sui(fee, model)));
                 ui.end_row();
             });
     }
 
-    /// 显示余额操作按钮
-    fn show_balance_actions(model: &Model, ui: &mut egui::Ui) {
-        ui.horizontal(|ui| {
-            if ui.button(&model.i18n.tr("refresh_balance_button")).clicked() {
-                // 刷新操作将在主视图中处理
-            }
-            
-            ui.separator();
-            
-            if ui.button(&model.i18n.tr("send_button")).clicked() {
-                // 发送操作（未来功能）
-            }
-            
-            if ui.button(&model.i18n.tr("receive_button")).clicked() {
-                // 接收操作（显示地址二维码等）
-            }
-        });
-    }
-
-    /// 显示历史记录面板
-    pub fn show_transaction_history(model: &Model, ui: &mut egui::Ui) {
+    /// 显示历史记录面板，返回用户保存的备注
+    pub fn show_transaction_history(model: &mut Model, ui: &mut egui::Ui) -> Option<ViewAction> {
+        let mut action = None;
         ui.group(|ui| {
             ui.vertical(|ui| {
-                ui.heading(&model.i18n.tr("transaction_history"));
+                ui.heading(model.i18n.tr("transaction_history"));
                 ui.separator();
                 
-                // 这里显示交易历史记录
-                // 目前显示占位符
-                ui.label(&model.i18n.tr("no_transactions"));
+                // 本地记录的转账（链上历史可用后通过 merged_with 按摘要去重合并）
+                let network = model.network.name();
+                let records: Vec<TransferRecord> = model
+                    .transfer_history
+                    .merged_with(&[])
+                    .into_iter()
+                    .filter(|r| r.network == network)
+                    .collect();
+
+                if records.is_empty() {
+                    ui.label(model.i18n.tr("no_transactions"));
+                } else {
+                    action = Self::show_transfer_records(&records, model, ui);
+                }
                 
                 ui.add_space(8.0);
                 
-                if ui.button(&model.i18n.tr("view_explorer")).clicked() {
-                    // 在区块链浏览器中查看地址
-                    if let crate::model::WalletState::Loaded { address, .. } = &model.wallet {
-                        let explorer_url = match model.network {
-                            crate::model::Network::Mainnet => format!("https://suiexplorer.com/address/{}", address),
-                            crate::model::Network::Testnet => format!("https://suiexplorer.com/address/{}?network=testnet", address),
-                            crate::model::Network::Devnet => format!("https://suiexplorer.com/address/{}?network=devnet", address),
-                        };
-                        
-                        // 这里可以打开浏览器（需要实现）
-                        println!("Open URL: {}", explorer_url);
-                    }
+                // 在区块链浏览器中查看地址
+                // 自定义网络没有公共浏览器，地址无效时也没有链接，此时禁用按钮
+                let provider = model.app_state.settings.explorer_provider;
+                let explorer_url = model
+                    .wallet
+                    .address()
+                    .and_then(|address| provider.address_url(&model.network, &address.to_string()));
+                let button = egui::Button::new(model.i18n.tr("view_explorer"));
+                if ui.add_enabled(explorer_url.is_some(), button).clicked()
+                    && let Some(explorer_url) = explorer_url
+                {
+                    ui.ctx().open_url(egui::OpenUrl::new_tab(explorer_url));
                 }
             });
         });
+        action
     }
 
-    /// 显示余额图表（可选功能）
-    pub fn show_balance_chart(model: &Model, ui: &mut egui::Ui) {
-        ui.group(|ui| {
-            ui.vertical(|ui| {
-                ui.heading(&model.i18n.tr("balance_chart"));
-                ui.separator();
-                
-                // 占位符图表
-                let available_rect = ui.available_rect_before_wrap();
-                let chart_rect = egui::Rect::from_min_size(
-                    available_rect.min,
-                    egui::Vec2::new(available_rect.width(), 100.0)
-                );
-                
-                ui.allocate_ui_at_rect(chart_rect, |ui| {
-                    ui.painter().rect_filled(
-                        chart_rect,
-                        4.0,
-                        egui::Color32::from_gray(240)
-                    );
-                    
-                    ui.centered_and_justified(|ui| {
-                        ui.label(&model.i18n.tr("chart_placeholder"));
+    /// 显示转账记录列表，有摘要的记录可以编辑本地备注并在区块链浏览器中查看
+    fn show_transfer_records(records: &[TransferRecord], model: &mut Model, ui: &mut egui::Ui) -> Option<ViewAction> {
+        let now = unix_now();
+        let provider = model.app_state.settings.explorer_provider;
+        let mut action = None;
+
+        egui::ScrollArea::vertical()
+            .id_salt("transfer_history")
+            .max_height(150.0)
+            .show(ui, |ui| {
+                egui::Grid::new("transfer_history_grid")
+                    .num_columns(5)
+                    .spacing([12.0, 4.0])
+                    .striped(true)
+                    .show(ui, |ui| {
+                        for record in records {
+                            ui.label(model.i18n.format_relative_age(record.age(now)));
+                            ui.label(Self::truncate_middle(&record.recipient, 6, 4))
+                                .on_hover_text(&record.recipient);
+                            ui.label(format!("{} SUI", Self::format_sui(record.amount(), model)));
+
+                            let color = match record.status {
+                                TransferStatus::Pending => egui::Color32::YELLOW,
+                                TransferStatus::Success => egui::Color32::GREEN,
+                                TransferStatus::Failed => egui::Color32::RED,
+                            };
+                            ui.horizontal(|ui| {
+                                let status = ui.colored_label(color, model.i18n.tr(record.status.i18n_key()));
+                                if let Some(digest) = &record.digest {
+                                    status.on_hover_text(digest);
+                                }
+                                let explorer_url = record
+                                    .digest
+                                    .as_deref()
+                                    .and_then(|digest| provider.transaction_url(&model.network, digest));
+                                if let Some(explorer_url) = explorer_url {
+                                    ui.hyperlink_to("🔗", explorer_url)
+                                        .on_hover_text(model.i18n.tr("view_transaction_explorer"));
+                                }
+                            });
+
+                            // 本地备注
+                            if let Some(note_action) = Self::show_transfer_note(record, model, ui) {
+                                action = Some(note_action);
+                            }
+                            ui.end_row();
+                        }
                     });
-                });
             });
-        });
+        action
     }
 
-    /// 解析 SUI 余额字符串
-    fn parse_sui_balance(balance_text: &str) -> Option<f64> {
-        balance_text
-            .trim_end_matches(" SUI")
-            .trim()
-            .parse::<f64>()
-            .ok()
+    /// 显示一条记录的本地备注；正在编辑时显示输入框和保存按钮
+    fn show_transfer_note(record: &TransferRecord, model: &mut Model, ui: &mut egui::Ui) -> Option<ViewAction> {
+        let mut action = None;
+        let Some(digest) = &record.digest else {
+            ui.label(record.note.as_deref().unwrap_or_default());
+            return action;
+        };
+
+        ui.horizontal(|ui| match &mut model.app_state.ui_state.note_edit_buffer {
+            Some((editing, buffer)) if editing == digest => {
+                ui.add(
+                    egui::TextEdit::singleline(buffer)
+                        .desired_width(160.0)
+                        .char_limit(TRANSFER_NOTE_MAX_CHARS),
+                );
+                if ui.small_button(model.i18n.tr("save_button")).clicked() {
+                    action = Some(ViewAction::SaveTransferNote { digest: digest.clone(), note: buffer.clone() });
+                    model.app_state.ui_state.note_edit_buffer = None;
+                } else if ui.small_button(model.i18n.tr("cancel_button")).clicked() {
+                    model.app_state.ui_state.note_edit_buffer = None;
+                }
+            }
+            _ => {
+                if let Some(note) = &record.note {
+                    ui.label(format!("📝 {}", truncate_with_ellipsis(note, 24)))
+                        .on_hover_text(note);
+                }
+                if ui.small_button("✏").on_hover_text(model.i18n.tr("edit_transfer_note")).clicked() {
+                    let current = record.note.clone().unwrap_or_default();
+                    model.app_state.ui_state.note_edit_buffer = Some((digest.clone(), current));
+                }
+            }
+        });
+
+        action
     }
 
-    /// 格式化余额显示
-    pub fn format_balance(amount: f64) -> String {
-        if amount >= 1_000_000.0 {
-            format!("{:.2}M SUI", amount / 1_000_000.0)
-        } else if amount >= 1_000.0 {
-            format!("{:.2}K SUI", amount / 1_000.0)
+    /// 截断过长的字符串，保留首尾
+    fn truncate_middle(text: &str, start_len: usize, end_len: usize) -> String {
+        if text.len() <= start_len + end_len + 3 || !text.is_ascii() {
+            text.to_string()
         } else {
-            format!("{:.4} SUI", amount)
+            format!("{}...{}", &text[..start_len], &text[text.len() - end_len..])
         }
     }
 
+    /// 按当前语言格式化 SUI 金额（保留 4 位小数，整数运算）
+    pub fn format_sui(balance: Balance, model: &Model) -> String {
+        model.i18n.format_decimal(&format_mist(balance.mist(), 4))
+    }
+
     /// 获取余额颜色（根据数量）
-    pub fn get_balance_color(amount: f64) -> egui::Color32 {
-        if amount >= 100.0 {
+    pub fn get_balance_color(balance: Balance) -> egui::Color32 {
+        if balance.whole_sui() >= 100 {
             egui::Color32::GREEN
-        } else if amount >= 10.0 {
+        } else if balance.whole_sui() >= 10 {
             egui::Color32::YELLOW
-        } else if amount > 0.0 {
+        } else if !balance.is_zero() {
             egui::Color32::ORANGE
         } else {
             egui::Color32::RED
         }
     }
 
-    /// 显示余额警告（如果余额过低）
-    pub fn show_low_balance_warning(amount: f64, model: &Model, ui: &mut egui::Ui) {
-        if amount < 1.0 && amount > 0.0 {
+    /// 显示余额警告（低于当前网络的阈值时）
+    pub fn show_low_balance_warning(balance: Balance, model: &Model, ui: &mut egui::Ui) {
+        let threshold = model.network.low_balance_threshold();
+        if balance < threshold && !balance.is_zero() {
             ui.group(|ui| {
                 ui.horizontal(|ui| {
                     ui.label("⚠️");
                     ui.vertical(|ui| {
-                        ui.colored_label(egui::Color32::YELLOW, &model.i18n.tr("low_balance_warning"));
-                        ui.small(&model.i18n.tr("consider_adding_funds"));
+                        ui.colored_label(egui::Color32::YELLOW, model.i18n.tr("low_balance_warning"));
+                        ui.small(
+                            model
+                                .i18n
+                                .tr("low_balance_threshold_hint")
+                                .replacen("{}", &Self::format_sui(threshold, model), 1)
+                                .replacen("{}", model.network.name(), 1),
+                        );
+                        ui.small(model.i18n.tr("consider_adding_funds"));
                     });
                 });
             });
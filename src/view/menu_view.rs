@@ -1,7 +1,8 @@
-use crate::model::{Model, Network};
+use crate::model::{file_manager_command, format_session_remaining, AddressExport, AppTheme, AddressExportFormat, CoinFilterMode, CoinFilterSettings, DialogState, EndpointTestStatus, ExplorerProvider, Model, Network, NetworkHealthStatus, RpcFallbackSettings, SecuritySettings, SessionSecretBackend, LOGIN_LOCKOUT_MINUTES_MAX, MAX_FAILED_LOGIN_ATTEMPTS_MAX, MIN_PASSWORD_LENGTH_FLOOR, MIN_PASSWORD_LENGTH_MAX, SESSION_EXPIRY_WARNING, UI_SCALE_DEFAULT, UI_SCALE_MAX, UI_SCALE_MIN};
 use crate::i18n::Language;
-use crate::view::ViewAction;
+use crate::view::{network_label_color, running_operations_text, ModalView, ViewAction};
 use eframe::egui;
+use std::time::Instant;
 
 /// 菜单视图 - 处理菜单栏和导航相关的UI组件
 pub struct MenuView;
@@ -12,7 +13,7 @@ impl MenuView {
         let mut action = ViewAction::None;
         
         egui::TopBottomPanel::top("menu_bar").show(ctx, |ui| {
-            egui::menu::bar(ui, |ui| {
+            egui::MenuBar::new().ui(ui, |ui| {
                 // 语言菜单
                 if let Some(lang_action) = Self::show_language_menu(model, ui) {
                     action = lang_action;
@@ -21,16 +22,22 @@ impl MenuView {
                 ui.separator();
                 
                 // 网络菜单
-                Self::show_network_menu(model, ui);
+                if let Some(network_action) = Self::show_network_menu(model, ui) {
+                    action = network_action;
+                }
                 
                 ui.separator();
                 
                 // 工具菜单
-                Self::show_tools_menu(model, ui);
+                if let Some(tools_action) = Self::show_tools_menu(model, ui) {
+                    action = tools_action;
+                }
                 
                 // 右侧状态显示
                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                    Self::show_status_indicators(model, ui);
+                    if let Some(status_action) = Self::show_status_indicators(model, ui) {
+                        action = status_action;
+                    }
                 });
             });
         });
@@ -38,12 +45,31 @@ impl MenuView {
         action
     }
 
+    /// 临时会话的顶部栏：只显示会话提示、剩余时间和结束会话按钮
+    pub fn show_temporary_session_bar(model: &Model, ctx: &egui::Context) -> ViewAction {
+        let mut action = ViewAction::None;
+
+        egui::TopBottomPanel::top("menu_bar").show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.colored_label(egui::Color32::YELLOW, model.i18n.tr("temporary_session_label"));
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if ui.button(model.i18n.tr("end_temporary_session_button")).clicked() {
+                        action = ViewAction::Logout;
+                    }
+                    Self::show_session_remaining(model, ui);
+                });
+            });
+        });
+
+        action
+    }
+
     /// 显示语言选择菜单
     fn show_language_menu(model: &mut Model, ui: &mut egui::Ui) -> Option<ViewAction> {
         let mut action = None;
         
-        ui.menu_button(&model.i18n.tr("language_label"), |ui| {
-            ui.style_mut().wrap = Some(false);
+        ui.menu_button(model.i18n.tr("language_label"), |ui| {
+            ui.style_mut().wrap_mode = Some(egui::TextWrapMode::Extend);
             
             for lang in Language::all() {
                 let current_lang = model.current_language();
@@ -56,10 +82,10 @@ impl MenuView {
                         ui.label("  ");
                     }
                     
-                    if ui.selectable_label(is_selected, lang.display_name()).clicked() {
-                        if lang != current_lang {
-                            action = Some(ViewAction::LanguageChanged(lang));
-                        }
+                    if ui.selectable_label(is_selected, lang.display_name()).clicked()
+                        && lang != current_lang
+                    {
+                        action = Some(ViewAction::LanguageChanged(lang));
                     }
                 });
             }
@@ -69,17 +95,23 @@ impl MenuView {
     }
 
     /// 显示网络选择菜单
-    fn show_network_menu(model: &mut Model, ui: &mut egui::Ui) {
-        ui.menu_button(&model.i18n.tr("network_label"), |ui| {
-            ui.style_mut().wrap = Some(false);
+    fn show_network_menu(model: &mut Model, ui: &mut egui::Ui) -> Option<ViewAction> {
+        let mut action = None;
+
+        ui.menu_button(model.i18n.tr("network_label"), |ui| {
+            ui.style_mut().wrap_mode = Some(egui::TextWrapMode::Extend);
             
-            let networks = [
-                (Network::Devnet, "devnet"),
-                (Network::Testnet, "testnet"),
-                (Network::Mainnet, "mainnet"),
+            let mut networks = vec![
+                (Network::Devnet, model.i18n.tr("devnet")),
+                (Network::Testnet, model.i18n.tr("testnet")),
+                (Network::Mainnet, model.i18n.tr("mainnet")),
             ];
+            if let Some(custom) = model.app_state.settings.custom_network() {
+                let label = custom.name().to_string();
+                networks.push((custom, label));
+            }
             
-            for (network, key) in networks {
+            for (network, label) in networks {
                 let is_selected = model.network == network;
                 
                 ui.horizontal(|ui| {
@@ -89,56 +121,631 @@ impl MenuView {
                         ui.label("  ");
                     }
                     
-                    if ui.selectable_label(is_selected, &model.i18n.tr(key)).clicked() {
-                        model.network = network;
+                    if ui.selectable_label(is_selected, label).clicked() && !is_selected {
+                        action = Some(ViewAction::SwitchNetwork(network.clone()));
                     }
                 });
             }
+
+            ui.separator();
+
+            // 自动切换时使用的首选网络
+            ui.menu_button(model.i18n.tr("preferred_network_label"), |ui| {
+                for network in Network::all() {
+                    let is_preferred = model.network_config.preferred_network == network;
+                    let label = model.i18n.tr(network.i18n_key());
+                    if ui.selectable_label(is_preferred, label).clicked() {
+                        action = Some(ViewAction::SetPreferredNetwork(network));
+                        ui.close();
+                    }
+                }
+            });
+
+            if ui.button(model.i18n.tr("custom_network_menu")).clicked() {
+                action = Some(ViewAction::OpenCustomNetwork);
+                ui.close();
+            }
+
+            if ui.button(model.i18n.tr("rpc_fallbacks_menu")).clicked() {
+                action = Some(ViewAction::OpenRpcFallbacks);
+                ui.close();
+            }
+        });
+
+        action
+    }
+
+    /// 显示自定义网络编辑对话框
+    pub fn show_custom_network_dialog(model: &mut Model, ctx: &egui::Context) -> ViewAction {
+        let mut action = ViewAction::None;
+
+        ModalView::show(ctx, "custom_network_title", &model.i18n.tr("custom_network_title"), |ui| {
+            let ui_state = &mut model.app_state.ui_state;
+
+            egui::Grid::new("custom_network_form")
+                .num_columns(2)
+                .spacing([12.0, 6.0])
+                .show(ui, |ui| {
+                    ui.label(model.i18n.tr("custom_network_name"));
+                    ui.text_edit_singleline(&mut ui_state.custom_network_name_input);
+                    ui.end_row();
+
+                    ui.label(model.i18n.tr("custom_network_url"));
+                    ui.text_edit_singleline(&mut ui_state.custom_network_url_input);
+                    ui.end_row();
+                });
+
+            let url_valid = Network::is_valid_rpc_url(&ui_state.custom_network_url_input);
+            if !url_valid {
+                ui.colored_label(egui::Color32::RED, model.i18n.tr("invalid_rpc_url"));
+            }
+
+            Self::show_endpoint_test_status(model, ui);
+
+            let ui_state = &mut model.app_state.ui_state;
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                let testing = ui_state.custom_network_test == EndpointTestStatus::Testing;
+                if ui.add_enabled(url_valid && !testing, egui::Button::new(model.i18n.tr("test_connection_button"))).clicked() {
+                    action = ViewAction::TestCustomNetwork(ui_state.custom_network_url_input.clone());
+                }
+                if ui.add_enabled(url_valid, egui::Button::new(model.i18n.tr("save_button"))).clicked() {
+                    action = ViewAction::SaveCustomNetwork {
+                        name: ui_state.custom_network_name_input.clone(),
+                        url: ui_state.custom_network_url_input.clone(),
+                    };
+                }
+                if ui.button(model.i18n.tr("cancel_button")).clicked() {
+                    ui_state.close_dialog();
+                }
+            });
+        });
+
+        action
+    }
+
+    /// 显示地址列表导出对话框（只导出标签和地址）
+    pub fn show_export_addresses_dialog(model: &mut Model, ctx: &egui::Context) -> ViewAction {
+        let mut action = ViewAction::None;
+
+        ModalView::show(ctx, "export_addresses_title", &model.i18n.tr("export_addresses_title"), |ui| {
+            let ui_state = &mut model.app_state.ui_state;
+            ui.label(model.i18n.tr("export_addresses_hint"));
+            ui.add_space(4.0);
+
+            egui::Grid::new("export_addresses_form")
+                .num_columns(2)
+                .spacing([12.0, 6.0])
+                .show(ui, |ui| {
+                    ui.label(model.i18n.tr("export_format"));
+                    ui.horizontal(|ui| {
+                        for format in AddressExportFormat::all() {
+                            if ui.radio_value(&mut ui_state.export_format, format, format.name()).changed() {
+                                ui_state.export_path_input =
+                                    AddressExport::switch_extension(&ui_state.export_path_input, format);
+                            }
+                        }
+                    });
+                    ui.end_row();
+
+                    ui.label(model.i18n.tr("export_path"));
+                    ui.add(egui::TextEdit::singleline(&mut ui_state.export_path_input).desired_width(320.0));
+                    ui.end_row();
+                });
+
+            let path_error = AddressExport::validate_path(&ui_state.export_path_input).err();
+            if let Some(error) = path_error {
+                ui.colored_label(egui::Color32::RED, model.i18n.tr(error.i18n_key()));
+            }
+
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                if ui.add_enabled(path_error.is_none(), egui::Button::new(model.i18n.tr("export_button"))).clicked() {
+                    action = ViewAction::ExportAddresses;
+                }
+                if ui.button(model.i18n.tr("cancel_button")).clicked() {
+                    ui_state.close_dialog();
+                }
+            });
+        });
+
+        action
+    }
+
+    /// 显示备用 RPC 端点设置对话框：主端点不可用时按顺序尝试
+    pub fn show_rpc_fallbacks_dialog(model: &mut Model, ctx: &egui::Context) -> ViewAction {
+        let mut action = ViewAction::None;
+
+        ModalView::show(ctx, "rpc_fallbacks_title", &model.i18n.tr("rpc_fallbacks_title"), |ui| {
+            ui.label(model.i18n.tr("rpc_fallbacks_hint"));
+            ui.add_space(4.0);
+
+            let mut all_valid = true;
+            egui::Grid::new("rpc_fallbacks_form")
+                .num_columns(2)
+                .spacing([12.0, 6.0])
+                .show(ui, |ui| {
+                    for (network, input) in &mut model.app_state.ui_state.rpc_fallback_inputs {
+                        ui.vertical(|ui| {
+                            ui.label(model.i18n.tr(network.i18n_key()));
+                            ui.small(network.url());
+                        });
+                        ui.vertical(|ui| {
+                            ui.add(egui::TextEdit::multiline(input).desired_rows(2).desired_width(320.0).code_editor());
+                            if let Err(invalid) = RpcFallbackSettings::parse_urls(input) {
+                                all_valid = false;
+                                let message = model.i18n.tr("invalid_fallback_urls").replace("{}", &invalid.join(", "));
+                                ui.colored_label(egui::Color32::RED, message);
+                            }
+                        });
+                        ui.end_row();
+                    }
+                });
+
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                if ui.add_enabled(all_valid, egui::Button::new(model.i18n.tr("save_button"))).clicked() {
+                    action = ViewAction::SaveRpcFallbacks;
+                }
+                if ui.button(model.i18n.tr("cancel_button")).clicked() {
+                    model.app_state.ui_state.close_dialog();
+                }
+            });
         });
+
+        action
+    }
+
+    /// 显示代币显示过滤设置对话框：白名单只显示列出的代币，黑名单隐藏包含任一片段的代币
+    pub fn show_coin_filter_dialog(model: &mut Model, ctx: &egui::Context) -> ViewAction {
+        let mut action = ViewAction::None;
+
+        ModalView::show(ctx, "coin_filter_title", &model.i18n.tr("coin_filter_title"), |ui| {
+            let ui_state = &mut model.app_state.ui_state;
+            ui.label(model.i18n.tr("coin_filter_hint"));
+            ui.add_space(4.0);
+
+            ui.horizontal(|ui| {
+                ui.radio_value(&mut ui_state.coin_filter_mode_input, CoinFilterMode::Denylist, model.i18n.tr("coin_filter_denylist"));
+                ui.radio_value(&mut ui_state.coin_filter_mode_input, CoinFilterMode::Allowlist, model.i18n.tr("coin_filter_allowlist"));
+            });
+            ui.add_space(4.0);
+
+            // 只编辑当前方式使用的列表，另一个列表保留
+            let (list, hint) = match ui_state.coin_filter_mode_input {
+                CoinFilterMode::Denylist => (&mut ui_state.coin_denylist_input, "coin_denylist_hint"),
+                CoinFilterMode::Allowlist => (&mut ui_state.coin_allowlist_input, "coin_allowlist_hint"),
+            };
+            ui.small(model.i18n.tr(hint));
+            ui.add(egui::TextEdit::multiline(list).desired_rows(6).desired_width(360.0).code_editor());
+
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                if ui.button(model.i18n.tr("save_button")).clicked() {
+                    action = ViewAction::SaveCoinFilter;
+                }
+                if ui.button(model.i18n.tr("restore_defaults_button")).clicked() {
+                    let defaults = CoinFilterSettings::default();
+                    ui_state.coin_filter_mode_input = defaults.mode;
+                    ui_state.coin_allowlist_input = CoinFilterSettings::list_text(&defaults.allowlist);
+                    ui_state.coin_denylist_input = CoinFilterSettings::list_text(&defaults.denylist);
+                }
+                if ui.button(model.i18n.tr("cancel_button")).clicked() {
+                    ui_state.close_dialog();
+                }
+            });
+        });
+
+        action
+    }
+
+    /// 显示自定义端点连接测试结果（仅针对当前输入的 URL）
+    fn show_endpoint_test_status(model: &Model, ui: &mut egui::Ui) {
+        let ui_state = &model.app_state.ui_state;
+        if ui_state.custom_network_tested_url != ui_state.custom_network_url_input.trim() {
+            return;
+        }
+
+        match &ui_state.custom_network_test {
+            EndpointTestStatus::Idle => {}
+            EndpointTestStatus::Testing => {
+                ui.horizontal(|ui| {
+                    ui.add(egui::Spinner::new());
+                    ui.label(model.i18n.tr("testing_connection"));
+                });
+            }
+            status @ EndpointTestStatus::Connected { chain_id } => {
+                let text = match status.known_network() {
+                    Some(network) => format!("{} {} ({})", model.i18n.tr("connection_ok"), chain_id, network),
+                    None => format!("{} {}", model.i18n.tr("connection_ok"), chain_id),
+                };
+                ui.colored_label(egui::Color32::GREEN, text);
+            }
+            EndpointTestStatus::Failed(error) => {
+                ui.colored_label(egui::Color32::RED, format!("{} {}", model.i18n.tr("connection_failed"), error));
+            }
+        }
     }
 
     /// 显示工具菜单
-    fn show_tools_menu(model: &Model, ui: &mut egui::Ui) {
-        ui.menu_button(&model.i18n.tr("tools_label"), |ui| {
-            if ui.button(&model.i18n.tr("clear_cache")).clicked() {
+    fn show_tools_menu(model: &mut Model, ui: &mut egui::Ui) -> Option<ViewAction> {
+        let mut action = None;
+
+        ui.menu_button(model.i18n.tr("tools_label"), |ui| {
+            // 区块链浏览器选择
+            ui.menu_button(model.i18n.tr("explorer_provider_label"), |ui| {
+                for provider in ExplorerProvider::all() {
+                    let settings = &mut model.app_state.settings;
+                    let is_selected = settings.explorer_provider == provider;
+                    if ui.selectable_label(is_selected, provider.name()).clicked() {
+                        settings.explorer_provider = provider;
+                        ui.close();
+                    }
+                }
+
+                // 自定义网络没有公共浏览器，此时禁用
+                ui.separator();
+                let home_url = model.app_state.settings.explorer_provider.home_url(&model.network);
+                let button = egui::Button::new(model.i18n.tr("open_explorer_home"));
+                if ui.add_enabled(home_url.is_some(), button).clicked()
+                    && let Some(home_url) = home_url
+                {
+                    ui.ctx().open_url(egui::OpenUrl::new_tab(home_url));
+                    ui.close();
+                }
+            });
+
+            // 界面主题（含高对比度）
+            ui.menu_button(model.i18n.tr("theme_label"), |ui| {
+                for theme in AppTheme::all() {
+                    let settings = &mut model.app_state.settings;
+                    let is_selected = settings.theme == theme;
+                    if ui.selectable_label(is_selected, model.i18n.tr(theme.i18n_key())).clicked() {
+                        settings.theme = theme;
+                        ui.close();
+                    }
+                }
+            });
+
+            if ui.button(model.i18n.tr("batch_transfer_menu")).clicked() {
+                model.app_state.ui_state.show_dialog(DialogState::BatchTransfer);
+                ui.close();
+            }
+
+            if ui.button(model.i18n.tr("coin_filter_menu")).clicked() {
+                action = Some(ViewAction::OpenCoinFilter);
+                ui.close();
+            }
+
+            let has_addresses = model.wallet.is_loaded();
+            if ui.add_enabled(has_addresses, egui::Button::new(model.i18n.tr("export_addresses_menu"))).clicked() {
+                // 默认使用上次导出的格式和路径
+                let last_used = &model.app_state.settings.last_used;
+                let ui_state = &mut model.app_state.ui_state;
+                if ui_state.export_path_input.trim().is_empty() {
+                    ui_state.export_format = last_used.export_format.unwrap_or(ui_state.export_format);
+                    ui_state.export_path_input = last_used
+                        .export_path
+                        .clone()
+                        .unwrap_or_else(|| AddressExport::default_path(ui_state.export_format));
+                }
+                ui_state.show_dialog(DialogState::ExportAddresses);
+                ui.close();
+            }
+
+            ui.checkbox(
+                &mut model.app_state.ui_state.show_advanced_options,
+                model.i18n.tr("show_advanced_options"),
+            );
+
+            Self::show_ui_scale_setting(model, ui);
+
+            // 屏幕截图保护
+            let supported = SecuritySettings::screenshot_protection_supported();
+            let security = &mut model.app_state.settings.security_settings;
+            ui.add_enabled(
+                supported,
+                egui::Checkbox::new(&mut security.screenshot_protection, model.i18n.tr("screenshot_protection")),
+            )
+            .on_disabled_hover_text(model.i18n.tr("screenshot_protection_unavailable"));
+            if !supported {
+                ui.small(model.i18n.tr("screenshot_protection_unavailable"));
+            }
+
+            let mut separate = model.app_state.settings.separate_accounts_per_network;
+            if ui
+                .checkbox(&mut separate, model.i18n.tr("separate_accounts_per_network"))
+                .on_hover_text(model.i18n.tr("separate_accounts_per_network_hint"))
+                .changed()
+            {
+                action = Some(ViewAction::SetSeparateAccountsPerNetwork(separate));
+            }
+
+            let mut auto_select = model.app_state.settings.auto_select_endpoint;
+            if ui
+                .checkbox(&mut auto_select, model.i18n.tr("auto_select_endpoint"))
+                .on_hover_text(model.i18n.tr("auto_select_endpoint_hint"))
+                .changed()
+            {
+                action = Some(ViewAction::SetAutoSelectEndpoint(auto_select));
+            }
+
+            let mut auto_switch = model.app_state.settings.auto_switch_network;
+            if ui
+                .checkbox(&mut auto_switch, model.i18n.tr("auto_switch_network"))
+                .on_hover_text(model.i18n.tr("auto_switch_network_hint"))
+                .changed()
+            {
+                action = Some(ViewAction::SetAutoSwitchNetwork(auto_switch));
+            }
+
+            ui.checkbox(
+                &mut model.app_state.settings.refresh_on_focus,
+                model.i18n.tr("refresh_on_focus"),
+            );
+
+            let mut write_crash_reports = model.app_state.settings.write_crash_reports;
+            if ui
+                .checkbox(&mut write_crash_reports, model.i18n.tr("write_crash_reports"))
+                .on_hover_text(model.i18n.tr("write_crash_reports_hint"))
+                .changed()
+            {
+                action = Some(ViewAction::SetWriteCrashReports(write_crash_reports));
+            }
+
+            // 重新显示已确认过的安全提醒
+            let mut show_security_warning = model.app_state.settings.should_show_security_warning();
+            if ui.checkbox(&mut show_security_warning, model.i18n.tr("show_security_warning")).changed() {
+                if show_security_warning {
+                    model.app_state.settings.reset_security_warning();
+                } else {
+                    model.app_state.settings.acknowledge_security_warning();
+                }
+            }
+
+            ui.checkbox(
+                &mut model.app_state.settings.security_settings.lock_on_blur,
+                model.i18n.tr("lock_on_blur"),
+            )
+            .on_hover_text(model.i18n.tr("lock_on_blur_hint"));
+
+            ui.checkbox(
+                &mut model.app_state.settings.security_settings.auto_save_keys,
+                model.i18n.tr("auto_save_keys"),
+            )
+            .on_hover_text(model.i18n.tr("auto_save_keys_hint"));
+
+            // 会话超时：勾选“永不超时”时不再自动锁定
+            ui.horizontal(|ui| {
+                let current = model.app_state.settings.session_timeout_minutes;
+                let mut never = current.is_none();
+                let mut minutes = current.unwrap_or(30);
+                ui.label(model.i18n.tr("session_timeout_label"));
+                let never_changed = ui.checkbox(&mut never, model.i18n.tr("session_never_times_out")).changed();
+                let minutes_changed = ui
+                    .add_enabled(!never, egui::DragValue::new(&mut minutes).range(1..=1440))
+                    .changed();
+                if never_changed || minutes_changed {
+                    model.set_session_timeout_minutes(if never { None } else { Some(minutes) });
+                }
+            });
+
+            ui.horizontal(|ui| {
+                ui.label(model.i18n.tr("idle_lock_grace_label"));
+                ui.add(
+                    egui::DragValue::new(&mut model.app_state.settings.security_settings.idle_lock_grace_seconds)
+                        .range(0..=60),
+                );
+            });
+
+            // 登录失败锁定（保存到磁盘，重启后仍然有效）
+            ui.horizontal(|ui| {
+                let security = &mut model.app_state.settings.security_settings;
+                ui.label(model.i18n.tr("max_failed_login_attempts_label"));
+                let attempts = ui.add(egui::DragValue::new(&mut security.max_failed_login_attempts).range(0..=MAX_FAILED_LOGIN_ATTEMPTS_MAX));
+                ui.label(model.i18n.tr("login_lockout_minutes_label"));
+                let minutes = ui.add(egui::DragValue::new(&mut security.login_lockout_minutes).range(1..=LOGIN_LOCKOUT_MINUTES_MAX));
+                if attempts.changed() || minutes.changed() {
+                    let (max, minutes) = (security.max_failed_login_attempts, security.login_lockout_minutes);
+                    model.auth_state.set_lockout_policy(max, minutes);
+                }
+            });
+
+            let mut reject_common = model.app_state.settings.security_settings.reject_common_passwords;
+            if ui
+                .checkbox(&mut reject_common, model.i18n.tr("reject_common_passwords"))
+                .on_hover_text(model.i18n.tr("reject_common_passwords_hint"))
+                .changed()
+            {
+                model.app_state.settings.security_settings.reject_common_passwords = reject_common;
+                model.auth_state.password_policy.reject_common = reject_common;
+            }
+
+            // 密码策略（设置或修改密码时生效）
+            ui.horizontal(|ui| {
+                let security = &mut model.app_state.settings.security_settings;
+                ui.label(model.i18n.tr("min_password_length_label"));
+                let mut changed = ui
+                    .add(egui::DragValue::new(&mut security.min_password_length).range(MIN_PASSWORD_LENGTH_FLOOR..=MIN_PASSWORD_LENGTH_MAX))
+                    .changed();
+                changed |= ui.checkbox(&mut security.password_require_lowercase, model.i18n.tr("password_require_lowercase")).changed();
+                changed |= ui.checkbox(&mut security.password_require_uppercase, model.i18n.tr("password_require_uppercase")).changed();
+                changed |= ui.checkbox(&mut security.password_require_digit, model.i18n.tr("password_require_digit")).changed();
+                changed |= ui.checkbox(&mut security.password_require_special, model.i18n.tr("password_require_special")).changed();
+                if changed {
+                    model.auth_state.password_policy = security.password_policy();
+                }
+            });
+
+            // 会话密码存储位置
+            let mut use_keyring = model.app_state.settings.security_settings.use_os_keyring;
+            if ui.checkbox(&mut use_keyring, model.i18n.tr("use_os_keyring")).changed() {
+                model.app_state.settings.security_settings.use_os_keyring = use_keyring;
+                model.auth_state.set_use_os_keyring(use_keyring);
+            }
+            if use_keyring && model.auth_state.session_secret_backend() == SessionSecretBackend::InMemory {
+                ui.small(model.i18n.tr("os_keyring_unavailable"));
+            }
+
+            ui.separator();
+
+            // 删除已保存的加密私钥（需确认）
+            let has_saved_key = model.auth_state.has_encrypted_private_key();
+            if ui.add_enabled(has_saved_key, egui::Button::new(model.i18n.tr("forget_saved_key_menu"))).clicked() {
+                action = Some(ViewAction::RequestDeleteSavedKey);
+                ui.close();
+            }
+            // 修改密码和重置钱包需要验证当前密码，临时会话中不可用
+            let authenticated = model.auth_state.is_authenticated;
+            if ui.add_enabled(authenticated, egui::Button::new(model.i18n.tr("change_password_menu"))).clicked() {
+                model.app_state.ui_state.show_dialog(DialogState::ChangePassword);
+                ui.close();
+            }
+            if ui.add_enabled(authenticated, egui::Button::new(model.i18n.tr("reset_wallet_menu"))).clicked() {
+                action = Some(ViewAction::RequestResetWallet);
+                ui.close();
+            }
+
+            // 配置目录（password.hash、private_key.enc 所在位置），便于排查问题
+            if ui
+                .button(model.i18n.tr("copy_config_path_menu"))
+                .on_hover_text(model.auth_state.config_dir().display().to_string())
+                .clicked()
+            {
+                action = Some(ViewAction::CopyConfigPath);
+                ui.close();
+            }
+            let can_open = file_manager_command(std::env::consts::OS).is_some();
+            if ui
+                .add_enabled(can_open, egui::Button::new(model.i18n.tr("open_config_folder_menu")))
+                .clicked()
+            {
+                action = Some(ViewAction::OpenConfigFolder);
+                ui.close();
+            }
+
+            if ui.button(model.i18n.tr("clear_cache")).clicked() {
                 // 清除缓存的逻辑
-                ui.close_menu();
+                ui.close();
             }
             
-            if ui.button(&model.i18n.tr("export_logs")).clicked() {
+            if ui.button(model.i18n.tr("export_logs")).clicked() {
                 // 导出日志的逻辑
-                ui.close_menu();
+                ui.close();
             }
             
             ui.separator();
             
-            if ui.button(&model.i18n.tr("about")).clicked() {
+            if ui.button(model.i18n.tr("about")).clicked() {
                 // 显示关于信息的逻辑
-                ui.close_menu();
+                ui.close();
+            }
+        });
+
+        action
+    }
+
+    /// 界面缩放设置：拖动时按新比例预览示例文字，松开后应用到整个界面
+    fn show_ui_scale_setting(model: &mut Model, ui: &mut egui::Ui) {
+        let applied = model.app_state.settings.effective_ui_scale();
+        let mut scale = model.app_state.ui_state.ui_scale_draft.unwrap_or(applied);
+
+        ui.horizontal(|ui| {
+            ui.label(model.i18n.tr("ui_scale_label"));
+            let response = ui.add(
+                egui::Slider::new(&mut scale, UI_SCALE_MIN..=UI_SCALE_MAX)
+                    .step_by(0.05)
+                    .custom_formatter(|value, _| format!("{:.0}%", value * 100.0)),
+            );
+            if response.dragged() {
+                model.app_state.ui_state.ui_scale_draft = Some(scale);
+            } else if response.changed() || response.drag_stopped() {
+                model.app_state.ui_state.ui_scale_draft = None;
+                model.app_state.settings.set_ui_scale(scale);
+            }
+            if ui
+                .add_enabled(applied != UI_SCALE_DEFAULT, egui::Button::new(model.i18n.tr("ui_scale_reset")))
+                .clicked()
+            {
+                model.app_state.ui_state.ui_scale_draft = None;
+                model.app_state.settings.set_ui_scale(UI_SCALE_DEFAULT);
             }
         });
+
+        // 预览：示例文字按新比例相对当前界面缩放
+        let body_size = egui::TextStyle::Body.resolve(ui.style()).size;
+        ui.label(egui::RichText::new(model.i18n.tr("ui_scale_preview")).size(body_size * scale / applied));
     }
 
     /// 显示状态指示器
-    fn show_status_indicators(model: &Model, ui: &mut egui::Ui) {
+    fn show_status_indicators(model: &Model, ui: &mut egui::Ui) -> Option<ViewAction> {
+        // 网络健康检查刷新按钮（右到左布局，显示在指示器右侧）
+        let action = Self::show_health_refresh_button(model, ui);
+
         // 连接状态指示器
         Self::show_connection_status(model, ui);
         
         ui.separator();
         
-        // 当前网络显示
+        // 当前网络显示，以及在最近两个网络之间快速切换的按钮
         Self::show_current_network(model, ui);
+        let action = Self::show_recent_network_toggle(model, ui).or(action);
         
         ui.separator();
         
         // 语言指示器
         Self::show_current_language(model, ui);
+
+        // 会话剩余时间
+        Self::show_session_remaining(model, ui);
+
+        action
+    }
+
+    /// 显示会话自动锁定倒计时，永不超时时隐藏
+    fn show_session_remaining(model: &Model, ui: &mut egui::Ui) {
+        let Some(remaining) = model.auth_state.session_remaining(Instant::now()) else {
+            return;
+        };
+
+        ui.separator();
+        let text = model
+            .i18n
+            .tr("session_locks_in")
+            .replace("{}", &format_session_remaining(remaining));
+        if remaining <= SESSION_EXPIRY_WARNING {
+            ui.colored_label(egui::Color32::RED, text);
+        } else {
+            ui.small(text);
+        }
+    }
+
+    /// 显示网络健康检查刷新按钮，检查期间显示加载动画
+    fn show_health_refresh_button(model: &Model, ui: &mut egui::Ui) -> Option<ViewAction> {
+        if model.network_health.checking {
+            ui.add(egui::Spinner::new().size(12.0));
+            return None;
+        }
+
+        let button = ui
+            .add_enabled(model.network_health.can_refresh(), egui::Button::new("⟳").small())
+            .on_hover_text(model.i18n.tr("check_connection"));
+        if button.clicked() {
+            Some(ViewAction::RefreshNetworkHealth)
+        } else {
+            None
+        }
     }
 
     /// 显示连接状态
     fn show_connection_status(model: &Model, ui: &mut egui::Ui) {
-        let (color, icon, tooltip) = if model.is_loading {
-            (egui::Color32::YELLOW, "🔄", model.i18n.tr("status_loading"))
+        let (color, icon, tooltip) = if !model.operations.is_idle() {
+            let tooltip = format!("{}\n{}", model.i18n.tr("status_loading"), running_operations_text(model));
+            (egui::Color32::YELLOW, "🔄", tooltip)
+        } else if model.network_health.status == NetworkHealthStatus::Unhealthy {
+            (egui::Color32::RED, "🔴", model.i18n.tr("status_unreachable"))
+        } else if model.network_health.status == NetworkHealthStatus::Degraded {
+            (egui::Color32::YELLOW, "🟡", model.i18n.tr("status_degraded"))
         } else {
             // 根据是否有钱包和网络连接状态决定
             match &model.wallet {
@@ -157,20 +764,30 @@ impl MenuView {
 
     /// 显示当前网络
     fn show_current_network(model: &Model, ui: &mut egui::Ui) {
-        let network_text = match model.network {
-            Network::Devnet => "DEV",
-            Network::Testnet => "TEST",
-            Network::Mainnet => "MAIN",
-        };
+        let network_text = model.network.short_name();
         
-        let color = match model.network {
-            Network::Devnet => egui::Color32::BLACK,
-            Network::Testnet => egui::Color32::BLACK,
-            Network::Mainnet => egui::Color32::BLACK,
-        };
+        let color = network_label_color(&model.network, model.app_state.settings.theme);
         
+        let tooltip = match &model.network {
+            Network::Custom { name, url } => format!("{} ({})", name, url),
+            other => model.i18n.tr(other.i18n_key()),
+        };
+        let tooltip = format!("{}\n{}: {}", tooltip, model.i18n.tr("active_endpoint"), model.active_endpoint());
         ui.colored_label(color, network_text)
-            .on_hover_text(&model.i18n.tr(&format!("{:?}", model.network).to_lowercase()));
+            .on_hover_text(tooltip);
+    }
+
+    /// 切换到最近使用的另一个网络（与菜单切换相同，会刷新余额）
+    fn show_recent_network_toggle(model: &Model, ui: &mut egui::Ui) -> Option<ViewAction> {
+        let settings = &model.app_state.settings;
+        let target = settings
+            .last_used
+            .toggle_network_target(&model.network, settings.custom_network().as_ref())?;
+        let hover = model.i18n.tr("toggle_recent_network_hint").replace("{}", target.name());
+        ui.small_button(format!("⇄ {}", target.short_name()))
+            .on_hover_text(hover)
+            .clicked()
+            .then_some(ViewAction::SwitchNetwork(target))
     }
 
     /// 显示当前语言
@@ -181,79 +798,6 @@ impl MenuView {
         };
         
         ui.small(lang_code)
-            .on_hover_text(&model.i18n.tr("current_language"));
-    }
-
-    /// 显示侧边菜单（可选功能）
-    pub fn show_side_menu(model: &Model, ui: &mut egui::Ui) {
-        ui.group(|ui| {
-            ui.vertical(|ui| {
-                ui.heading(&model.i18n.tr("quick_actions"));
-                ui.separator();
-                
-                if ui.button(&model.i18n.tr("refresh_balance_button")).clicked() {
-                    // 刷新余额
-                }
-                
-                if ui.button(&model.i18n.tr("copy_address_button")).clicked() {
-                    // 复制地址
-                }
-                
-                ui.separator();
-                
-                if ui.button(&model.i18n.tr("settings")).clicked() {
-                    // 打开设置
-                }
-            });
-        });
-    }
-
-    /// 显示上下文菜单（右键菜单）
-    pub fn show_context_menu(model: &Model, ui: &mut egui::Ui, response: &egui::Response) {
-        response.context_menu(|ui| {
-            if ui.button(&model.i18n.tr("copy")).clicked() {
-                ui.close_menu();
-            }
-            
-            if ui.button(&model.i18n.tr("paste")).clicked() {
-                ui.close_menu();
-            }
-            
-            ui.separator();
-            
-            if ui.button(&model.i18n.tr("select_all")).clicked() {
-                ui.close_menu();
-            }
-        });
-    }
-
-    /// 显示快捷键提示
-    pub fn show_shortcuts_tooltip(model: &Model, ui: &mut egui::Ui) {
-        ui.group(|ui| {
-            ui.vertical(|ui| {
-                ui.heading(&model.i18n.tr("keyboard_shortcuts"));
-                ui.separator();
-                
-                ui.horizontal(|ui| {
-                    ui.code("Ctrl+C");
-                    ui.label(&model.i18n.tr("copy"));
-                });
-                
-                ui.horizontal(|ui| {
-                    ui.code("Ctrl+V");
-                    ui.label(&model.i18n.tr("paste"));
-                });
-                
-                ui.horizontal(|ui| {
-                    ui.code("F5");
-                    ui.label(&model.i18n.tr("refresh"));
-                });
-                
-                ui.horizontal(|ui| {
-                    ui.code("Esc");
-                    ui.label(&model.i18n.tr("close"));
-                });
-            });
-        });
+            .on_hover_text(model.i18n.tr("current_language"));
     }
 }
\ No newline at end of file
@@ -0,0 +1,33 @@
+use eframe::egui;
+
+/// 模态对话框的最大宽度
+const MODAL_MAX_WIDTH: f32 = 420.0;
+
+/// 模态对话框框架 - 统一渲染 `DialogState` 对应的对话框
+/// 对话框显示在窗口中央，背后的界面被暗色遮罩覆盖且不可交互，
+/// 键盘焦点（Tab 切换）限制在对话框内；各功能只需提供对话框内容
+pub struct ModalView;
+
+impl ModalView {
+    /// 显示模态对话框，`id_salt` 用于区分不同的对话框
+    pub fn show<R>(
+        ctx: &egui::Context,
+        id_salt: &str,
+        title: &str,
+        add_contents: impl FnOnce(&mut egui::Ui) -> R,
+    ) -> R {
+        egui::Modal::new(egui::Id::new("modal_dialog").with(id_salt))
+            .show(ctx, |ui| {
+                ui.set_max_width(MODAL_MAX_WIDTH);
+                ui.heading(title);
+                ui.separator();
+                add_contents(ui)
+            })
+            .inner
+    }
+
+    /// 本帧是否按下了 Esc（用于关闭当前对话框）
+    pub fn escape_pressed(ctx: &egui::Context) -> bool {
+        ctx.input(|i| i.key_pressed(egui::Key::Escape))
+    }
+}
@@ -0,0 +1,364 @@
+use crate::model::{AmountUnit, Balance, BatchTransfer, DialogState, Model, WalletUtils, TRANSFER_NOTE_MAX_CHARS};
+use crate::view::{BalanceView, ModalView, ViewAction};
+use eframe::egui;
+use std::time::Instant;
+
+/// 转账视图 - 处理单笔转账和批量转账相关的UI组件
+pub struct TransferView;
+
+impl TransferView {
+    /// 显示转账面板：收款地址、金额和发送按钮
+    /// 发送条件不满足时禁用发送按钮，悬停显示原因
+    pub fn show_transfer_panel(model: &mut Model, ui: &mut egui::Ui) -> ViewAction {
+        let mut action = ViewAction::None;
+
+        ui.group(|ui| {
+            ui.vertical(|ui| {
+                ui.heading(model.i18n.tr("transfer_title"));
+                ui.separator();
+
+                egui::Grid::new("transfer_form")
+                    .num_columns(2)
+                    .spacing([12.0, 6.0])
+                    .show(ui, |ui| {
+                        ui.label(model.i18n.tr("recipient_label"));
+                        let response = ui.add(
+                            egui::TextEdit::singleline(&mut model.app_state.recipient_address)
+                                .desired_width(420.0)
+                                .font(egui::TextStyle::Monospace)
+                                .hint_text("0x..."),
+                        );
+                        // 换了收款地址后需要重新确认转账给自己；粘贴的支付 URI 由控制器拆分
+                        if response.changed() {
+                            model.app_state.self_send_confirmed = false;
+                            action = ViewAction::RecipientChanged;
+                        }
+                        ui.end_row();
+
+                        ui.label(model.i18n.tr("amount_label"));
+                        ui.horizontal(|ui| {
+                            let response = ui.add(
+                                egui::TextEdit::singleline(&mut model.app_state.transfer_amount)
+                                    .desired_width(160.0)
+                                    .hint_text("0.0"),
+                            );
+                            // 改了金额后需要重新核对大额转账，手动输入的金额也不再跟随余额更新
+                            if response.changed() {
+                                model.app_state.large_amount_confirmed = false;
+                                model.app_state.transfer_amount_is_max = false;
+                            }
+                            Self::show_amount_unit_toggle(model, ui);
+                            let max = ui
+                                .add_enabled(
+                                    model.max_transfer_mist().is_some(),
+                                    egui::Button::selectable(
+                                        model.app_state.transfer_amount_is_max,
+                                        model.i18n.tr("max_button"),
+                                    ),
+                                )
+                                .on_hover_text(model.i18n.tr("max_button_tooltip"))
+                                .on_disabled_hover_text(model.i18n.tr("send_blocked_balance_unknown"));
+                            if max.clicked() && model.fill_max_transfer_amount() {
+                                model.app_state.large_amount_confirmed = false;
+                            }
+                        });
+                        ui.end_row();
+
+                        ui.label(model.i18n.tr("transfer_note_label"));
+                        ui.add(
+                            egui::TextEdit::singleline(&mut model.app_state.transfer_note)
+                                .desired_width(420.0)
+                                .char_limit(TRANSFER_NOTE_MAX_CHARS)
+                                .hint_text(model.i18n.tr("transfer_note_hint")),
+                        );
+                        ui.end_row();
+                    });
+
+                // 以美元输入时显示按当前价格换算的 SUI 金额，供发送前核对；价格到达前显示查询中
+                if model.app_state.transfer_amount_unit == AmountUnit::Usd {
+                    match (model.app_state.transfer_amount_mist(), model.app_state.sui_price_usd) {
+                        (Ok(amount_mist), Some(price)) => {
+                            let amount = BalanceView::format_sui(Balance::from_mist(amount_mist), model);
+                            let price = model.i18n.format_number(price, 4);
+                            ui.small(
+                                model
+                                    .i18n
+                                    .tr("usd_converted_amount")
+                                    .replacen("{}", &amount, 1)
+                                    .replacen("{}", &price, 1),
+                            );
+                        }
+                        (_, None) => {
+                            ui.small(model.i18n.tr("usd_price_fetching"));
+                        }
+                        _ => {}
+                    }
+                }
+
+                // 高级选项：手动 Gas 预算
+                if model.app_state.ui_state.show_advanced_options {
+                    ui.add_space(6.0);
+                    Self::show_gas_budget_override(model.network.estimated_tx_fee_mist(), model, ui);
+                }
+
+                ui.add_space(8.0);
+                let blocker = model.send_blocker(Instant::now());
+                let send = ui.add_enabled(blocker.is_none(), egui::Button::new(model.i18n.tr("send_button")));
+                let send = match blocker {
+                    Some(blocker) => send.on_disabled_hover_text(model.i18n.tr(blocker.i18n_key())),
+                    None => send,
+                };
+                if send.clicked() {
+                    action = ViewAction::SendTransfer;
+                }
+            });
+        });
+
+        action
+    }
+
+    /// 金额单位切换（SUI / USD），只有选择美元时才会联网查询价格
+    fn show_amount_unit_toggle(model: &mut Model, ui: &mut egui::Ui) {
+        let unit = model.app_state.transfer_amount_unit;
+        if ui.add(egui::Button::selectable(unit == AmountUnit::Sui, "SUI")).clicked() {
+            model.app_state.set_transfer_amount_unit(AmountUnit::Sui);
+            model.app_state.large_amount_confirmed = false;
+        }
+        let usd = ui
+            .add(egui::Button::selectable(unit == AmountUnit::Usd, "USD"))
+            .on_hover_text(model.i18n.tr("usd_price_source"));
+        if usd.clicked() {
+            model.app_state.set_transfer_amount_unit(AmountUnit::Usd);
+            model.app_state.large_amount_confirmed = false;
+        }
+    }
+
+    /// 收款地址是自己的地址时确认是否继续发送
+    pub fn show_self_send_dialog(model: &mut Model, ctx: &egui::Context) -> ViewAction {
+        let mut action = ViewAction::None;
+
+        ModalView::show(ctx, "self_send_title", &model.i18n.tr("self_send_title"), |ui| {
+            ui.colored_label(egui::Color32::YELLOW, model.i18n.tr("self_send_warning"));
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                if ui.button(model.i18n.tr("send_anyway_button")).clicked() {
+                    action = ViewAction::ConfirmSelfSend;
+                }
+                if ui.button(model.i18n.tr("cancel_button")).clicked() {
+                    model.app_state.ui_state.close_dialog();
+                }
+            });
+        });
+
+        action
+    }
+
+    /// 金额可疑（占余额比例过高或远超近期转账）时请用户核对后再发送
+    pub fn show_large_amount_dialog(model: &mut Model, ctx: &egui::Context) -> ViewAction {
+        let mut action = ViewAction::None;
+        let DialogState::ConfirmLargeAmount(warning) = model.app_state.ui_state.dialog_state.clone() else {
+            return action;
+        };
+
+        ModalView::show(ctx, "large_amount_title", &model.i18n.tr("large_amount_title"), |ui| {
+            ui.colored_label(egui::Color32::YELLOW, &warning);
+            ui.add_space(4.0);
+            if let Ok(amount_mist) = model.app_state.transfer_amount_mist() {
+                ui.strong(format!("{} SUI", BalanceView::format_sui(Balance::from_mist(amount_mist), model)));
+            }
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                if ui.button(model.i18n.tr("send_anyway_button")).clicked() {
+                    action = ViewAction::ConfirmLargeAmount;
+                }
+                if ui.button(model.i18n.tr("cancel_button")).clicked() {
+                    model.app_state.ui_state.close_dialog();
+                }
+            });
+        });
+
+        action
+    }
+
+    /// 显示批量转账（空投）对话框
+    pub fn show_batch_transfer_dialog(model: &mut Model, ctx: &egui::Context) {
+        ModalView::show(ctx, "batch_transfer_title", &model.i18n.tr("batch_transfer_title"), |ui| {
+            ui.label(model.i18n.tr("batch_transfer_hint"));
+            ui.add(
+                egui::TextEdit::multiline(&mut model.app_state.ui_state.batch_input)
+                    .desired_rows(6)
+                    .font(egui::TextStyle::Monospace)
+                    .hint_text("0x...,1.5"),
+            );
+
+            let batch = BatchTransfer::parse_csv(&model.app_state.ui_state.batch_input);
+            ui.add_space(6.0);
+            Self::show_batch_rows(&batch, model, ui);
+
+            ui.add_space(6.0);
+            Self::show_batch_summary(&batch, model, ui);
+
+            if model.app_state.ui_state.show_advanced_options {
+                ui.add_space(6.0);
+                Self::show_gas_budget_override(batch.estimated_gas_mist(), model, ui);
+            }
+
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                // 签名前先查看完整的转账摘要
+                let can_review = batch.is_valid() && model.batch_gas_budget(&batch).is_ok();
+                if ui
+                    .add_enabled(can_review, egui::Button::new(model.i18n.tr("batch_review_button")))
+                    .clicked()
+                {
+                    model.app_state.ui_state.show_dialog(DialogState::ConfirmBatchTransfer);
+                }
+                if ui.button(model.i18n.tr("close")).clicked() {
+                    model.app_state.ui_state.close_dialog();
+                }
+            });
+        });
+    }
+
+    /// 显示批量转账的确认摘要（每个收款人和金额、总金额、Gas），列表较长时可滚动
+    pub fn show_batch_confirm_dialog(model: &mut Model, ctx: &egui::Context) -> ViewAction {
+        let mut action = ViewAction::None;
+        let batch = BatchTransfer::parse_csv(&model.app_state.ui_state.batch_input);
+        let summary = model
+            .batch_gas_budget(&batch)
+            .and_then(|gas_budget| batch.confirmation_summary(gas_budget, &model.i18n));
+
+        ModalView::show(ctx, "batch_confirm_title", &model.i18n.tr("batch_confirm_title"), |ui| {
+            ui.label(model.i18n.tr("batch_confirm_hint"));
+            ui.add_space(6.0);
+            match &summary {
+                Ok(summary) => {
+                    egui::ScrollArea::vertical()
+                        .id_salt("batch_confirm_summary")
+                        .max_height(300.0)
+                        .show(ui, |ui| {
+                            ui.add(egui::Label::new(egui::RichText::new(summary).monospace()).wrap());
+                        });
+                }
+                Err(error) => {
+                    ui.colored_label(egui::Color32::RED, error);
+                }
+            }
+
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                // 所有收款人在同一笔交易中支付，条件不满足时悬停显示原因
+                let blocker = model.batch_send_blocker(Instant::now());
+                let send = ui.add_enabled(blocker.is_none(), egui::Button::new(model.i18n.tr("send_button")));
+                let send = match blocker {
+                    Some(blocker) => send.on_disabled_hover_text(model.i18n.tr(blocker.i18n_key())),
+                    None => send,
+                };
+                if send.clicked() {
+                    action = ViewAction::SendBatchTransfer;
+                }
+                if ui.button(model.i18n.tr("back_button")).clicked() {
+                    model.app_state.ui_state.show_dialog(DialogState::BatchTransfer);
+                }
+                if ui.button(model.i18n.tr("cancel_button")).clicked() {
+                    model.app_state.ui_state.close_dialog();
+                }
+            });
+        });
+
+        action
+    }
+
+    /// 显示每一行的校验结果和执行状态
+    fn show_batch_rows(batch: &BatchTransfer, model: &Model, ui: &mut egui::Ui) {
+        egui::ScrollArea::vertical()
+            .id_salt("batch_rows")
+            .max_height(160.0)
+            .show(ui, |ui| {
+                egui::Grid::new("batch_rows_grid")
+                    .num_columns(3)
+                    .spacing([12.0, 4.0])
+                    .striped(true)
+                    .show(ui, |ui| {
+                        for row in &batch.rows {
+                            ui.label(format!("#{}", row.line));
+                            ui.monospace(format!(
+                                "{} → {} SUI",
+                                WalletUtils::truncate_address(&row.recipient, 6, 4),
+                                BalanceView::format_sui(Balance::from_mist(row.amount_mist), model)
+                            ));
+                            ui.colored_label(egui::Color32::GREEN, "✔");
+                            ui.end_row();
+                        }
+
+                        for issue in &batch.issues {
+                            ui.label(format!("#{}", issue.line));
+                            ui.monospace(&issue.content);
+                            ui.colored_label(egui::Color32::RED, model.i18n.tr(issue.error.i18n_key()));
+                            ui.end_row();
+                        }
+                    });
+            });
+    }
+
+    /// 显示手动 Gas 预算输入（高级选项）
+    fn show_gas_budget_override(estimate_mist: u64, model: &mut Model, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.label(model.i18n.tr("gas_budget_label"));
+            ui.add(
+                egui::TextEdit::singleline(&mut model.app_state.gas_budget_input)
+                    .desired_width(120.0)
+                    .hint_text(estimate_mist.to_string()),
+            );
+            ui.label("MIST");
+        });
+
+        match model.app_state.transfer_gas_budget(estimate_mist) {
+            Err(error) => {
+                ui.colored_label(egui::Color32::RED, error);
+            }
+            Ok(_) => {
+                if let Some(warning) = model.app_state.gas_budget_warning(estimate_mist) {
+                    ui.colored_label(egui::Color32::YELLOW, warning);
+                }
+            }
+        }
+    }
+
+    /// 显示总金额和 Gas 预算（与确认对话框使用同一个数值）
+    fn show_batch_summary(batch: &BatchTransfer, model: &Model, ui: &mut egui::Ui) {
+        let to_sui = |mist: u64| BalanceView::format_sui(Balance::from_mist(mist), model);
+        let gas_budget = model.batch_gas_budget(batch);
+
+        egui::Grid::new("batch_summary")
+            .num_columns(2)
+            .spacing([20.0, 4.0])
+            .show(ui, |ui| {
+                ui.label(model.i18n.tr("batch_recipients"));
+                ui.label(batch.rows.len().to_string());
+                ui.end_row();
+
+                ui.label(model.i18n.tr("batch_total_amount"));
+                match batch.total_amount_mist() {
+                    Some(total) => ui.label(format!("{} SUI", to_sui(total))),
+                    None => ui.colored_label(egui::Color32::RED, model.i18n.tr("batch_total_overflow")),
+                };
+                ui.end_row();
+
+                ui.label(model.i18n.tr("network_fee"));
+                match &gas_budget {
+                    Ok(gas_budget) => ui.label(format!("~{} SUI", to_sui(*gas_budget))),
+                    Err(error) => ui.colored_label(egui::Color32::RED, error),
+                };
+                ui.end_row();
+
+                ui.label(model.i18n.tr("batch_estimated_total"));
+                match gas_budget.map(|gas_budget| batch.estimated_total_mist(gas_budget)) {
+                    Ok(Some(total)) => ui.strong(format!("{} SUI", to_sui(total))),
+                    Ok(None) => ui.colored_label(egui::Color32::RED, model.i18n.tr("batch_total_overflow")),
+                    Err(_) => ui.label("-"),
+                };
+                ui.end_row();
+            });
+    }
+}
@@ -1,5 +1,5 @@
-use crate::model::{Model, WalletState};
-use crate::view::ViewAction;
+use crate::model::{AddressFormat, AsyncOperation, MnemonicUtils, Model, PrivateKeyFormat, PublicKeyFormat, WalletState, WalletUtils, KEY_FORMAT_DOCS_URL};
+use crate::view::{network_display_name, BalanceView, SubmitAction, SubmitField, ViewAction, ACCOUNT_SHORTCUT_KEYS};
 use eframe::egui;
 use sui_sdk::types::base_types::SuiAddress;
 
@@ -29,34 +29,69 @@ impl WalletView {
         
         ui.group(|ui| {
             ui.vertical(|ui| {
-                ui.heading(&model.i18n.tr("import_wallet_title"));
+                ui.heading(model.i18n.tr("import_wallet_title"));
                 ui.add_space(8.0);
                 
-                ui.label(&model.i18n.tr("import_wallet_message"));
+                ui.label(model.i18n.tr("import_wallet_message"));
                 ui.add_space(4.0);
                 
                 // 私钥输入框
-                if let WalletState::NoWallet { private_key_input } = &mut model.wallet {
-                    ui.add(
-                        egui::TextEdit::multiline(private_key_input)
+                if let WalletState::NoWallet { private_key_input } = &model.wallet {
+                    let mut private_key_input = private_key_input.lock();
+                    let response = ui.add(
+                        egui::TextEdit::multiline(&mut *private_key_input)
                             .password(true)
-                            .hint_text(&model.i18n.tr("private_key_hint"))
+                            .hint_text(model.i18n.tr("private_key_hint"))
                             .desired_rows(3)
                     );
+                    // Ctrl/Cmd+Enter 提交导入
+                    let form_complete = !private_key_input.trim().is_empty();
+                    if let Some(command) = SubmitField::ImportKey.enter_pressed(&response, ui)
+                        && SubmitField::ImportKey.on_enter(command, form_complete) == SubmitAction::ImportKey
+                    {
+                        action = ViewAction::ImportKey;
+                    }
+                    // 输入变化后旧的助记词预览不再对应当前输入
+                    if response.changed() {
+                        model.mnemonic_preview = None;
+                    }
                 }
                 
                 ui.add_space(8.0);
                 
                 // 导入按钮和格式说明
                 ui.horizontal(|ui| {
-                    if ui.button(&model.i18n.tr("import_wallet_button")).clicked() {
+                    if ui.button(model.i18n.tr("import_wallet_button")).clicked() {
                         action = ViewAction::ImportKey;
                     }
+                    if ui
+                        .button(model.i18n.tr("import_temporarily_button"))
+                        .on_hover_text(model.i18n.tr("import_temporarily_hint"))
+                        .clicked()
+                    {
+                        action = ViewAction::ImportKeyTemporarily;
+                    }
                     
                     ui.separator();
                     
-                    ui.label(&model.i18n.tr("supported_formats"));
+                    ui.label(model.i18n.tr("supported_formats"));
                 });
+
+                ui.add_space(8.0);
+                Self::show_security_warning(model, ui);
+
+                // 输入的是助记词时预览其派生的账户
+                let is_mnemonic = model
+                    .wallet
+                    .private_key_input()
+                    .and_then(|input| MnemonicUtils::looks_like_mnemonic(&input))
+                    .is_some();
+                if is_mnemonic {
+                    ui.add_space(8.0);
+                    if let Some(preview_action) = Self::show_mnemonic_preview(model, ui) {
+                        action = preview_action;
+                    }
+                }
                 
                 ui.add_space(4.0);
                 Self::show_format_help(model, ui);
@@ -66,9 +101,73 @@ impl WalletView {
         action
     }
 
+    /// 显示助记词派生的前几个账户及其余额，用户选择要导入的账户
+    fn show_mnemonic_preview(model: &mut Model, ui: &mut egui::Ui) -> Option<ViewAction> {
+        let mut action = None;
+
+        ui.horizontal(|ui| {
+            ui.label(model.i18n.tr("mnemonic_detected"));
+            let fetching = model.operations.is_running(AsyncOperation::MnemonicPreview);
+            if ui
+                .add_enabled(!fetching, egui::Button::new(model.i18n.tr("mnemonic_preview_button")))
+                .clicked()
+            {
+                action = Some(ViewAction::PreviewMnemonicAccounts);
+            }
+        });
+
+        // 复制一份用于显示，避免与格式化余额时对 model 的借用冲突
+        let Some(preview) = model.mnemonic_preview.clone() else {
+            return action;
+        };
+        let mut selected = preview.selected;
+
+        ui.small(
+            model
+                .i18n
+                .tr("mnemonic_preview_hint")
+                .replace("{}", &network_display_name(&model.i18n, &preview.network)),
+        );
+        egui::Grid::new("mnemonic_preview")
+            .num_columns(3)
+            .spacing([12.0, 4.0])
+            .show(ui, |ui| {
+                for (index, (address, balance)) in preview.accounts.iter().enumerate() {
+                    let index = index as u32;
+                    ui.radio_value(&mut selected, index, format!("#{}", index));
+                    ui.monospace(WalletUtils::truncate_address(address, 10, 6));
+                    match balance {
+                        None => {
+                            ui.add(egui::Spinner::new().size(14.0));
+                        }
+                        Some(Ok(balance)) => {
+                            ui.colored_label(
+                                BalanceView::get_balance_color(*balance),
+                                format!("{} SUI", BalanceView::format_sui(*balance, model)),
+                            );
+                        }
+                        Some(Err(e)) => {
+                            ui.colored_label(egui::Color32::RED, model.i18n.tr("network_unavailable"))
+                                .on_hover_text(e);
+                        }
+                    }
+                    ui.end_row();
+                }
+            });
+
+        if let Some(preview) = model.mnemonic_preview.as_mut() {
+            preview.selected = selected;
+        }
+        if ui.button(model.i18n.tr("mnemonic_import_selected")).clicked() {
+            action = Some(ViewAction::ImportMnemonicAccount(selected));
+        }
+
+        action
+    }
+
     /// 显示已加载钱包的信息
     fn show_loaded_wallet_info(
-        model: &Model, 
+        model: &mut Model, 
         address: &SuiAddress, 
         ui: &mut egui::Ui
     ) -> ViewAction {
@@ -76,7 +175,13 @@ impl WalletView {
         
         ui.group(|ui| {
             ui.vertical(|ui| {
-                ui.heading(&model.i18n.tr("wallet_loaded"));
+                ui.heading(model.i18n.tr("wallet_loaded"));
+                // 临时会话不保存任何内容，不提供账户标签
+                if model.auth_state.is_authenticated
+                    && let Some(rename_action) = Self::show_account_label(model, ui)
+                {
+                    action = rename_action;
+                }
                 ui.add_space(8.0);
                 
                 // 钱包信息网格
@@ -86,24 +191,57 @@ impl WalletView {
                     .show(ui, |ui| {
                         Self::show_wallet_info_grid(model, address, ui);
                     });
+
+                ui.add_space(4.0);
+                ui.small(model.i18n.tr("same_address_all_networks"));
                 
                 ui.add_space(12.0);
                 
                 // 操作按钮
                 ui.horizontal(|ui| {
-                    if ui.button(&model.i18n.tr("refresh_balance_button")).clicked() {
+                    if ui.button(model.i18n.tr("refresh_balance_button")).clicked() {
                         action = ViewAction::RefreshBalance;
                     }
                     
                     ui.separator();
                     
-                    if ui.button(&model.i18n.tr("copy_address_button")).clicked() {
+                    if ui.button(model.i18n.tr("copy_address_button")).clicked() {
                         ui.ctx().copy_text(address.to_string());
                     }
+
+                    if model.auth_state.is_authenticated
+                        && ui.button(model.i18n.tr("copy_private_key_button")).clicked()
+                    {
+                        action = ViewAction::CopyPrivateKey;
+                    }
+
+                    if ui
+                        .button(model.i18n.tr("verify_ownership_button"))
+                        .on_hover_text(model.i18n.tr("verify_ownership_hint"))
+                        .clicked()
+                    {
+                        action = ViewAction::VerifyOwnership;
+                    }
+                    // 只显示当前地址的自检结果
+                    match model.ownership_check.filter(|check| check.address == *address) {
+                        Some(check) if check.verified => {
+                            ui.colored_label(egui::Color32::GREEN, format!("✔ {}", model.i18n.tr("ownership_verified")));
+                        }
+                        Some(_) => {
+                            ui.colored_label(egui::Color32::RED, format!("✖ {}", model.i18n.tr("ownership_mismatch")));
+                        }
+                        None => {}
+                    }
+                    
+                    ui.separator();
+                    
+                    if ui.button(model.i18n.tr("import_another_key_button")).clicked() {
+                        action = ViewAction::ImportKey;
+                    }
                     
                     ui.separator();
                     
-                    if ui.button(&model.i18n.tr("logout_button")).clicked() {
+                    if ui.button(model.i18n.tr("logout_button")).clicked() {
                         action = ViewAction::Logout;
                     }
                 });
@@ -113,10 +251,68 @@ impl WalletView {
         action
     }
 
+    /// 显示账户标签及重命名控件
+    fn show_account_label(model: &mut Model, ui: &mut egui::Ui) -> Option<ViewAction> {
+        let mut action = None;
+
+        ui.horizontal(|ui| {
+            if let Some(buffer) = &mut model.app_state.ui_state.label_edit_buffer {
+                ui.add(
+                    egui::TextEdit::singleline(buffer)
+                        .hint_text(model.i18n.tr("account_label_hint"))
+                        .desired_width(160.0)
+                );
+                if ui.button(model.i18n.tr("save_button")).clicked() {
+                    action = Some(ViewAction::RenameAccount(buffer.clone()));
+                    model.app_state.ui_state.label_edit_buffer = None;
+                } else if ui.button(model.i18n.tr("cancel_button")).clicked() {
+                    model.app_state.ui_state.label_edit_buffer = None;
+                }
+            } else {
+                if let Some(select_action) = Self::show_account_switcher(model, ui) {
+                    action = Some(select_action);
+                }
+                if ui.small_button("✏").on_hover_text(model.i18n.tr("rename_account")).clicked() {
+                    let current = model.wallet.label().unwrap_or_default().to_string();
+                    model.app_state.ui_state.label_edit_buffer = Some(current);
+                }
+            }
+        });
+
+        action
+    }
+
+    /// 显示账户切换器：列出各账户的标签（未设置时为截断的地址）及切换快捷键
+    fn show_account_switcher(model: &Model, ui: &mut egui::Ui) -> Option<ViewAction> {
+        let current = model.wallet.display_label().unwrap_or_default();
+        let mut action = None;
+
+        egui::ComboBox::from_id_salt("account_switcher")
+            .selected_text(egui::RichText::new(&current).strong())
+            .show_ui(ui, |ui| {
+                for (index, label) in model.wallet.account_labels().into_iter().enumerate() {
+                    let shortcut = ACCOUNT_SHORTCUT_KEYS
+                        .get(index)
+                        .map(|key| ui.ctx().format_shortcut(&egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, *key)));
+                    let text = match shortcut {
+                        Some(shortcut) => format!("{}    {}", label, shortcut),
+                        None => label.clone(),
+                    };
+                    if ui.selectable_label(label == current, text).clicked() {
+                        action = Some(ViewAction::SelectAccount(index));
+                    }
+                }
+            })
+            .response
+            .on_hover_text(model.i18n.tr("switch_account"));
+
+        action
+    }
+
     /// 显示钱包信息网格
     fn show_wallet_info_grid(model: &Model, address: &SuiAddress, ui: &mut egui::Ui) {
         // 地址行
-        ui.label(&model.i18n.tr("address_label"));
+        ui.label(model.i18n.tr("address_label"));
         ui.horizontal(|ui| {
             // 截断显示长地址
             let address_str = address.to_string();
@@ -127,91 +323,132 @@ impl WalletView {
             };
             
             if ui.add(egui::Label::new(display_address).sense(egui::Sense::click())).clicked() {
-                ui.ctx().copy_text(address_str);
+                ui.ctx().copy_text(address_str.clone());
             }
             
-            // 复制图标或按钮
-            if ui.small_button("📋").clicked() {
-                ui.ctx().copy_text(address.to_string());
-            }
+            // 复制格式下拉菜单
+            Self::show_copy_address_menu(model, &address_str, ui);
         });
         ui.end_row();
 
         // 网络行
-        ui.label(&model.i18n.tr("network_label"));
-        ui.label(&model.i18n.tr(&format!("{:?}", model.network).to_lowercase()));
+        ui.label(model.i18n.tr("network_label"));
+        ui.label(network_display_name(&model.i18n, &model.network));
         ui.end_row();
+    }
 
-        // 余额行 - 这里可以显示余额信息
-        ui.label(&model.i18n.tr("balance_label"));
-        ui.horizontal(|ui| {
-            if model.is_loading {
-                ui.add(egui::Spinner::new().size(16.0));
-                ui.label(&model.i18n.tr("loading"));
-            } else {
-                // 余额信息显示在 result_text 中
-                let balance_text = if model.result_text.contains("SUI") {
-                    model.result_text.clone()
-                } else {
-                    model.i18n.tr("balance_unknown")
-                };
-                ui.label(balance_text);
+    /// 显示地址复制格式菜单（带前缀、无前缀、二维码图片）
+    fn show_copy_address_menu(model: &Model, address: &str, ui: &mut egui::Ui) {
+        if !WalletUtils::is_valid_address_format(address) {
+            return;
+        }
+
+        ui.menu_button("📋", |ui| {
+            if ui.button(model.i18n.tr("copy_address_prefixed")).clicked() {
+                ui.ctx().copy_text(WalletUtils::format_address(address, AddressFormat::Prefixed));
+                ui.close();
             }
-        });
-        ui.end_row();
-    }
 
-    /// 显示支持的私钥格式帮助信息
-    fn show_format_help(model: &Model, ui: &mut egui::Ui) {
-        ui.collapsing(&model.i18n.tr("format_help_title"), |ui| {
-            ui.label(&model.i18n.tr("format_help_bech32"));
-            ui.code("suiprivkey1...");
-            ui.add_space(4.0);
-            
-            ui.label(&model.i18n.tr("format_help_base64"));
-            ui.code("Base64 encoded key (44 characters)");
-            ui.add_space(4.0);
-            
-            ui.label(&model.i18n.tr("format_help_hex"));
-            ui.code("Hex string (64 characters)");
+            if ui.button(model.i18n.tr("copy_address_bare")).clicked() {
+                ui.ctx().copy_text(WalletUtils::format_address(address, AddressFormat::Bare));
+                ui.close();
+            }
+
+            if ui.button(model.i18n.tr("copy_address_qr")).clicked() {
+                if let Some(image) = Self::render_qr_image(address) {
+                    ui.ctx().copy_image(image);
+                }
+                ui.close();
+            }
+
+            ui.separator();
+
+            // 公钥（需要已加载的密钥对）
+            let has_keypair = model.wallet.has_keypair();
+            for (format, label) in [
+                (PublicKeyFormat::Base64, "copy_public_key_base64"),
+                (PublicKeyFormat::Hex, "copy_public_key_hex"),
+            ] {
+                let clicked = ui
+                    .add_enabled(has_keypair, egui::Button::new(model.i18n.tr(label)))
+                    .on_disabled_hover_text(model.i18n.tr("public_key_unavailable"))
+                    .clicked();
+                if clicked {
+                    if let Some(public_key) = model.wallet.with_keypair(|keypair| WalletUtils::encode_public_key(keypair, format)) {
+                        ui.ctx().copy_text(public_key);
+                    }
+                    ui.close();
+                }
+            }
         });
     }
 
-    /// 显示私钥验证状态
-    pub fn show_key_validation_status(private_key: &str, model: &Model, ui: &mut egui::Ui) {
-        if !private_key.is_empty() {
-            let is_valid = Self::validate_private_key_format(private_key);
-            let (color, text) = if is_valid {
-                (egui::Color32::GREEN, model.i18n.tr("valid_format"))
-            } else {
-                (egui::Color32::RED, model.i18n.tr("invalid_format"))
-            };
-            
-            ui.horizontal(|ui| {
-                ui.label(&model.i18n.tr("format_status"));
-                ui.colored_label(color, text);
-            });
+    /// 将文本渲染为二维码图片
+    pub fn render_qr_image(data: &str) -> Option<egui::ColorImage> {
+        const MODULE_PIXELS: usize = 8;
+        const QUIET_ZONE: usize = 4;
+
+        let code = qrcode::QrCode::new(data.as_bytes()).ok()?;
+        let modules = code.width();
+        let colors = code.to_colors();
+        let side = (modules + QUIET_ZONE * 2) * MODULE_PIXELS;
+
+        let mut gray = vec![255u8; side * side];
+        for (index, color) in colors.iter().enumerate() {
+            if *color != qrcode::Color::Dark {
+                continue;
+            }
+            let x0 = (index % modules + QUIET_ZONE) * MODULE_PIXELS;
+            let y0 = (index / modules + QUIET_ZONE) * MODULE_PIXELS;
+            for y in y0..y0 + MODULE_PIXELS {
+                gray[y * side + x0..y * side + x0 + MODULE_PIXELS].fill(0);
+            }
         }
+
+        Some(egui::ColorImage::from_gray([side, side], &gray))
     }
 
-    /// 验证私钥格式
-    fn validate_private_key_format(private_key: &str) -> bool {
-        let trimmed = private_key.trim();
-        !trimmed.is_empty() && (
-            trimmed.starts_with("suiprivkey1") || // Bech32 format
-            (trimmed.len() == 44 && trimmed.chars().all(|c| c.is_alphanumeric() || c == '+' || c == '/' || c == '=')) || // Base64
-            (trimmed.len() == 64 && trimmed.chars().all(|c| c.is_ascii_hexdigit())) // Hex
-        )
+    /// 显示支持的私钥格式帮助信息
+    /// 折叠状态保存在设置中，下次打开时保持不变
+    fn show_format_help(model: &mut Model, ui: &mut egui::Ui) {
+        let expanded = model.app_state.settings.format_help_expanded;
+        let response = egui::CollapsingHeader::new(model.i18n.tr("format_help_title"))
+            .default_open(expanded)
+            .show(ui, |ui| {
+                ui.label(model.i18n.tr("format_help_what_is_key"));
+                ui.add_space(6.0);
+
+                for format in PrivateKeyFormat::all() {
+                    ui.strong(format.name());
+                    ui.label(model.i18n.tr(format.help_key()));
+                    // 示例均为截断的占位内容，不是真实私钥
+                    ui.code(format.example());
+                    ui.add_space(4.0);
+                }
+
+                ui.colored_label(egui::Color32::from_rgb(255, 165, 0), model.i18n.tr("format_help_safety_warning"));
+                ui.hyperlink_to(model.i18n.tr("format_help_docs_link"), KEY_FORMAT_DOCS_URL);
+            });
+        if response.header_response.clicked() {
+            model.app_state.settings.format_help_expanded = !expanded;
+        }
     }
 
     /// 显示安全提示
-    pub fn show_security_warning(model: &Model, ui: &mut egui::Ui) {
+    /// 用户确认"不再显示"后隐藏，可在工具菜单中重新开启
+    pub fn show_security_warning(model: &mut Model, ui: &mut egui::Ui) {
+        if !model.app_state.settings.should_show_security_warning() {
+            return;
+        }
         ui.group(|ui| {
             ui.horizontal(|ui| {
                 ui.label("⚠️");
                 ui.vertical(|ui| {
-                    ui.label(&model.i18n.tr("security_warning_title"));
-                    ui.small(&model.i18n.tr("security_warning_message"));
+                    ui.label(model.i18n.tr("security_warning_title"));
+                    ui.small(model.i18n.tr("security_warning_message"));
+                    if ui.small_button(model.i18n.tr("security_warning_dismiss")).clicked() {
+                        model.app_state.settings.acknowledge_security_warning();
+                    }
                 });
             });
         });